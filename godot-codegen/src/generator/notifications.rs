@@ -55,6 +55,20 @@ pub fn make_notify_methods(class_name: &TyName, ctx: &mut Context) -> TokenStrea
         pub fn notify_reversed(&mut self, what: #enum_name) {
             self.notification(i32::from(what), true);
         }
+
+        /// Like [`Self::notify()`], but sends the notification at idle time, once the current frame's processing is done.
+        ///
+        /// The notification is queued as a deferred call (see `Object::call_deferred()`). This is useful when the receiver cannot be
+        /// notified right away, for example because the current code holds a `GdMut` guard on it. If the object is freed before
+        /// the end of the frame, the notification is not sent.
+        ///
+        /// Custom notifications, which are not known to Godot, can be sent using the `Unknown` variant, or converted from `i32`.
+        pub fn notify_deferred(&mut self, what: #enum_name) {
+            use crate::meta::ToGodot as _;
+
+            let args = [i32::from(what).to_variant(), false.to_variant()];
+            self.call_deferred(StringName::from("notification"), &args);
+        }
     }
 }
 
@@ -65,7 +65,8 @@ fn make_special_virtual_methods(notification_enum_name: &Ident) -> TokenStream {
         /// String representation of the Godot instance.
         ///
         /// Override this method to define how the instance is represented as a string.
-        /// Used by `impl Display for Gd<T>`, as well as `str()` and `print()` in GDScript.
+        /// Used by `impl Display for Gd<T>` and [`Variant::stringify()`][crate::builtin::Variant::stringify], as well as `str()`,
+        /// `print()` and string formatting (`"%s" % obj`) in GDScript.
         fn to_string(&self) -> crate::builtin::GString {
             unimplemented!()
         }
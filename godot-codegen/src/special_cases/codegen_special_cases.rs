@@ -162,6 +162,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "ResourceLoader",
     "ResourceSaver",
     "RigidBody2D",
+    "SceneState",
     "SceneTree",
     "SceneTreeTimer",
     "Script",
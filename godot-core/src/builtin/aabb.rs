@@ -145,6 +145,19 @@ impl Aabb {
             && point.z < self.size.z
     }
 
+    /// Returns the point within the AABB that is closest to `point`.
+    ///
+    /// Points inside the AABB (including its faces) are returned unchanged; points outside are clamped onto the nearest face.
+    ///
+    /// # Panics
+    /// If `self.size` is negative.
+    #[inline]
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        self.assert_nonnegative();
+
+        point.clamp(self.position, self.end())
+    }
+
     /// Returns `true` if the AABB has area, and `false` if the AABB is linear, empty, or has a negative size. See also `Aabb.area()`.
     #[inline]
     pub fn has_area(self) -> bool {
@@ -456,6 +469,30 @@ mod test {
         assert_eq!(aabb.longest_axis_index(), Some(Vector3Axis::Z));
     }
 
+    #[test]
+    fn test_closest_point() {
+        let aabb = Aabb::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 3.0, 2.0));
+
+        // Inside.
+        let inside = Vector3::new(2.0, 3.0, 4.0);
+        assert_eq!(aabb.closest_point(inside), inside);
+
+        // On a face and a corner.
+        let face = Vector3::new(5.0, 3.0, 4.0);
+        assert_eq!(aabb.closest_point(face), face);
+        assert_eq!(aabb.closest_point(aabb.end()), aabb.end());
+
+        // Outside.
+        assert_eq!(
+            aabb.closest_point(Vector3::new(0.0, 3.0, 4.0)),
+            Vector3::new(1.0, 3.0, 4.0)
+        );
+        assert_eq!(
+            aabb.closest_point(Vector3::new(10.0, -10.0, 10.0)),
+            Vector3::new(5.0, 2.0, 5.0)
+        );
+    }
+
     #[test]
     fn test_intersects() {
         let aabb1 = Aabb {
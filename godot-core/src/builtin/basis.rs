@@ -413,11 +413,14 @@ impl Basis {
     /// Assuming that the matrix is a proper rotation matrix, slerp performs
     /// a spherical-linear interpolation with another rotation matrix.
     ///
+    /// The length of each row is interpolated linearly. If one of the bases is degenerate (has a zero determinant, e.g. due to zero
+    /// scale), it has no rotation; the rotation of the other basis is used for both sides. If both are degenerate, the identity
+    /// rotation is used.
+    ///
     /// _Godot equivalent: `Basis.slerp()`_
     #[must_use]
     pub fn slerp(&self, other: &Self, weight: real) -> Self {
-        let from = self.to_quat();
-        let to = other.to_quat();
+        let (from, to) = Self::interpolation_rotations(self, other);
 
         let mut result = Self::from_quat(from.slerp(to, weight));
 
@@ -428,6 +431,24 @@ impl Basis {
         result
     }
 
+    /// Rotations of `from` and `to` used as interpolation endpoints, with degenerate bases taking over the other rotation.
+    pub(crate) fn interpolation_rotations(from: &Self, to: &Self) -> (Quaternion, Quaternion) {
+        let rotation = |basis: &Self| {
+            if basis.determinant().is_zero_approx() {
+                None
+            } else {
+                Some(basis.to_quat().normalized())
+            }
+        };
+
+        match (rotation(from), rotation(to)) {
+            (Some(from), Some(to)) => (from, to),
+            (Some(from), None) => (from, from),
+            (None, Some(to)) => (to, to),
+            (None, None) => (Quaternion::default(), Quaternion::default()),
+        }
+    }
+
     /// Transposed dot product with the X axis (column) of the matrix.
     ///
     /// _Godot equivalent: `Basis.tdotx()`_
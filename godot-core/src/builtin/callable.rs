@@ -26,6 +26,15 @@ use sys::{ffi_methods, GodotFfi};
 ///
 /// [godot-cpp#802]: https://github.com/godotengine/godot-cpp/issues/802
 ///
+/// # Equality
+///
+/// `Callable` implements `PartialEq` using Godot's own equality:
+/// - Standard callables are equal if they refer to the same object and method name.
+/// - Callables created with [`from_fn()`][Self::from_fn] are only equal to their own clones, even if another callable wraps the
+///   same function.
+/// - Callables created with [`from_custom()`][Self::from_custom] are compared using the `PartialEq` impl of the Rust type.
+/// - A standard callable is never equal to a custom one.
+///
 /// # Godot docs
 ///
 /// [`Callable` (stable)](https://docs.godotengine.org/en/stable/classes/class_callable.html)
@@ -182,7 +182,9 @@ impl<T: ArrayElement> Array<T> {
 
     /// Returns the value at the specified index, or `None` if the index is out-of-bounds.
     ///
-    /// If you know the index is correct, use [`at()`](Self::at) instead.
+    /// Never panics, so it is suitable for indices from untrusted sources. If you know the index is correct, use [`at()`](Self::at)
+    /// instead.
+    #[doc(alias = "try_get")]
     pub fn get(&self, index: usize) -> Option<T> {
         let ptr = self.ptr_or_null(index);
         if ptr.is_null() {
@@ -252,6 +254,7 @@ impl<T: ArrayElement> Array<T> {
 
     /// Clears the array, removing all elements.
     pub fn clear(&mut self) {
+        self.ensure_mutable();
         // SAFETY: No new values are written to the array, we only remove values from the array.
         unsafe { self.as_inner_mut() }.clear();
     }
@@ -264,6 +267,7 @@ impl<T: ArrayElement> Array<T> {
     ///
     /// If `index` is out of bounds.
     pub fn set(&mut self, index: usize, value: impl Borrow<T>) {
+        self.ensure_mutable();
         let ptr_mut = self.ptr_mut(index);
 
         let variant = value.borrow().to_variant();
@@ -280,6 +284,7 @@ impl<T: ArrayElement> Array<T> {
     #[doc(alias = "append")]
     #[doc(alias = "push_back")]
     pub fn push(&mut self, value: impl Borrow<T>) {
+        self.ensure_mutable();
         // SAFETY: The array has type `T` and we're writing a value of type `T` to it.
         let mut inner = unsafe { self.as_inner_mut() };
         inner.push_back(&value.borrow().to_variant());
@@ -290,6 +295,7 @@ impl<T: ArrayElement> Array<T> {
     /// On large arrays, this method is much slower than [`push()`][Self::push], as it will move all the array's elements.
     /// The larger the array, the slower `push_front()` will be.
     pub fn push_front(&mut self, value: T) {
+        self.ensure_mutable();
         // SAFETY: The array has type `T` and we're writing a value of type `T` to it.
         unsafe { self.as_inner_mut() }.push_front(&value.to_variant());
     }
@@ -299,6 +305,7 @@ impl<T: ArrayElement> Array<T> {
     /// _Godot equivalent: `pop_back`_
     #[doc(alias = "pop_back")]
     pub fn pop(&mut self) -> Option<T> {
+        self.ensure_mutable();
         (!self.is_empty()).then(|| {
             // SAFETY: We do not write any values to the array, we just remove one.
             let variant = unsafe { self.as_inner_mut() }.pop_back();
@@ -311,6 +318,7 @@ impl<T: ArrayElement> Array<T> {
     /// Note: On large arrays, this method is much slower than `pop()` as it will move all the
     /// array's elements. The larger the array, the slower `pop_front()` will be.
     pub fn pop_front(&mut self) -> Option<T> {
+        self.ensure_mutable();
         (!self.is_empty()).then(|| {
             // SAFETY: We do not write any values to the array, we just remove one.
             let variant = unsafe { self.as_inner_mut() }.pop_front();
@@ -326,6 +334,7 @@ impl<T: ArrayElement> Array<T> {
     /// # Panics
     /// If `index > len()`.
     pub fn insert(&mut self, index: usize, value: T) {
+        self.ensure_mutable();
         let len = self.len();
         assert!(
             index <= len,
@@ -346,6 +355,7 @@ impl<T: ArrayElement> Array<T> {
     /// If `index` is out of bounds.
     #[doc(alias = "pop_at")]
     pub fn remove(&mut self, index: usize) -> T {
+        self.ensure_mutable();
         self.check_bounds(index);
 
         // SAFETY: We do not write any values to the array, we just remove one.
@@ -360,6 +370,7 @@ impl<T: ArrayElement> Array<T> {
     /// On large arrays, this method is much slower than [`pop()`][Self::pop], as it will move all the array's
     /// elements after the removed element.
     pub fn erase(&mut self, value: &T) {
+        self.ensure_mutable();
         // SAFETY: We don't write anything to the array.
         unsafe { self.as_inner_mut() }.erase(&value.to_variant());
     }
@@ -367,6 +378,7 @@ impl<T: ArrayElement> Array<T> {
     /// Assigns the given value to all elements in the array. This can be used together with
     /// `resize` to create an array with a given size and initialized elements.
     pub fn fill(&mut self, value: &T) {
+        self.ensure_mutable();
         // SAFETY: The array has type `T` and we're writing values of type `T` to it.
         unsafe { self.as_inner_mut() }.fill(&value.to_variant());
     }
@@ -378,6 +390,7 @@ impl<T: ArrayElement> Array<T> {
     ///
     /// If you know that the new size is smaller, then consider using [`shrink`](Array::shrink) instead.
     pub fn resize(&mut self, new_size: usize, value: &T) {
+        self.ensure_mutable();
         let original_size = self.len();
 
         // SAFETY: While we do insert `Variant::nil()` if the new size is larger, we then fill it with `value` ensuring that all values in the
@@ -397,6 +410,7 @@ impl<T: ArrayElement> Array<T> {
     /// If you want to increase the size of the array, use [`resize`](Array::resize) instead.
     #[doc(alias = "resize")]
     pub fn shrink(&mut self, new_size: usize) -> bool {
+        self.ensure_mutable();
         if new_size >= self.len() {
             return false;
         }
@@ -409,6 +423,7 @@ impl<T: ArrayElement> Array<T> {
 
     /// Appends another array at the end of this array. Equivalent of `append_array` in GDScript.
     pub fn extend_array(&mut self, other: &Array<T>) {
+        self.ensure_mutable();
         // SAFETY: `append_array` will only read values from `other`, and all types can be converted to `Variant`.
         let other: &VariantArray = unsafe { other.assume_type_ref::<Variant>() };
 
@@ -509,6 +524,69 @@ impl<T: ArrayElement> Array<T> {
         }
     }
 
+    /// Converts this array to a Rust vector, converting each element from `Variant`.
+    ///
+    /// All elements are read in one batch from the array's storage, avoiding the per-element FFI lookup of [`get()`][Self::get] or
+    /// [`iter_shared()`][Self::iter_shared]. The conversion from `Variant` still happens once per element.
+    ///
+    /// Equivalent to `Vec::from(&array)`.
+    pub fn to_vec(&self) -> Vec<T> {
+        let len = self.len();
+
+        // SAFETY: Unless `experimental-threads` is enabled, then we cannot have concurrent access to this array.
+        // And since we don't concurrently access the array in this function, we can create a slice to its contents.
+        // For empty arrays, the pointer is null, for which `borrow_slice()` returns an empty slice.
+        let elements = unsafe { Variant::borrow_slice(self.ptr_or_null(0), len) };
+
+        elements.iter().map(T::from_variant).collect()
+    }
+
+    /// Returns a new array with `f` applied to each element.
+    ///
+    /// Elements are read one by one with [`iter_shared()`][Self::iter_shared] and pushed directly into the resulting array, without an
+    /// intermediate `Vec`. Each element is converted from `Variant` exactly once. Since elements are read by index, `f` may modify this
+    /// array (or a shared clone of it). Only the indices that existed when the call started are visited, so elements pushed by `f` are
+    /// not mapped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let numbers: Array<i64> = array![1, 2, 3];
+    /// let strings: Array<GString> = numbers.map_godot(|n| GString::from(format!("#{n}")));
+    /// assert_eq!(strings.at(0), GString::from("#1"));
+    /// ```
+    pub fn map_godot<U, F>(&self, mut f: F) -> Array<U>
+    where
+        U: ArrayElement,
+        F: FnMut(T) -> U,
+    {
+        let mut result = Array::<U>::new();
+        for element in self.iter_shared().take(self.len()) {
+            result.push(f(element));
+        }
+
+        result
+    }
+
+    /// Returns a new array containing only the elements for which `predicate` returns `true`, keeping their order.
+    ///
+    /// Elements are read with [`iter_shared()`][Self::iter_shared] and pushed directly into the resulting array, which has the same
+    /// element type as `self`. Like with [`map_godot()`][Self::map_godot], `predicate` may modify this array; elements it pushes are not
+    /// visited.
+    pub fn filter_godot<P>(&self, mut predicate: P) -> Self
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut result = Self::new();
+        for element in self.iter_shared().take(self.len()) {
+            if predicate(&element) {
+                result.push(element);
+            }
+        }
+
+        result
+    }
+
     /// Returns the minimum value contained in the array if all elements are of comparable types.
     ///
     /// If the elements can't be compared or the array is empty, `None` is returned.
@@ -589,18 +667,105 @@ impl<T: ArrayElement> Array<T> {
         )
     }
 
+    /// Binary-searches a sorted array with a Rust comparator, like [`slice::binary_search_by()`].
+    ///
+    /// `f` is called with elements of the array and must return how each compares to the searched value. Returns `Ok(index)` of a
+    /// matching element, or `Err(index)` with the insertion position that would maintain the sorting order. If several elements
+    /// match, any of them may be returned.
+    ///
+    /// Unlike [`bsearch_custom()`][Self::bsearch_custom], this needs no `Callable`, and only converts the visited elements from
+    /// `Variant`. Calling it on an array that is not sorted according to `f` results in unspecified (but safe) behavior.
+    pub fn bsearch_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> std::cmp::Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match f(&self.at(mid)) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => return Ok(mid),
+            }
+        }
+
+        Err(low)
+    }
+
+    /// Inserts `value` into an array sorted by `cmp`, keeping it sorted, and returns the index where it was inserted.
+    ///
+    /// The insertion point is found with a binary search, so this avoids re-sorting after each insertion, e.g. when using the array as
+    /// a priority queue. `value` is inserted _after_ all elements which compare equal to it, so that such elements stay in insertion
+    /// order.
+    ///
+    /// Like [`insert()`][Self::insert], this moves all elements after the insertion point. Calling it on an array that is not sorted
+    /// according to `cmp` results in unspecified (but safe) behavior.
+    pub fn insert_sorted<F>(&mut self, value: T, mut cmp: F) -> usize
+    where
+        F: FnMut(&T, &T) -> std::cmp::Ordering,
+    {
+        // Treat equal elements as smaller, so the search ends up behind them.
+        let index = self
+            .bsearch_by(|element| cmp(element, &value).then(std::cmp::Ordering::Less))
+            .unwrap_err();
+
+        self.insert(index, value);
+        index
+    }
+
     /// Reverses the order of the elements in the array.
     pub fn reverse(&mut self) {
+        self.ensure_mutable();
         // SAFETY: We do not write any values that don't already exist in the array, so all values have the correct type.
         unsafe { self.as_inner_mut() }.reverse();
     }
 
+    /// Rotates the array in place, such that the element at index `n` becomes the first one.
+    ///
+    /// The first `n` elements are moved to the end, keeping their order. If `n` is greater than the length, it wraps around,
+    /// so rotating by `len()` leaves the array unchanged. Empty arrays are not modified.
+    pub fn rotate_left(&mut self, n: usize) {
+        if let Some(elements) = self.elements_mut_for_rotation() {
+            let len = elements.len();
+            elements.rotate_left(n % len);
+        }
+    }
+
+    /// Rotates the array in place, such that the first element moves to index `n`.
+    ///
+    /// The last `n` elements are moved to the front, keeping their order. If `n` is greater than the length, it wraps around,
+    /// so rotating by `len()` leaves the array unchanged. Empty arrays are not modified.
+    pub fn rotate_right(&mut self, n: usize) {
+        if let Some(elements) = self.elements_mut_for_rotation() {
+            let len = elements.len();
+            elements.rotate_right(n % len);
+        }
+    }
+
+    /// Returns all elements as a mutable slice, or `None` if the array is empty.
+    fn elements_mut_for_rotation(&mut self) -> Option<&mut [Variant]> {
+        self.ensure_mutable();
+
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+
+        // SAFETY: Godot stores the elements contiguously, and `ptr_mut()` ensures that the storage is not shared with other arrays.
+        // The slice borrows `self`, so the array cannot be accessed otherwise while it exists. Elements are only moved within the
+        // slice, so all values keep the correct type.
+        Some(unsafe { Variant::borrow_slice_mut(self.ptr_mut(0), len) })
+    }
+
     /// Sorts the array.
     ///
     /// Note: The sorting algorithm used is not [stable](https://en.wikipedia.org/wiki/Sorting_algorithm#Stability).
     /// This means that values considered equal may have their order changed when using `sort_unstable`.
     #[doc(alias = "sort")]
     pub fn sort_unstable(&mut self) {
+        self.ensure_mutable();
         // SAFETY: We do not write any values that don't already exist in the array, so all values have the correct type.
         unsafe { self.as_inner_mut() }.sort();
     }
@@ -613,6 +778,7 @@ impl<T: ArrayElement> Array<T> {
     /// This means that values considered equal may have their order changed when using `sort_unstable_custom`.
     #[doc(alias = "sort_custom")]
     pub fn sort_unstable_custom(&mut self, func: Callable) {
+        self.ensure_mutable();
         // SAFETY: We do not write any values that don't already exist in the array, so all values have the correct type.
         unsafe { self.as_inner_mut() }.sort_custom(func);
     }
@@ -621,10 +787,68 @@ impl<T: ArrayElement> Array<T> {
     /// global random number generator common to methods such as `randi`. Call `randomize` to
     /// ensure that a new seed will be used each time if you want non-reproducible shuffling.
     pub fn shuffle(&mut self) {
+        self.ensure_mutable();
         // SAFETY: We do not write any values that don't already exist in the array, so all values have the correct type.
         unsafe { self.as_inner_mut() }.shuffle();
     }
 
+    /// Turns the array into a read-only array, which can no longer be modified.
+    ///
+    /// Afterwards, all mutating methods such as [`push()`][Self::push] or [`set()`][Self::set] panic, in Debug and Release builds alike.
+    /// This cannot be undone, but [`duplicate_shallow()`][Self::duplicate_shallow] and [`duplicate_deep()`][Self::duplicate_deep] return
+    /// modifiable copies.
+    ///
+    /// Since arrays are reference-counted, this affects all `Array` values sharing the same data, including those held by scripts.
+    /// Nested containers are not affected; they need to be made read-only separately.
+    pub fn make_read_only(&mut self) {
+        // SAFETY: Changes a flag of the array, but does not write any values.
+        unsafe { self.as_inner_mut() }.make_read_only();
+    }
+
+    /// Returns `true` if the array is read-only. See [`make_read_only()`][Self::make_read_only].
+    pub fn is_read_only(&self) -> bool {
+        self.as_inner().is_read_only()
+    }
+
+    /// Returns `true` if the array is typed, i.e. Godot restricts its elements to one type.
+    ///
+    /// This holds for every `Array<T>` except [`VariantArray`], no matter how it was created (e.g. with [`new()`][Self::new], from a
+    /// slice or `Vec`, or collected from an iterator). GDScript then sees e.g. an `Array<i64>` as `Array[int]`, which can be passed
+    /// to parameters declared with that type.
+    ///
+    /// _Godot equivalent: `is_typed()`_
+    pub fn is_typed(&self) -> bool {
+        self.type_info().is_typed()
+    }
+
+    /// Returns the variant type of the elements, as seen by Godot, or [`VariantType::NIL`] for an untyped array.
+    ///
+    /// _Godot equivalent: `get_typed_builtin()`_
+    #[doc(alias = "get_typed_builtin")]
+    pub fn element_type(&self) -> VariantType {
+        self.type_info().variant_type()
+    }
+
+    /// Returns the class name of the elements, if they are objects.
+    ///
+    /// _Godot equivalent: `get_typed_class_name()`_
+    #[doc(alias = "get_typed_class_name")]
+    pub fn element_class_name(&self) -> Option<StringName> {
+        self.type_info().class_name
+    }
+
+    /// Asserts that the array can be modified.
+    ///
+    /// # Panics
+    ///
+    /// If the array is read-only.
+    fn ensure_mutable(&self) {
+        assert!(
+            !self.is_read_only(),
+            "mutating operation on read-only array; see Array::make_read_only()",
+        );
+    }
+
     /// Asserts that the given index refers to an existing element.
     ///
     /// # Panics
@@ -655,12 +879,14 @@ impl<T: ArrayElement> Array<T> {
 
     /// Returns a pointer to the element at the given index, or null if out of bounds.
     fn ptr_or_null(&self, index: usize) -> sys::GDExtensionConstVariantPtr {
-        // SAFETY: array_operator_index_const returns null for invalid indexes.
-        let variant_ptr = unsafe {
-            let index = to_i64(index);
-            interface_fn!(array_operator_index_const)(self.sys(), index)
+        // Indices beyond i64::MAX are out of bounds, too.
+        let Ok(index) = i64::try_from(index) else {
+            return std::ptr::null();
         };
 
+        // SAFETY: array_operator_index_const returns null for invalid indexes.
+        let variant_ptr = unsafe { interface_fn!(array_operator_index_const)(self.sys(), index) };
+
         // Signature is wrong in GDExtension, semantically this is a const ptr
         sys::SysPtr::as_const(variant_ptr)
     }
@@ -682,11 +908,12 @@ impl<T: ArrayElement> Array<T> {
 
     /// Returns a pointer to the element at the given index, or null if out of bounds.
     fn ptr_mut_or_null(&mut self, index: usize) -> sys::GDExtensionVariantPtr {
+        let Ok(index) = i64::try_from(index) else {
+            return std::ptr::null_mut();
+        };
+
         // SAFETY: array_operator_index returns null for invalid indexes.
-        unsafe {
-            let index = to_i64(index);
-            interface_fn!(array_operator_index)(self.sys_mut(), index)
-        }
+        unsafe { interface_fn!(array_operator_index)(self.sys_mut(), index) }
     }
 
     /// # Safety
@@ -857,6 +1084,19 @@ impl<T: ArrayElement> Array<T> {
 }
 
 impl VariantArray {
+    /// Converts this untyped array to a typed `Array<T>`, converting each element.
+    ///
+    /// Some engine methods return untyped arrays, even though all elements have the same type (e.g. an array of dictionaries).
+    /// This provides a typed view of such results, so that elements need not be converted one by one when accessed. The result is
+    /// a new array; `self` is not modified.
+    ///
+    /// Returns an error if any element cannot be converted to `T`.
+    pub fn to_typed<T: ArrayElement>(&self) -> Result<Array<T>, ConvertError> {
+        self.iter_shared()
+            .map(|variant| variant.try_to::<T>())
+            .collect()
+    }
+
     /// # Safety
     /// - Variant must have type `VariantType::ARRAY`.
     /// - Subsequent operations on this array must not rely on the type of the array.
@@ -1148,6 +1388,13 @@ impl<T: ArrayElement + ToGodot> From<&[T]> for Array<T> {
     }
 }
 
+/// Creates a typed `Array` from the given vector.
+impl<T: ArrayElement + ToGodot> From<Vec<T>> for Array<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Self::from(vec.as_slice())
+    }
+}
+
 /// Creates a `Array` from an iterator.
 impl<T: ArrayElement + ToGodot> FromIterator<T> for Array<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
@@ -1171,19 +1418,10 @@ impl<T: ArrayElement + ToGodot> Extend<T> for Array<T> {
     }
 }
 
-/// Converts this array to a strongly typed Rust vector.
+/// Converts this array to a strongly typed Rust vector, see [`Array::to_vec()`].
 impl<T: ArrayElement + FromGodot> From<&Array<T>> for Vec<T> {
     fn from(array: &Array<T>) -> Vec<T> {
-        let len = array.len();
-        let mut vec = Vec::with_capacity(len);
-
-        // SAFETY: Unless `experimental-threads` is enabled, then we cannot have concurrent access to this array.
-        // And since we don't concurrently access the array in this function, we can create a slice to its contents.
-        let elements = unsafe { Variant::borrow_slice(array.ptr(0), len) };
-
-        vec.extend(elements.iter().map(T::from_variant));
-
-        vec
+        array.to_vec()
     }
 }
 
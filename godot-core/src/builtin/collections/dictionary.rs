@@ -188,6 +188,7 @@ impl Dictionary {
 
     /// Removes all key-value pairs from the dictionary.
     pub fn clear(&mut self) {
+        self.ensure_mutable();
         self.as_inner().clear()
     }
 
@@ -197,6 +198,7 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `dict[key] = value`_
     pub fn set<K: ToGodot, V: ToGodot>(&mut self, key: K, value: V) {
+        self.ensure_mutable();
         let key = key.to_variant();
 
         // SAFETY: `self.get_ptr_mut(key)` always returns a valid pointer to a value in the dictionary; either pre-existing or newly inserted.
@@ -222,6 +224,7 @@ impl Dictionary {
     /// _Godot equivalent: `erase`_
     #[doc(alias = "erase")]
     pub fn remove<K: ToGodot>(&mut self, key: K) -> Option<Variant> {
+        self.ensure_mutable();
         let key = key.to_variant();
         let old_value = self.get(key.clone());
         self.as_inner().erase(&key);
@@ -257,6 +260,7 @@ impl Dictionary {
     /// _Godot equivalent: `merge`_
     #[doc(alias = "merge")]
     pub fn extend_dictionary(&mut self, other: &Self, overwrite: bool) {
+        self.ensure_mutable();
         self.as_inner().merge(other, overwrite)
     }
 
@@ -286,6 +290,23 @@ impl Dictionary {
         self.as_inner().duplicate(false)
     }
 
+    /// Turns the dictionary into a read-only dictionary, which can no longer be modified.
+    ///
+    /// Afterwards, all mutating methods such as [`set()`][Self::set] or [`remove()`][Self::remove] panic, in Debug and Release builds
+    /// alike. This cannot be undone, but [`duplicate_shallow()`][Self::duplicate_shallow] and [`duplicate_deep()`][Self::duplicate_deep]
+    /// return modifiable copies.
+    ///
+    /// Since dictionaries are reference-counted, this affects all `Dictionary` values sharing the same data, including those held by
+    /// scripts. Nested containers are not affected; they need to be made read-only separately.
+    pub fn make_read_only(&mut self) {
+        self.as_inner().make_read_only()
+    }
+
+    /// Returns `true` if the dictionary is read-only. See [`make_read_only()`][Self::make_read_only].
+    pub fn is_read_only(&self) -> bool {
+        self.as_inner().is_read_only()
+    }
+
     /// Returns an iterator over the key-value pairs of the `Dictionary`.
     ///
     /// The pairs are each of type `(Variant, Variant)`. Each pair references the original `Dictionary`, but instead of a `&`-reference
@@ -317,6 +338,17 @@ impl Dictionary {
         inner::InnerDictionary::from_outer(self)
     }
 
+    /// Asserts that the dictionary can be modified.
+    ///
+    /// # Panics
+    /// If the dictionary is read-only.
+    fn ensure_mutable(&self) {
+        assert!(
+            !self.is_read_only(),
+            "mutating operation on read-only dictionary; see Dictionary::make_read_only()",
+        );
+    }
+
     /// Get the pointer corresponding to the given key in the dictionary.
     ///
     /// If there exists no value at the given key, a `NIL` variant will be inserted for that key.
@@ -8,6 +8,7 @@
 use godot_ffi as sys;
 
 use crate::builtin::*;
+use crate::meta::error::ByteOffsetError;
 use crate::meta::ToGodot;
 use std::{fmt, ops, ptr};
 use sys::types::*;
@@ -91,7 +92,9 @@ macro_rules! impl_packed_array {
 
             /// Returns a copy of the value at the specified index, or `None` if out-of-bounds.
             ///
-            /// If you know the index is valid, use the `[]` operator (`Index`/`IndexMut` traits) instead.
+            /// Never panics, so it is suitable for indices from untrusted sources. If you know the index is valid, use the `[]` operator
+            /// (`Index`/`IndexMut` traits) instead.
+            #[doc(alias = "try_get")]
             pub fn get(&self, index: usize) -> Option<$Element> {
                 let ptr = self.ptr_or_none(index)?;
 
@@ -188,22 +191,13 @@ macro_rules! impl_packed_array {
             }
 
             /// Converts this array to a Rust vector, making a copy of its contents.
+            ///
+            /// Packed arrays are stored contiguously, so this accesses the storage only once through FFI, and then copies all elements
+            /// in bulk. For plain-data elements (numbers, vectors, colors), this is a single `memcpy`, whereas reading elements one by one
+            /// through [`get()`][Self::get] makes one FFI call per element. `GString` elements are cloned, which increments their
+            /// reference counts.
             pub fn to_vec(&self) -> Vec<$Element> {
-                let len = self.len();
-                let mut vec = Vec::with_capacity(len);
-                if len > 0 {
-                    let ptr = self.ptr(0);
-                    for offset in 0..to_isize(len) {
-                        // SAFETY: Packed arrays are stored contiguously in memory, so we can use
-                        // pointer arithmetic instead of going through `$operator_index_const` for
-                        // every index.
-                        // Note that we do need to use `.clone()` because `GString` is refcounted;
-                        // we can't just do a memcpy.
-                        let element = unsafe { (*ptr.offset(offset)).clone() };
-                        vec.push(element);
-                    }
-                }
-                vec
+                self.as_slice().to_vec()
             }
 
             /// Returns a sub-range `begin..end`, as a new packed array.
@@ -330,9 +324,12 @@ macro_rules! impl_packed_array {
 
             /// Returns a pointer to the element at the given index, or `None` if out of bounds.
             fn ptr_or_none(&self, index: usize) -> Option<*const $Element> {
+                // Indices beyond i64::MAX are out of bounds, too.
+                let index = i64::try_from(index).ok()?;
+
                 // SAFETY: The packed array index operators return a null pointer on out-of-bounds.
                 let item_ptr: *const $IndexRetType = unsafe {
-                    interface_fn!($operator_index_const)(self.sys(), to_i64(index))
+                    interface_fn!($operator_index_const)(self.sys(), index)
                 };
 
                 if item_ptr.is_null() {
@@ -348,9 +345,13 @@ macro_rules! impl_packed_array {
             ///
             /// If `index` is out of bounds.
             fn ptr_mut(&mut self, index: usize) -> *mut $Element {
+                let Ok(sys_index) = i64::try_from(index) else {
+                    self.panic_out_of_bounds(index)
+                };
+
                 // SAFETY: The packed array index operators return a null pointer on out-of-bounds.
                 let item_ptr: *mut $IndexRetType = unsafe {
-                    interface_fn!($operator_index)(self.sys_mut(), to_i64(index))
+                    interface_fn!($operator_index)(self.sys_mut(), sys_index)
                 };
 
                 if item_ptr.is_null() {
@@ -488,6 +489,13 @@ macro_rules! impl_packed_array {
             }
         }
 
+        #[doc = concat!("Converts a `", stringify!($PackedArray), "` to a Rust vec, see [`", stringify!($PackedArray), "::to_vec()`].")]
+        impl From<&$PackedArray> for Vec<$Element> {
+            fn from(array: &$PackedArray) -> Self {
+                array.to_vec()
+            }
+        }
+
         #[doc = concat!("Creates a `", stringify!($PackedArray), "` from an iterator.")]
         impl FromIterator<$Element> for $PackedArray {
             fn from_iter<I: IntoIterator<Item = $Element>>(iter: I) -> Self {
@@ -623,6 +631,88 @@ impl_packed_array!(
     },
 );
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// PackedByteArray: encoding and decoding of primitives
+
+macro_rules! impl_byte_array_codec {
+    ($(
+        $T:ty: $encode_le:ident, $encode_be:ident, $decode_le:ident, $decode_be:ident;
+    )+) => {
+        impl PackedByteArray {
+            $(
+                #[doc = concat!("Writes `value` as little-endian `", stringify!($T), "` at byte `offset`, overwriting existing bytes.")]
+                ///
+                /// Returns an error if the value does not fit into the array at `offset`; the array is not resized.
+                pub fn $encode_le(&mut self, offset: usize, value: $T) -> Result<(), ByteOffsetError> {
+                    self.encode_bytes(offset, value.to_le_bytes())
+                }
+
+                #[doc = concat!("Writes `value` as big-endian `", stringify!($T), "` at byte `offset`, overwriting existing bytes.")]
+                ///
+                /// Returns an error if the value does not fit into the array at `offset`; the array is not resized.
+                pub fn $encode_be(&mut self, offset: usize, value: $T) -> Result<(), ByteOffsetError> {
+                    self.encode_bytes(offset, value.to_be_bytes())
+                }
+
+                #[doc = concat!("Reads a little-endian `", stringify!($T), "` at byte `offset`.")]
+                ///
+                /// Returns an error if the array ends before the value does.
+                pub fn $decode_le(&self, offset: usize) -> Result<$T, ByteOffsetError> {
+                    self.decode_bytes(offset).map(<$T>::from_le_bytes)
+                }
+
+                #[doc = concat!("Reads a big-endian `", stringify!($T), "` at byte `offset`.")]
+                ///
+                /// Returns an error if the array ends before the value does.
+                pub fn $decode_be(&self, offset: usize) -> Result<$T, ByteOffsetError> {
+                    self.decode_bytes(offset).map(<$T>::from_be_bytes)
+                }
+            )+
+        }
+    };
+}
+
+impl_byte_array_codec!(
+    u16: encode_u16_le, encode_u16_be, decode_u16_le, decode_u16_be;
+    i16: encode_i16_le, encode_i16_be, decode_i16_le, decode_i16_be;
+    u32: encode_u32_le, encode_u32_be, decode_u32_le, decode_u32_be;
+    i32: encode_i32_le, encode_i32_be, decode_i32_le, decode_i32_be;
+    u64: encode_u64_le, encode_u64_be, decode_u64_le, decode_u64_be;
+    i64: encode_i64_le, encode_i64_be, decode_i64_le, decode_i64_be;
+    f32: encode_f32_le, encode_f32_be, decode_f32_le, decode_f32_be;
+    f64: encode_f64_le, encode_f64_be, decode_f64_le, decode_f64_be;
+);
+
+impl PackedByteArray {
+    fn encode_bytes<const N: usize>(
+        &mut self,
+        offset: usize,
+        bytes: [u8; N],
+    ) -> Result<(), ByteOffsetError> {
+        let range = self.byte_range(offset, N)?;
+        self.as_mut_slice()[range].copy_from_slice(&bytes);
+        Ok(())
+    }
+
+    fn decode_bytes<const N: usize>(&self, offset: usize) -> Result<[u8; N], ByteOffsetError> {
+        let range = self.byte_range(offset, N)?;
+        let bytes = self.as_slice()[range]
+            .try_into()
+            .expect("range has N bytes");
+
+        Ok(bytes)
+    }
+
+    fn byte_range(&self, offset: usize, size: usize) -> Result<ops::Range<usize>, ByteOffsetError> {
+        let len = self.len();
+
+        match offset.checked_add(size) {
+            Some(end) if end <= len => Ok(offset..end),
+            _ => Err(ByteOffsetError::new(offset, size, len)),
+        }
+    }
+}
+
 impl_packed_array!(
     type_name: PackedInt32Array,
     variant_type: PACKED_INT32_ARRAY,
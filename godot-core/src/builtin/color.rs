@@ -257,17 +257,49 @@ impl Color {
     /// Returns the color converted to the [sRGB](https://en.wikipedia.org/wiki/SRGB) color space.
     /// This method assumes the original color is in the linear color space. See also
     /// [`Color::srgb_to_linear`] which performs the opposite operation.
+    ///
+    /// Uses the same formula as Godot, so that CPU-side results match those of the engine. Alpha is left unchanged.
     #[must_use]
     pub fn linear_to_srgb(self) -> Self {
-        self.as_inner().linear_to_srgb()
+        fn convert(c: f32) -> f32 {
+            if c < 0.0031308 {
+                12.92 * c
+            } else {
+                (1.0 + 0.055) * c.powf(1.0 / 2.4) - 0.055
+            }
+        }
+
+        Self::from_rgba(convert(self.r), convert(self.g), convert(self.b), self.a)
     }
 
     /// Returns the color converted to the linear color space. This method assumes the original
     /// color is in the sRGB color space. See also [`Color::linear_to_srgb`] which performs the
     /// opposite operation.
+    ///
+    /// Uses the same formula as Godot, so that CPU-side results match those of the engine. Alpha is left unchanged.
     #[must_use]
     pub fn srgb_to_linear(self) -> Self {
-        self.as_inner().srgb_to_linear()
+        fn convert(c: f32) -> f32 {
+            if c < 0.04045 {
+                c * (1.0 / 12.92)
+            } else {
+                ((c + 0.055) * (1.0 / (1.0 + 0.055))).powf(2.4)
+            }
+        }
+
+        Self::from_rgba(convert(self.r), convert(self.g), convert(self.b), self.a)
+    }
+
+    /// Shorthand for [`srgb_to_linear()`][Self::srgb_to_linear].
+    #[must_use]
+    pub fn to_linear(self) -> Self {
+        self.srgb_to_linear()
+    }
+
+    /// Shorthand for [`linear_to_srgb()`][Self::linear_to_srgb].
+    #[must_use]
+    pub fn to_srgb(self) -> Self {
+        self.linear_to_srgb()
     }
 
     /// Returns the HTML color code representation of this color, as 8 lowercase hex digits in the
@@ -568,6 +600,29 @@ impl std::fmt::Display for Color {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+    use crate::assert_eq_approx;
+
+    #[test]
+    fn srgb_linear_known_values() {
+        // Reference values of the sRGB transfer function.
+        let srgb = Color::from_rgba(0.5, 0.04, 1.0, 0.25);
+        let linear = Color::from_rgba(0.21404114, 0.04 / 12.92, 1.0, 0.25);
+
+        assert_eq_approx!(srgb.srgb_to_linear(), linear);
+        assert_eq_approx!(linear.linear_to_srgb(), srgb);
+
+        assert_eq_approx!(Color::BLACK.srgb_to_linear(), Color::BLACK);
+        assert_eq_approx!(Color::WHITE.linear_to_srgb(), Color::WHITE);
+        assert_eq_approx!(
+            Color::from_rgb(0.5, 0.5, 0.5).linear_to_srgb(),
+            Color::from_rgb(0.7353569, 0.7353569, 0.7353569)
+        );
+
+        assert_eq!(srgb.to_linear(), srgb.srgb_to_linear());
+        assert_eq!(linear.to_srgb(), linear.linear_to_srgb());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
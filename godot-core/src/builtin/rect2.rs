@@ -187,6 +187,35 @@ impl Rect2 {
         point.abs() == point && point.x < self.size.x && point.y < self.size.y
     }
 
+    /// Returns the point within the Rect2 that is closest to `point`.
+    ///
+    /// Points inside the Rect2 (including its edges) are returned unchanged; points outside are clamped onto the nearest edge.
+    ///
+    /// # Panics
+    /// If `self.size` is negative.
+    #[inline]
+    pub fn closest_point(self, point: Vector2) -> Vector2 {
+        self.assert_nonnegative();
+
+        point.clamp(self.position, self.end())
+    }
+
+    /// Returns the support point in a given direction, i.e. the corner that lies furthest along `dir`.
+    ///
+    /// This is useful for collision detection algorithms such as GJK.
+    #[inline]
+    pub fn support(self, dir: Vector2) -> Vector2 {
+        let half_extents = self.size * 0.5;
+        let relative_center_point = self.position + half_extents;
+
+        let signs = Vector2 {
+            x: dir.x.signum(),
+            y: dir.y.signum(),
+        };
+
+        half_extents * signs + relative_center_point
+    }
+
     /// Returns the intersection of this Rect2 and `b`. If the rectangles do not intersect, an empty Rect2 is returned.
     #[inline]
     pub fn intersection(self, b: Self) -> Option<Self> {
@@ -308,6 +337,36 @@ impl std::fmt::Display for Rect2 {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+
+    #[test]
+    fn test_closest_point() {
+        let rect = Rect2::new(Vector2::new(1.0, 2.0), Vector2::new(4.0, 3.0));
+        let closest = |x, y| rect.closest_point(Vector2::new(x, y));
+
+        // Inside.
+        assert_eq!(closest(2.0, 3.0), Vector2::new(2.0, 3.0));
+
+        // On edges and corners.
+        assert_eq!(closest(5.0, 3.0), Vector2::new(5.0, 3.0));
+        assert_eq!(closest(1.0, 2.0), Vector2::new(1.0, 2.0));
+
+        // Outside.
+        assert_eq!(closest(0.0, 3.0), Vector2::new(1.0, 3.0));
+        assert_eq!(closest(10.0, 10.0), Vector2::new(5.0, 5.0));
+        assert_eq!(closest(3.0, -4.0), Vector2::new(3.0, 2.0));
+    }
+
+    #[test]
+    fn test_support() {
+        let rect = Rect2::new(Vector2::new(1.0, 2.0), Vector2::new(4.0, 3.0));
+        let support = |x, y| rect.support(Vector2::new(x, y));
+
+        assert_eq!(support(1.0, 1.0), Vector2::new(5.0, 5.0));
+        assert_eq!(support(-1.0, 0.5), Vector2::new(1.0, 5.0));
+        assert_eq!(support(-2.0, -3.0), Vector2::new(1.0, 2.0));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::borrow::Borrow;
 use std::fmt;
 use std::ptr;
 
@@ -13,9 +14,12 @@ use godot_ffi as sys;
 use crate::builtin::{inner, Array, Callable, Dictionary, StringName, Variant};
 use crate::classes::Object;
 use crate::global::Error;
+use crate::meta::error::ConvertError;
 use crate::meta::{FromGodot, GodotType, ToGodot};
 use crate::obj::bounds::DynMemory;
 use crate::obj::{Bounds, Gd, GodotClass, InstanceId};
+#[cfg(since_api = "4.2")]
+use crate::tools::SignalTimeoutFuture;
 use sys::{ffi_methods, GodotFfi};
 
 /// A `Signal` represents a signal of an Object instance in Godot.
@@ -109,9 +113,8 @@ impl Signal {
     pub fn connections(&self) -> Array<Dictionary> {
         self.as_inner()
             .get_connections()
-            .iter_shared()
-            .map(|variant| variant.to())
-            .collect()
+            .to_typed()
+            .expect("Signal::get_connections() returns dictionaries")
     }
 
     /// Returns the name of the signal.
@@ -151,6 +154,18 @@ impl Signal {
         self.as_inner().is_null()
     }
 
+    /// Returns a future that resolves with the signal's arguments on the next emission, or `None` if `timeout_seconds` elapse first.
+    ///
+    /// The timeout is driven by a [`SceneTreeTimer`](crate::classes::SceneTreeTimer). Whichever side wins, both connections are removed
+    /// again. See [`SignalTimeoutFuture`] for details.
+    ///
+    /// # Panics
+    /// If the engine's main loop is not a [`SceneTree`](crate::classes::SceneTree).
+    #[cfg(since_api = "4.2")]
+    pub fn to_signal_timeout(&self, timeout_seconds: f64) -> SignalTimeoutFuture {
+        SignalTimeoutFuture::new(self.clone(), timeout_seconds)
+    }
+
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerSignal {
         inner::InnerSignal::from_outer(self)
@@ -208,3 +223,107 @@ impl fmt::Display for Signal {
         write!(f, "{}", self.to_variant())
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Emits a signal by name, converting each argument with [`ToGodot`].
+///
+/// `emit_signal!(object, "name", arg1, arg2, ...)` expands to a single call of `object.emit_signal("name", &[arg1.to_variant(), ...])`.
+/// This works for any signal of engine or user classes, including ones only known at runtime. The object must be accessible mutably,
+/// e.g. a `let mut` variable of type `Gd<T>`. The macro evaluates to the [`Error`] returned by Godot.
+///
+/// Argument types are not checked against the signal's declaration; as with `emit_signal()`, mismatches are reported by Godot
+/// when the connected callables are invoked.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::builtin::emit_signal;
+///
+/// fn take_damage(mut enemy: Gd<Node>, amount: i32, source: Gd<Node>) {
+///     emit_signal!(enemy, "damaged", amount, source);
+/// }
+/// ```
+#[macro_export]
+macro_rules! emit_signal {
+    ($object:expr, $signal:expr $(, $args:expr)* $(,)?) => {
+        $object.emit_signal(
+            ::std::convert::Into::<$crate::builtin::StringName>::into($signal),
+            &[ $( $crate::meta::ToGodot::to_variant(&$args) ),* ],
+        )
+    };
+}
+
+/// Converts the arguments of a signal, as received by an untyped connection, into a typed tuple.
+///
+/// `signal_args!(args)` checks that `args` has as many elements as the tuple, and converts each of them with [`FromGodot`]. It evaluates
+/// to `Result<(T1, T2, ...), ConvertError>`, so the tuple type is usually inferred from a `let` binding. The error names the argument
+/// which failed to convert.
+///
+/// `args` can be anything that can be sliced into `[Variant]` or `[&Variant]`, such as the arguments of a
+/// [`Callable::from_fn()`][crate::builtin::Callable::from_fn] closure.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::builtin::signal_args;
+///
+/// fn on_damaged(args: &[&Variant]) -> Result<(), ConvertError> {
+///     let (amount, source): (i64, Gd<Node>) = signal_args!(args)?;
+///     godot_print!("took {amount} damage from {}", source.get_name());
+///     Ok(())
+/// }
+/// ```
+#[macro_export]
+macro_rules! signal_args {
+    ($args:expr) => {
+        $crate::builtin::FromSignalArgs::from_signal_args(&$args[..])
+    };
+}
+
+/// Tuples which can be converted from a list of signal arguments, see [`signal_args!`][crate::builtin::signal_args].
+///
+/// Implemented for tuples of up to 10 elements, each of which implements [`FromGodot`].
+pub trait FromSignalArgs: Sized {
+    /// Converts `args` into a tuple, failing if the number of arguments or any of their types does not match.
+    fn from_signal_args<V: Borrow<Variant>>(args: &[V]) -> Result<Self, ConvertError>;
+}
+
+macro_rules! impl_from_signal_args {
+    ($count:literal; $($T:ident: $n:tt),*) => {
+        impl<$($T: FromGodot),*> FromSignalArgs for ($($T,)*) {
+            fn from_signal_args<V: Borrow<Variant>>(args: &[V]) -> Result<Self, ConvertError> {
+                if args.len() != $count {
+                    return Err(ConvertError::new(format!(
+                        "wrong number of signal arguments: expected {}, got {}",
+                        $count,
+                        args.len()
+                    )));
+                }
+
+                Ok(($( signal_arg::<$T>(args[$n].borrow(), $n)?, )*))
+            }
+        }
+    };
+}
+
+impl_from_signal_args!(0;);
+impl_from_signal_args!(1; T0: 0);
+impl_from_signal_args!(2; T0: 0, T1: 1);
+impl_from_signal_args!(3; T0: 0, T1: 1, T2: 2);
+impl_from_signal_args!(4; T0: 0, T1: 1, T2: 2, T3: 3);
+impl_from_signal_args!(5; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4);
+impl_from_signal_args!(6; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5);
+impl_from_signal_args!(7; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6);
+impl_from_signal_args!(8; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7);
+impl_from_signal_args!(9; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8);
+impl_from_signal_args!(10; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7, T8: 8, T9: 9);
+
+fn signal_arg<T: FromGodot>(arg: &Variant, index: usize) -> Result<T, ConvertError> {
+    arg.try_to::<T>().map_err(|err| {
+        ConvertError::new(format!(
+            "signal argument {index} has wrong type, expected {}: {err}",
+            std::any::type_name::<T>()
+        ))
+    })
+}
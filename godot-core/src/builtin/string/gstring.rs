@@ -69,6 +69,23 @@ impl GString {
         Self::default()
     }
 
+    /// Creates a string from Rust format arguments, as produced by [`format_args!`].
+    ///
+    /// Usually invoked through the [`gstr_format!`][crate::builtin::gstr_format] macro. Short results are formatted into a stack buffer,
+    /// and format strings without arguments are converted directly, so no intermediate `String` needs to be allocated in these cases.
+    pub fn from_fmt(args: fmt::Arguments) -> Self {
+        if let Some(literal) = args.as_str() {
+            return Self::from(literal);
+        }
+
+        let mut buffer = FormatBuffer::new();
+        buffer
+            .write_fmt(args)
+            .expect("a Display implementation returned an error");
+
+        Self::from(buffer.as_str())
+    }
+
     pub fn len(&self) -> usize {
         self.as_inner().length().try_into().unwrap()
     }
@@ -205,6 +222,76 @@ impl fmt::Debug for GString {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Formatting
+
+/// Formats into a buffer on the stack, falling back to a heap-allocated `String` once the output exceeds it.
+struct FormatBuffer {
+    bytes: [u8; Self::CAPACITY],
+    len: usize,
+    overflow: Option<String>,
+}
+
+impl FormatBuffer {
+    const CAPACITY: usize = 256;
+
+    fn new() -> Self {
+        Self {
+            bytes: [0; Self::CAPACITY],
+            len: 0,
+            overflow: None,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        match &self.overflow {
+            Some(string) => string,
+            // Only whole `&str` slices are ever copied into the buffer, so it always holds valid UTF-8.
+            None => std::str::from_utf8(&self.bytes[..self.len]).expect("valid UTF-8"),
+        }
+    }
+}
+
+impl Write for FormatBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if let Some(string) = &mut self.overflow {
+            string.push_str(s);
+        } else if let Some(target) = self.bytes.get_mut(self.len..self.len + s.len()) {
+            target.copy_from_slice(s.as_bytes());
+            self.len += s.len();
+        } else {
+            let mut string = String::with_capacity(2 * (self.len + s.len()));
+            string.push_str(self.as_str());
+            string.push_str(s);
+            self.overflow = Some(string);
+        }
+
+        Ok(())
+    }
+}
+
+/// Creates a [`GString`] using Rust's formatting syntax.
+///
+/// Accepts the same arguments as [`format!`], i.e. any values implementing [`Display`][std::fmt::Display] or [`Debug`][std::fmt::Debug].
+/// Most Godot types implement `Display`; other [`ToGodot`][crate::meta::ToGodot] values can be formatted through `value.to_variant()`.
+///
+/// This is a more idiomatic alternative to Godot's `%` operator and `String.format()`. See [`GString::from_fmt()`] for performance notes.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::{gstr_format, GString, Vector2};
+///
+/// let score = 42;
+/// let position = Vector2::new(1.0, 2.0);
+/// let text: GString = gstr_format!("Score: {score}, position: {}", position);
+/// ```
+#[macro_export]
+macro_rules! gstr_format {
+    ($($arg:tt)*) => {
+        $crate::builtin::GString::from_fmt(::std::format_args!($($arg)*))
+    };
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Conversion from/into Rust string-types
 
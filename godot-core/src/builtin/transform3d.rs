@@ -135,14 +135,19 @@ impl Transform3D {
 
     /// Returns a transform interpolated between this transform and another by
     /// a given weight (on the range of 0.0 to 1.0).
+    ///
+    /// The origin and scale are interpolated linearly, the rotation spherically (see [`Quaternion::slerp()`][crate::builtin::Quaternion::slerp]).
+    /// If one of the bases is degenerate (e.g. zero scale), the rotation of the other one is used, like in [`Basis::slerp()`].
+    ///
+    /// _Godot equivalent: `Transform3D.interpolate_with()`_
     #[must_use]
     pub fn interpolate_with(&self, other: &Self, weight: real) -> Self {
+        let (src_rot, dst_rot) = Basis::interpolation_rotations(&self.basis, &other.basis);
+
         let src_scale = self.basis.scale();
-        let src_rot = self.basis.to_quat().normalized();
         let src_loc = self.origin;
 
         let dst_scale = other.basis.scale();
-        let dst_rot = other.basis.to_quat().normalized();
         let dst_loc = other.origin;
 
         let mut basis = Basis::from_scale(src_scale.lerp(dst_scale, weight));
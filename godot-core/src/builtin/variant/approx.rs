@@ -0,0 +1,203 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{
+    Aabb, Basis, Color, Dictionary, PackedColorArray, PackedFloat32Array, PackedFloat64Array,
+    PackedVector2Array, PackedVector3Array, Plane, Projection, Quaternion, RealConv, Rect2,
+    Transform2D, Transform3D, Variant, VariantArray, VariantType, Vector2, Vector3, Vector4,
+};
+use crate::global::is_same;
+
+impl Variant {
+    /// Returns `true` if both variants are equal, allowing floating-point components to differ by up to `epsilon`.
+    ///
+    /// Floats and all builtin types composed of floats -- vectors, `Color`, `Quaternion`, `Transform3D`, packed float and vector arrays,
+    /// etc. -- are compared component-wise, with an absolute tolerance. Like with `==`, `int` and `float` values are compared
+    /// numerically, and NaN is never equal to anything. Arrays and dictionaries are compared recursively: they must have the same size,
+    /// and dictionaries the same keys, which are compared exactly. All other values use `==`.
+    ///
+    /// This is mostly useful for test assertions on nested data, where computed values may carry rounding errors. For exact comparisons
+    /// with dictionary-key semantics, see [`VariantKey`][crate::builtin::VariantKey].
+    ///
+    /// Containers which (directly or indirectly) contain themselves are only supported if both sides refer to the same container.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let computed = dict! { "speed": 0.1 + 0.2, "direction": Vector2::new(0.6, 0.8).normalized() };
+    /// let expected = dict! { "speed": 0.3, "direction": Vector2::new(0.6, 0.8) };
+    ///
+    /// assert!(computed.to_variant().approx_eq(&expected.to_variant(), 1e-5));
+    /// ```
+    pub fn approx_eq(&self, other: &Variant, epsilon: f64) -> bool {
+        let ty = self.get_type();
+        if ty != other.get_type() {
+            return match (as_number(self), as_number(other)) {
+                (Some(lhs), Some(rhs)) => floats_approx_eq(lhs, rhs, epsilon),
+                _ => self == other,
+            };
+        }
+
+        match ty {
+            VariantType::ARRAY | VariantType::DICTIONARY if is_same(self, other) => true,
+            VariantType::ARRAY => {
+                // SAFETY: type is checked, and the arrays are only read. Avoids conversion failures for typed arrays, see `Debug` impl.
+                let (lhs, rhs) = unsafe {
+                    (
+                        VariantArray::from_variant_unchecked(self),
+                        VariantArray::from_variant_unchecked(other),
+                    )
+                };
+
+                lhs.len() == rhs.len()
+                    && lhs
+                        .iter_shared()
+                        .zip(rhs.iter_shared())
+                        .all(|(lhs, rhs)| lhs.approx_eq(&rhs, epsilon))
+            }
+            VariantType::DICTIONARY => dictionaries_approx_eq(&self.to(), &other.to(), epsilon),
+            _ => match (float_components(self), float_components(other)) {
+                (Some(lhs), Some(rhs)) => {
+                    lhs.len() == rhs.len()
+                        && lhs
+                            .iter()
+                            .zip(&rhs)
+                            .all(|(&lhs, &rhs)| floats_approx_eq(lhs, rhs, epsilon))
+                }
+                _ => self == other,
+            },
+        }
+    }
+}
+
+fn dictionaries_approx_eq(lhs: &Dictionary, rhs: &Dictionary, epsilon: f64) -> bool {
+    lhs.len() == rhs.len()
+        && lhs.iter_shared().all(|(key, value)| {
+            rhs.get(key)
+                .is_some_and(|other| value.approx_eq(&other, epsilon))
+        })
+}
+
+fn floats_approx_eq(lhs: f64, rhs: f64, epsilon: f64) -> bool {
+    // Exact check first, so that equal infinities match.
+    lhs == rhs || (lhs - rhs).abs() <= epsilon
+}
+
+fn as_number(value: &Variant) -> Option<f64> {
+    match value.get_type() {
+        VariantType::INT => Some(value.to::<i64>() as f64),
+        VariantType::FLOAT => Some(value.to::<f64>()),
+        _ => None,
+    }
+}
+
+/// Flattens float-based builtins into their components, or returns `None` for other types.
+fn float_components(value: &Variant) -> Option<Vec<f64>> {
+    let mut out = Vec::new();
+    match value.get_type() {
+        VariantType::FLOAT => out.push(value.to::<f64>()),
+        VariantType::VECTOR2 => push_vector2(&mut out, value.to()),
+        VariantType::VECTOR3 => push_vector3(&mut out, value.to()),
+        VariantType::VECTOR4 => push_vector4(&mut out, value.to()),
+        VariantType::RECT2 => {
+            let rect: Rect2 = value.to();
+            push_vector2(&mut out, rect.position);
+            push_vector2(&mut out, rect.size);
+        }
+        VariantType::TRANSFORM2D => {
+            let transform: Transform2D = value.to();
+            push_vector2(&mut out, transform.a);
+            push_vector2(&mut out, transform.b);
+            push_vector2(&mut out, transform.origin);
+        }
+        VariantType::PLANE => {
+            let plane: Plane = value.to();
+            push_vector3(&mut out, plane.normal);
+            out.push(plane.d.as_f64());
+        }
+        VariantType::QUATERNION => {
+            let quat: Quaternion = value.to();
+            out.extend([quat.x, quat.y, quat.z, quat.w].map(RealConv::as_f64));
+        }
+        VariantType::AABB => {
+            let aabb: Aabb = value.to();
+            push_vector3(&mut out, aabb.position);
+            push_vector3(&mut out, aabb.size);
+        }
+        VariantType::BASIS => push_basis(&mut out, value.to()),
+        VariantType::TRANSFORM3D => {
+            let transform: Transform3D = value.to();
+            push_basis(&mut out, transform.basis);
+            push_vector3(&mut out, transform.origin);
+        }
+        VariantType::PROJECTION => {
+            let projection: Projection = value.to();
+            for col in projection.cols {
+                push_vector4(&mut out, col);
+            }
+        }
+        VariantType::COLOR => push_color(&mut out, value.to()),
+        VariantType::PACKED_FLOAT32_ARRAY => {
+            let array: PackedFloat32Array = value.to();
+            out.extend(array.as_slice().iter().copied().map(f64::from));
+        }
+        VariantType::PACKED_FLOAT64_ARRAY => {
+            let array: PackedFloat64Array = value.to();
+            out.extend_from_slice(array.as_slice());
+        }
+        VariantType::PACKED_VECTOR2_ARRAY => {
+            let array: PackedVector2Array = value.to();
+            for &vector in array.as_slice() {
+                push_vector2(&mut out, vector);
+            }
+        }
+        VariantType::PACKED_VECTOR3_ARRAY => {
+            let array: PackedVector3Array = value.to();
+            for &vector in array.as_slice() {
+                push_vector3(&mut out, vector);
+            }
+        }
+        #[cfg(since_api = "4.3")]
+        VariantType::PACKED_VECTOR4_ARRAY => {
+            let array: crate::builtin::PackedVector4Array = value.to();
+            for &vector in array.as_slice() {
+                push_vector4(&mut out, vector);
+            }
+        }
+        VariantType::PACKED_COLOR_ARRAY => {
+            let array: PackedColorArray = value.to();
+            for &color in array.as_slice() {
+                push_color(&mut out, color);
+            }
+        }
+        _ => return None,
+    }
+
+    Some(out)
+}
+
+fn push_vector2(out: &mut Vec<f64>, vector: Vector2) {
+    out.extend([vector.x, vector.y].map(RealConv::as_f64));
+}
+
+fn push_vector3(out: &mut Vec<f64>, vector: Vector3) {
+    out.extend([vector.x, vector.y, vector.z].map(RealConv::as_f64));
+}
+
+fn push_vector4(out: &mut Vec<f64>, vector: Vector4) {
+    out.extend([vector.x, vector.y, vector.z, vector.w].map(RealConv::as_f64));
+}
+
+fn push_basis(out: &mut Vec<f64>, basis: Basis) {
+    for row in basis.rows {
+        push_vector3(out, row);
+    }
+}
+
+fn push_color(out: &mut Vec<f64>, color: Color) {
+    out.extend([color.r, color.g, color.b, color.a].map(f64::from));
+}
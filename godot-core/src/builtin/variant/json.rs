@@ -0,0 +1,45 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{GString, Variant};
+use crate::classes::Json;
+use crate::global::Error;
+use crate::obj::NewGd;
+
+/// Conversions to and from JSON, using Godot's [`JSON`](https://docs.godotengine.org/en/stable/classes/class_json.html) class.
+///
+/// The output and accepted input are exactly the same as with GDScript's `JSON.stringify()` and `JSON.parse()`. In particular, JSON has
+/// no integer type, so all numbers are parsed as `float`, and types without a JSON equivalent (e.g. `Vector2`) are stored as strings.
+impl Variant {
+    /// Converts this variant to a compact JSON string, with dictionary keys sorted.
+    pub fn to_json_string(&self) -> GString {
+        Json::stringify(self)
+    }
+
+    /// Converts this variant to a multi-line JSON string, with each nesting level indented by `indent` (e.g. `"\t"` or `"  "`).
+    ///
+    /// Dictionary keys are sorted. If `indent` is empty, the output is the same as [`to_json_string()`][Self::to_json_string].
+    pub fn to_json_string_indented(&self, indent: &str) -> GString {
+        Json::stringify_ex(self).indent(indent.into()).done()
+    }
+
+    /// Parses a JSON string into a variant.
+    ///
+    /// On failure, returns the parser's error message, together with the line where the error occurred. Godot's `keep_text` option is
+    /// not needed here: it only preserves the source text inside the `JSON` object, which is discarded after parsing.
+    pub fn from_json_string(json: &str) -> Result<Variant, GString> {
+        let mut parser = Json::new_gd();
+
+        if parser.parse(json.into()) == Error::OK {
+            Ok(parser.get_data())
+        } else {
+            let message = parser.get_error_message();
+            let line = parser.get_error_line();
+            Err(format!("{message} (line {line})").into())
+        }
+    }
+}
@@ -0,0 +1,88 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::builtin::Variant;
+use crate::meta::ToGodot;
+
+/// Variant usable as key in Rust's hash-based collections, such as `HashSet<VariantKey>` or `HashMap<VariantKey, V>`.
+///
+/// [`Variant`] itself implements neither [`Eq`] nor [`Hash`]: its `==` operator follows GDScript, where `1 == 1.0` holds even though
+/// the hashes differ, and NaN is not equal to itself. `VariantKey` instead uses the same semantics as Godot's `Dictionary` keys, which
+/// are consistent with [`Variant::hash()`]:
+/// - Values are only equal if they have the same type (`1` and `1.0` are different keys).
+/// - Floats (also inside vectors etc.) compare numerically, except that NaN is equal to NaN, so it can be used as a key.
+/// - Arrays and dictionaries compare by content.
+/// - Objects compare by identity. Two `Gd` handles pointing to the same object yield equal keys, regardless of their static type.
+///
+/// # Freed objects
+/// A key holding an object keeps referring to that object's identity after it has been freed; it does not become equal to `nil`.
+/// Since Godot may reuse the memory of a freed object for a new one, keys of freed objects should not be used for lookups. Remove
+/// them from the collection before freeing the object, or check [`Gd::is_instance_valid()`][crate::obj::Gd::is_instance_valid]
+/// when iterating.
+///
+/// # Example
+/// ```no_run
+/// use std::collections::HashSet;
+/// use godot::prelude::*;
+///
+/// fn count_unique(nodes: &[Gd<Node>]) -> usize {
+///     let seen: HashSet<VariantKey> = nodes.iter().map(|node| VariantKey::new(node.clone())).collect();
+///     seen.len()
+/// }
+/// ```
+#[derive(Clone)]
+pub struct VariantKey {
+    variant: Variant,
+}
+
+impl VariantKey {
+    /// Creates a key from any value convertible to `Variant`.
+    pub fn new(value: impl ToGodot) -> Self {
+        Self {
+            variant: value.to_variant(),
+        }
+    }
+
+    /// Returns the wrapped variant.
+    pub fn as_variant(&self) -> &Variant {
+        &self.variant
+    }
+
+    /// Consumes the key, returning the wrapped variant.
+    pub fn into_variant(self) -> Variant {
+        self.variant
+    }
+}
+
+impl From<Variant> for VariantKey {
+    fn from(variant: Variant) -> Self {
+        Self { variant }
+    }
+}
+
+impl PartialEq for VariantKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.variant.hash_eq(&other.variant)
+    }
+}
+
+impl Eq for VariantKey {}
+
+impl Hash for VariantKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.variant.hash().hash(state);
+    }
+}
+
+impl fmt::Debug for VariantKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VariantKey").field(&self.variant).finish()
+    }
+}
@@ -6,15 +6,24 @@
  */
 
 use crate::builtin::{
-    GString, StringName, VariantArray, VariantDispatch, VariantOperator, VariantType,
+    Dictionary, GString, StringName, VariantArray, VariantDispatch, VariantOperator, VariantType,
 };
-use crate::meta::error::ConvertError;
-use crate::meta::{ArrayElement, FromGodot, ToGodot};
+use crate::meta::error::{ConvertError, FromVariantError, PropertyError};
+use crate::meta::{ArrayElement, FromGodot, GodotConvert, GodotType, IntegerTarget, ToGodot};
+use crate::obj::{Gd, GodotClass};
 use godot_ffi as sys;
 use std::{fmt, ptr};
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
+mod approx;
 mod impls;
+#[cfg(feature = "codegen-full")]
+mod json;
+mod key;
+mod pretty;
+mod typed_bytes;
+
+pub use key::VariantKey;
 
 /// Godot variant type, able to store a variety of different types.
 ///
@@ -68,6 +77,111 @@ impl Variant {
         T::try_from_variant(self)
     }
 
+    /// Convert to type `T`, after checking that this variant holds the Godot type that `T` is represented by.
+    ///
+    /// In contrast to [`try_to()`][Self::try_to], a type mismatch is always reported as such, e.g. `expected type ARRAY, got
+    /// DICTIONARY` when converting a dictionary to `Vec<i32>`. This keeps validation of untrusted data precise, also for types whose
+    /// [`FromGodot`] impl would otherwise produce a custom error message.
+    ///
+    /// `NIL` variants are passed on to the conversion, since they are valid for `Option<T>` and `Variant` targets.
+    pub fn expect_type<T: FromGodot>(&self) -> Result<T, ConvertError> {
+        let expected = <<T as GodotConvert>::Via as GodotType>::Ffi::variant_type();
+        let actual = self.get_type();
+
+        // A `Variant` target has expected type NIL and accepts everything.
+        if expected != VariantType::NIL && actual != VariantType::NIL && actual != expected {
+            return Err(FromVariantError::BadType { expected, actual }.into_error(self.clone()));
+        }
+
+        self.try_to()
+    }
+
+    /// Convert an object variant to `Gd<T>`, checking the object's runtime class.
+    ///
+    /// This is the typed counterpart to object arguments received as `Variant`, e.g. in signal handlers connected through
+    /// [`Callable`][crate::builtin::Callable]s. Unlike [`try_to::<Option<Gd<T>>>()`][Self::try_to], nil is not accepted.
+    ///
+    /// # Errors
+    /// - If the variant is nil, which includes null objects.
+    /// - If the variant does not hold an object, e.g. an `INT`.
+    /// - If the object's class is neither `T` nor derived from `T`.
+    pub fn to_gd<T: GodotClass>(&self) -> Result<Gd<T>, ConvertError> {
+        let actual = self.get_type();
+        if actual != VariantType::OBJECT {
+            return Err(FromVariantError::BadType {
+                expected: VariantType::OBJECT,
+                actual,
+            }
+            .into_error(self.clone()));
+        }
+
+        // Reports `WrongClass` if the runtime class does not match.
+        self.try_to::<Gd<T>>()
+    }
+
+    /// Convert a numeric variant to integer type `T`, clamping values outside of `T`'s range.
+    ///
+    /// This is useful to ingest numbers from scripts or data files, where magnitudes are not guaranteed. For example, an `INT` variant
+    /// holding `i64::MAX` converts to `i32::MAX`, and `-1` converts to `0u8`. In contrast, [`try_to()`][Self::try_to] fails for such
+    /// values, see also [`to_checked()`][Self::to_checked].
+    ///
+    /// Both `INT` and `FLOAT` variants are accepted; floats are truncated towards zero before clamping.
+    ///
+    /// # Errors
+    /// If the variant holds neither `INT` nor `FLOAT`, or a NaN float.
+    pub fn to_saturating<T: IntegerTarget>(&self) -> Result<T, ConvertError> {
+        match self.get_type() {
+            VariantType::INT => Ok(T::saturating_from_i64(self.to::<i64>())),
+            VariantType::FLOAT => {
+                let value = self.to::<f64>();
+                if value.is_nan() {
+                    return Err(FromVariantError::BadValue.into_error(self.clone()));
+                }
+
+                Ok(T::saturating_from_f64(value))
+            }
+            actual => Err(FromVariantError::BadType {
+                expected: VariantType::INT,
+                actual,
+            }
+            .into_error(self.clone())),
+        }
+    }
+
+    /// Convert a numeric variant to integer type `T`, returning `Err` if the value does not fit.
+    ///
+    /// Like [`to_saturating()`][Self::to_saturating], this accepts `INT` and `FLOAT` variants. Floats must hold an integral value, so
+    /// `3.0` converts to `3`, while `3.5` is an error.
+    ///
+    /// # Errors
+    /// If the variant holds neither `INT` nor `FLOAT`, or a value outside `T`'s range, or a non-integral or NaN float.
+    pub fn to_checked<T: IntegerTarget>(&self) -> Result<T, ConvertError> {
+        let out_of_range = || {
+            FromVariantError::IntOutOfRange {
+                target: T::TYPE_NAME,
+            }
+            .into_error(self.clone())
+        };
+
+        match self.get_type() {
+            VariantType::INT => T::checked_from_i64(self.to::<i64>()).ok_or_else(out_of_range),
+            VariantType::FLOAT => {
+                let value = self.to::<f64>();
+                // Infinite floats are reported as out of range; NaN as bad value.
+                if !value.is_infinite() && value.fract() != 0.0 {
+                    return Err(FromVariantError::BadValue.into_error(self.clone()));
+                }
+
+                T::checked_from_f64(value).ok_or_else(out_of_range)
+            }
+            actual => Err(FromVariantError::BadType {
+                expected: VariantType::INT,
+                actual,
+            }
+            .into_error(self.clone())),
+        }
+    }
+
     /// Checks whether the variant is empty (`null` value in GDScript).
     ///
     /// See also [`Self::get_type`].
@@ -106,6 +220,24 @@ impl Variant {
         }
     }
 
+    /// Captures the storage properties of the object held by this variant, as a dictionary from property name to value.
+    ///
+    /// Equivalent to [`Object::to_dictionary()`][crate::classes::Object::to_dictionary] with
+    /// [`ObjectProperties::ByReference`][crate::tools::ObjectProperties::ByReference]; nested objects are thus stored as references to
+    /// their instance ID.
+    ///
+    /// Returns `None` if the variant does not hold an object, or if the object has been freed.
+    pub fn object_properties(&self) -> Option<Dictionary> {
+        // Check validity via instance ID before converting, since the variant of a freed object still holds its dangling pointer.
+        if self.get_type() != VariantType::OBJECT || !crate::gen::utilities::is_instance_valid(self)
+        {
+            return None;
+        }
+
+        let object = self.try_to::<Gd<crate::classes::Object>>().ok()?;
+        Some(object.to_dictionary(crate::tools::ObjectProperties::ByReference))
+    }
+
     /// ⚠️ Calls the specified `method` with the given `args`.
     ///
     /// Supports `Object` as well as built-ins with methods (e.g. `Array`, `Vector3`, `GString`, etc.).
@@ -174,6 +306,89 @@ impl Variant {
         }
     }
 
+    /// Returns the named component `name` of a built-in value, such as `x` of a `Vector2` or `r` of a `Color`.
+    ///
+    /// This mirrors GDScript's `value.x` and `value["x"]` syntax on built-in types, and allows generic access to sub-properties, e.g.
+    /// to animate a single component of a property. Available components are those listed in Godot's docs for each built-in type,
+    /// including derived ones such as `h` (hue) of `Color`.
+    ///
+    /// # Errors
+    /// If the variant's type has no component `name`. Objects are not supported; use [`Object::get()`][crate::classes::Object::get]
+    /// for their properties instead.
+    pub fn get_component(&self, name: impl Into<StringName>) -> Result<Variant, PropertyError> {
+        let name = name.into();
+
+        if self.get_type() == VariantType::OBJECT {
+            return Err(self.component_not_found(&name));
+        }
+
+        self.get_named(&name)
+            .ok_or_else(|| self.component_not_found(&name))
+    }
+
+    /// Sets the named component `name` of a built-in value to `value`, such as `x` of a `Vector2` or `r` of a `Color`.
+    ///
+    /// The value is converted to the component's type like in GDScript, e.g. an `INT` can be assigned to a float component.
+    /// See [`get_component()`][Self::get_component] for details.
+    ///
+    /// # Errors
+    /// If the variant's type has no component `name`, or if `value` cannot be assigned to it. In that case, the variant is unchanged.
+    pub fn set_component(
+        &mut self,
+        name: impl Into<StringName>,
+        value: &Variant,
+    ) -> Result<(), PropertyError> {
+        let name = name.into();
+
+        if self.get_type() == VariantType::OBJECT {
+            return Err(self.component_not_found(&name));
+        }
+
+        let mut is_valid = false as u8;
+        unsafe {
+            interface_fn!(variant_set_named)(
+                self.var_sys_mut(),
+                name.string_sys(),
+                value.var_sys(),
+                ptr::addr_of_mut!(is_valid),
+            )
+        };
+
+        if is_valid == 1 {
+            Ok(())
+        } else {
+            Err(self.component_not_found(&name))
+        }
+    }
+
+    fn component_not_found(&self, name: &StringName) -> PropertyError {
+        PropertyError::not_found(format!("{:?}.{name}", self.get_type()), name.to_string())
+    }
+
+    /// Returns the named member `key` of this variant, or `None` if no such member exists.
+    ///
+    /// For objects, this is a property lookup; for built-ins, it accesses named components such as `x` or `r`.
+    pub(crate) fn get_named(&self, key: &StringName) -> Option<Variant> {
+        let mut is_valid = false as u8;
+
+        let result = unsafe {
+            Self::new_with_var_uninit(|variant_ptr| {
+                interface_fn!(variant_get_named)(
+                    self.var_sys(),
+                    key.string_sys(),
+                    variant_ptr,
+                    ptr::addr_of_mut!(is_valid),
+                )
+            })
+        };
+
+        if is_valid == 1 {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn sys_type(&self) -> sys::GDExtensionVariantType {
         unsafe {
             let ty: sys::GDExtensionVariantType = interface_fn!(variant_get_type)(self.var_sys());
@@ -200,6 +415,47 @@ impl Variant {
         unsafe { interface_fn!(variant_hash)(self.var_sys()) }
     }
 
+    /// Compares two variants the way Godot compares dictionary keys, which is consistent with [`hash()`][Self::hash].
+    ///
+    /// Unlike `==`, this requires both variants to have the same type (`1` and `1.0` differ), and NaN floats are equal to themselves.
+    /// Objects are compared by identity.
+    pub(crate) fn hash_eq(&self, other: &Variant) -> bool {
+        unsafe { interface_fn!(variant_hash_compare)(self.var_sys(), other.var_sys()) != 0 }
+    }
+
+    /// Returns `true` if both variants hold the same `Array` or `Dictionary`, so that modifications through one are visible in the other.
+    ///
+    /// Arrays and dictionaries have reference semantics: cloning a variant, or passing it to several objects, does not copy the container.
+    /// Godot does not expose how many references to a container exist, so sharing can only be detected between two given variants.
+    ///
+    /// Other types are never considered shared. Strings and packed arrays are copy-on-write, and thus behave like values; objects are
+    /// always referenced by identity.
+    pub fn is_shared_with(&self, other: &Variant) -> bool {
+        matches!(
+            self.get_type(),
+            VariantType::ARRAY | VariantType::DICTIONARY
+        ) && crate::global::is_same(self, other)
+    }
+
+    /// Replaces an `Array` or `Dictionary` with a deep copy, so that it no longer shares data with other variants.
+    ///
+    /// Nested arrays and dictionaries are copied as well; typed arrays keep their element type. Since it is not known whether a container
+    /// is actually shared (see [`is_shared_with()`][Self::is_shared_with]), the copy is always made. The copy of a read-only container
+    /// is not read-only. Other types are left unchanged.
+    pub fn ensure_unique(&mut self) {
+        match self.get_type() {
+            VariantType::ARRAY => {
+                // SAFETY: Type is checked, and duplication does not rely on the element type; the copy keeps the type of the original.
+                let array = unsafe { VariantArray::from_variant_unchecked(self) };
+                *self = array.duplicate_deep().to_variant();
+            }
+            VariantType::DICTIONARY => {
+                *self = self.to::<Dictionary>().duplicate_deep().to_variant();
+            }
+            _ => {}
+        }
+    }
+
     /// Interpret the `Variant` as `bool`.
     ///
     /// Returns `false` only if the variant's current value is the default value for its type. For example:
@@ -441,6 +697,33 @@ impl PartialEq for Variant {
     }
 }
 
+/// Orders variants with Godot's comparison operators, as [`evaluate()`][Variant::evaluate] does with `EQUAL`, `LESS` and `GREATER`.
+///
+/// Returns `None` if the variants are incomparable: if Godot defines no ordering between their types (e.g. `Dictionary`, or `int` and
+/// `String`), or if none of the operators holds (e.g. NaN floats). Equal values of unordered types, such as two identical
+/// dictionaries, still compare as `Equal`, consistent with `==`.
+///
+/// Like in GDScript, some different types are comparable: `int` and `float` are compared numerically, and `String` and `StringName`
+/// by their content. `Array`s are compared element-wise.
+///
+/// This is not a total order, not even among values of one type (e.g. NaN floats, or arrays containing them). Treating incomparable values
+/// as equal, e.g. in `sort_by()`, therefore does not produce a consistent ordering, and Rust's sort functions may panic on it.
+impl PartialOrd for Variant {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let holds = |op| Self::evaluate(self, other, op).is_some_and(|result| result.booleanize());
+
+        if holds(VariantOperator::EQUAL) {
+            Some(std::cmp::Ordering::Equal)
+        } else if holds(VariantOperator::LESS) {
+            Some(std::cmp::Ordering::Less)
+        } else if holds(VariantOperator::GREATER) {
+            Some(std::cmp::Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
 impl fmt::Display for Variant {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = self.stringify();
@@ -463,3 +746,137 @@ impl fmt::Debug for Variant {
         }
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Matches on the type of a [`Variant`], binding its converted value in each arm.
+///
+/// Each arm names a variant type, optionally followed by a pattern for the value, which is converted to the corresponding Rust type.
+/// Arms are checked in order, and a final `_` arm handles all other types. Arms must be separated by commas, also if their body is a
+/// block.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::builtin::match_variant;
+///
+/// fn describe(value: &Variant) -> String {
+///     match_variant!(value, {
+///         Int(i) => format!("int {i}"),
+///         GString(s) => format!("string {s}"),
+///         Array(a) => format!("array of {} elements", a.len()),
+///         Nil => "nothing".to_string(),
+///         _ => format!("other {:?}", value.get_type()),
+///     })
+/// }
+/// ```
+///
+/// The type names are those of the Rust types: `Nil`, `Bool`, `Int`, `Float`, `GString`, `StringName`, `NodePath`, `Object`, `Array`,
+/// `Dictionary`, `Vector2`, `Color`, `PackedByteArray`, etc. Values are converted to `()`, `bool`, `i64`, `f64`, `Gd<Object>`,
+/// [`VariantArray`][crate::builtin::VariantArray] and the respective builtin types. Like [`Variant::get_type()`], a null object is
+/// matched by `Nil`, not `Object`.
+///
+/// The macro expands to a comparison of [`Variant::get_type()`] with each arm's type, followed by a conversion of the matching one.
+#[macro_export]
+macro_rules! match_variant {
+    ($variant:expr, { $( $Kind:ident $( ( $binding:pat ) )? => $body:expr ),+ , _ => $fallback:expr $(,)? }) => {{
+        let variant: &$crate::builtin::Variant = &$variant;
+        let variant_type = variant.get_type();
+
+        $(
+            if variant_type == $crate::__match_variant_kind!(@tag $Kind) {
+                $(
+                    let $binding: $crate::__match_variant_kind!(@type $Kind) =
+                        $crate::meta::FromGodot::from_variant(variant);
+                )?
+                $body
+            } else
+        )+
+        {
+            $fallback
+        }
+    }};
+}
+
+/// Maps the type names accepted by [`match_variant!`] to their `VariantType` and Rust type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __match_variant_kind {
+    (@tag Nil) => { $crate::builtin::VariantType::NIL };
+    (@type Nil) => { () };
+    (@tag Bool) => { $crate::builtin::VariantType::BOOL };
+    (@type Bool) => { bool };
+    (@tag Int) => { $crate::builtin::VariantType::INT };
+    (@type Int) => { i64 };
+    (@tag Float) => { $crate::builtin::VariantType::FLOAT };
+    (@type Float) => { f64 };
+    (@tag GString) => { $crate::builtin::VariantType::STRING };
+    (@type GString) => { $crate::builtin::GString };
+    (@tag Vector2) => { $crate::builtin::VariantType::VECTOR2 };
+    (@type Vector2) => { $crate::builtin::Vector2 };
+    (@tag Vector2i) => { $crate::builtin::VariantType::VECTOR2I };
+    (@type Vector2i) => { $crate::builtin::Vector2i };
+    (@tag Rect2) => { $crate::builtin::VariantType::RECT2 };
+    (@type Rect2) => { $crate::builtin::Rect2 };
+    (@tag Rect2i) => { $crate::builtin::VariantType::RECT2I };
+    (@type Rect2i) => { $crate::builtin::Rect2i };
+    (@tag Vector3) => { $crate::builtin::VariantType::VECTOR3 };
+    (@type Vector3) => { $crate::builtin::Vector3 };
+    (@tag Vector3i) => { $crate::builtin::VariantType::VECTOR3I };
+    (@type Vector3i) => { $crate::builtin::Vector3i };
+    (@tag Transform2D) => { $crate::builtin::VariantType::TRANSFORM2D };
+    (@type Transform2D) => { $crate::builtin::Transform2D };
+    (@tag Vector4) => { $crate::builtin::VariantType::VECTOR4 };
+    (@type Vector4) => { $crate::builtin::Vector4 };
+    (@tag Vector4i) => { $crate::builtin::VariantType::VECTOR4I };
+    (@type Vector4i) => { $crate::builtin::Vector4i };
+    (@tag Plane) => { $crate::builtin::VariantType::PLANE };
+    (@type Plane) => { $crate::builtin::Plane };
+    (@tag Quaternion) => { $crate::builtin::VariantType::QUATERNION };
+    (@type Quaternion) => { $crate::builtin::Quaternion };
+    (@tag Aabb) => { $crate::builtin::VariantType::AABB };
+    (@type Aabb) => { $crate::builtin::Aabb };
+    (@tag Basis) => { $crate::builtin::VariantType::BASIS };
+    (@type Basis) => { $crate::builtin::Basis };
+    (@tag Transform3D) => { $crate::builtin::VariantType::TRANSFORM3D };
+    (@type Transform3D) => { $crate::builtin::Transform3D };
+    (@tag Projection) => { $crate::builtin::VariantType::PROJECTION };
+    (@type Projection) => { $crate::builtin::Projection };
+    (@tag Color) => { $crate::builtin::VariantType::COLOR };
+    (@type Color) => { $crate::builtin::Color };
+    (@tag StringName) => { $crate::builtin::VariantType::STRING_NAME };
+    (@type StringName) => { $crate::builtin::StringName };
+    (@tag NodePath) => { $crate::builtin::VariantType::NODE_PATH };
+    (@type NodePath) => { $crate::builtin::NodePath };
+    (@tag Rid) => { $crate::builtin::VariantType::RID };
+    (@type Rid) => { $crate::builtin::Rid };
+    (@tag Object) => { $crate::builtin::VariantType::OBJECT };
+    (@type Object) => { $crate::obj::Gd<$crate::classes::Object> };
+    (@tag Callable) => { $crate::builtin::VariantType::CALLABLE };
+    (@type Callable) => { $crate::builtin::Callable };
+    (@tag Signal) => { $crate::builtin::VariantType::SIGNAL };
+    (@type Signal) => { $crate::builtin::Signal };
+    (@tag Dictionary) => { $crate::builtin::VariantType::DICTIONARY };
+    (@type Dictionary) => { $crate::builtin::Dictionary };
+    (@tag Array) => { $crate::builtin::VariantType::ARRAY };
+    (@type Array) => { $crate::builtin::VariantArray };
+    (@tag PackedByteArray) => { $crate::builtin::VariantType::PACKED_BYTE_ARRAY };
+    (@type PackedByteArray) => { $crate::builtin::PackedByteArray };
+    (@tag PackedInt32Array) => { $crate::builtin::VariantType::PACKED_INT32_ARRAY };
+    (@type PackedInt32Array) => { $crate::builtin::PackedInt32Array };
+    (@tag PackedInt64Array) => { $crate::builtin::VariantType::PACKED_INT64_ARRAY };
+    (@type PackedInt64Array) => { $crate::builtin::PackedInt64Array };
+    (@tag PackedFloat32Array) => { $crate::builtin::VariantType::PACKED_FLOAT32_ARRAY };
+    (@type PackedFloat32Array) => { $crate::builtin::PackedFloat32Array };
+    (@tag PackedFloat64Array) => { $crate::builtin::VariantType::PACKED_FLOAT64_ARRAY };
+    (@type PackedFloat64Array) => { $crate::builtin::PackedFloat64Array };
+    (@tag PackedStringArray) => { $crate::builtin::VariantType::PACKED_STRING_ARRAY };
+    (@type PackedStringArray) => { $crate::builtin::PackedStringArray };
+    (@tag PackedVector2Array) => { $crate::builtin::VariantType::PACKED_VECTOR2_ARRAY };
+    (@type PackedVector2Array) => { $crate::builtin::PackedVector2Array };
+    (@tag PackedVector3Array) => { $crate::builtin::VariantType::PACKED_VECTOR3_ARRAY };
+    (@type PackedVector3Array) => { $crate::builtin::PackedVector3Array };
+    (@tag PackedColorArray) => { $crate::builtin::VariantType::PACKED_COLOR_ARRAY };
+    (@type PackedColorArray) => { $crate::builtin::PackedColorArray };
+    (@tag PackedVector4Array) => { $crate::builtin::VariantType::PACKED_VECTOR4_ARRAY };
+    (@type PackedVector4Array) => { $crate::builtin::PackedVector4Array };
+}
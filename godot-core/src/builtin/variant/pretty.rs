@@ -0,0 +1,154 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::Write;
+
+use crate::builtin::{
+    Dictionary, GString, NodePath, StringName, Variant, VariantArray, VariantType,
+};
+use crate::global::{is_same, type_string};
+use crate::obj::EngineEnum;
+
+impl Variant {
+    /// Returns a multi-line, indented representation of the variant, with the type of each value annotated.
+    ///
+    /// Dictionaries and arrays are expanded recursively; each nesting level is indented by `indent` spaces. Every other value is printed
+    /// as its type name followed by its [`stringify()`][Self::stringify] representation, with strings quoted. This is intended for logs
+    /// and test snapshots, not for parsing -- use `JSON` or `var_to_str()` for round-trips.
+    ///
+    /// Containers which (directly or indirectly) contain themselves are not expanded again; the repeated occurrence is printed as
+    /// `<cycle>`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let dict = dict! { "name": "Godot", "versions": array![3, 4] };
+    /// let pretty = dict.to_variant().to_pretty_string(2);
+    ///
+    /// assert_eq!(pretty, r#"Dictionary {
+    ///   String "name": String "Godot",
+    ///   String "versions": Array [
+    ///     int 3,
+    ///     int 4
+    ///   ]
+    /// }"#);
+    /// ```
+    pub fn to_pretty_string(&self, indent: usize) -> String {
+        let mut printer = PrettyPrinter {
+            out: String::new(),
+            indent,
+            ancestors: Vec::new(),
+        };
+
+        printer.write_value(self, 0);
+        printer.out
+    }
+}
+
+struct PrettyPrinter {
+    out: String,
+    indent: usize,
+
+    /// Containers currently being expanded, from outermost to innermost. Used to detect cycles.
+    ancestors: Vec<Variant>,
+}
+
+impl PrettyPrinter {
+    fn write_value(&mut self, value: &Variant, depth: usize) {
+        let ty = value.get_type();
+        match ty {
+            VariantType::NIL => self.out.push_str("null"),
+            VariantType::DICTIONARY | VariantType::ARRAY => self.write_container(value, ty, depth),
+            VariantType::STRING => self.write_quoted(ty, value.to::<GString>().to_string()),
+            VariantType::STRING_NAME => self.write_quoted(ty, value.to::<StringName>().to_string()),
+            VariantType::NODE_PATH => self.write_quoted(ty, value.to::<NodePath>().to_string()),
+            _ => {
+                let _ = write!(self.out, "{} {}", type_name(ty), value.stringify());
+            }
+        }
+    }
+
+    fn write_container(&mut self, value: &Variant, ty: VariantType, depth: usize) {
+        self.out.push_str(&type_name(ty));
+
+        if self
+            .ancestors
+            .iter()
+            .any(|ancestor| is_same(ancestor, value))
+        {
+            self.out.push_str(" <cycle>");
+            return;
+        }
+
+        self.ancestors.push(value.clone());
+        if ty == VariantType::DICTIONARY {
+            self.write_dictionary(&value.to::<Dictionary>(), depth);
+        } else {
+            // SAFETY: type is checked, and the array is only read. Avoids conversion failures for typed arrays, see `Debug` impl.
+            let array = unsafe { VariantArray::from_variant_unchecked(value) };
+            self.write_array(&array, depth);
+        }
+        self.ancestors.pop();
+    }
+
+    fn write_dictionary(&mut self, dictionary: &Dictionary, depth: usize) {
+        if dictionary.is_empty() {
+            self.out.push_str(" {}");
+            return;
+        }
+
+        self.out.push_str(" {");
+        for (index, (key, value)) in dictionary.iter_shared().enumerate() {
+            self.write_separator(index, depth + 1);
+            self.write_value(&key, depth + 1);
+            self.out.push_str(": ");
+            self.write_value(&value, depth + 1);
+        }
+        self.write_closing('}', depth);
+    }
+
+    fn write_array(&mut self, array: &VariantArray, depth: usize) {
+        if array.is_empty() {
+            self.out.push_str(" []");
+            return;
+        }
+
+        self.out.push_str(" [");
+        for (index, element) in array.iter_shared().enumerate() {
+            self.write_separator(index, depth + 1);
+            self.write_value(&element, depth + 1);
+        }
+        self.write_closing(']', depth);
+    }
+
+    fn write_quoted(&mut self, ty: VariantType, string: String) {
+        let _ = write!(self.out, "{} {string:?}", type_name(ty));
+    }
+
+    /// Starts a new line for the container element at `index`, including the comma after the previous element.
+    fn write_separator(&mut self, index: usize, depth: usize) {
+        if index > 0 {
+            self.out.push(',');
+        }
+        self.write_newline(depth);
+    }
+
+    fn write_closing(&mut self, bracket: char, depth: usize) {
+        self.write_newline(depth);
+        self.out.push(bracket);
+    }
+
+    fn write_newline(&mut self, depth: usize) {
+        self.out.push('\n');
+        self.out
+            .extend(std::iter::repeat(' ').take(depth * self.indent));
+    }
+}
+
+fn type_name(ty: VariantType) -> String {
+    type_string(ty.ord() as i64).to_string()
+}
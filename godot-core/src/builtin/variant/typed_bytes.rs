@@ -0,0 +1,316 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{
+    real, Aabb, Basis, Color, Plane, Projection, Quaternion, Rect2, Rect2i, Transform2D,
+    Transform3D, Variant, VariantType, Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i,
+};
+use crate::meta::error::TypedBytesError;
+use crate::meta::ToGodot;
+
+/// Size of a floating-point component, independent of the `double-precision` feature.
+const REAL: usize = std::mem::size_of::<f32>();
+
+impl Variant {
+    /// Interprets `bytes` as a value of type `variant_type`, without any header.
+    ///
+    /// This complements Godot's `bytes_to_var()` for formats that store the type separately, e.g. in a schema. Only fixed-size types
+    /// are supported: `INT`, `FLOAT`, `COLOR` and the vector, rectangle, transform and other math types. Their components are stored
+    /// in order of declaration and little-endian:
+    /// - `INT` and `FLOAT` as 64-bit values, `COLOR` as four `f32`.
+    /// - Integer vectors and rectangles as `i32` components.
+    /// - All other components as `f32`, regardless of the `double-precision` feature, so that the format is the same in every build.
+    ///   Matrices are stored like in memory: [`Basis`] row by row, [`Projection`] column by column.
+    ///
+    /// For example, a `VECTOR3` is always 12 bytes, holding `x`, `y` and `z`.
+    ///
+    /// # Errors
+    /// If `variant_type` has no fixed-size representation, or if the length of `bytes` does not match it.
+    pub fn from_typed_bytes(
+        variant_type: VariantType,
+        bytes: &[u8],
+    ) -> Result<Variant, TypedBytesError> {
+        let expected = typed_byte_len(variant_type)
+            .ok_or_else(|| TypedBytesError::unsupported_type(variant_type))?;
+
+        if bytes.len() != expected {
+            return Err(TypedBytesError::length_mismatch(
+                variant_type,
+                expected,
+                bytes.len(),
+            ));
+        }
+
+        let mut reader = ByteReader { bytes };
+        let variant = match variant_type {
+            VariantType::INT => i64::from_le_bytes(reader.take()).to_variant(),
+            VariantType::FLOAT => f64::from_le_bytes(reader.take()).to_variant(),
+            VariantType::VECTOR2 => reader.vector2().to_variant(),
+            VariantType::VECTOR2I => reader.vector2i().to_variant(),
+            VariantType::RECT2 => Rect2::new(reader.vector2(), reader.vector2()).to_variant(),
+            VariantType::RECT2I => Rect2i::new(reader.vector2i(), reader.vector2i()).to_variant(),
+            VariantType::VECTOR3 => reader.vector3().to_variant(),
+            VariantType::VECTOR3I => reader.vector3i().to_variant(),
+            VariantType::TRANSFORM2D => {
+                Transform2D::from_cols(reader.vector2(), reader.vector2(), reader.vector2())
+                    .to_variant()
+            }
+            VariantType::VECTOR4 => reader.vector4().to_variant(),
+            VariantType::VECTOR4I => {
+                let [x, y, z, w] = [reader.int(), reader.int(), reader.int(), reader.int()];
+                Vector4i::new(x, y, z, w).to_variant()
+            }
+            VariantType::PLANE => Plane {
+                normal: reader.vector3(),
+                d: reader.real(),
+            }
+            .to_variant(),
+            VariantType::QUATERNION => {
+                let [x, y, z, w] = [reader.real(), reader.real(), reader.real(), reader.real()];
+                Quaternion { x, y, z, w }.to_variant()
+            }
+            VariantType::AABB => Aabb::new(reader.vector3(), reader.vector3()).to_variant(),
+            VariantType::BASIS => reader.basis().to_variant(),
+            VariantType::TRANSFORM3D => {
+                Transform3D::new(reader.basis(), reader.vector3()).to_variant()
+            }
+            VariantType::PROJECTION => Projection::from_cols(
+                reader.vector4(),
+                reader.vector4(),
+                reader.vector4(),
+                reader.vector4(),
+            )
+            .to_variant(),
+            VariantType::COLOR => {
+                let [r, g, b, a] = [reader.f32(), reader.f32(), reader.f32(), reader.f32()];
+                Color::from_rgba(r, g, b, a).to_variant()
+            }
+            _ => unreachable!("type {variant_type:?} has a byte length"),
+        };
+
+        Ok(variant)
+    }
+
+    /// Returns the raw bytes of this variant's value, in the format read by [`from_typed_bytes()`][Self::from_typed_bytes].
+    ///
+    /// The type itself is not part of the output; use [`get_type()`][Self::get_type] to store it separately.
+    ///
+    /// Floating-point components other than `FLOAT` are written as `f32`. With the `double-precision` feature, this rounds [`real`]
+    /// values to the nearest `f32`, so they may not round-trip exactly.
+    ///
+    /// # Errors
+    /// If the variant's type has no fixed-size representation.
+    pub fn to_typed_bytes(&self) -> Result<Vec<u8>, TypedBytesError> {
+        let variant_type = self.get_type();
+        let len = typed_byte_len(variant_type)
+            .ok_or_else(|| TypedBytesError::unsupported_type(variant_type))?;
+
+        let mut writer = ByteWriter {
+            bytes: Vec::with_capacity(len),
+        };
+        match variant_type {
+            VariantType::INT => writer.put(self.to::<i64>().to_le_bytes()),
+            VariantType::FLOAT => writer.put(self.to::<f64>().to_le_bytes()),
+            VariantType::VECTOR2 => writer.vector2(self.to()),
+            VariantType::VECTOR2I => writer.vector2i(self.to()),
+            VariantType::RECT2 => {
+                let rect = self.to::<Rect2>();
+                writer.vector2(rect.position);
+                writer.vector2(rect.size);
+            }
+            VariantType::RECT2I => {
+                let rect = self.to::<Rect2i>();
+                writer.vector2i(rect.position);
+                writer.vector2i(rect.size);
+            }
+            VariantType::VECTOR3 => writer.vector3(self.to()),
+            VariantType::VECTOR3I => writer.vector3i(self.to()),
+            VariantType::TRANSFORM2D => {
+                let transform = self.to::<Transform2D>();
+                writer.vector2(transform.a);
+                writer.vector2(transform.b);
+                writer.vector2(transform.origin);
+            }
+            VariantType::VECTOR4 => writer.vector4(self.to()),
+            VariantType::VECTOR4I => {
+                let v = self.to::<Vector4i>();
+                for c in [v.x, v.y, v.z, v.w] {
+                    writer.int(c);
+                }
+            }
+            VariantType::PLANE => {
+                let plane = self.to::<Plane>();
+                writer.vector3(plane.normal);
+                writer.real(plane.d);
+            }
+            VariantType::QUATERNION => {
+                let q = self.to::<Quaternion>();
+                for c in [q.x, q.y, q.z, q.w] {
+                    writer.real(c);
+                }
+            }
+            VariantType::AABB => {
+                let aabb = self.to::<Aabb>();
+                writer.vector3(aabb.position);
+                writer.vector3(aabb.size);
+            }
+            VariantType::BASIS => writer.basis(self.to()),
+            VariantType::TRANSFORM3D => {
+                let transform = self.to::<Transform3D>();
+                writer.basis(transform.basis);
+                writer.vector3(transform.origin);
+            }
+            VariantType::PROJECTION => {
+                for col in self.to::<Projection>().cols {
+                    writer.vector4(col);
+                }
+            }
+            VariantType::COLOR => {
+                let color = self.to::<Color>();
+                for c in [color.r, color.g, color.b, color.a] {
+                    writer.put(c.to_le_bytes());
+                }
+            }
+            _ => unreachable!("type {variant_type:?} has a byte length"),
+        }
+
+        debug_assert_eq!(writer.bytes.len(), len);
+        Ok(writer.bytes)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Number of bytes used by [`Variant::from_typed_bytes()`] for a type, or `None` if not supported.
+fn typed_byte_len(variant_type: VariantType) -> Option<usize> {
+    let len = match variant_type {
+        VariantType::INT | VariantType::FLOAT => 8,
+        VariantType::VECTOR2 => 2 * REAL,
+        VariantType::VECTOR2I => 2 * 4,
+        VariantType::RECT2 => 4 * REAL,
+        VariantType::RECT2I => 4 * 4,
+        VariantType::VECTOR3 => 3 * REAL,
+        VariantType::VECTOR3I => 3 * 4,
+        VariantType::TRANSFORM2D => 6 * REAL,
+        VariantType::VECTOR4 => 4 * REAL,
+        VariantType::VECTOR4I => 4 * 4,
+        VariantType::PLANE => 4 * REAL,
+        VariantType::QUATERNION => 4 * REAL,
+        VariantType::AABB => 6 * REAL,
+        VariantType::BASIS => 9 * REAL,
+        VariantType::TRANSFORM3D => 12 * REAL,
+        VariantType::PROJECTION => 16 * REAL,
+        VariantType::COLOR => 4 * 4,
+        _ => return None,
+    };
+
+    Some(len)
+}
+
+/// Reads components front to back. The total length is validated upfront.
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl ByteReader<'_> {
+    fn take<const N: usize>(&mut self) -> [u8; N] {
+        let (head, tail) = self.bytes.split_at(N);
+        self.bytes = tail;
+        head.try_into().expect("split_at() yields N bytes")
+    }
+
+    fn real(&mut self) -> real {
+        self.f32() as real
+    }
+
+    fn f32(&mut self) -> f32 {
+        f32::from_le_bytes(self.take())
+    }
+
+    fn int(&mut self) -> i32 {
+        i32::from_le_bytes(self.take())
+    }
+
+    fn vector2(&mut self) -> Vector2 {
+        Vector2::new(self.real(), self.real())
+    }
+
+    fn vector2i(&mut self) -> Vector2i {
+        Vector2i::new(self.int(), self.int())
+    }
+
+    fn vector3(&mut self) -> Vector3 {
+        Vector3::new(self.real(), self.real(), self.real())
+    }
+
+    fn vector3i(&mut self) -> Vector3i {
+        Vector3i::new(self.int(), self.int(), self.int())
+    }
+
+    fn vector4(&mut self) -> Vector4 {
+        Vector4::new(self.real(), self.real(), self.real(), self.real())
+    }
+
+    fn basis(&mut self) -> Basis {
+        Basis::from_rows(self.vector3(), self.vector3(), self.vector3())
+    }
+}
+
+struct ByteWriter {
+    bytes: Vec<u8>,
+}
+
+impl ByteWriter {
+    fn put<const N: usize>(&mut self, bytes: [u8; N]) {
+        self.bytes.extend_from_slice(&bytes);
+    }
+
+    fn real(&mut self, value: real) {
+        self.put((value as f32).to_le_bytes());
+    }
+
+    fn int(&mut self, value: i32) {
+        self.put(value.to_le_bytes());
+    }
+
+    fn vector2(&mut self, v: Vector2) {
+        for c in [v.x, v.y] {
+            self.real(c);
+        }
+    }
+
+    fn vector2i(&mut self, v: Vector2i) {
+        for c in [v.x, v.y] {
+            self.int(c);
+        }
+    }
+
+    fn vector3(&mut self, v: Vector3) {
+        for c in [v.x, v.y, v.z] {
+            self.real(c);
+        }
+    }
+
+    fn vector3i(&mut self, v: Vector3i) {
+        for c in [v.x, v.y, v.z] {
+            self.int(c);
+        }
+    }
+
+    fn vector4(&mut self, v: Vector4) {
+        for c in [v.x, v.y, v.z, v.w] {
+            self.real(c);
+        }
+    }
+
+    fn basis(&mut self, basis: Basis) {
+        for row in basis.rows {
+            self.vector3(row);
+        }
+    }
+}
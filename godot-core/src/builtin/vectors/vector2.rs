@@ -235,6 +235,19 @@ mod test {
         assert_eq!(vector.sign(), Vector2::new(1., 0.));
     }
 
+    #[test]
+    fn array_tuple_conversions() {
+        let vector = Vector2::new(1.5, -2.0);
+
+        let array: [real; 2] = vector.into();
+        assert_eq!(array, [1.5, -2.0]);
+        assert_eq!(Vector2::from(array), vector);
+
+        let tuple: (real, real) = vector.into();
+        assert_eq!(tuple, (1.5, -2.0));
+        assert_eq!(Vector2::from(tuple), vector);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
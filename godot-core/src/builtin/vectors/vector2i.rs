@@ -141,6 +141,19 @@ mod test {
         assert_eq!(a.coord_max(b), Vector2i::new(1, 5));
     }
 
+    #[test]
+    fn array_tuple_conversions() {
+        let vector = Vector2i::new(3, -4);
+
+        let array: [i32; 2] = vector.into();
+        assert_eq!(array, [3, -4]);
+        assert_eq!(Vector2i::from(array), vector);
+
+        let tuple: (i32, i32) = vector.into();
+        assert_eq!(tuple, (3, -4));
+        assert_eq!(Vector2i::from(tuple), vector);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -412,6 +412,19 @@ mod test {
         assert_eq_approx!(sum_refs, Vector3::new(12.0, 15.0, 18.0));
     }
 
+    #[test]
+    fn array_tuple_conversions() {
+        let vector = Vector3::new(1.5, -2.0, 0.25);
+
+        let array: [real; 3] = vector.into();
+        assert_eq!(array, [1.5, -2.0, 0.25]);
+        assert_eq!(Vector3::from(array), vector);
+
+        let tuple: (real, real, real) = vector.into();
+        assert_eq!(tuple, (1.5, -2.0, 0.25));
+        assert_eq!(Vector3::from(tuple), vector);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -145,6 +145,19 @@ mod test {
         assert_eq!(a.coord_max(b), Vector3i::new(1, 5, 5));
     }
 
+    #[test]
+    fn array_tuple_conversions() {
+        let vector = Vector3i::new(3, -4, 5);
+
+        let array: [i32; 3] = vector.into();
+        assert_eq!(array, [3, -4, 5]);
+        assert_eq!(Vector3i::from(array), vector);
+
+        let tuple: (i32, i32, i32) = vector.into();
+        assert_eq!(tuple, (3, -4, 5));
+        assert_eq!(Vector3i::from(tuple), vector);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -144,6 +144,19 @@ mod test {
         assert_eq!(vector.sign(), Vector4::new(1., -1., 0., 1.));
     }
 
+    #[test]
+    fn array_tuple_conversions() {
+        let vector = Vector4::new(1.5, -2.0, 0.25, 8.0);
+
+        let array: [real; 4] = vector.into();
+        assert_eq!(array, [1.5, -2.0, 0.25, 8.0]);
+        assert_eq!(Vector4::from(array), vector);
+
+        let tuple: (real, real, real, real) = vector.into();
+        assert_eq!(tuple, (1.5, -2.0, 0.25, 8.0));
+        assert_eq!(Vector4::from(tuple), vector);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
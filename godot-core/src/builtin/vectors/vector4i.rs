@@ -148,6 +148,19 @@ mod test {
         assert_eq!(a.coord_max(b), Vector4i::new(1, 5, 5, 1));
     }
 
+    #[test]
+    fn array_tuple_conversions() {
+        let vector = Vector4i::new(3, -4, 5, -6);
+
+        let array: [i32; 4] = vector.into();
+        assert_eq!(array, [3, -4, 5, -6]);
+        assert_eq!(Vector4i::from(array), vector);
+
+        let tuple: (i32, i32, i32, i32) = vector.into();
+        assert_eq!(tuple, (3, -4, 5, -6));
+        assert_eq!(Vector4i::from(tuple), vector);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
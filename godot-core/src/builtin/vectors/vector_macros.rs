@@ -498,6 +498,34 @@ macro_rules! impl_vector_fns {
                 )
             }
         }
+
+        impl From<tuple_type!($Scalar; $($comp),*)> for $Vector {
+            #[inline]
+            fn from(tuple: tuple_type!($Scalar; $($comp),*)) -> Self {
+                Self::from_tuple(tuple)
+            }
+        }
+
+        impl From<array_type!($Scalar; $($comp),*)> for $Vector {
+            #[inline]
+            fn from(array: array_type!($Scalar; $($comp),*)) -> Self {
+                Self::from_array(array)
+            }
+        }
+
+        impl From<$Vector> for tuple_type!($Scalar; $($comp),*) {
+            #[inline]
+            fn from(vector: $Vector) -> Self {
+                vector.to_tuple()
+            }
+        }
+
+        impl From<$Vector> for array_type!($Scalar; $($comp),*) {
+            #[inline]
+            fn from(vector: $Vector) -> Self {
+                vector.to_array()
+            }
+        }
     }
 }
 
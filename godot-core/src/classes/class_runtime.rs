@@ -8,7 +8,7 @@
 //! Runtime checks and inspection of Godot classes.
 
 use crate::builtin::GString;
-use crate::classes::{ClassDb, Object};
+use crate::classes::{ClassDb, Node, Object};
 use crate::meta::{CallContext, ClassName};
 use crate::obj::{bounds, Bounds, Gd, GodotClass, InstanceId};
 use crate::sys;
@@ -18,18 +18,36 @@ pub(crate) fn debug_string<T: GodotClass>(
     f: &mut std::fmt::Formatter<'_>,
     ty: &str,
 ) -> std::fmt::Result {
-    if let Some(id) = obj.instance_id_or_none() {
-        let class: GString = obj.raw.as_object().get_class();
-        write!(f, "{ty} {{ id: {id}, class: {class} }}")
-    } else {
-        write!(f, "{ty} {{ freed obj }}")
+    let Some(id) = obj.instance_id_or_none() else {
+        return write!(f, "{ty} {{ freed obj }}");
+    };
+
+    let object = obj.raw.as_object();
+    let class: GString = object.get_class();
+    write!(f, "{ty} {{ id: {id}, class: {class}")?;
+
+    // Nodes are usually told apart by name; unnamed ones (e.g. not yet added to the tree) are printed like other objects.
+    // Calls `get_name()` directly instead of `get("name")`, which would go through a user class' `_get()` and bind the instance -- possibly
+    // while it is already bound mutably, e.g. when formatting `self.base()`.
+    if let Ok(node) = object.to_gd_object().try_cast::<Node>() {
+        let name = node.get_name();
+        if !name.is_empty() {
+            write!(f, ", name: \"{name}\"")?;
+        }
     }
+
+    write!(f, " }}")
 }
 
 pub(crate) fn display_string<T: GodotClass>(
     obj: &Gd<T>,
     f: &mut std::fmt::Formatter<'_>,
 ) -> std::fmt::Result {
+    // Same as Godot's `str()` of a freed object.
+    if obj.instance_id_or_none().is_none() {
+        return write!(f, "<Freed Object>");
+    }
+
     let string: GString = obj.raw.as_object().to_string();
     <GString as std::fmt::Display>::fmt(&string, f)
 }
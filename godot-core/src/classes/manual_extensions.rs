@@ -5,9 +5,682 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::NodePath;
-use crate::classes::{Node, PackedScene};
-use crate::obj::{Gd, Inherits};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use godot_ffi as sys;
+
+use crate::builtin::math::FloatExt;
+#[cfg(feature = "codegen-full")]
+use crate::builtin::{
+    real, real_consts, Array, Basis, Color, PackedFloat32Array, RealConv, Rect2i, Vector2i,
+};
+use crate::builtin::{
+    Callable, Dictionary, GString, NodePath, StringName, Transform2D, Transform3D, Variant,
+    VariantType, Vector2, Vector3,
+};
+use crate::classes::scene_tree::GroupCallFlags;
+use crate::classes::{Node, Object, PackedScene, SceneTree, Script, Viewport};
+use crate::global::{Error as GodotError, PropertyUsageFlags};
+use crate::meta::error::{
+    ConnectError, ConnectErrorKind, FromVariantError, PropertyError, SceneChangeError,
+    SceneChangeErrorKind,
+};
+use crate::meta::{FromGodot, PropertyInfo, ToGodot};
+use crate::obj::{EngineBitfield, EngineEnum, Gd, Inherits};
+#[cfg(since_api = "4.2")]
+use crate::tools::{
+    AudioPlaybackFuture, Debouncer, GroupNodeStream, NodeReadyFuture, ProcessCallbackHandle,
+    ReadyBarrierFuture, SignalFuture, TaskHandle,
+};
+use crate::tools::{MethodArgs, ObjectProperties, ProcessToggles};
+#[cfg(feature = "codegen-full")]
+use crate::tools::{RaycastHit, UndoAction, UndoManager};
+
+/// Manual extensions for the `Object` class.
+impl Object {
+    /// ⚠️ Retrieves the value at property path `path`, panicking if not found or bad type.
+    ///
+    /// See [`try_get_indexed_as()`][Self::try_get_indexed_as] for details on property paths.
+    ///
+    /// # Panics
+    /// If the property path does not exist on this object, or if its value cannot be converted to `V`.
+    pub fn get_indexed_as<V>(&self, path: impl Into<NodePath>) -> V
+    where
+        V: FromGodot,
+    {
+        self.try_get_indexed_as(path)
+            .unwrap_or_else(|err| panic!("get_indexed_as() failed: {err}"))
+    }
+
+    /// Retrieves the value at property path `path` (fallible).
+    ///
+    /// Property paths address a property, followed by any number of sub-properties, separated by colons. For example, `"position:x"`
+    /// reads the `x` component of the `position` property, and `"modulate:a"` the alpha channel of the `modulate` color.
+    ///
+    /// In contrast to [`get_indexed()`][Self::get_indexed], which returns `NIL` for non-existent paths, this method distinguishes
+    /// a missing (sub-)property from a value that cannot be converted to `V`. See [`PropertyError`].
+    pub fn try_get_indexed_as<V>(&self, path: impl Into<NodePath>) -> Result<V, PropertyError>
+    where
+        V: FromGodot,
+    {
+        let path = path.into();
+        let value = self.resolve_property_path(&path)?;
+
+        value
+            .try_to::<V>()
+            .map_err(|err| PropertyError::conversion(path.to_string(), err))
+    }
+
+    /// Reads the property `name` and converts it to `V`, after checking that the property exists.
+    ///
+    /// [`get()`][Self::get] returns `NIL` for non-existent properties, which cannot be told apart from a property holding `nil`.
+    /// This method looks up `name` in the [property list][Self::property_list] first, and reports three different failures through
+    /// [`PropertyError`]:
+    /// - The object has no property `name`: [`is_not_found()`][PropertyError::is_not_found].
+    /// - The property holds `nil`, which `V` does not accept: [`is_nil()`][PropertyError::is_nil].
+    /// - The property holds a value of another type: [`conversion_error()`][PropertyError::conversion_error].
+    ///
+    /// If `V` accepts `nil` (e.g. `Variant` or `Option<Gd<T>>`), a `nil` value is returned successfully.
+    pub fn get_property_checked<V>(&self, name: impl Into<StringName>) -> Result<V, PropertyError>
+    where
+        V: FromGodot,
+    {
+        let name = name.into();
+        let exists = self
+            .property_list()
+            .iter()
+            .any(|info| info.property_name == name);
+
+        if !exists {
+            return Err(PropertyError::not_found(name.to_string(), name.to_string()));
+        }
+
+        let value = self.get(name.clone());
+        value.try_to::<V>().map_err(|err| {
+            if value.is_nil() {
+                PropertyError::nil(name.to_string(), err)
+            } else {
+                PropertyError::conversion(name.to_string(), err)
+            }
+        })
+    }
+
+    /// Sets the value at property path `path`, after checking that the path exists.
+    ///
+    /// Accepts the same property paths as [`try_get_indexed_as()`][Self::try_get_indexed_as], e.g. `"modulate:a"`.
+    ///
+    /// Returns an error if a (sub-)property in the path does not exist. Note that Godot silently ignores values with an incompatible
+    /// type, so those cannot be detected here.
+    pub fn try_set_indexed(
+        &mut self,
+        path: impl Into<NodePath>,
+        value: impl ToGodot,
+    ) -> Result<(), PropertyError> {
+        let path = path.into();
+        self.resolve_property_path(&path)?;
+
+        self.set_indexed(path, &value.to_variant());
+        Ok(())
+    }
+
+    /// Schedules the property `property` to be set to `value` at idle time, after checking that the property exists.
+    ///
+    /// This wraps [`set_deferred()`][Self::set_deferred], accepting any [`ToGodot`] value. The assignment does not happen immediately:
+    /// Godot queues it as a deferred call, and performs it once the current frame's processing is done (after all `process()` and
+    /// `physics_process()` callbacks). Until then, reading the property returns its old value.
+    ///
+    /// This is useful when a signal handler wants to modify state that the engine is currently iterating over, for example
+    /// physics properties inside a collision callback.
+    ///
+    /// Returns an error if the property does not exist. Like with [`try_set_indexed()`][Self::try_set_indexed], values with an
+    /// incompatible type are silently ignored by Godot.
+    pub fn try_set_deferred(
+        &mut self,
+        property: impl Into<StringName>,
+        value: impl ToGodot,
+    ) -> Result<(), PropertyError> {
+        let property = property.into();
+        self.resolve_property_path(&NodePath::from(&property))?;
+
+        self.set_deferred(property, &value.to_variant());
+        Ok(())
+    }
+
+    /// Reads the variable `name` declared by the script attached to this object, such as a GDScript `var`.
+    ///
+    /// In contrast to [`get()`][Self::get], only properties defined by the script are considered. Engine properties (e.g. `name` on a
+    /// `Node`) yield `None`, even though `get()` would return their value. This is handy in projects mixing Rust and GDScript, where
+    /// part of an object's state lives on the script side.
+    ///
+    /// Returns `None` if no script is attached, if the script does not declare `name`, or if the value cannot be converted to `V`.
+    pub fn script_var<V>(&self, name: impl Into<StringName>) -> Option<V>
+    where
+        V: FromGodot,
+    {
+        let name = name.into();
+        let mut script = self.get_script().try_to::<Gd<Script>>().ok()?;
+
+        let name_str = name.to_string();
+        let is_script_var = script
+            .get_script_property_list()
+            .iter_shared()
+            .any(|property| {
+                property
+                    .get("name")
+                    .is_some_and(|n| n.to_string() == name_str)
+            });
+
+        if !is_script_var {
+            return None;
+        }
+
+        self.get(name).try_to::<V>().ok()
+    }
+
+    /// Returns all properties of this object, including those of its class hierarchy and attached script.
+    ///
+    /// Typed counterpart to [`get_property_list()`][Self::get_property_list], which returns one dictionary per property.
+    /// Apart from real properties, the list also contains entries for categories, groups and subgroups, which can be told apart
+    /// by their [`usage`][PropertyInfo::usage] flags. See [`PropertyInfo::from_dictionary()`] for details on the conversion.
+    pub fn property_list(&self) -> Vec<PropertyInfo> {
+        self.get_property_list()
+            .iter_shared()
+            .map(|dict| PropertyInfo::from_dictionary(&dict))
+            .collect()
+    }
+
+    /// Sets the properties named by the keys of `properties` to the corresponding values, if all entries are valid.
+    ///
+    /// Useful for data-driven configuration, e.g. applying saved data to an object. Before anything is changed, all entries are
+    /// validated: each key must be a string naming an existing property (see [`property_list()`][Self::property_list]), and each value
+    /// must be convertible to the property's declared type, according to Godot's rules (e.g. an `int` is accepted for a `float`
+    /// property). For object-typed properties, the class of the value is not checked.
+    ///
+    /// If any entry is invalid, no property is changed, and an error is returned for each invalid entry. Otherwise, the properties are
+    /// set in the dictionary's order. To set the valid entries regardless, use
+    /// [`apply_properties_best_effort()`][Self::apply_properties_best_effort].
+    pub fn apply_properties(&mut self, properties: &Dictionary) -> Result<(), Vec<PropertyError>> {
+        let (valid, errors) = self.validate_properties(properties);
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        for (name, value) in valid {
+            self.set(name, &value);
+        }
+        Ok(())
+    }
+
+    /// Like [`apply_properties()`][Self::apply_properties], but sets all valid entries, even if others are invalid.
+    ///
+    /// Returns an error for each invalid entry, which is skipped.
+    pub fn apply_properties_best_effort(
+        &mut self,
+        properties: &Dictionary,
+    ) -> Result<(), Vec<PropertyError>> {
+        let (valid, errors) = self.validate_properties(properties);
+        for (name, value) in valid {
+            self.set(name, &value);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Captures the state of this object as a dictionary, mapping property names to their values.
+    ///
+    /// Only properties flagged with [`PropertyUsageFlags::STORAGE`] are included, i.e. those which Godot would save in a scene or
+    /// resource file. This works for any object, including engine nodes, and can serve as a building block for save or undo systems.
+    /// Use [`restore_from_dictionary()`][Self::restore_from_dictionary] to apply the dictionary again.
+    ///
+    /// Values are taken as-is; for example, arrays and resources are not duplicated. Properties holding objects are handled according
+    /// to `objects`. Note that the attached script is an object-typed property, too.
+    pub fn to_dictionary(&self, objects: ObjectProperties) -> Dictionary {
+        let mut dictionary = Dictionary::new();
+        for info in self.property_list() {
+            if !info.usage.is_set(PropertyUsageFlags::STORAGE) {
+                continue;
+            }
+
+            let value = self.get(info.property_name.clone());
+            if value.get_type() == VariantType::OBJECT && objects == ObjectProperties::Skip {
+                continue;
+            }
+
+            // Also escapes dictionaries which could be mistaken for object references.
+            let value = crate::tools::__serialize_field(&value);
+
+            dictionary.set(info.property_name, value);
+        }
+
+        dictionary
+    }
+
+    /// Restores the state captured by [`to_dictionary()`][Self::to_dictionary].
+    ///
+    /// Object references (stored with [`ObjectProperties::ByReference`]) are mapped back to objects with `resolver`, e.g.
+    /// [`resolve_live_instance`][crate::tools::resolve_live_instance]; if it returns `None`, the property is set to null. Properties
+    /// are then set like in [`apply_properties()`][Self::apply_properties]: if any entry is invalid, nothing is changed.
+    pub fn restore_from_dictionary(
+        &mut self,
+        dictionary: &Dictionary,
+        resolver: &mut dyn FnMut(crate::obj::InstanceId) -> Option<Gd<Object>>,
+    ) -> Result<(), Vec<PropertyError>> {
+        let mut properties = Dictionary::new();
+        for (key, value) in dictionary.iter_shared() {
+            let value = crate::tools::__deserialize_field::<Variant>(value, resolver)
+                .expect("conversion to Variant is infallible");
+
+            properties.set(key, value);
+        }
+
+        self.apply_properties(&properties)
+    }
+
+    /// Splits `properties` into entries that can be set, and errors for the others.
+    fn validate_properties(
+        &self,
+        properties: &Dictionary,
+    ) -> (Vec<(StringName, Variant)>, Vec<PropertyError>) {
+        let non_properties =
+            PropertyUsageFlags::CATEGORY | PropertyUsageFlags::GROUP | PropertyUsageFlags::SUBGROUP;
+
+        let property_types: HashMap<StringName, VariantType> = self
+            .property_list()
+            .into_iter()
+            .filter(|info| !info.usage.is_set(non_properties))
+            .map(|info| (info.property_name, info.variant_type))
+            .collect();
+
+        let mut valid = Vec::new();
+        let mut errors = Vec::new();
+        for (key, value) in properties.iter_shared() {
+            let key_type = key.get_type();
+            if key_type != VariantType::STRING && key_type != VariantType::STRING_NAME {
+                let error = FromVariantError::BadType {
+                    expected: VariantType::STRING_NAME,
+                    actual: key_type,
+                };
+                errors.push(PropertyError::conversion(
+                    key.stringify().to_string(),
+                    error.into_error(key),
+                ));
+                continue;
+            }
+
+            let name = StringName::from(&key.stringify());
+            let Some(&expected) = property_types.get(&name) else {
+                errors.push(PropertyError::not_found(name.to_string(), name.to_string()));
+                continue;
+            };
+
+            let actual = value.get_type();
+            if !can_convert_strict(actual, expected) {
+                let error = FromVariantError::BadType { expected, actual };
+                errors.push(PropertyError::conversion(
+                    name.to_string(),
+                    error.into_error(value),
+                ));
+                continue;
+            }
+
+            valid.push((name, value));
+        }
+
+        (valid, errors)
+    }
+
+    /// Calls `method` with `args` on the main thread, without waiting for the result.
+    ///
+    /// Most engine APIs may only be used from the main thread. When already on the main thread, the method is called directly.
+    /// Otherwise, the call is queued with [`call_deferred()`][Self::call_deferred], and the main thread performs it at idle time, once
+    /// the current frame's processing is done. If the object is freed before then, the call is skipped.
+    ///
+    /// On a thread other than the main one, obtain the object with [`Gd::from_instance_id()`], as `Gd` cannot be sent across threads.
+    /// Use [`call_on_main_blocking()`][Self::call_on_main_blocking] if the result is needed.
+    #[cfg(feature = "experimental-threads")]
+    pub fn call_on_main(&mut self, method: impl Into<StringName>, args: &[Variant]) {
+        if is_main_thread() {
+            self.call(method.into(), args);
+        } else {
+            self.call_deferred(method.into(), args);
+        }
+    }
+
+    /// Calls `method` with `args` on the main thread, blocking the current thread until the result is available.
+    ///
+    /// The call is queued like [`call_on_main()`][Self::call_on_main], and the main thread performs it at idle time, while the current
+    /// thread waits. Apart from queueing, no engine APIs are used on the current thread.
+    ///
+    /// Returns `None` if the object was freed before the call, if the result cannot be converted to `R`, or if the engine shuts down
+    /// before the call is performed.
+    ///
+    /// # Panics
+    /// If called on the main thread, which would wait for itself forever. Use [`call()`][Self::call] there.
+    ///
+    /// # Deadlocks
+    /// The main thread must keep running frames until the call is done. Do not call this from a thread that the main thread is
+    /// waiting for (e.g. with `JoinHandle::join()` or a lock it holds); use the non-blocking [`call_on_main()`][Self::call_on_main]
+    /// in such cases.
+    #[cfg(all(feature = "experimental-threads", since_api = "4.2"))]
+    pub fn call_on_main_blocking<R>(
+        &mut self,
+        method: impl Into<StringName>,
+        args: &[Variant],
+    ) -> Option<R>
+    where
+        R: FromGodot + Send + 'static,
+    {
+        assert!(
+            !is_main_thread(),
+            "Object::call_on_main_blocking() must not be called on the main thread, as it would deadlock; use call() instead",
+        );
+
+        let method = method.into();
+
+        // SAFETY: `self` is only accessible through a `Gd` pointing to a live object. Reading the ID does not touch the object's state.
+        let raw_id = unsafe { sys::interface_fn!(object_get_instance_id)(self.__object_ptr()) };
+        let object_id =
+            crate::obj::InstanceId::try_from_u64(raw_id).expect("live object has an instance ID");
+
+        let (sender, receiver) = std::sync::mpsc::sync_channel(1);
+        let callable = Callable::from_fn("Object::call_on_main_blocking", move |args| {
+            let args: Vec<Variant> = args.iter().map(|&arg| arg.clone()).collect();
+            let result = Gd::<Object>::try_from_instance_id(object_id)
+                .ok()
+                .and_then(|mut object| object.call(method.clone(), &args).try_to::<R>().ok());
+
+            let _ = sender.send(result);
+            Ok(Variant::nil())
+        });
+
+        // `Callable::call_deferred()` only pushes onto Godot's message queue, which is safe from any thread.
+        callable.to_variant().call("call_deferred", args);
+
+        // If the queue is discarded without performing the call (engine shutdown), the sender is dropped and `recv()` fails.
+        receiver.recv().ok().flatten()
+    }
+
+    /// Returns a future which resolves on the next emission of the signal `name`, with the emitted arguments.
+    ///
+    /// This works for any signal known to Godot at runtime, including signals of engine classes, scripts and user signals added with
+    /// [`add_user_signal()`][Self::add_user_signal]. The connection is one-shot and removed once the future resolves or is dropped.
+    ///
+    /// If this object is freed before the signal is emitted, the future resolves to `None` instead of waiting forever.
+    /// See [`SignalFuture`] for details.
+    ///
+    /// # Panics
+    /// If the object has no signal named `name`, or if the engine's main loop is not a [`SceneTree`].
+    #[cfg(since_api = "4.2")]
+    pub fn await_signal(&self, name: &str) -> SignalFuture {
+        SignalFuture::new(self.to_gd_object(), name)
+    }
+
+    /// Connects the signal `signal` of this object to the method `method` of `target`, after checking that the connection can work.
+    ///
+    /// Connecting to a method by name with [`connect()`][Self::connect] succeeds even if the method does not exist or takes a
+    /// different number of parameters than the signal passes; the mistake only shows up as an error once the signal is emitted.
+    /// This method checks upfront that:
+    /// - this object has a signal named `signal` (including user signals),
+    /// - `target` has a method named `method`, either registered in its class or defined in its script,
+    /// - the method can be called with the signal's arguments, taking default parameters and varargs into account.
+    ///
+    /// Argument _types_ are not checked, since signals and script methods often don't declare them.
+    ///
+    /// Returns an error if one of the checks fails, or if Godot rejects the connection, e.g. because it already exists.
+    pub fn connect_validated<T>(
+        &mut self,
+        signal: impl Into<StringName>,
+        target: &Gd<T>,
+        method: impl Into<StringName>,
+    ) -> Result<(), ConnectError>
+    where
+        T: Inherits<Object>,
+    {
+        let signal = signal.into();
+        let method = method.into();
+        let source = self.to_gd_object().to_string();
+        let error = |kind| ConnectError::for_object(kind, source.clone(), signal.to_string());
+
+        let Some(signal_args) = self.signal_arg_count(&signal) else {
+            return Err(error(ConnectErrorKind::SignalNotFound));
+        };
+
+        let target_object = target.upcast_ref::<Object>();
+        let Some((min_args, max_args)) = target_object.method_arg_range(&method) else {
+            return Err(error(ConnectErrorKind::MethodNotFound {
+                target: target.to_string(),
+                method: method.to_string(),
+            }));
+        };
+
+        if signal_args < min_args || max_args.is_some_and(|max| signal_args > max) {
+            return Err(error(ConnectErrorKind::ArgumentCountMismatch {
+                method: method.to_string(),
+                signal_args,
+                min_args,
+                max_args,
+            }));
+        }
+
+        let callable = Callable::from_object_method(target, method);
+        self.try_connect(&signal, &callable, error)
+    }
+
+    /// Connects `signal` to `callable`, creating the error with `error` if Godot rejects the connection.
+    ///
+    /// An existing connection is reported as `ERR_INVALID_PARAMETER`. It is checked upfront, as Godot would print an error otherwise.
+    pub(crate) fn try_connect(
+        &mut self,
+        signal: &StringName,
+        callable: &Callable,
+        error: impl FnOnce(ConnectErrorKind) -> ConnectError,
+    ) -> Result<(), ConnectError> {
+        if self.is_connected(signal.clone(), callable.clone()) {
+            return Err(error(ConnectErrorKind::Godot(
+                GodotError::ERR_INVALID_PARAMETER,
+            )));
+        }
+
+        match self.connect(signal.clone(), callable.clone()) {
+            GodotError::OK => Ok(()),
+            err => Err(error(ConnectErrorKind::Godot(err))),
+        }
+    }
+
+    /// Disconnects all signals of this object from callables bound to `target`, returning the number of removed connections.
+    ///
+    /// This covers every signal known to Godot at runtime (including script and user signals), and all callables whose
+    /// [`object()`][Callable::object] is `target`, such as method callables from [`Callable::from_object_method()`] and bound
+    /// variants of them. Connections to other objects are left intact.
+    ///
+    /// Useful during teardown, before `target` is freed, so that no connections to it remain behind. Only connections _from_ this object
+    /// are affected; to also remove connections from signals of `target` to this object, call `disconnect_all_from()` on `target`.
+    pub fn disconnect_all_from<T>(&mut self, target: &Gd<T>) -> usize
+    where
+        T: Inherits<Object>,
+    {
+        let target_id = target.instance_id();
+
+        // Collect first, so the connection lists are not modified while being iterated.
+        let mut connections = Vec::new();
+        for info in self.get_signal_list().iter_shared() {
+            let Some(signal) = info
+                .get("name")
+                .and_then(|name| name.try_to::<StringName>().ok())
+            else {
+                continue;
+            };
+
+            let signal_connections = self.get_signal_connection_list(signal.clone());
+            for connection in signal_connections.iter_shared() {
+                let Some(callable) = connection
+                    .get("callable")
+                    .and_then(|callable| callable.try_to::<Callable>().ok())
+                else {
+                    continue;
+                };
+
+                if callable.object_id() == Some(target_id) {
+                    connections.push((signal.clone(), callable));
+                }
+            }
+        }
+
+        let count = connections.len();
+        for (signal, callable) in connections {
+            self.disconnect(signal, callable);
+        }
+
+        count
+    }
+
+    /// Attaches `value` to this object, replacing any previous value of the same type `T`.
+    ///
+    /// This allows associating Rust state with any object, including engine objects such as nodes of built-in classes, without
+    /// subclassing. One value per type can be stored; use a dedicated type (e.g. a newtype or struct) to avoid clashes with other code.
+    /// Values are dropped when the object is destroyed, unless [`Rc`][std::rc::Rc] clones obtained from
+    /// [`get_user_data()`][Self::get_user_data] are still alive.
+    ///
+    /// User data is only accessible from Rust, and not persisted when saving the object. It is stored in a Godot instance binding, and
+    /// must only be accessed on the thread where it was first attached (usually the main thread). If the object is destroyed on another
+    /// thread, the values cannot be dropped safely; they are leaked and an error is printed.
+    ///
+    /// # Panics
+    /// If user data of this object was attached on another thread.
+    pub fn set_user_data<T: 'static>(&mut self, value: T) {
+        // SAFETY: the object is alive while `self` is accessible.
+        let map = unsafe { crate::storage::user_data_map(self.__object_ptr(), true) };
+
+        map.expect("user data binding created").insert(value);
+    }
+
+    /// Returns the value of type `T` attached with [`set_user_data()`][Self::set_user_data], or `None` if there is none.
+    ///
+    /// The value is returned as an `Rc`, so it stays valid even if it is replaced or removed meanwhile.
+    ///
+    /// # Panics
+    /// If user data of this object was attached on another thread.
+    pub fn get_user_data<T: 'static>(&self) -> Option<std::rc::Rc<T>> {
+        // SAFETY: the object is alive while `self` is accessible.
+        let map = unsafe { crate::storage::user_data_map(self.__object_ptr(), false) };
+
+        map?.get::<T>()
+    }
+
+    /// Detaches the value of type `T` from this object, returning it if present.
+    ///
+    /// # Panics
+    /// If user data of this object was attached on another thread.
+    pub fn remove_user_data<T: 'static>(&mut self) -> Option<std::rc::Rc<T>> {
+        // SAFETY: the object is alive while `self` is accessible.
+        let map = unsafe { crate::storage::user_data_map(self.__object_ptr(), false) };
+
+        map?.remove::<T>()
+    }
+
+    /// Walks the property path segment by segment, returning the final value.
+    fn resolve_property_path(&self, path: &NodePath) -> Result<Variant, PropertyError> {
+        // "position:x" is parsed as name "position" + subname "x"; the property-path form moves all names into subnames.
+        let property_path = path.as_inner().get_as_property_path();
+        let segments = property_path.as_inner();
+
+        let segment_count = segments.get_subname_count();
+        if segment_count == 0 {
+            return Err(PropertyError::not_found(path.to_string(), ""));
+        }
+
+        let mut current = self.to_gd_object().to_variant();
+        for i in 0..segment_count {
+            let key = segments.get_subname(i);
+
+            current = current
+                .get_named(&key)
+                .ok_or_else(|| PropertyError::not_found(path.to_string(), key.to_string()))?;
+        }
+
+        Ok(current)
+    }
+
+    /// Number of arguments that the signal `signal` passes, or `None` if there is no such signal.
+    fn signal_arg_count(&self, signal: &StringName) -> Option<usize> {
+        if !self.has_signal(signal.clone()) {
+            return None;
+        }
+
+        let signal_str = signal.to_string();
+        let info = self.get_signal_list().iter_shared().find(|info| {
+            info.get("name")
+                .is_some_and(|name| name.to_string() == signal_str)
+        })?;
+
+        Some(dict_array_len(&info, "args"))
+    }
+
+    /// Minimum and maximum number of arguments that `method` accepts, or `None` if there is no such method.
+    ///
+    /// The maximum is `None` for vararg methods.
+    fn method_arg_range(&self, method: &StringName) -> Option<(usize, Option<usize>)> {
+        if !self.has_method(method.clone()) {
+            return None;
+        }
+
+        let method_str = method.to_string();
+        let info = self.get_method_list().iter_shared().find(|info| {
+            info.get("name")
+                .is_some_and(|name| name.to_string() == method_str)
+        })?;
+
+        let arg_count = dict_array_len(&info, "args");
+        let default_count = dict_array_len(&info, "default_args");
+        let flags = info
+            .get("flags")
+            .and_then(|flags| flags.try_to::<u64>().ok())
+            .unwrap_or(0);
+
+        let min_args = arg_count.saturating_sub(default_count);
+        let is_vararg = flags & crate::global::MethodFlags::VARARG.ord() != 0;
+        let max_args = (!is_vararg).then_some(arg_count);
+
+        Some((min_args, max_args))
+    }
+
+    /// Returns a new strong `Gd` pointer to this object.
+    pub(crate) fn to_gd_object(&self) -> Gd<Object> {
+        // SAFETY: `self` is only accessible through a `Gd` pointing to a live object; from_obj_sys() increments the ref-count.
+        unsafe { Gd::from_obj_sys(self.__object_ptr()) }
+    }
+}
+
+/// Length of the array stored under `key` in a method or signal info dictionary, or 0 if absent.
+fn dict_array_len(info: &Dictionary, key: &str) -> usize {
+    info.get(key)
+        .and_then(|array| array.try_to::<crate::builtin::VariantArray>().ok())
+        .map_or(0, |array| array.len())
+}
+
+/// Whether Godot accepts a value of type `from` for a parameter or property of type `to`. `NIL` stands for `Variant` and accepts all.
+fn can_convert_strict(from: VariantType, to: VariantType) -> bool {
+    if to == VariantType::NIL || from == to {
+        return true;
+    }
+
+    // SAFETY: both arguments are valid variant types.
+    let result = unsafe { sys::interface_fn!(variant_can_convert_strict)(from.sys(), to.sys()) };
+    result != 0
+}
+
+/// Whether the current thread is the one on which Godot runs the main loop.
+#[cfg(feature = "experimental-threads")]
+fn is_main_thread() -> bool {
+    let os = crate::classes::Os::singleton();
+    os.get_thread_caller_id() == os.get_main_thread_id()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Manual extensions for the `Node` class.
 impl Node {
@@ -44,31 +717,2003 @@ impl Node {
         self.get_node_or_null(path)
             .and_then(|node| node.try_cast::<T>().ok())
     }
-}
 
-// ----------------------------------------------------------------------------------------------------------------------------------------------
-
-/// Manual extensions for the `PackedScene` class.
-impl PackedScene {
-    /// ⚠️ Instantiates the scene as type `T`, panicking if not found or bad type.
+    /// ⚠️ Retrieves the node with scene-unique name `name`, panicking if not found or bad type.
+    ///
+    /// See [`try_get_unique_node_as()`][Self::try_get_unique_node_as] for details on unique names.
     ///
     /// # Panics
-    /// If the scene is not type `T` or inherited.
-    pub fn instantiate_as<T>(&self) -> Gd<T>
+    /// If the node is not found, or if it does not have type `T` or inherited.
+    pub fn get_unique_node_as<T>(&self, name: impl Into<StringName>) -> Gd<T>
     where
         T: Inherits<Node>,
     {
-        self.try_instantiate_as::<T>()
-            .unwrap_or_else(|| panic!("Failed to instantiate {to}", to = T::class_name()))
+        let name = name.into();
+
+        self.try_get_unique_node_as(name.clone())
+            .unwrap_or_else(|| {
+                panic!(
+                    "There is no node of type {ty} with unique name `%{name}`",
+                    ty = T::class_name()
+                )
+            })
     }
 
-    /// Instantiates the scene as type `T` (fallible).
+    /// Retrieves the node with scene-unique name `name` (fallible).
     ///
-    /// If the scene is not type `T` or inherited.
-    pub fn try_instantiate_as<T>(&self) -> Option<Gd<T>>
+    /// Nodes marked with [`set_unique_name_in_owner()`][Self::set_unique_name_in_owner] can be accessed by name from any node in the same
+    /// scene, independently of their position in the tree. This is equivalent to [`try_get_node_as()`][Self::try_get_node_as] with the
+    /// path `%name`, which is also GDScript's `%Name` syntax. `name` is given without the `%` prefix.
+    ///
+    /// Unique names are resolved within the scene of this node's owner (or of this node, if it is a scene root). If no such node exists,
+    /// or if it does not have type `T` or inherited, `None` will be returned.
+    pub fn try_get_unique_node_as<T>(&self, name: impl Into<StringName>) -> Option<Gd<T>>
     where
         T: Inherits<Node>,
     {
-        self.instantiate().and_then(|gd| gd.try_cast::<T>().ok())
+        let path = NodePath::from(format!("%{}", name.into()));
+        self.try_get_node_as(path)
+    }
+
+    /// Calls `f` on the children of this node in order, returning the first non-`None` result.
+    ///
+    /// If `recursive` is true, all descendants are visited depth-first: each child's subtree is searched before its next sibling.
+    /// Children are accessed one by one, so the search stops as soon as `f` returns `Some`, without collecting the children into an
+    /// array first. Internal children are not visited.
+    ///
+    /// If `f` adds or removes children of the nodes being visited, some nodes may be skipped or visited twice.
+    pub fn find_map_child<R>(
+        &self,
+        recursive: bool,
+        mut f: impl FnMut(Gd<Node>) -> Option<R>,
+    ) -> Option<R> {
+        self.find_map_child_dyn(recursive, &mut f)
+    }
+
+    fn find_map_child_dyn<R>(
+        &self,
+        recursive: bool,
+        f: &mut dyn FnMut(Gd<Node>) -> Option<R>,
+    ) -> Option<R> {
+        for index in 0..self.get_child_count() {
+            let Some(child) = self.get_child(index) else {
+                continue;
+            };
+
+            if let Some(result) = f(child.clone()) {
+                return Some(result);
+            }
+
+            if recursive {
+                if let Some(result) = child.find_map_child_dyn(true, f) {
+                    return Some(result);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Connects `callable` to the signal `signal` of the node at path `path`, relative to this node.
+    ///
+    /// Combines [`get_node_or_null()`][Self::get_node_or_null] and [`connect()`][Object::connect], but reports a missing node or signal
+    /// as an error instead of a panic or an engine error. This is handy when connections are described as data, e.g. in a table
+    /// authored in the editor, where a renamed node should be reported gracefully.
+    ///
+    /// Returns an error if there is no node at `path`, if the node has no signal named `signal`, or if Godot rejects the connection,
+    /// for example because `callable` is already connected to that signal.
+    pub fn connect_node_signal(
+        &self,
+        path: impl Into<NodePath>,
+        signal: impl Into<StringName>,
+        callable: Callable,
+    ) -> Result<(), ConnectError> {
+        let path = path.into();
+        let signal = signal.into();
+        let error = |kind| ConnectError::new(kind, path.to_string(), signal.to_string());
+
+        let Some(mut node) = self.get_node_or_null(path.clone()) else {
+            return Err(error(ConnectErrorKind::NodeNotFound));
+        };
+
+        if !node.has_signal(signal.clone()) {
+            return Err(error(ConnectErrorKind::SignalNotFound));
+        }
+
+        node.try_connect(&signal, &callable, error)
+    }
+
+    /// Returns the scene tree this node is part of, or `None` if the node is not inside a tree.
+    ///
+    /// Unlike [`get_tree()`][Self::get_tree], this does not make Godot print an error for nodes outside a tree. It can thus be used
+    /// in constructors and other code that may run before the node is added.
+    pub fn scene_tree(&self) -> Option<Gd<SceneTree>> {
+        if self.is_inside_tree() {
+            self.get_tree()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the viewport this node is rendered in, or `None` if the node is not inside a tree.
+    ///
+    /// Equivalent to [`get_viewport()`][Self::get_viewport], but makes explicit that nodes outside the scene tree have no viewport.
+    /// For the root node of the tree, this returns the root window itself.
+    pub fn viewport(&self) -> Option<Gd<Viewport>> {
+        if self.is_inside_tree() {
+            self.get_viewport()
+        } else {
+            None
+        }
+    }
+
+    /// Sets `owner` as the owner of all descendants of this node, e.g. before saving a subtree built at runtime.
+    ///
+    /// [`PackedScene::pack()`] only stores nodes that are owned by the packed root, so nodes created from code need their owner
+    /// assigned first. `owner` must be this node or one of its ancestors; usually, it is the node which is going to be packed.
+    ///
+    /// # Owner rules
+    /// - Internal children (added with an `InternalMode` other than `DISABLED`) are skipped, together with their subtrees. They are
+    ///   part of their parent's implementation and are not saved.
+    /// - Roots of instanced sub-scenes, i.e. nodes with a non-empty [`get_scene_file_path()`][Self::get_scene_file_path], are owned
+    ///   by `owner`, but their descendants are left unchanged. Those stay owned by the sub-scene root, so that the packed scene
+    ///   references the sub-scene's file instead of storing a copy of its nodes.
+    pub fn set_owner_recursive<T>(&self, owner: &Gd<T>)
+    where
+        T: Inherits<Node>,
+    {
+        for mut child in self.get_children().iter_shared() {
+            child.set_owner(owner);
+
+            if child.get_scene_file_path().is_empty() {
+                child.set_owner_recursive(owner);
+            }
+        }
+    }
+
+    /// Returns an iterator over all descendants of this node in breadth-first order, i.e. level by level.
+    ///
+    /// First yields all children of this node, then all grandchildren, and so on. Within each level, nodes appear in child order, with
+    /// children of earlier siblings first. This node itself and internal children are not included. For a depth-first search, see
+    /// [`find_map_child()`][Self::find_map_child].
+    ///
+    /// The iterator is lazy: children of a node are only queried once the node has been yielded, so modifications of the tree during
+    /// iteration are taken into account for the parts not yet visited. Nodes freed in the meantime are skipped. Each node is yielded at
+    /// most once, even if it is moved to another place in the subtree during iteration.
+    pub fn descendants_bfs(&self) -> impl Iterator<Item = Gd<Node>> {
+        let mut queue: VecDeque<Gd<Node>> = self.get_children().iter_shared().collect();
+        let mut visited = HashSet::new();
+
+        std::iter::from_fn(move || loop {
+            let node = queue.pop_front()?;
+            if !node.is_instance_valid() || !visited.insert(node.instance_id()) {
+                continue;
+            }
+
+            queue.extend(node.get_children().iter_shared());
+            return Some(node);
+        })
+    }
+
+    /// Reads the value at an animation-style path, which addresses a node relative to this one, followed by a property path.
+    ///
+    /// The path has the form `"Node/Child:property:subproperty"`, as used by `Animation` tracks: the
+    /// part before the first colon is a node path resolved like in [`get_node_or_null()`][Self::get_node_or_null], the rest a property
+    /// path as in [`Object::try_get_indexed_as()`]. For example, `"Sprite2D:modulate:a"` reads the alpha channel of the child
+    /// `Sprite2D`. Without a node part (e.g. `":position:x"`), the property is read from this node.
+    ///
+    /// Returns an error if the node does not exist ([`PropertyError::is_node_not_found()`]), if a (sub-)property does not exist
+    /// ([`PropertyError::is_not_found()`]), or if the value cannot be converted to `V`.
+    pub fn resolve_path_value<V>(&self, path: impl Into<NodePath>) -> Result<V, PropertyError>
+    where
+        V: FromGodot,
+    {
+        let path = path.into();
+        let inner = path.as_inner();
+        let property_path = NodePath::from(format!(":{}", inner.get_concatenated_subnames()));
+
+        let value = if inner.get_name_count() == 0 {
+            self.resolve_property_path(&property_path)
+        } else {
+            let node_path = NodePath::from(&inner.get_concatenated_names());
+            let Some(node) = self.get_node_or_null(node_path.clone()) else {
+                return Err(PropertyError::node_not_found(
+                    path.to_string(),
+                    node_path.to_string(),
+                ));
+            };
+
+            node.resolve_property_path(&property_path)
+        };
+
+        value
+            .map_err(|err| err.with_path(path.to_string()))?
+            .try_to::<V>()
+            .map_err(|err| PropertyError::conversion(path.to_string(), err))
+    }
+
+    /// Renames this node to `name` and makes it accessible as `%name` from nodes of the same owner, e.g. via
+    /// [`get_node_as()`][Self::get_node_as].
+    ///
+    /// Combines [`set_name()`][Self::set_name] and [`set_unique_name_in_owner()`][Self::set_unique_name_in_owner], for scenes which are
+    /// built at runtime. Unique names are registered with the node's [owner][Self::get_owner], so an owner must already be set.
+    ///
+    /// Returns `false` and leaves the node unchanged if it has no owner, if another node in the same owner already has the unique name
+    /// `name`, or if a sibling is named `name` (in which case Godot would choose a different name).
+    pub fn set_unique_name(&mut self, name: impl Into<StringName>) -> bool {
+        let name = name.into();
+        let own_id = self.get_instance_id();
+        let is_other =
+            |node: Option<Gd<Node>>| node.is_some_and(|node| node.instance_id().to_i64() != own_id);
+
+        let Some(owner) = self.get_owner() else {
+            return false;
+        };
+
+        let unique = owner.get_node_or_null(NodePath::from(format!("%{name}")));
+        let sibling = self
+            .get_parent()
+            .and_then(|parent| parent.get_node_or_null(NodePath::from(&name)));
+
+        if is_other(unique) || is_other(sibling) {
+            return false;
+        }
+
+        self.set_name(GString::from(&name));
+        self.set_unique_name_in_owner(true);
+        true
+    }
+
+    /// Stops this node from being accessible as `%name`. Its name stays the same.
+    pub fn clear_unique_name(&mut self) {
+        self.set_unique_name_in_owner(false);
+    }
+
+    /// Returns an iterator over the children of this node, ordered by their index in the scene tree.
+    ///
+    /// The `i`-th item is the same node as [`get_child(i)`][Self::get_child], and its [`get_index()`][Self::get_index] is `i`. This is
+    /// the order in which Godot processes, draws and saves children, so it can be relied on for reproducible serialization. Internal
+    /// children are not included.
+    ///
+    /// The children are collected when this method is called, so later changes to the tree do not affect the iteration. Note that
+    /// children [queued for deletion][Self::is_queued_for_deletion] are still included until they are freed; see
+    /// [`is_child_order_stable()`][Self::is_child_order_stable].
+    pub fn children_ordered(&self) -> impl Iterator<Item = Gd<Node>> {
+        let children: Vec<Gd<Node>> = (0..self.get_child_count())
+            .filter_map(|index| self.get_child(index))
+            .collect();
+
+        children.into_iter()
+    }
+
+    /// Returns `true` if the order of this node's children will stay the same at the end of the frame.
+    ///
+    /// This is `false` if any (non-internal) child is queued for deletion: once it is freed, the indices of all later children shift.
+    /// Saving or comparing children during such a frame yields a different result than after it.
+    pub fn is_child_order_stable(&self) -> bool {
+        self.get_children()
+            .iter_shared()
+            .all(|child| !child.is_queued_for_deletion())
+    }
+
+    /// Returns an iterator over the siblings of this node, i.e. the other children of its parent, in child order.
+    ///
+    /// This node itself and internal children are not included. If the node has no parent, the iterator is empty. The children are
+    /// collected when this method is called, so later changes to the tree do not affect the iteration.
+    pub fn siblings(&self) -> impl Iterator<Item = Gd<Node>> {
+        let own_id = self.get_instance_id();
+        let children = self
+            .get_parent()
+            .map(|parent| parent.get_children())
+            .unwrap_or_default();
+
+        let siblings: Vec<Gd<Node>> = children
+            .iter_shared()
+            .filter(|node| node.instance_id().to_i64() != own_id)
+            .collect();
+
+        siblings.into_iter()
+    }
+
+    /// Returns an iterator over the siblings of this node which are of class `T` (or a subclass), in child order.
+    ///
+    /// See [`siblings()`][Self::siblings] for details.
+    pub fn siblings_of_type<T>(&self) -> impl Iterator<Item = Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        self.siblings().filter_map(|node| node.try_cast::<T>().ok())
+    }
+
+    /// Returns the nearest ancestor of type `T`, or `None` if there is none.
+    ///
+    /// Walks up the tree with [`get_parent()`][Self::get_parent], starting at the parent of this node, until a node of class `T` (or a
+    /// subclass) is found, or the root is reached. This node itself is not considered. This is useful to find the enclosing
+    /// [`Control`][crate::classes::Control] or a custom manager node; for the opposite direction, see
+    /// [`find_map_child()`][Self::find_map_child].
+    pub fn ancestor_of_type<T>(&self) -> Option<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        let mut current = self.get_parent();
+
+        while let Some(node) = current {
+            match node.try_cast::<T>() {
+                Ok(ancestor) => return Some(ancestor),
+                Err(node) => current = node.get_parent(),
+            }
+        }
+
+        None
+    }
+
+    /// Returns the distance of this node from the root of the scene tree, or `None` if the node is not inside the tree.
+    ///
+    /// The root [`Window`][crate::classes::Window] has depth 0, the current scene 1, its children 2, and so on. Computed by walking up
+    /// with [`get_parent()`][Self::get_parent], so the cost is linear in the depth.
+    pub fn depth_in_tree(&self) -> Option<usize> {
+        if !self.is_inside_tree() {
+            return None;
+        }
+
+        let mut depth = 0;
+        let mut current = self.get_parent();
+        while let Some(node) = current {
+            depth += 1;
+            current = node.get_parent();
+        }
+
+        Some(depth)
+    }
+
+    /// Returns `true` if this node is a (direct or indirect) child of `ancestor`.
+    ///
+    /// Counterpart to [`is_ancestor_of()`][Self::is_ancestor_of]. A node is not its own descendant. Works inside the scene tree as well
+    /// as for subtrees outside of it; if `ancestor` has been freed, `false` is returned.
+    pub fn is_descendant_of<T>(&self, ancestor: &Gd<T>) -> bool
+    where
+        T: Inherits<Node>,
+    {
+        let ancestor_id = ancestor.instance_id_unchecked();
+        let mut current = self.get_parent();
+
+        while let Some(node) = current {
+            if node.instance_id() == ancestor_id {
+                return true;
+            }
+            current = node.get_parent();
+        }
+
+        false
+    }
+
+    /// Queues all given nodes for deletion at the end of the current frame, returning how many were newly queued.
+    ///
+    /// This is a bulk version of [`queue_free()`][Self::queue_free], e.g. to despawn many entities at once. It is safe to pass a list
+    /// of nodes which was collected earlier and may have changed in the meantime:
+    /// - Nodes appearing multiple times are only queued once.
+    /// - Nodes which are already [queued for deletion][Self::is_queued_for_deletion] are skipped.
+    /// - Handles whose node has already been freed are skipped.
+    ///
+    /// It is fine to pass both a node and some of its descendants; freeing the parent also frees the children.
+    pub fn queue_free_all<T>(nodes: impl IntoIterator<Item = Gd<T>>) -> usize
+    where
+        T: Inherits<Node>,
+    {
+        let mut seen = HashSet::new();
+        let mut queued_count = 0;
+
+        for node in nodes {
+            if !node.is_instance_valid() || !seen.insert(node.instance_id()) {
+                continue;
+            }
+
+            let mut node = node.upcast::<Node>();
+            if !node.is_queued_for_deletion() {
+                node.queue_free();
+                queued_count += 1;
+            }
+        }
+
+        queued_count
+    }
+
+    /// Adds all given nodes as children at idle time, in the given order, returning how many additions were queued.
+    ///
+    /// This is a bulk version of `call_deferred("add_child", node)`. Adding children directly fails while the parent is busy, e.g. while
+    /// its children are being set up, or inside a physics callback; deferring the additions is safe in any situation. The nodes appear
+    /// as children once the deferred calls are flushed, at the end of the current frame. The following nodes are skipped:
+    /// - Nodes appearing multiple times (only the first occurrence is queued).
+    /// - Nodes which already have a parent.
+    /// - This node itself, and handles whose node has already been freed.
+    ///
+    /// Queued nodes must not be freed before they are added; if this node is freed before, the additions are dropped.
+    pub fn add_children_deferred<T>(&mut self, nodes: impl IntoIterator<Item = Gd<T>>) -> usize
+    where
+        T: Inherits<Node>,
+    {
+        let self_id = self.to_gd_object().instance_id();
+        let mut seen = HashSet::new();
+        let mut queued_count = 0;
+
+        for node in nodes {
+            if !node.is_instance_valid() || node.instance_id() == self_id {
+                continue;
+            }
+
+            let node = node.upcast::<Node>();
+            if node.get_parent().is_some() || !seen.insert(node.instance_id()) {
+                continue;
+            }
+
+            self.call_deferred("add_child".into(), &[node.to_variant()]);
+            queued_count += 1;
+        }
+
+        queued_count
+    }
+
+    /// Enables or disables the node's per-frame and input callbacks all at once.
+    ///
+    /// This is equivalent to calling [`set_process()`][Self::set_process], [`set_physics_process()`][Self::set_physics_process],
+    /// [`set_process_input()`][Self::set_process_input] etc. with the corresponding fields of `toggles`.
+    pub fn set_processing(&mut self, toggles: ProcessToggles) {
+        self.set_process(toggles.process);
+        self.set_physics_process(toggles.physics_process);
+        self.set_process_input(toggles.input);
+        self.set_process_shortcut_input(toggles.shortcut_input);
+        self.set_process_unhandled_input(toggles.unhandled_input);
+        self.set_process_unhandled_key_input(toggles.unhandled_key_input);
+    }
+
+    /// Returns which per-frame and input callbacks of the node are currently enabled.
+    ///
+    /// Note that Godot enables a callback automatically when the node (or its script) overrides it, so a freshly created node may
+    /// already have some toggles set.
+    pub fn processing(&self) -> ProcessToggles {
+        ProcessToggles {
+            process: self.is_processing(),
+            physics_process: self.is_physics_processing(),
+            input: self.is_processing_input(),
+            shortcut_input: self.is_processing_shortcut_input(),
+            unhandled_input: self.is_processing_unhandled_input(),
+            unhandled_key_input: self.is_processing_unhandled_key_input(),
+        }
+    }
+
+    /// Disables all per-frame and input callbacks, remembering the previous toggles for [`unfreeze()`][Self::unfreeze].
+    ///
+    /// Unlike pausing via [`set_process_mode()`][Self::set_process_mode], this only affects this node and not its children. Freezing an
+    /// already frozen node has no effect, so the toggles from before the first `freeze()` are kept.
+    pub fn freeze(&mut self) {
+        if self.is_frozen() {
+            return;
+        }
+
+        let toggles = self.processing();
+        self.set_user_data(FrozenToggles(toggles));
+        self.set_processing(ProcessToggles::NONE);
+    }
+
+    /// Restores the callbacks that were enabled before [`freeze()`][Self::freeze].
+    ///
+    /// Does nothing if the node is not frozen. Toggles changed while the node was frozen are overwritten.
+    pub fn unfreeze(&mut self) {
+        if let Some(frozen) = self.remove_user_data::<FrozenToggles>() {
+            self.set_processing(frozen.0);
+        }
+    }
+
+    /// Returns `true` if [`freeze()`][Self::freeze] was called without a subsequent [`unfreeze()`][Self::unfreeze].
+    pub fn is_frozen(&self) -> bool {
+        self.get_user_data::<FrozenToggles>().is_some()
+    }
+
+    /// Returns a future which resolves once this node is ready.
+    ///
+    /// A node becomes ready when it enters the scene tree for the first time, after `ready()` has run for it and all its children.
+    /// Awaiting this, e.g. after instantiating and adding a scene, makes it safe to access state initialized in `ready()`, such as
+    /// [`OnReady`][crate::obj::OnReady] fields. If the node is already ready, the future resolves immediately.
+    ///
+    /// See [`NodeReadyFuture`] for details.
+    #[cfg(since_api = "4.2")]
+    pub fn await_ready(&self) -> NodeReadyFuture {
+        let node = self.to_gd_object().cast::<Node>();
+        NodeReadyFuture::new(node)
+    }
+
+    /// Returns a future which resolves once all siblings of this node are ready.
+    ///
+    /// Siblings are the other children of this node's parent, at the time of the call. Since Godot runs `ready()` in child order, a node
+    /// can await this, e.g. in a task spawned from its `ready()`, before accessing siblings further down in the tree. If this node has no
+    /// parent or all siblings are already ready, the future resolves immediately.
+    ///
+    /// See [`ReadyBarrierFuture`] for details.
+    #[cfg(since_api = "4.2")]
+    pub fn await_siblings_ready(&self) -> ReadyBarrierFuture {
+        let id = self.get_instance_id();
+        let siblings = self
+            .get_parent()
+            .map(|parent| parent.get_children().iter_shared().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        ReadyBarrierFuture::new(
+            siblings
+                .into_iter()
+                .filter(|sibling| sibling.get_instance_id() != id),
+        )
+    }
+
+    /// Runs `future` as a task that is tied to the lifetime of this node.
+    ///
+    /// Like [`spawn_task()`][crate::tools::spawn_task], the task runs on godot-rust's built-in executor, and is polled for the first time
+    /// right away. It is cancelled automatically once this node exits the scene tree (signal `tree_exited`), which includes the node
+    /// being freed while inside the tree. Freeing a node that is not inside the tree also stops the task, the next time it is woken.
+    ///
+    /// This ensures that long-running gameplay logic, e.g. a loop awaiting frames or timers, does not outlive its node and touch freed
+    /// state. The returned handle can be used to cancel the task earlier.
+    #[cfg(since_api = "4.2")]
+    pub fn spawn_task(
+        &self,
+        future: impl std::future::Future<Output = ()> + 'static,
+    ) -> TaskHandle {
+        let node = self.to_gd_object().cast::<Node>();
+        crate::tools::spawn_node_task(node, future)
+    }
+
+    /// Runs `callback` once per frame, with the elapsed time since the previous frame in seconds, for as long as this node exists.
+    ///
+    /// This is a lightweight alternative to overriding `process()` in a dedicated class, e.g. for prototyping small behaviors. Like
+    /// `process()`, the callback only runs while the node is inside the scene tree and not paused (see [`can_process()`][Self::can_process]),
+    /// but it is independent of [`set_process()`][Self::set_process]. It is run on the scene tree's `process_frame` signal, i.e. before
+    /// the `process()` callbacks of nodes in the same frame.
+    ///
+    /// The callback is removed automatically once the node is freed. The returned handle can be used to remove it earlier.
+    ///
+    /// # Panics
+    /// If the engine's main loop is not a [`SceneTree`].
+    #[cfg(since_api = "4.2")]
+    pub fn on_process(&self, callback: impl FnMut(f64) + 'static) -> ProcessCallbackHandle {
+        let node = self.to_gd_object().cast::<Node>();
+        crate::tools::connect_process_callback(&node, callback)
+    }
+
+    /// Runs `callback` once, after `frames` process frames have passed.
+    ///
+    /// Useful for short-lived gameplay effects, without setting up a `Timer`. A delay of 0 is treated like 1, i.e. the callback runs on
+    /// the next frame. Like [`on_process()`][Self::on_process], frames only count while the node is inside the scene tree and not paused.
+    ///
+    /// The callback is dropped without running if the node is freed before. The returned handle can be used to cancel it earlier.
+    ///
+    /// # Panics
+    /// If the engine's main loop is not a [`SceneTree`].
+    #[cfg(since_api = "4.2")]
+    pub fn after_frames(
+        &self,
+        frames: u32,
+        callback: impl FnOnce() + 'static,
+    ) -> ProcessCallbackHandle {
+        let node = self.to_gd_object().cast::<Node>();
+        crate::tools::connect_delayed_callback(&node, frames, callback)
+    }
+
+    /// Returns a [`Debouncer`], which runs `callback` once on the next frame, no matter how often it is triggered before.
+    ///
+    /// The debouncer is bound to this node: frames only count while it is inside the scene tree and not paused, and pending runs are
+    /// cancelled once it is freed.
+    ///
+    /// # Panics
+    /// On [`Debouncer::trigger()`], if the engine's main loop is not a [`SceneTree`].
+    #[cfg(since_api = "4.2")]
+    pub fn debounce(&self, callback: impl FnMut() + 'static) -> Debouncer {
+        let node = self.to_gd_object().cast::<Node>();
+        Debouncer::new(&node, callback)
+    }
+}
+
+/// Toggles of a node before [`Node::freeze()`], stored as user data.
+struct FrozenToggles(ProcessToggles);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Node2D` class.
+impl crate::classes::Node2D {
+    /// Moves this node under `new_parent`, adjusting its local transform so that its global transform stays the same.
+    ///
+    /// The new local transform is derived from the full global transforms of the node and the new parent. This preserves the node's
+    /// on-screen position, rotation and shape also under parents with rotation and non-uniform scale, where the result may need skew.
+    /// Nodes that are [top-level][crate::classes::CanvasItem::set_as_top_level] keep their transform. If the new parent is not a
+    /// `CanvasItem`, the node's global transform becomes its local transform.
+    ///
+    /// Returns `false` and leaves the node unchanged if the global transform cannot be preserved:
+    /// - The node or `new_parent` is not inside the scene tree, so there are no global transforms.
+    /// - `new_parent` is this node or one of its descendants.
+    /// - The global transform of `new_parent` is not invertible, e.g. because it has a scale of zero.
+    pub fn reparent_keep_position<T>(&mut self, new_parent: &Gd<T>) -> bool
+    where
+        T: Inherits<Node>,
+    {
+        let self_id = self.to_gd_object().instance_id();
+        let parent_node = new_parent.clone().upcast::<Node>();
+
+        if !self.is_inside_tree()
+            || !parent_node.is_inside_tree()
+            || parent_node.instance_id() == self_id
+            || self.is_ancestor_of(&parent_node)
+        {
+            return false;
+        }
+
+        let parent_transform = match parent_node.try_cast::<crate::classes::CanvasItem>() {
+            Ok(parent) if !self.is_set_as_top_level() => parent.get_global_transform(),
+            _ => Transform2D::IDENTITY,
+        };
+        if parent_transform.determinant().is_zero_approx() {
+            return false;
+        }
+
+        let global_transform = self.get_global_transform();
+        self.reparent_ex(new_parent)
+            .keep_global_transform(false)
+            .done();
+        self.set_transform(parent_transform.affine_inverse() * global_transform);
+        true
+    }
+
+    /// Moves this node to the global position `target` over `duration` seconds, returning a future which resolves once it arrives.
+    ///
+    /// This creates a [`Tween`][crate::classes::Tween] bound to the node, with linear interpolation. Use
+    /// [`tween_to_eased()`][Self::tween_to_eased] to customize the motion.
+    ///
+    /// If the node is freed before the tween is done, the tween is killed and the future resolves to
+    /// [`TweenEnd::Cancelled`][crate::tools::TweenEnd::Cancelled]. See [`TweenFuture`][crate::tools::TweenFuture] for details.
+    ///
+    /// # Panics
+    /// If the node is not inside the scene tree.
+    #[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+    pub fn tween_to(
+        &mut self,
+        target: crate::builtin::Vector2,
+        duration: f64,
+    ) -> crate::tools::TweenFuture {
+        use crate::classes::tween::{EaseType, TransitionType};
+
+        self.tween_to_eased(target, duration, TransitionType::LINEAR, EaseType::IN_OUT)
+    }
+
+    /// Like [`tween_to()`][Self::tween_to], but with the given transition curve and easing.
+    ///
+    /// See Godot's [tween cheatsheet](https://docs.godotengine.org/en/stable/classes/class_tween.html#enum-tween-transitiontype) for
+    /// the available curves.
+    ///
+    /// # Panics
+    /// If the node is not inside the scene tree.
+    #[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+    pub fn tween_to_eased(
+        &mut self,
+        target: crate::builtin::Vector2,
+        duration: f64,
+        transition: crate::classes::tween::TransitionType,
+        ease: crate::classes::tween::EaseType,
+    ) -> crate::tools::TweenFuture {
+        assert!(
+            self.is_inside_tree(),
+            "Node2D::tween_to() requires the node to be inside the scene tree"
+        );
+
+        let node = self.to_gd_object().cast::<crate::classes::Node2D>();
+        let node_id = node.instance_id();
+
+        let mut tween = self
+            .create_tween()
+            .expect("Node::create_tween() returned null");
+        tween
+            .tween_property(
+                &node,
+                NodePath::from("global_position"),
+                &target.to_variant(),
+                duration,
+            )
+            .expect("Tween::tween_property() returned null")
+            .set_trans(transition)
+            .expect("PropertyTweener::set_trans() returned null")
+            .set_ease(ease);
+
+        crate::tools::TweenFuture::new(tween, node_id)
+    }
+
+    /// Converts `point` from this node's local coordinates to the local coordinates of `other`.
+    ///
+    /// Composes this node's global transform with the inverse global transform of `other`, which replaces chaining
+    /// [`to_global()`][Self::to_global] on this node and [`to_local()`][Self::to_local] on `other`.
+    ///
+    /// Returns `None` if one of the nodes is not inside the scene tree, or if the global transform of `other` is not invertible, e.g.
+    /// because it has a scale of zero.
+    pub fn to_local_of<T>(&self, point: Vector2, other: &Gd<T>) -> Option<Vector2>
+    where
+        T: Inherits<crate::classes::Node2D>,
+    {
+        let other = other.upcast_ref::<crate::classes::Node2D>();
+        if !self.is_inside_tree() || !other.is_inside_tree() {
+            return None;
+        }
+
+        let target_transform = other.get_global_transform();
+        if target_transform.determinant().is_zero_approx() {
+            return None;
+        }
+
+        Some(target_transform.affine_inverse() * (self.get_global_transform() * point))
+    }
+
+    /// Converts `point` from the local coordinates of `from` to the local coordinates of `to`.
+    ///
+    /// Equivalent to [`from.to_local_of(point, to)`][Self::to_local_of], see there for details.
+    pub fn convert_point<F, T>(point: Vector2, from: &Gd<F>, to: &Gd<T>) -> Option<Vector2>
+    where
+        F: Inherits<crate::classes::Node2D>,
+        T: Inherits<crate::classes::Node2D>,
+    {
+        from.upcast_ref::<crate::classes::Node2D>()
+            .to_local_of(point, to)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Node3D` class.
+impl crate::classes::Node3D {
+    /// Rotates the node so that it faces global position `target`, with `up` as the up direction, if such a rotation exists.
+    ///
+    /// Like [`look_at_ex()`][Self::look_at_ex], the node's forward axis `-Z` is turned towards `target`. With `use_model_front`,
+    /// the model front axis `+Z` is used instead, which matches imported models facing the viewer (available in all Godot versions).
+    /// Pass `Vector3::UP` for `up` to keep the node upright, like `look_at()` does by default.
+    ///
+    /// Returns `false` and leaves the node unchanged if the rotation is not well-defined, instead of letting Godot print an error:
+    /// - The node is not inside the scene tree, so it has no global transform.
+    /// - `target` coincides with the node's global position, so there is no direction to face.
+    /// - `up` is zero or parallel to the direction towards `target` (e.g. aiming straight up with `Vector3::UP`). In this case, the roll
+    ///   around the view axis is ambiguous; choose another `up` vector, such as the node's current forward or right axis.
+    pub fn try_look_at(&mut self, target: Vector3, up: Vector3, use_model_front: bool) -> bool {
+        if !self.is_inside_tree() {
+            return false;
+        }
+
+        let position = self.get_global_position();
+        let direction = target - position;
+        if direction.is_zero_approx() || direction.cross(up).is_zero_approx() {
+            return false;
+        }
+
+        // Facing away from the point mirrored at the node's position turns +Z towards the target.
+        let target = if use_model_front {
+            position - direction
+        } else {
+            target
+        };
+
+        self.look_at_ex(target).up(up).done();
+        true
+    }
+
+    /// Moves this node under `new_parent`, adjusting its local transform so that its global transform stays the same.
+    ///
+    /// The new local transform is derived from the full global transforms of the node and the new parent, so the node keeps its position,
+    /// orientation and shape in the world. Under parents with rotation and non-uniform scale, the resulting basis may contain shear;
+    /// it is applied as-is. Nodes that are [top-level][Self::set_as_top_level] keep their transform. If the new parent is not a
+    /// `Node3D`, the node's global transform becomes its local transform.
+    ///
+    /// Returns `false` and leaves the node unchanged if the global transform cannot be preserved:
+    /// - The node or `new_parent` is not inside the scene tree, so there are no global transforms.
+    /// - `new_parent` is this node or one of its descendants.
+    /// - The global transform of `new_parent` is not invertible, e.g. because it has a scale of zero.
+    pub fn reparent_keep_position<T>(&mut self, new_parent: &Gd<T>) -> bool
+    where
+        T: Inherits<Node>,
+    {
+        let self_id = self.to_gd_object().instance_id();
+        let parent_node = new_parent.clone().upcast::<Node>();
+
+        if !self.is_inside_tree()
+            || !parent_node.is_inside_tree()
+            || parent_node.instance_id() == self_id
+            || self.is_ancestor_of(&parent_node)
+        {
+            return false;
+        }
+
+        let parent_transform = match parent_node.try_cast::<crate::classes::Node3D>() {
+            Ok(parent) if !self.is_set_as_top_level() => parent.get_global_transform(),
+            _ => Transform3D::IDENTITY,
+        };
+        if parent_transform.basis.determinant().is_zero_approx() {
+            return false;
+        }
+
+        let global_transform = self.get_global_transform();
+        self.reparent_ex(new_parent)
+            .keep_global_transform(false)
+            .done();
+        self.set_transform(parent_transform.affine_inverse() * global_transform);
+        true
+    }
+
+    /// Returns the 3D world this node is part of, or `None` if the node is not inside a tree.
+    ///
+    /// The world gives access to the physics space (for queries such as ray casts) and the rendering scenario. Unlike
+    /// [`get_world_3d()`][Self::get_world_3d], this does not make Godot print an error for nodes outside a tree.
+    #[cfg(feature = "codegen-full")]
+    pub fn world_3d(&self) -> Option<Gd<crate::classes::World3D>> {
+        if self.is_inside_tree() {
+            self.get_world_3d()
+        } else {
+            None
+        }
+    }
+
+    /// Casts a physics ray from this node's global position along its forward axis (-Z), up to `distance` units far.
+    ///
+    /// This is the typical look or aim ray cast: it composes the global transform with a query on the physics space of the node's
+    /// [world][Self::world_3d]. Only colliders in the physics layers of `collision_mask` are hit. If this node is itself a
+    /// [`CollisionObject3D`][crate::classes::CollisionObject3D], such as a character body, it is excluded from the ray cast.
+    ///
+    /// Returns `None` if nothing was hit, or if the node is not inside the scene tree and thus in no physics space. Like all direct
+    /// space queries, this should be called from `physics_process()`, when the physics state is up to date.
+    #[cfg(feature = "codegen-full")]
+    pub fn raycast_forward(&self, distance: real, collision_mask: u32) -> Option<RaycastHit> {
+        let space_state = self.world_3d()?.get_direct_space_state()?;
+
+        let transform = self.get_global_transform();
+        let forward = -transform.basis.col_c().normalized();
+        let from = transform.origin;
+        let to = from + forward * distance;
+
+        let exclude = match self
+            .to_gd_object()
+            .try_cast::<crate::classes::CollisionObject3D>()
+        {
+            Ok(body) => Array::from(&[body.get_rid()]),
+            Err(_) => Array::new(),
+        };
+
+        crate::tools::cast_ray_3d(space_state, from, to, collision_mask, exclude)
+    }
+
+    /// Converts `point` from this node's local coordinates to the local coordinates of `other`.
+    ///
+    /// Composes this node's global transform with the inverse global transform of `other`, which replaces chaining
+    /// [`to_global()`][Self::to_global] on this node and [`to_local()`][Self::to_local] on `other`.
+    ///
+    /// Returns `None` if one of the nodes is not inside the scene tree, or if the global transform of `other` is not invertible, e.g.
+    /// because it has a scale of zero.
+    pub fn to_local_of<T>(&self, point: Vector3, other: &Gd<T>) -> Option<Vector3>
+    where
+        T: Inherits<crate::classes::Node3D>,
+    {
+        let other = other.upcast_ref::<crate::classes::Node3D>();
+        if !self.is_inside_tree() || !other.is_inside_tree() {
+            return None;
+        }
+
+        let target_transform = other.get_global_transform();
+        if target_transform.basis.determinant().is_zero_approx() {
+            return None;
+        }
+
+        Some(target_transform.affine_inverse() * (self.get_global_transform() * point))
+    }
+
+    /// Converts `point` from the local coordinates of `from` to the local coordinates of `to`.
+    ///
+    /// Equivalent to [`from.to_local_of(point, to)`][Self::to_local_of], see there for details.
+    pub fn convert_point<F, T>(point: Vector3, from: &Gd<F>, to: &Gd<T>) -> Option<Vector3>
+    where
+        F: Inherits<crate::classes::Node3D>,
+        T: Inherits<crate::classes::Node3D>,
+    {
+        from.upcast_ref::<crate::classes::Node3D>()
+            .to_local_of(point, to)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Camera3D` class.
+impl crate::classes::Camera3D {
+    /// Returns the ray through screen position `screen_pos`, as origin and normalized direction in global coordinates.
+    ///
+    /// Combines [`project_ray_origin()`][Self::project_ray_origin] and [`project_ray_normal()`][Self::project_ray_normal], e.g. to pick
+    /// objects under the mouse cursor with a physics ray cast. Returns `None` if the camera is not inside the scene tree, instead of
+    /// letting Godot print an error.
+    pub fn screen_ray(&self, screen_pos: Vector2) -> Option<(Vector3, Vector3)> {
+        if !self.is_inside_tree() {
+            return None;
+        }
+
+        let origin = self.project_ray_origin(screen_pos);
+        let normal = self.project_ray_normal(screen_pos);
+        Some((origin, normal))
+    }
+
+    /// Returns the global position that appears at screen position `screen_pos`, at distance `depth` along the camera's view axis.
+    ///
+    /// Inverse of [`world_to_screen()`][Self::world_to_screen], where `depth` is the distance of the original position from the camera
+    /// plane. Returns `None` if the camera is not inside the scene tree.
+    pub fn screen_to_world(&self, screen_pos: Vector2, depth: f32) -> Option<Vector3> {
+        if self.is_inside_tree() {
+            Some(self.project_position(screen_pos, depth))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the screen position at which global position `world_pos` appears.
+    ///
+    /// Unlike [`unproject_position()`][Self::unproject_position], this returns `None` for positions behind the camera, for which Godot
+    /// computes a mirrored position that is not meaningful. Also returns `None` if the camera is not inside the scene tree.
+    ///
+    /// Positions in front of the camera but outside its view, e.g. to the side, are still returned and lie outside the viewport's
+    /// visible rectangle. This is useful to place on-screen indicators pointing towards them. Use
+    /// [`is_position_in_frustum()`][Self::is_position_in_frustum] to check whether a position is actually visible.
+    pub fn world_to_screen(&self, world_pos: Vector3) -> Option<Vector2> {
+        if !self.is_inside_tree() || self.is_position_behind(world_pos) {
+            return None;
+        }
+
+        Some(self.unproject_position(world_pos))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Camera2D` class.
+impl crate::classes::Camera2D {
+    /// Returns the screen position at which global position `world_pos` is drawn.
+    ///
+    /// Screen positions are in the viewport's coordinates, like [`Viewport::get_mouse_position()`]. The conversion uses the transform
+    /// of the canvas the camera is part of, which reflects the camera's scrolling and zoom while it is the current camera. Positions
+    /// outside the visible area are still converted and lie outside the viewport's visible rectangle.
+    ///
+    /// Returns `None` if the camera is not inside the scene tree.
+    pub fn world_to_screen(&self, world_pos: Vector2) -> Option<Vector2> {
+        if self.is_inside_tree() {
+            Some(self.get_canvas_transform() * world_pos)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the global position drawn at screen position `screen_pos`, e.g. to find what the mouse cursor points at.
+    ///
+    /// Inverse of [`world_to_screen()`][Self::world_to_screen]. Returns `None` if the camera is not inside the scene tree.
+    pub fn screen_to_world(&self, screen_pos: Vector2) -> Option<Vector2> {
+        if self.is_inside_tree() {
+            Some(self.get_canvas_transform().affine_inverse() * screen_pos)
+        } else {
+            None
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Area3D` class.
+///
+/// Overlaps are updated once per physics frame. Bodies and areas which just entered the area (or were just added to the tree) are only
+/// reported after the next physics step. While [monitoring][Self::set_monitoring] is disabled, the area reports no overlaps; these
+/// methods then return empty results, instead of letting Godot print an error.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::Area3D {
+    /// Returns the overlapping physics bodies (and grid maps) which are of class `T` or derived from it.
+    ///
+    /// Like [`get_overlapping_bodies()`][Self::get_overlapping_bodies], but skips bodies of other classes instead of requiring a cast.
+    pub fn overlapping_bodies_typed<T>(&self) -> Array<Gd<T>>
+    where
+        T: Inherits<crate::classes::Node3D>,
+    {
+        if !self.is_monitoring() {
+            return Array::new();
+        }
+
+        self.get_overlapping_bodies()
+            .iter_shared()
+            .filter_map(|body| body.try_cast::<T>().ok())
+            .collect()
+    }
+
+    /// Returns the overlapping areas which are of class `T` or derived from it.
+    ///
+    /// Like [`get_overlapping_areas()`][Self::get_overlapping_areas], but skips areas of other classes instead of requiring a cast.
+    /// Only areas which are [monitorable][Self::set_monitorable] are reported.
+    pub fn overlapping_areas_typed<T>(&self) -> Array<Gd<T>>
+    where
+        T: Inherits<crate::classes::Area3D>,
+    {
+        if !self.is_monitoring() {
+            return Array::new();
+        }
+
+        self.get_overlapping_areas()
+            .iter_shared()
+            .filter_map(|area| area.try_cast::<T>().ok())
+            .collect()
+    }
+
+    /// Returns `true` if `node` currently overlaps this area.
+    ///
+    /// `node` can be a physics body, grid map or another area; for other nodes, `false` is returned. Combines
+    /// [`overlaps_body()`][Self::overlaps_body] and [`overlaps_area()`][Self::overlaps_area].
+    pub fn is_overlapping<T>(&self, node: &Gd<T>) -> bool
+    where
+        T: Inherits<Node>,
+    {
+        if !self.is_monitoring() {
+            return false;
+        }
+
+        let node = node.clone().upcast::<Node>();
+        match node.try_cast::<crate::classes::Area3D>() {
+            Ok(area) => self.overlaps_area(&area),
+            Err(node)
+                if node.is_class("PhysicsBody3D".into()) || node.is_class("GridMap".into()) =>
+            {
+                self.overlaps_body(&node)
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `CanvasItem` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::CanvasItem {
+    /// Returns the 2D world this node is part of, or `None` if the node is not inside a tree.
+    ///
+    /// The world gives access to the physics space (for queries such as ray casts) and the canvas. Unlike
+    /// [`get_world_2d()`][Self::get_world_2d], this does not make Godot print an error for nodes outside a tree.
+    pub fn world_2d(&self) -> Option<Gd<crate::classes::World2D>> {
+        if self.is_inside_tree() {
+            self.get_world_2d()
+        } else {
+            None
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `PackedScene` class.
+impl PackedScene {
+    /// ⚠️ Instantiates the scene as type `T`, panicking if not found or bad type.
+    ///
+    /// # Panics
+    /// If the scene is not type `T` or inherited.
+    pub fn instantiate_as<T>(&self) -> Gd<T>
+    where
+        T: Inherits<Node>,
+    {
+        self.try_instantiate_as::<T>()
+            .unwrap_or_else(|| panic!("Failed to instantiate {to}", to = T::class_name()))
+    }
+
+    /// Instantiates the scene as type `T` (fallible).
+    ///
+    /// If the scene is not type `T` or inherited.
+    pub fn try_instantiate_as<T>(&self) -> Option<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        self.instantiate().and_then(|gd| gd.try_cast::<T>().ok())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `SceneTree` class.
+impl SceneTree {
+    /// Changes the current scene to the one stored at `path`, whose root node must be of class `T` (or inherit from it).
+    ///
+    /// Unlike [`change_scene_to_file()`][Self::change_scene_to_file], the scene is loaded and checked right away, so that load failures
+    /// and a mismatching root type are reported as errors, before the current scene is touched. The root type is read from the scene's
+    /// [`SceneState`][crate::classes::SceneState], without instantiating it.
+    ///
+    /// The switch itself is performed by [`change_scene_to_packed()`][Self::change_scene_to_packed], which instantiates the scene; if it
+    /// fails, Godot's error is returned. The old scene is removed from the tree and freed at the end of the current frame, and the new
+    /// instance is added to the tree and becomes the current scene. To wait until the new scene is ready, use
+    /// [`change_scene_and_await_ready()`][Self::change_scene_and_await_ready].
+    pub fn change_scene_to<T>(&mut self, path: impl Into<GString>) -> Result<(), SceneChangeError>
+    where
+        T: Inherits<Node>,
+    {
+        let path = path.into();
+        let error = |kind| SceneChangeError::new(kind, path.to_string());
+
+        let scene = crate::tools::try_load::<PackedScene>(path.clone())
+            .map_err(|err| error(SceneChangeErrorKind::Load(err)))?;
+
+        // Scenes without nodes fail in change_scene_to_packed() below.
+        if let Some(actual) = scene_root_class(&scene) {
+            let expected = T::class_name().to_string_name();
+            let class_db = crate::classes::ClassDb::singleton();
+            if !class_db.is_parent_class(actual.clone(), expected.clone()) {
+                return Err(error(SceneChangeErrorKind::RootType {
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                }));
+            }
+        }
+
+        match self.change_scene_to_packed(&scene) {
+            GodotError::OK => Ok(()),
+            err => Err(error(SceneChangeErrorKind::Instantiate(err))),
+        }
+    }
+
+    /// Like [`change_scene_to()`][Self::change_scene_to], but returns a future which resolves once the new scene is ready.
+    ///
+    /// At that point, the new root node is the current scene, and `ready()` has run for it and all its children. This is useful for
+    /// loading screens or game flow logic, which need to access the new scene after switching. The future resolves to the new root.
+    ///
+    /// Errors are returned right away, without switching scenes. If no new scene of class `T` is current and ready within
+    /// [`SCENE_CHANGE_MAX_FRAMES`][Self::SCENE_CHANGE_MAX_FRAMES] process frames, e.g. because the scene was changed again or removed,
+    /// the future resolves to `None`.
+    #[cfg(since_api = "4.2")]
+    pub fn change_scene_and_await_ready<T>(
+        &mut self,
+        path: impl Into<GString>,
+    ) -> Result<impl std::future::Future<Output = Option<Gd<T>>>, SceneChangeError>
+    where
+        T: Inherits<Node>,
+    {
+        let old_scene = self.get_current_scene().map(|scene| scene.instance_id());
+        self.change_scene_to::<T>(path)?;
+
+        let tree = self.to_gd_object().cast::<SceneTree>();
+        Ok(async move {
+            // The switch happens in a deferred call, which adds the new root to the tree (running `ready()`) and makes it current.
+            for _ in 0..Self::SCENE_CHANGE_MAX_FRAMES {
+                tree.await_signal("process_frame").await;
+
+                let Some(scene) = tree.get_current_scene() else {
+                    continue;
+                };
+                if Some(scene.instance_id()) == old_scene || !scene.is_node_ready() {
+                    continue;
+                }
+                if let Ok(root) = scene.try_cast::<T>() {
+                    return Some(root);
+                }
+            }
+
+            None
+        })
+    }
+
+    /// Number of process frames that [`change_scene_and_await_ready()`][Self::change_scene_and_await_ready] waits for the new scene.
+    ///
+    /// Godot switches scenes within the next frame, so this leaves ample room.
+    #[cfg(since_api = "4.2")]
+    pub const SCENE_CHANGE_MAX_FRAMES: u32 = 10;
+
+    /// Returns a future which resolves once the current scene and all nodes in it are ready.
+    ///
+    /// The nodes are determined at the time of the call; nodes added to the scene later are not waited for. If there is no current scene
+    /// or it is fully ready, the future resolves immediately.
+    ///
+    /// See [`ReadyBarrierFuture`] for details.
+    #[cfg(since_api = "4.2")]
+    pub fn await_scene_ready(&self) -> ReadyBarrierFuture {
+        let nodes = match self.get_current_scene() {
+            Some(scene) => std::iter::once(scene.clone())
+                .chain(scene.descendants_bfs())
+                .collect(),
+            None => Vec::new(),
+        };
+
+        ReadyBarrierFuture::new(nodes)
+    }
+
+    /// Calls `method` on all nodes in `group`, converting the Rust arguments to variants.
+    ///
+    /// This is a typed version of [`call_group()`][Self::call_group], e.g. to broadcast gameplay events such as
+    /// `tree.call_group_typed("enemies", "set_paused", (true,))`. `args` is a tuple of [`ToGodot`] values, or `()` for no arguments.
+    /// Nodes that don't have the method are skipped; a method with wrong parameter types causes a Godot error for each node.
+    ///
+    /// The call is immediate. For other behaviors, see [`call_group_flags_typed()`][Self::call_group_flags_typed].
+    pub fn call_group_typed(
+        &mut self,
+        group: impl Into<StringName>,
+        method: impl Into<StringName>,
+        args: impl MethodArgs,
+    ) {
+        self.call_group(group.into(), method.into(), &args.to_variants());
+    }
+
+    /// Like [`call_group_typed()`][Self::call_group_typed], but calls the method at the end of the current frame.
+    pub fn call_group_deferred_typed(
+        &mut self,
+        group: impl Into<StringName>,
+        method: impl Into<StringName>,
+        args: impl MethodArgs,
+    ) {
+        self.call_group_flags_typed(&[GroupCallFlags::DEFERRED], group, method, args);
+    }
+
+    /// Like [`call_group_typed()`][Self::call_group_typed], with [`GroupCallFlags`] that control the order and timing of the calls.
+    ///
+    /// All given flags are combined, e.g. `&[GroupCallFlags::REVERSE, GroupCallFlags::DEFERRED]`. An empty slice is equivalent to
+    /// [`GroupCallFlags::DEFAULT`].
+    pub fn call_group_flags_typed(
+        &mut self,
+        flags: &[GroupCallFlags],
+        group: impl Into<StringName>,
+        method: impl Into<StringName>,
+        args: impl MethodArgs,
+    ) {
+        let flags = flags.iter().fold(0, |acc, flag| acc | flag.ord() as i64);
+
+        self.call_group_flags(flags, group.into(), method.into(), &args.to_variants());
+    }
+
+    /// Returns a stream of the nodes in `group`, starting with the current members and followed by each member entering the tree.
+    ///
+    /// This is useful for reactive systems, e.g. registering every enemy as it is spawned. See [`GroupNodeStream`] for details.
+    #[cfg(since_api = "4.2")]
+    pub fn nodes_added_to_group(&self, group: impl Into<StringName>) -> GroupNodeStream {
+        let tree = self.to_gd_object().cast::<SceneTree>();
+        GroupNodeStream::new(&tree, group.into())
+    }
+}
+
+/// Class of the root node of `scene`, read without instantiating it, or `None` if the scene has no nodes.
+///
+/// The root of an inherited scene is an instance of its base scene, whose root class is looked up recursively.
+fn scene_root_class(scene: &Gd<PackedScene>) -> Option<StringName> {
+    let state = scene.get_state()?;
+    if state.get_node_count() == 0 {
+        return None;
+    }
+
+    let class = state.get_node_type(0);
+    if !class.is_empty() {
+        return Some(class);
+    }
+
+    scene_root_class(&state.get_node_instance(0)?)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `AudioStreamPlayer` class.
+#[cfg(since_api = "4.2")]
+impl crate::classes::AudioStreamPlayer {
+    /// Plays the stream from the beginning, and returns a future which resolves once playback ends.
+    ///
+    /// The future resolves to [`PlaybackEnd::Finished`][crate::tools::PlaybackEnd::Finished] when the player emits `finished`, or to
+    /// [`PlaybackEnd::Stopped`][crate::tools::PlaybackEnd::Stopped] if playback ends early, e.g. through [`stop()`][Self::stop].
+    ///
+    /// Looping streams never emit `finished`. For them, the future only resolves once playback is stopped.
+    /// See [`AudioPlaybackFuture`] for details.
+    pub fn play_and_await(&mut self) -> AudioPlaybackFuture {
+        let player = self
+            .to_gd_object()
+            .cast::<crate::classes::AudioStreamPlayer>();
+        let future = AudioPlaybackFuture::new(player);
+
+        self.play();
+        future
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `HTTPRequest` class.
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+impl crate::classes::HttpRequest {
+    /// Starts a request to `url`, and returns a future which resolves once the request completes.
+    ///
+    /// This is the equivalent of calling [`request_raw()`][Self::request_raw] followed by `await request_completed` in GDScript.
+    /// The future resolves to the response, or to an error if the request could not be started (e.g. because the node is not inside the
+    /// scene tree or still busy with another request), or failed due to a network error or timeout.
+    ///
+    /// See [`HttpRequestOptions`][crate::tools::HttpRequestOptions] for method, headers, body and timeout, and
+    /// [`HttpRequestFuture`][crate::tools::HttpRequestFuture] for details.
+    pub fn request_async(
+        &mut self,
+        url: impl Into<GString>,
+        options: crate::tools::HttpRequestOptions,
+    ) -> crate::tools::HttpRequestFuture {
+        let request = self.to_gd_object().cast::<crate::classes::HttpRequest>();
+
+        crate::tools::HttpRequestFuture::new(request, url.into(), options)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `AnimationTree` class.
+///
+/// These control the state machine at the root of the tree, e.g. to drive a character's animation states from gameplay code.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::AnimationTree {
+    /// Returns the playback of the root state machine, or `None` if the tree root is not an `AnimationNodeStateMachine`.
+    ///
+    /// Equivalent to reading the `parameters/playback` property. Playbacks of nested state machines are available under
+    /// `parameters/<path>/playback`.
+    pub fn state_machine_playback(
+        &self,
+    ) -> Option<Gd<crate::classes::AnimationNodeStateMachinePlayback>> {
+        self.get("parameters/playback".into()).try_to().ok()
+    }
+
+    /// Travels to `state` in the root state machine, following the shortest path of transitions.
+    ///
+    /// Returns `false` and does nothing if the tree root is not a state machine, or has no state named `state`. As with Godot's
+    /// [`travel()`][crate::classes::AnimationNodeStateMachinePlayback::travel], the transition happens during the next processing of
+    /// the tree; if no path exists, the playback teleports to `state`.
+    pub fn travel(&mut self, state: &str) -> bool {
+        let state = StringName::from(state);
+
+        let has_state = self
+            .get_tree_root()
+            .and_then(|root| {
+                root.try_cast::<crate::classes::AnimationNodeStateMachine>()
+                    .ok()
+            })
+            .is_some_and(|machine| machine.has_node(state.clone()));
+
+        if !has_state {
+            return false;
+        }
+
+        let Some(mut playback) = self.state_machine_playback() else {
+            return false;
+        };
+
+        playback.travel(state);
+        true
+    }
+
+    /// Returns the name of the current state of the root state machine, or an empty string if there is none.
+    pub fn current_state(&self) -> GString {
+        self.state_machine_playback()
+            .map(|playback| GString::from(playback.get_current_node()))
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the root state machine is currently playing a state.
+    pub fn is_playing(&self) -> bool {
+        self.state_machine_playback()
+            .is_some_and(|playback| playback.is_playing())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `ConfigFile` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::ConfigFile {
+    /// Retrieves the value of `key` in `section` as type `V`, or `default` if not available.
+    ///
+    /// `default` is returned if the section or key does not exist, or if the stored value cannot be converted to `V`.
+    pub fn get_value_as<V>(
+        &self,
+        section: impl Into<GString>,
+        key: impl Into<GString>,
+        default: V,
+    ) -> V
+    where
+        V: FromGodot,
+    {
+        let section = section.into();
+        let key = key.into();
+
+        if !self.has_section_key(section.clone(), key.clone()) {
+            return default;
+        }
+
+        self.get_value(section, key).try_to().unwrap_or(default)
+    }
+
+    /// Stores `value` under `key` in `section`, creating the section if necessary.
+    ///
+    /// Typed counterpart to [`set_value()`][Self::set_value]. Note that passing `Variant::nil()` removes the key, like in Godot.
+    pub fn set_value_as(
+        &mut self,
+        section: impl Into<GString>,
+        key: impl Into<GString>,
+        value: impl ToGodot,
+    ) {
+        self.set_value(section.into(), key.into(), &value.to_variant());
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `TileMap` class.
+///
+/// In Godot 4.3+, `TileMap` is deprecated in favor of one `TileMapLayer` node per layer, which offers the same extensions.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::TileMap {
+    /// Returns the coordinates of the cell containing the global position `global_position`.
+    ///
+    /// Combines [`to_local()`][Self::to_local] and [`local_to_map()`][Self::local_to_map].
+    pub fn global_to_map(&self, global_position: Vector2) -> Vector2i {
+        self.local_to_map(self.to_local(global_position))
+    }
+
+    /// Returns the global position of the center of the cell at `coords`.
+    ///
+    /// Combines [`map_to_local()`][Self::map_to_local] and [`to_global()`][Self::to_global].
+    pub fn map_to_global(&self, coords: Vector2i) -> Vector2 {
+        self.to_global(self.map_to_local(coords))
+    }
+
+    /// Returns the tile source ID of the cell at `coords` on layer `layer`, or `None` if the cell is empty.
+    ///
+    /// Typed counterpart to [`get_cell_source_id()`][Self::get_cell_source_id], which returns `-1` for empty cells.
+    pub fn cell_source_id(&self, layer: i32, coords: Vector2i) -> Option<i32> {
+        match self.get_cell_source_id(layer, coords) {
+            -1 => None,
+            source_id => Some(source_id),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `TileMapLayer` class.
+#[cfg(all(feature = "codegen-full", since_api = "4.3"))]
+impl crate::classes::TileMapLayer {
+    /// Returns the coordinates of the cell containing the global position `global_position`.
+    ///
+    /// Combines [`to_local()`][Self::to_local] and [`local_to_map()`][Self::local_to_map].
+    pub fn global_to_map(&self, global_position: Vector2) -> Vector2i {
+        self.local_to_map(self.to_local(global_position))
+    }
+
+    /// Returns the global position of the center of the cell at `coords`.
+    ///
+    /// Combines [`map_to_local()`][Self::map_to_local] and [`to_global()`][Self::to_global].
+    pub fn map_to_global(&self, coords: Vector2i) -> Vector2 {
+        self.to_global(self.map_to_local(coords))
+    }
+
+    /// Returns the tile source ID of the cell at `coords`, or `None` if the cell is empty.
+    ///
+    /// Typed counterpart to [`get_cell_source_id()`][Self::get_cell_source_id], which returns `-1` for empty cells.
+    pub fn cell_source_id(&self, coords: Vector2i) -> Option<i32> {
+        match self.get_cell_source_id(coords) {
+            -1 => None,
+            source_id => Some(source_id),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `MultiMesh` class.
+///
+/// These methods write the data of all instances with a single [`set_buffer()`][Self::set_buffer] call, which is much faster than
+/// calling e.g. [`set_instance_transform()`][Self::set_instance_transform] once per instance.
+///
+/// # Buffer layout
+/// The buffer stores the data of each instance contiguously, as 32-bit floats:
+/// - The transform, as 12 floats for 3D (`basis.x.x, basis.y.x, basis.z.x, origin.x, basis.x.y, ..., origin.z`, i.e. the rows of the
+///   3x4 matrix), or 8 floats for 2D (`x.x, y.x, 0, origin.x, x.y, y.y, 0, origin.y`).
+/// - The color as 4 floats `r, g, b, a`, if [`use_colors`][Self::set_use_colors] is enabled.
+/// - The custom data as 4 floats, if [`use_custom_data`][Self::set_use_custom_data] is enabled.
+///
+/// Each method only overwrites its own part of the buffer, so transforms, colors and custom data can be set independently.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::MultiMesh {
+    /// Sets the 3D transforms of all instances at once.
+    ///
+    /// # Panics
+    /// If `transforms.len()` differs from the instance count, or if the transform format is not `TRANSFORM_3D`.
+    pub fn set_transforms(&mut self, transforms: &[Transform3D]) {
+        use crate::classes::multi_mesh::TransformFormat;
+
+        assert_eq!(
+            self.get_transform_format(),
+            TransformFormat::TRANSFORM_3D,
+            "MultiMesh::set_transforms() requires transform format TRANSFORM_3D"
+        );
+
+        self.write_instance_data("transforms", transforms, 0, |t, out| {
+            let rows = &t.basis.rows;
+            for (i, row) in rows.iter().enumerate() {
+                out[i * 4] = row.x.as_f32();
+                out[i * 4 + 1] = row.y.as_f32();
+                out[i * 4 + 2] = row.z.as_f32();
+            }
+
+            out[3] = t.origin.x.as_f32();
+            out[7] = t.origin.y.as_f32();
+            out[11] = t.origin.z.as_f32();
+        });
+    }
+
+    /// Sets the 2D transforms of all instances at once.
+    ///
+    /// # Panics
+    /// If `transforms.len()` differs from the instance count, or if the transform format is not `TRANSFORM_2D`.
+    pub fn set_transforms_2d(&mut self, transforms: &[Transform2D]) {
+        use crate::classes::multi_mesh::TransformFormat;
+
+        assert_eq!(
+            self.get_transform_format(),
+            TransformFormat::TRANSFORM_2D,
+            "MultiMesh::set_transforms_2d() requires transform format TRANSFORM_2D"
+        );
+
+        self.write_instance_data("transforms", transforms, 0, |t, out| {
+            out[..8].copy_from_slice(&[
+                t.a.x.as_f32(),
+                t.b.x.as_f32(),
+                0.0,
+                t.origin.x.as_f32(),
+                t.a.y.as_f32(),
+                t.b.y.as_f32(),
+                0.0,
+                t.origin.y.as_f32(),
+            ]);
+        });
+    }
+
+    /// Sets the colors of all instances at once.
+    ///
+    /// # Panics
+    /// If `colors.len()` differs from the instance count, or if [`use_colors`][Self::set_use_colors] is disabled.
+    pub fn set_colors(&mut self, colors: &[Color]) {
+        assert!(
+            self.is_using_colors(),
+            "MultiMesh::set_colors() requires use_colors to be enabled"
+        );
+
+        let offset = self.transform_float_count();
+        self.write_instance_data("colors", colors, offset, write_color);
+    }
+
+    /// Sets the custom data of all instances at once.
+    ///
+    /// # Panics
+    /// If `custom_data.len()` differs from the instance count, or if [`use_custom_data`][Self::set_use_custom_data] is disabled.
+    pub fn set_custom_data(&mut self, custom_data: &[Color]) {
+        assert!(
+            self.is_using_custom_data(),
+            "MultiMesh::set_custom_data() requires use_custom_data to be enabled"
+        );
+
+        let offset = self.transform_float_count() + if self.is_using_colors() { 4 } else { 0 };
+        self.write_instance_data("custom data", custom_data, offset, write_color);
+    }
+
+    /// Number of floats occupied by an instance's transform in the buffer.
+    fn transform_float_count(&self) -> usize {
+        use crate::classes::multi_mesh::TransformFormat;
+
+        match self.get_transform_format() {
+            TransformFormat::TRANSFORM_2D => 8,
+            _ => 12,
+        }
+    }
+
+    /// Overwrites one part of each instance's data, starting `offset` floats into the instance, and uploads the buffer.
+    fn write_instance_data<E>(
+        &mut self,
+        what: &str,
+        elements: &[E],
+        offset: usize,
+        write: impl Fn(&E, &mut [f32]),
+    ) {
+        let instance_count = self.get_instance_count() as usize;
+        assert_eq!(
+            elements.len(),
+            instance_count,
+            "MultiMesh: number of {what} must match instance count"
+        );
+
+        let mut stride = self.transform_float_count();
+        if self.is_using_colors() {
+            stride += 4;
+        }
+        if self.is_using_custom_data() {
+            stride += 4;
+        }
+
+        let mut buffer: PackedFloat32Array = self.get_buffer();
+        if buffer.len() != instance_count * stride {
+            buffer.resize(instance_count * stride);
+        }
+
+        for (instance, element) in buffer.as_mut_slice().chunks_exact_mut(stride).zip(elements) {
+            write(element, &mut instance[offset..]);
+        }
+
+        self.set_buffer(&buffer);
+    }
+}
+
+#[cfg(feature = "codegen-full")]
+fn write_color(color: &Color, out: &mut [f32]) {
+    out[..4].copy_from_slice(&[color.r, color.g, color.b, color.a]);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Skeleton3D` class.
+///
+/// # Buffer layout
+/// Bone poses are stored contiguously in order of bone index, each as 12 floats forming the rows of the 3x4 transform matrix
+/// (`basis.x.x, basis.y.x, basis.z.x, origin.x, basis.x.y, ..., origin.z`). This is the same layout as the transforms in a
+/// [`MultiMesh`][crate::classes::MultiMesh] buffer.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::Skeleton3D {
+    /// Number of floats per bone pose in the buffers of [`set_bone_poses()`][Self::set_bone_poses] and
+    /// [`bone_poses()`][Self::bone_poses].
+    pub const BONE_POSE_FLOATS: usize = 12;
+
+    /// Sets the poses of all bones, from a buffer of transforms.
+    ///
+    /// Godot has no bulk setter for bone poses, so this is not faster than setting each pose separately: it makes one `set_bone_pose()`
+    /// call per bone. It is meant for animation data produced on the Rust side in the layout of [`bone_poses()`][Self::bone_poses]. Before
+    /// Godot 4.3, which lacks `set_bone_pose()`, each pose is decomposed into position, rotation and scale, which are set separately.
+    ///
+    /// # Panics
+    /// If the length of `poses` is not [`BONE_POSE_FLOATS`][Self::BONE_POSE_FLOATS] times the bone count.
+    pub fn set_bone_poses(&mut self, poses: &PackedFloat32Array) {
+        let bone_count = self.get_bone_count() as usize;
+        assert_eq!(
+            poses.len(),
+            bone_count * Self::BONE_POSE_FLOATS,
+            "Skeleton3D::set_bone_poses(): buffer must contain {} floats for each of the {bone_count} bones",
+            Self::BONE_POSE_FLOATS,
+        );
+
+        let floats = poses.as_slice().chunks_exact(Self::BONE_POSE_FLOATS);
+        for (bone, floats) in floats.enumerate() {
+            let pose = read_transform_rows(floats);
+            let bone = bone as i32;
+
+            #[cfg(since_api = "4.3")]
+            self.set_bone_pose(bone, pose);
+
+            #[cfg(before_api = "4.3")]
+            {
+                // Like Basis::get_rotation_quaternion(), remove reflection so that the basis is a pure rotation.
+                let rotation = if pose.basis.determinant() < 0.0 {
+                    pose.basis.scaled(Vector3::splat(-1.0)).to_quat()
+                } else {
+                    pose.basis.to_quat()
+                };
+
+                self.set_bone_pose_position(bone, pose.origin);
+                self.set_bone_pose_rotation(bone, rotation);
+                self.set_bone_pose_scale(bone, pose.basis.scale());
+            }
+        }
+    }
+
+    /// Returns the poses of all bones as a buffer of transforms.
+    ///
+    /// The buffer can be modified and passed to [`set_bone_poses()`][Self::set_bone_poses].
+    pub fn bone_poses(&self) -> PackedFloat32Array {
+        let bone_count = self.get_bone_count();
+
+        let mut poses = PackedFloat32Array::new();
+        poses.resize(bone_count as usize * Self::BONE_POSE_FLOATS);
+
+        let floats = poses
+            .as_mut_slice()
+            .chunks_exact_mut(Self::BONE_POSE_FLOATS);
+        for (bone, out) in (0..bone_count).zip(floats) {
+            write_transform_rows(&self.get_bone_pose(bone), out);
+        }
+
+        poses
+    }
+}
+
+#[cfg(feature = "codegen-full")]
+fn read_transform_rows(floats: &[f32]) -> Transform3D {
+    let row = |i: usize| {
+        Vector3::new(
+            real::from_f32(floats[i * 4]),
+            real::from_f32(floats[i * 4 + 1]),
+            real::from_f32(floats[i * 4 + 2]),
+        )
+    };
+    let origin = Vector3::new(
+        real::from_f32(floats[3]),
+        real::from_f32(floats[7]),
+        real::from_f32(floats[11]),
+    );
+
+    Transform3D::new(Basis::from_rows(row(0), row(1), row(2)), origin)
+}
+
+#[cfg(feature = "codegen-full")]
+fn write_transform_rows(transform: &Transform3D, out: &mut [f32]) {
+    for (i, row) in transform.basis.rows.iter().enumerate() {
+        out[i * 4] = row.x.as_f32();
+        out[i * 4 + 1] = row.y.as_f32();
+        out[i * 4 + 2] = row.z.as_f32();
+    }
+
+    out[3] = transform.origin.x.as_f32();
+    out[7] = transform.origin.y.as_f32();
+    out[11] = transform.origin.z.as_f32();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `RandomNumberGenerator` class.
+///
+/// All values are derived from the engine's generator, so a given seed produces the same sequence as in GDScript, when the same methods
+/// are called in the same order.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::RandomNumberGenerator {
+    /// Returns a random number in `[0.0, 1.0]`, as [`real`].
+    ///
+    /// [`real`]-typed counterpart to [`randf()`][Self::randf].
+    pub fn rand_real(&mut self) -> real {
+        real::from_f32(self.randf())
+    }
+
+    /// Returns a random number in `[min, max]`, as [`real`].
+    ///
+    /// [`real`]-typed counterpart to [`randf_range()`][Self::randf_range]. For integers, use [`randi_range()`][Self::randi_range].
+    pub fn rand_real_range(&mut self, min: real, max: real) -> real {
+        real::from_f32(self.randf_range(min.as_f32(), max.as_f32()))
+    }
+
+    /// Returns a random 2D vector of length 1, with uniformly distributed direction.
+    pub fn rand_vector2_unit(&mut self) -> Vector2 {
+        let angle = self.rand_real_range(0.0, real_consts::TAU);
+        Vector2::from_angle(angle)
+    }
+
+    /// Returns a random 3D vector of length 1, uniformly distributed on the unit sphere.
+    pub fn rand_vector3_unit(&mut self) -> Vector3 {
+        // Uniform height and azimuth yield a uniform distribution on the sphere (Archimedes' hat-box theorem).
+        let z = self.rand_real_range(-1.0, 1.0);
+        let azimuth = self.rand_real_range(0.0, real_consts::TAU);
+        let radius = (1.0 - z * z).max(0.0).sqrt();
+
+        Vector3::new(radius * azimuth.cos(), radius * azimuth.sin(), z)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Curve` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::Curve {
+    /// Returns the Y value of the curve at X position `offset`, as [`real`].
+    ///
+    /// Uses the baked cache, like [`sample_baked()`][Self::sample_baked]. If the curve has been modified since it was last baked, it is
+    /// baked again first.
+    pub fn sample_at(&self, offset: real) -> real {
+        real::from_f64(self.sample_baked(offset.as_f64()))
+    }
+
+    /// Samples the curve at `count` evenly spaced X positions, from the start to the end of its domain (both inclusive).
+    ///
+    /// For `count == 1`, only the start of the domain is sampled.
+    pub fn sample_evenly(&self, count: usize) -> impl Iterator<Item = real> + '_ {
+        #[cfg(since_api = "4.4")]
+        let (min, max) = (
+            real::from_f64(self.get_min_domain()),
+            real::from_f64(self.get_max_domain()),
+        );
+        #[cfg(before_api = "4.4")]
+        let (min, max) = (0.0, 1.0);
+
+        evenly_spaced(min, max, count).map(|offset| self.sample_at(offset))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Curve2D` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::Curve2D {
+    /// Returns the position at distance `offset` along the curve, measured from the first point.
+    ///
+    /// Uses the baked cache, like [`sample_baked()`][Self::sample_baked]. If the curve has been modified since it was last baked, it is
+    /// baked again first. Returns `Vector2::ZERO` if the curve has no points.
+    pub fn sample_position(&self, offset: real) -> Vector2 {
+        if self.get_point_count() == 0 {
+            return Vector2::ZERO;
+        }
+
+        self.sample_baked(offset.as_f64())
+    }
+
+    /// Samples `count` positions, evenly spaced along the whole length of the curve (both ends inclusive).
+    ///
+    /// Useful to place objects along a path. For `count == 1`, only the first point is sampled.
+    pub fn sample_positions_evenly(&self, count: usize) -> impl Iterator<Item = Vector2> + '_ {
+        // Also bakes the curve, if necessary.
+        let length = real::from_f64(self.get_baked_length());
+
+        evenly_spaced(0.0, length, count).map(|offset| self.sample_position(offset))
+    }
+}
+
+/// Returns `count` values from `start` to `end`, both inclusive.
+#[cfg(feature = "codegen-full")]
+fn evenly_spaced(start: real, end: real, count: usize) -> impl Iterator<Item = real> {
+    let step = if count > 1 {
+        (end - start) / (count - 1) as real
+    } else {
+        0.0
+    };
+
+    (0..count).map(move |i| start + step * i as real)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `ShaderMaterial` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::ShaderMaterial {
+    /// Sets the shader uniform `name` to `value`.
+    ///
+    /// Typed counterpart to [`set_shader_parameter()`][Self::set_shader_parameter], accepting any [`ToGodot`] value.
+    ///
+    /// Godot does not validate `name` or the type of `value`: values for uniforms that the shader does not declare (e.g. due to a typo),
+    /// or whose type does not match the uniform's type, are silently ignored during rendering. They are still stored in the material,
+    /// so [`get_param()`][Self::get_param] returns them. The declared uniforms can be listed with
+    /// [`Shader::get_shader_uniform_list()`][crate::classes::Shader::get_shader_uniform_list].
+    ///
+    /// Passing `Variant::nil()` resets the uniform to the default value from the shader code.
+    pub fn set_param(&mut self, name: impl Into<StringName>, value: impl ToGodot) {
+        self.set_shader_parameter(name.into(), &value.to_variant());
+    }
+
+    /// Returns the current value of the shader uniform `name`, converted to `V`.
+    ///
+    /// Returns `None` if no value has been set for the uniform, or if the value cannot be converted to `V`. Default values from the shader
+    /// code are not considered.
+    pub fn get_param<V>(&self, name: impl Into<StringName>) -> Option<V>
+    where
+        V: FromGodot,
+    {
+        let value = self.get_shader_parameter(name.into());
+        if value.is_nil() {
+            return None;
+        }
+
+        value.try_to().ok()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `Texture2D` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::Texture2D {
+    /// Returns the size of the texture in pixels.
+    ///
+    /// Integer counterpart to [`get_size()`][Self::get_size].
+    pub fn size(&self) -> Vector2i {
+        Vector2i::new(self.get_width(), self.get_height())
+    }
+
+    /// Returns a copy of the texture's pixel data, accessible on the CPU.
+    ///
+    /// Like [`get_image()`][Self::get_image], but also returns `None` if the image is empty. This is the case for textures whose data
+    /// only exists on the GPU or not at all, e.g. placeholder textures, or any texture when running with the dummy renderer (headless).
+    ///
+    /// Reading the data back from the GPU can be slow; avoid calling this every frame.
+    pub fn to_image(&self) -> Option<Gd<crate::classes::Image>> {
+        self.get_image().filter(|image| !image.is_empty())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `AtlasTexture` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::AtlasTexture {
+    /// Returns the region of the atlas used by this texture, in pixels.
+    ///
+    /// Integer counterpart to [`get_region()`][Self::get_region]; fractional coordinates are truncated.
+    pub fn pixel_region(&self) -> Rect2i {
+        self.get_region().cast_int()
+    }
+
+    /// Sets the region of the atlas used by this texture, in pixels.
+    ///
+    /// Integer counterpart to [`set_region()`][Self::set_region].
+    pub fn set_pixel_region(&mut self, region: Rect2i) {
+        self.set_region(region.cast_float());
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `UndoRedo` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::UndoRedo {
+    /// Records an undoable action named `action_name` and executes it.
+    ///
+    /// The closure registers the changes of the action on the [`UndoAction`]. Once it returns, they are passed to this object in one
+    /// [`create_action()`][Self::create_action]/[`commit_action()`][Self::commit_action] pair. If nothing was registered, no action
+    /// is created. If the closure panics, nothing is registered either.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::classes::UndoRedo;
+    /// fn move_node(undo_redo: &mut Gd<UndoRedo>, node: &Gd<Node2D>, target: Vector2) {
+    ///     undo_redo.with_undo("Move node", |action| {
+    ///         action.set_property(node, "position", target);
+    ///     });
+    /// }
+    /// ```
+    pub fn with_undo(
+        &mut self,
+        action_name: impl Into<GString>,
+        record: impl FnOnce(&mut UndoAction),
+    ) {
+        let mut action = UndoAction::default();
+        record(&mut action);
+        action.commit_to(self, action_name.into());
+    }
+
+    /// Records an undoable action named `action_name` and executes it, unless recording fails.
+    ///
+    /// Like [`with_undo()`][Self::with_undo], but if the closure returns `Err`, none of its changes are registered and the error is
+    /// returned.
+    pub fn try_with_undo<R, E>(
+        &mut self,
+        action_name: impl Into<GString>,
+        record: impl FnOnce(&mut UndoAction) -> Result<R, E>,
+    ) -> Result<R, E> {
+        let mut action = UndoAction::default();
+        let result = record(&mut action)?;
+        action.commit_to(self, action_name.into());
+
+        Ok(result)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Manual extensions for the `EditorUndoRedoManager` class.
+#[cfg(feature = "codegen-full")]
+impl crate::classes::EditorUndoRedoManager {
+    /// Records an undoable editor action named `action_name` and executes it.
+    ///
+    /// See [`UndoRedo::with_undo()`][crate::classes::UndoRedo::with_undo] for details. The action is added to the history determined
+    /// by the objects it changes, as with [`create_action()`][Self::create_action].
+    pub fn with_undo(
+        &mut self,
+        action_name: impl Into<GString>,
+        record: impl FnOnce(&mut UndoAction),
+    ) {
+        let mut action = UndoAction::default();
+        record(&mut action);
+        action.commit_to(self, action_name.into());
+    }
+
+    /// Records an undoable editor action named `action_name` and executes it, unless recording fails.
+    ///
+    /// See [`UndoRedo::try_with_undo()`][crate::classes::UndoRedo::try_with_undo] for details.
+    pub fn try_with_undo<R, E>(
+        &mut self,
+        action_name: impl Into<GString>,
+        record: impl FnOnce(&mut UndoAction) -> Result<R, E>,
+    ) -> Result<R, E> {
+        let mut action = UndoAction::default();
+        let result = record(&mut action)?;
+        action.commit_to(self, action_name.into());
+
+        Ok(result)
     }
 }
@@ -24,7 +24,10 @@
 
 mod print;
 
-pub use crate::{godot_error, godot_print, godot_print_rich, godot_script_error, godot_warn};
+pub use crate::{
+    godot_dbg, godot_error, godot_print, godot_print_rich, godot_print_variants,
+    godot_script_error, godot_warn,
+};
 
 // Some enums are directly re-exported from crate::builtin.
 pub use crate::gen::central::global_enums::*;
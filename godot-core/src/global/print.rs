@@ -105,3 +105,76 @@ macro_rules! godot_print_rich {
         ])
     };
 }
+
+/// Prints values to the Godot console, converting each one to `Variant` and formatting it like GDScript does.
+///
+/// Arguments can be of any type implementing [`ToGodot`][crate::meta::ToGodot]. They are concatenated without separator, so the output
+/// is the same as that of GDScript's `print(a, b, ...)` with the same values. This differs from [`godot_print!`], which formats with
+/// Rust's `Display` and thus needs format strings, and is not available for many Godot types (e.g. objects or dictionaries).
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// let position = Vector2::new(1.5, 2.0);
+/// godot_print_variants!("Position: ", position, ", visible: ", true);
+/// // Prints "Position: (1.5, 2), visible: true".
+/// ```
+///
+/// _Godot equivalent: [`@GlobalScope.print()`](https://docs.godotengine.org/en/stable/classes/class_@globalscope.html#class-globalscope-method-print)_.
+#[macro_export]
+macro_rules! godot_print_variants {
+    ($($args:expr),* $(,)?) => {
+        $crate::global::print(&[
+            $(
+                $crate::meta::ToGodot::to_variant(&$args),
+            )*
+        ])
+    };
+}
+
+/// Prints and returns the value of an expression to the Godot console, for quick debugging. Godot's counterpart to [`dbg!`].
+///
+/// The output has the form `[file:line:column] expr = value`, where `value` is formatted by Godot (see [`Variant::stringify()`]), like
+/// GDScript's `print()` would. The expression must implement [`ToGodot`][crate::meta::ToGodot]. Like `dbg!`, the macro takes ownership
+/// of its argument and returns it unchanged. References cannot be passed, as `ToGodot` is not implemented for `&T`; to keep using a
+/// non-`Copy` value, pass a clone instead (for reference-counted types such as `Array` or `Gd`, this is cheap). Multiple arguments are
+/// returned as a tuple.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// let health = godot_dbg!(100 - 35);
+/// // Prints "[src/lib.rs:2:14] 100 - 35 = 65".
+/// ```
+///
+/// [`Variant::stringify()`]: crate::builtin::Variant::stringify
+#[macro_export]
+macro_rules! godot_dbg {
+    () => {
+        $crate::global::print(&[$crate::builtin::Variant::from(format!(
+            "[{}:{}:{}]",
+            file!(),
+            line!(),
+            column!()
+        ))])
+    };
+    ($val:expr $(,)?) => {
+        // `match` keeps temporaries in `$val` alive, like in `dbg!`.
+        match $val {
+            tmp => {
+                let line = $crate::private::format_dbg_line(
+                    file!(),
+                    line!(),
+                    column!(),
+                    stringify!($val),
+                    &$crate::meta::ToGodot::to_variant(&tmp),
+                );
+                $crate::global::print(&[$crate::builtin::Variant::from(line)]);
+                tmp
+            }
+        }
+    };
+    ($($val:expr),+ $(,)?) => {
+        ($($crate::godot_dbg!($val)),+,)
+    };
+}
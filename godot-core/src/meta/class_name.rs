@@ -79,6 +79,13 @@ impl ClassNameSource {
         }
     }
 
+    fn as_str(&self) -> &str {
+        match self {
+            ClassNameSource::Owned(s) => s,
+            ClassNameSource::Borrowed(cstr) => ascii_cstr_to_str(cstr),
+        }
+    }
+
     fn as_cow_str(&self) -> Cow<'static, str> {
         match self {
             ClassNameSource::Owned(s) => Cow::Owned(s.clone()),
@@ -116,7 +123,7 @@ impl ClassName {
         let type_id = TypeId::of::<T>();
         let mut map = DYNAMIC_INDEX_BY_CLASS_TYPE.lock();
 
-        // Insert into linear vector. Note: this doesn't check for overlaps of TypeId between static and dynamic class names.
+        // Insert into linear vector, or reuse an entry of the same name.
         let global_index = *map.entry(type_id).or_insert_with(|| {
             let name = init_fn();
 
@@ -174,6 +181,18 @@ impl ClassName {
         Self { global_index }
     }
 
+    /// Looks up a class name only known at runtime, adding it to the cache if it has not been seen yet.
+    ///
+    /// Used for names received from Godot, e.g. in property lists. Linear in the number of cached class names.
+    pub(crate) fn from_runtime_name(name: &str) -> Self {
+        if name.is_empty() {
+            return Self::none();
+        }
+
+        let global_index = insert_class(ClassNameSource::Owned(name.to_owned()));
+        Self { global_index }
+    }
+
     #[doc(hidden)]
     pub fn is_none(&self) -> bool {
         self.global_index == 0
@@ -229,9 +248,19 @@ impl fmt::Display for ClassName {
     }
 }
 
-/// Adds a new class name to the cache, returning its index.
+/// Adds a class name to the cache, returning its index. If the name is already cached, the existing index is returned.
+///
+/// Names may be cached before their class is first used from Rust, e.g. when they appear in a property list. Since `ClassName` compares
+/// indices, each name must have exactly one entry. Lookup and insertion happen under the same lock.
 fn insert_class(name: ClassNameSource) -> u16 {
     let mut names = CLASS_NAMES.lock();
+    if let Some(index) = names
+        .iter()
+        .position(|entry| entry.rust_str.as_str() == name.as_str())
+    {
+        return index as u16;
+    }
+
     let index = names
         .len()
         .try_into()
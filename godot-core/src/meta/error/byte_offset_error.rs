@@ -0,0 +1,53 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// Error that occurs when encoding or decoding a value at an offset that exceeds a byte array.
+///
+/// Returned by the `encode_*` and `decode_*` methods of [`PackedByteArray`][crate::builtin::PackedByteArray]. The array is left unchanged.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ByteOffsetError {
+    offset: usize,
+    size: usize,
+    len: usize,
+}
+
+impl ByteOffsetError {
+    pub(crate) fn new(offset: usize, size: usize, len: usize) -> Self {
+        Self { offset, size, len }
+    }
+
+    /// The offset at which the value was to be accessed.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The size of the value in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The length of the array.
+    pub fn array_len(&self) -> usize {
+        self.len
+    }
+}
+
+impl fmt::Display for ByteOffsetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { offset, size, len } = self;
+
+        write!(
+            f,
+            "cannot access {size} bytes at offset {offset} in byte array of length {len}"
+        )
+    }
+}
+
+impl Error for ByteOffsetError {}
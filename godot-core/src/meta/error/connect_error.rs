@@ -0,0 +1,188 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::global::Error as GodotError;
+
+/// Error that can occur when connecting a signal with upfront validation.
+///
+/// Returned by [`Node::connect_node_signal()`][crate::classes::Node::connect_node_signal], which connects a signal of a node identified
+/// by path, and [`Object::connect_validated()`][crate::classes::Object::connect_validated], which checks the target method.
+#[derive(Debug)]
+pub struct ConnectError {
+    kind: ConnectErrorKind,
+    source: ConnectSource,
+    signal: String,
+}
+
+impl ConnectError {
+    pub(crate) fn new(
+        kind: ConnectErrorKind,
+        path: impl Into<String>,
+        signal: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            source: ConnectSource::Path(path.into()),
+            signal: signal.into(),
+        }
+    }
+
+    pub(crate) fn for_object(
+        kind: ConnectErrorKind,
+        object: impl Into<String>,
+        signal: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            source: ConnectSource::Object(object.into()),
+            signal: signal.into(),
+        }
+    }
+
+    /// The path of the node whose signal was connected.
+    ///
+    /// `None` if the error stems from [`Object::connect_validated()`][crate::classes::Object::connect_validated], which does not
+    /// look up a node.
+    pub fn path(&self) -> Option<&str> {
+        match &self.source {
+            ConnectSource::Path(path) => Some(path),
+            ConnectSource::Object(_) => None,
+        }
+    }
+
+    /// The name of the signal.
+    pub fn signal(&self) -> &str {
+        &self.signal
+    }
+
+    /// Returns `true` if there is no node at the given path.
+    pub fn is_node_not_found(&self) -> bool {
+        matches!(self.kind, ConnectErrorKind::NodeNotFound)
+    }
+
+    /// Returns `true` if the node exists, but has no signal with the given name.
+    pub fn is_signal_not_found(&self) -> bool {
+        matches!(self.kind, ConnectErrorKind::SignalNotFound)
+    }
+
+    /// Returns `true` if the object whose signal was connected has been freed.
+    pub fn is_object_freed(&self) -> bool {
+        matches!(self.kind, ConnectErrorKind::ObjectFreed)
+    }
+
+    /// Returns `true` if the target object has no method with the given name.
+    pub fn is_method_not_found(&self) -> bool {
+        matches!(self.kind, ConnectErrorKind::MethodNotFound { .. })
+    }
+
+    /// Returns `true` if the target method cannot be called with the number of arguments the signal passes.
+    pub fn is_argument_count_mismatch(&self) -> bool {
+        matches!(self.kind, ConnectErrorKind::ArgumentCountMismatch { .. })
+    }
+
+    /// If Godot rejected the connection (e.g. because the callable was already connected), returns the engine error.
+    pub fn godot_error(&self) -> Option<GodotError> {
+        match self.kind {
+            ConnectErrorKind::Godot(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ConnectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = &self.source;
+        let signal = &self.signal;
+
+        match &self.kind {
+            ConnectErrorKind::NodeNotFound => {
+                let path = self.path().unwrap_or_default();
+                write!(f, "no node found at path '{path}'")
+            }
+            ConnectErrorKind::SignalNotFound => {
+                write!(f, "{source} has no signal '{signal}'")
+            }
+            ConnectErrorKind::ObjectFreed => {
+                write!(
+                    f,
+                    "{source} was freed before signal '{signal}' could be connected"
+                )
+            }
+            ConnectErrorKind::MethodNotFound { target, method } => {
+                write!(f, "target object {target} has no method '{method}'")
+            }
+            ConnectErrorKind::ArgumentCountMismatch {
+                method,
+                signal_args,
+                min_args,
+                max_args,
+            } => {
+                write!(
+                    f,
+                    "signal '{signal}' of {source} passes {signal_args} argument(s), but method '{method}' "
+                )?;
+
+                match max_args {
+                    Some(max_args) if max_args == min_args => write!(f, "takes {min_args}"),
+                    Some(max_args) => write!(f, "takes {min_args} to {max_args}"),
+                    None => write!(f, "takes at least {min_args}"),
+                }
+            }
+            ConnectErrorKind::Godot(err) => {
+                write!(
+                    f,
+                    "failed to connect signal '{signal}' of {source}: {err:?}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for ConnectError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub(crate) enum ConnectErrorKind {
+    NodeNotFound,
+    SignalNotFound,
+    ObjectFreed,
+    MethodNotFound {
+        target: String,
+        method: String,
+    },
+    ArgumentCountMismatch {
+        method: String,
+        signal_args: usize,
+        min_args: usize,
+        /// `None` for vararg methods.
+        max_args: Option<usize>,
+    },
+    Godot(GodotError),
+}
+
+/// Object whose signal was connected.
+#[derive(Debug)]
+enum ConnectSource {
+    /// Node path, relative to the node on which the connection was requested.
+    Path(String),
+
+    /// String representation of the object.
+    Object(String),
+}
+
+impl fmt::Display for ConnectSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Path(path) => write!(f, "node at path '{path}'"),
+            Self::Object(object) => write!(f, "object {object}"),
+        }
+    }
+}
@@ -297,6 +297,11 @@ pub(crate) enum FromVariantError {
     WrongClass {
         expected: ClassName,
     },
+
+    /// Numeric value does not fit into the integer type `target`.
+    IntOutOfRange {
+        target: &'static str,
+    },
 }
 
 impl FromVariantError {
@@ -319,6 +324,9 @@ impl fmt::Display for FromVariantError {
             Self::WrongClass { expected } => {
                 write!(f, "expected class {expected}")
             }
+            Self::IntOutOfRange { target } => {
+                write!(f, "value is out of range for `{target}`")
+            }
         }
     }
 }
@@ -0,0 +1,98 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::classes::http_request::Result as RequestResult;
+use crate::global::Error as GodotError;
+
+/// Error that can occur when performing an HTTP request.
+///
+/// Returned by [`HttpRequestFuture`][crate::tools::HttpRequestFuture]. Responses with an HTTP error status (such as 404) are not errors;
+/// they are returned as [`HttpResponse`][crate::tools::HttpResponse] with the corresponding status code.
+#[derive(Debug)]
+pub struct HttpRequestError {
+    kind: HttpRequestErrorKind,
+    url: String,
+}
+
+impl HttpRequestError {
+    pub(crate) fn new(kind: HttpRequestErrorKind, url: impl Into<String>) -> Self {
+        Self {
+            kind,
+            url: url.into(),
+        }
+    }
+
+    /// The requested URL.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The error returned by Godot when starting the request, if it could not be started.
+    ///
+    /// Examples are [`ERR_UNCONFIGURED`][GodotError::ERR_UNCONFIGURED] if the `HTTPRequest` node is not inside the scene tree,
+    /// [`ERR_BUSY`][GodotError::ERR_BUSY] if it is still processing another request, or
+    /// [`ERR_INVALID_PARAMETER`][GodotError::ERR_INVALID_PARAMETER] for malformed URLs.
+    pub fn godot_error(&self) -> Option<GodotError> {
+        match self.kind {
+            HttpRequestErrorKind::Start(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// The result reported by Godot if the request was started, but failed (e.g. due to a network error).
+    pub fn request_result(&self) -> Option<RequestResult> {
+        match self.kind {
+            HttpRequestErrorKind::Failed(result) => Some(result),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if no response was received within the request's timeout.
+    pub fn is_timeout(&self) -> bool {
+        matches!(
+            self.kind,
+            HttpRequestErrorKind::Failed(RequestResult::TIMEOUT)
+        )
+    }
+
+    /// Returns `true` if the `HTTPRequest` node was freed before the request completed.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.kind, HttpRequestErrorKind::Cancelled)
+    }
+}
+
+impl fmt::Display for HttpRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let url = &self.url;
+
+        match &self.kind {
+            HttpRequestErrorKind::Start(err) => {
+                write!(f, "failed to start HTTP request to '{url}': {err:?}")
+            }
+            HttpRequestErrorKind::Failed(result) => {
+                write!(f, "HTTP request to '{url}' failed: {result:?}")
+            }
+            HttpRequestErrorKind::Cancelled => {
+                write!(f, "HTTP request to '{url}' was cancelled")
+            }
+        }
+    }
+}
+
+impl Error for HttpRequestError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub(crate) enum HttpRequestErrorKind {
+    Start(GodotError),
+    Failed(RequestResult),
+    Cancelled,
+}
@@ -7,10 +7,30 @@
 
 //! Errors in the gdext library.
 
+mod byte_offset_error;
 mod call_error;
+mod connect_error;
 mod convert_error;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod http_request_error;
 mod io_error;
+mod property_error;
+mod scene_change_error;
+mod schema_error;
+mod signature_error;
+mod tree_transaction_error;
+mod typed_bytes_error;
 
+pub use byte_offset_error::*;
 pub use call_error::*;
+pub use connect_error::*;
 pub use convert_error::*;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+pub use http_request_error::*;
 pub use io_error::*;
+pub use property_error::*;
+pub use scene_change_error::*;
+pub use schema_error::*;
+pub use signature_error::*;
+pub use tree_transaction_error::*;
+pub use typed_bytes_error::*;
@@ -0,0 +1,142 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::meta::error::ConvertError;
+
+/// Error that can occur when accessing properties of an object by name or path.
+///
+/// Distinguishes between a property (path) that does not exist on the object, and a property which exists but holds a value
+/// that cannot be converted to the requested type. Where relevant, a `nil` value is told apart from a value of the wrong type. For paths
+/// which also navigate through nodes, such as those of [`Node::resolve_path_value()`][crate::classes::Node::resolve_path_value], a missing
+/// node is reported separately.
+#[derive(Debug)]
+pub struct PropertyError {
+    kind: PropertyErrorKind,
+    path: String,
+}
+
+impl PropertyError {
+    pub(crate) fn not_found(path: impl Into<String>, missing_segment: impl Into<String>) -> Self {
+        Self {
+            kind: PropertyErrorKind::NotFound {
+                segment: missing_segment.into(),
+            },
+            path: path.into(),
+        }
+    }
+
+    pub(crate) fn node_not_found(path: impl Into<String>, node_path: impl Into<String>) -> Self {
+        Self {
+            kind: PropertyErrorKind::NodeNotFound {
+                node_path: node_path.into(),
+            },
+            path: path.into(),
+        }
+    }
+
+    pub(crate) fn conversion(path: impl Into<String>, error: ConvertError) -> Self {
+        Self {
+            kind: PropertyErrorKind::Conversion(error),
+            path: path.into(),
+        }
+    }
+
+    pub(crate) fn nil(path: impl Into<String>, error: ConvertError) -> Self {
+        Self {
+            kind: PropertyErrorKind::Nil(error),
+            path: path.into(),
+        }
+    }
+
+    /// The property name or path that was accessed.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns `true` if the property (or one of the sub-properties in the path) does not exist.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self.kind, PropertyErrorKind::NotFound { .. })
+    }
+
+    /// Returns `true` if the node part of the path does not lead to a node, so that no property could be looked up.
+    pub fn is_node_not_found(&self) -> bool {
+        matches!(self.kind, PropertyErrorKind::NodeNotFound { .. })
+    }
+
+    /// Returns `true` if the property exists, but holds `nil`, which cannot be converted to the requested type.
+    ///
+    /// Only reported by methods that check for `nil` separately, such as
+    /// [`Object::get_property_checked()`][crate::classes::Object::get_property_checked].
+    pub fn is_nil(&self) -> bool {
+        matches!(self.kind, PropertyErrorKind::Nil(_))
+    }
+
+    /// Replaces the accessed path, e.g. with the full path when only a part of it was resolved.
+    pub(crate) fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// If the property exists but its value could not be converted, returns the conversion error.
+    ///
+    /// This includes `nil` values, see [`is_nil()`][Self::is_nil].
+    pub fn conversion_error(&self) -> Option<&ConvertError> {
+        match &self.kind {
+            PropertyErrorKind::Conversion(err) | PropertyErrorKind::Nil(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PropertyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = &self.path;
+
+        match &self.kind {
+            PropertyErrorKind::NotFound { segment } => {
+                write!(
+                    f,
+                    "property path '{path}' not found (no property '{segment}')"
+                )
+            }
+            PropertyErrorKind::NodeNotFound { node_path } => {
+                write!(
+                    f,
+                    "property path '{path}' not found (no node '{node_path}')"
+                )
+            }
+            PropertyErrorKind::Conversion(err) => {
+                write!(f, "property '{path}' has incompatible type: {err}")
+            }
+            PropertyErrorKind::Nil(err) => {
+                write!(f, "property '{path}' holds nil: {err}")
+            }
+        }
+    }
+}
+
+impl Error for PropertyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            PropertyErrorKind::Conversion(err) | PropertyErrorKind::Nil(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+enum PropertyErrorKind {
+    NotFound { segment: String },
+    NodeNotFound { node_path: String },
+    Conversion(ConvertError),
+    Nil(ConvertError),
+}
@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::global::Error as GodotError;
+use crate::meta::error::IoError;
+
+/// Error that can occur when changing the current scene of a scene tree.
+///
+/// Returned by [`SceneTree::change_scene_to()`][crate::classes::SceneTree::change_scene_to]. If an error occurs, the current scene is
+/// left unchanged.
+#[derive(Debug)]
+pub struct SceneChangeError {
+    kind: SceneChangeErrorKind,
+    path: String,
+}
+
+impl SceneChangeError {
+    pub(crate) fn new(kind: SceneChangeErrorKind, path: impl Into<String>) -> Self {
+        Self {
+            kind,
+            path: path.into(),
+        }
+    }
+
+    /// The path of the scene that was to be loaded.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns `true` if the file could not be loaded as a `PackedScene`.
+    pub fn is_load_failed(&self) -> bool {
+        matches!(self.kind, SceneChangeErrorKind::Load(_))
+    }
+
+    /// Returns `true` if the scene was loaded, but could not be instantiated.
+    pub fn is_instantiate_failed(&self) -> bool {
+        matches!(self.kind, SceneChangeErrorKind::Instantiate(_))
+    }
+
+    /// If Godot rejected the scene change, returns the engine error.
+    pub fn godot_error(&self) -> Option<GodotError> {
+        match self.kind {
+            SceneChangeErrorKind::Instantiate(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the scene's root node is not of the requested class.
+    pub fn is_wrong_root_type(&self) -> bool {
+        matches!(self.kind, SceneChangeErrorKind::RootType { .. })
+    }
+}
+
+impl fmt::Display for SceneChangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = &self.path;
+
+        match &self.kind {
+            SceneChangeErrorKind::Load(err) => write!(f, "failed to load scene '{path}': {err}"),
+            SceneChangeErrorKind::Instantiate(err) => {
+                write!(f, "failed to instantiate scene '{path}': {err:?}")
+            }
+            SceneChangeErrorKind::RootType { expected, actual } => write!(
+                f,
+                "root of scene '{path}' has class '{actual}', expected '{expected}'"
+            ),
+        }
+    }
+}
+
+impl Error for SceneChangeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            SceneChangeErrorKind::Load(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub(crate) enum SceneChangeErrorKind {
+    Load(IoError),
+    Instantiate(GodotError),
+    RootType { expected: String, actual: String },
+}
@@ -0,0 +1,104 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::VariantType;
+
+/// Violation of a [`Schema`][crate::tools::Schema] by a dictionary.
+///
+/// Returned by [`Schema::validate()`][crate::tools::Schema::validate], which collects one error per violating field.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SchemaError {
+    kind: SchemaErrorKind,
+    field: String,
+}
+
+impl SchemaError {
+    pub(crate) fn missing(field: impl Into<String>) -> Self {
+        Self {
+            kind: SchemaErrorKind::Missing,
+            field: field.into(),
+        }
+    }
+
+    pub(crate) fn wrong_type(
+        field: impl Into<String>,
+        expected: VariantType,
+        actual: VariantType,
+    ) -> Self {
+        Self {
+            kind: SchemaErrorKind::WrongType { expected, actual },
+            field: field.into(),
+        }
+    }
+
+    pub(crate) fn unknown(field: impl Into<String>) -> Self {
+        Self {
+            kind: SchemaErrorKind::Unknown,
+            field: field.into(),
+        }
+    }
+
+    /// The name of the field that violates the schema.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Returns `true` if a required field is absent.
+    pub fn is_missing(&self) -> bool {
+        matches!(self.kind, SchemaErrorKind::Missing)
+    }
+
+    /// Returns `true` if the field holds a value of another type than declared.
+    pub fn is_wrong_type(&self) -> bool {
+        matches!(self.kind, SchemaErrorKind::WrongType { .. })
+    }
+
+    /// Returns `true` if the field is not declared in the schema, and the schema denies unknown fields.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self.kind, SchemaErrorKind::Unknown)
+    }
+
+    /// For fields of the wrong type, returns the declared and the actual type.
+    pub fn type_mismatch(&self) -> Option<(VariantType, VariantType)> {
+        match self.kind {
+            SchemaErrorKind::WrongType { expected, actual } => Some((expected, actual)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field = &self.field;
+
+        match self.kind {
+            SchemaErrorKind::Missing => write!(f, "required field '{field}' is missing"),
+            SchemaErrorKind::WrongType { expected, actual } => write!(
+                f,
+                "field '{field}' must be of type {expected:?}, but is {actual:?}"
+            ),
+            SchemaErrorKind::Unknown => write!(f, "field '{field}' is not part of the schema"),
+        }
+    }
+}
+
+impl Error for SchemaError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum SchemaErrorKind {
+    Missing,
+    WrongType {
+        expected: VariantType,
+        actual: VariantType,
+    },
+    Unknown,
+}
@@ -0,0 +1,116 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::VariantType;
+
+/// Error that can occur when looking up a method in `ClassDB` and validating it against a Rust signature.
+///
+/// Returned by [`TypedMethod::new()`][crate::tools::TypedMethod::new].
+#[derive(Debug)]
+pub struct SignatureError {
+    kind: SignatureErrorKind,
+    class_name: String,
+    method_name: String,
+}
+
+impl SignatureError {
+    pub(crate) fn new(
+        kind: SignatureErrorKind,
+        class_name: impl Into<String>,
+        method_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            class_name: class_name.into(),
+            method_name: method_name.into(),
+        }
+    }
+
+    /// The class in which the method was looked up.
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// The name of the method that was looked up.
+    pub fn method_name(&self) -> &str {
+        &self.method_name
+    }
+
+    /// Returns `true` if the class or the method does not exist.
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self.kind,
+            SignatureErrorKind::ClassNotFound | SignatureErrorKind::MethodNotFound
+        )
+    }
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let class = &self.class_name;
+        let method = &self.method_name;
+
+        match &self.kind {
+            SignatureErrorKind::ClassNotFound => write!(f, "class '{class}' does not exist"),
+            SignatureErrorKind::MethodNotFound => {
+                write!(f, "method '{class}::{method}' does not exist")
+            }
+            SignatureErrorKind::ParamCount { min, max, actual } => {
+                let expected = match max {
+                    Some(max) if max == min => format!("{min}"),
+                    Some(max) => format!("{min} to {max}"),
+                    None => format!("at least {min}"),
+                };
+
+                write!(
+                    f,
+                    "method '{class}::{method}' takes {expected} parameters, but signature has {actual}"
+                )
+            }
+            SignatureErrorKind::ParamType {
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "parameter {index} of method '{class}::{method}' has type {expected:?}, but signature has {actual:?}"
+            ),
+            SignatureErrorKind::ReturnType { expected, actual } => write!(
+                f,
+                "method '{class}::{method}' returns type {expected:?}, but signature has {actual:?}"
+            ),
+        }
+    }
+}
+
+impl Error for SignatureError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub(crate) enum SignatureErrorKind {
+    ClassNotFound,
+    MethodNotFound,
+    ParamCount {
+        min: usize,
+        /// `None` for varargs methods.
+        max: Option<usize>,
+        actual: usize,
+    },
+    ParamType {
+        index: usize,
+        expected: VariantType,
+        actual: VariantType,
+    },
+    ReturnType {
+        expected: VariantType,
+        actual: VariantType,
+    },
+}
@@ -0,0 +1,125 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// Operation of a [`TreeTransaction`][crate::tools::TreeTransaction] that cannot be performed.
+///
+/// Returned by [`TreeTransaction::commit()`][crate::tools::TreeTransaction::commit]. Usually the batch is rejected before any operation
+/// is applied, leaving the tree unchanged; see [`applied()`][Self::applied] for the exception.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct TreeTransactionError {
+    kind: TreeTransactionErrorKind,
+    operation: &'static str,
+    index: usize,
+    node: String,
+    applied: usize,
+}
+
+impl TreeTransactionError {
+    pub(crate) fn new(
+        kind: TreeTransactionErrorKind,
+        operation: &'static str,
+        index: usize,
+        node: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            operation,
+            index,
+            node: node.into(),
+            applied: 0,
+        }
+    }
+
+    pub(crate) fn with_applied(self, applied: usize) -> Self {
+        Self { applied, ..self }
+    }
+
+    /// Position of the failing operation in the transaction, starting at 0.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Name of the failing operation, e.g. `"add_child"`.
+    pub fn operation(&self) -> &'static str {
+        self.operation
+    }
+
+    /// Textual representation of the node that the operation could not be applied to.
+    pub fn node(&self) -> &str {
+        &self.node
+    }
+
+    /// Number of operations that were applied before the error.
+    ///
+    /// This is 0 unless a signal handler invoked by an earlier operation has changed the tree in a way that made a later one impossible.
+    pub fn applied(&self) -> usize {
+        self.applied
+    }
+
+    /// Returns `true` if a node involved in the operation has been (or is going to be) freed.
+    pub fn is_node_freed(&self) -> bool {
+        matches!(self.kind, TreeTransactionErrorKind::Freed)
+    }
+
+    /// Returns `true` if a node was to be added as a child, but already has a parent.
+    pub fn is_already_parented(&self) -> bool {
+        matches!(self.kind, TreeTransactionErrorKind::AlreadyParented)
+    }
+
+    /// Returns `true` if a node was to be removed from a parent, but is not its child.
+    pub fn is_not_child(&self) -> bool {
+        matches!(self.kind, TreeTransactionErrorKind::NotChild)
+    }
+
+    /// Returns `true` if a node was to be added below itself or one of its descendants.
+    pub fn is_cycle(&self) -> bool {
+        matches!(self.kind, TreeTransactionErrorKind::Cycle)
+    }
+}
+
+impl fmt::Display for TreeTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            operation,
+            index,
+            node,
+            ..
+        } = self;
+
+        write!(f, "operation #{index} ({operation}) on {node}: ")?;
+        match self.kind {
+            TreeTransactionErrorKind::Freed => write!(f, "node has been freed"),
+            TreeTransactionErrorKind::AlreadyParented => write!(f, "node already has a parent"),
+            TreeTransactionErrorKind::NotChild => {
+                write!(f, "node is not a child of the given parent")
+            }
+            TreeTransactionErrorKind::Cycle => {
+                write!(f, "node cannot be added below itself or its descendants")
+            }
+        }?;
+
+        if self.applied > 0 {
+            write!(f, " (after {} applied operations)", self.applied)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for TreeTransactionError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum TreeTransactionErrorKind {
+    Freed,
+    AlreadyParented,
+    NotChild,
+    Cycle,
+}
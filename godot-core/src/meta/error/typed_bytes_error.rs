@@ -0,0 +1,85 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::VariantType;
+
+/// Error that occurs when converting a variant from or to raw bytes of a given type.
+///
+/// Returned by [`Variant::from_typed_bytes()`][crate::builtin::Variant::from_typed_bytes] and
+/// [`Variant::to_typed_bytes()`][crate::builtin::Variant::to_typed_bytes].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TypedBytesError {
+    kind: TypedBytesErrorKind,
+    variant_type: VariantType,
+}
+
+impl TypedBytesError {
+    pub(crate) fn unsupported_type(variant_type: VariantType) -> Self {
+        Self {
+            kind: TypedBytesErrorKind::UnsupportedType,
+            variant_type,
+        }
+    }
+
+    pub(crate) fn length_mismatch(
+        variant_type: VariantType,
+        expected: usize,
+        actual: usize,
+    ) -> Self {
+        Self {
+            kind: TypedBytesErrorKind::LengthMismatch { expected, actual },
+            variant_type,
+        }
+    }
+
+    /// The variant type that was to be converted.
+    pub fn variant_type(&self) -> VariantType {
+        self.variant_type
+    }
+
+    /// Returns `true` if the variant type has no fixed-size byte representation.
+    pub fn is_unsupported_type(&self) -> bool {
+        matches!(self.kind, TypedBytesErrorKind::UnsupportedType)
+    }
+
+    /// Returns `true` if the number of bytes does not match the size of the variant type.
+    pub fn is_length_mismatch(&self) -> bool {
+        matches!(self.kind, TypedBytesErrorKind::LengthMismatch { .. })
+    }
+}
+
+impl fmt::Display for TypedBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let variant_type = self.variant_type;
+
+        match self.kind {
+            TypedBytesErrorKind::UnsupportedType => {
+                write!(
+                    f,
+                    "type {variant_type:?} has no fixed-size byte representation"
+                )
+            }
+            TypedBytesErrorKind::LengthMismatch { expected, actual } => write!(
+                f,
+                "type {variant_type:?} requires {expected} bytes, got {actual}"
+            ),
+        }
+    }
+}
+
+impl Error for TypedBytesError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum TypedBytesErrorKind {
+    UnsupportedType,
+    LengthMismatch { expected: usize, actual: usize },
+}
@@ -5,7 +5,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::{Array, Variant};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+use crate::builtin::{Array, Dictionary, PackedByteArray, Variant};
 use crate::meta::error::{ConvertError, ErrorKind, FromFfiError, FromVariantError};
 use crate::meta::{
     ArrayElement, ClassName, FromGodot, GodotConvert, GodotNullableFfi, GodotType,
@@ -353,6 +356,50 @@ impl FromGodot for u64 {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// u128, i128: Godot has no 128-bit integers, so they are stored as bytes.
+
+macro_rules! impl_godot_as_bytes {
+    ($T:ty) => {
+        /// Represented in Godot as a `PackedByteArray` of 16 bytes, in little-endian order (least significant byte first).
+        ///
+        /// This allows storing 128-bit identifiers such as UUIDs in Godot data. Converting from Godot fails if the array does not have
+        /// exactly 16 elements.
+        impl GodotConvert for $T {
+            type Via = PackedByteArray;
+        }
+
+        impl ToGodot for $T {
+            type ToVia<'v> = PackedByteArray;
+
+            fn to_godot(&self) -> Self::ToVia<'_> {
+                PackedByteArray::from(self.to_le_bytes().as_slice())
+            }
+        }
+
+        impl FromGodot for $T {
+            fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                let Ok(bytes) = <[u8; 16]>::try_from(via.as_slice()) else {
+                    let message = format!(
+                        "PackedByteArray of length {} cannot be converted to {}, which requires 16 bytes",
+                        via.len(),
+                        stringify!($T),
+                    );
+                    return Err(ConvertError::with_kind_value(
+                        ErrorKind::Custom(Some(message.into())),
+                        via,
+                    ));
+                };
+
+                Ok(<$T>::from_le_bytes(bytes))
+            }
+        }
+    };
+}
+
+impl_godot_as_bytes!(u128);
+impl_godot_as_bytes!(i128);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Collections
 
@@ -427,6 +474,89 @@ impl<T: ArrayElement> ToGodot for &[T] {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Maps
+
+impl<K, V, S> GodotConvert for HashMap<K, V, S> {
+    type Via = Dictionary;
+}
+
+impl<K: ToGodot, V: ToGodot, S> ToGodot for HashMap<K, V, S> {
+    type ToVia<'v> = Dictionary;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        map_to_dictionary(self.iter())
+    }
+}
+
+impl<K, V, S> FromGodot for HashMap<K, V, S>
+where
+    K: FromGodot + Eq + Hash,
+    V: FromGodot,
+    S: BuildHasher + Default,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        dictionary_to_map(&via)
+    }
+}
+
+impl<K, V> GodotConvert for BTreeMap<K, V> {
+    type Via = Dictionary;
+}
+
+impl<K: ToGodot, V: ToGodot> ToGodot for BTreeMap<K, V> {
+    type ToVia<'v> = Dictionary;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        map_to_dictionary(self.iter())
+    }
+}
+
+impl<K: FromGodot + Ord, V: FromGodot> FromGodot for BTreeMap<K, V> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        dictionary_to_map(&via)
+    }
+}
+
+fn map_to_dictionary<'a, K, V>(entries: impl Iterator<Item = (&'a K, &'a V)>) -> Dictionary
+where
+    K: ToGodot + 'a,
+    V: ToGodot + 'a,
+{
+    entries
+        .map(|(key, value)| (key.to_variant(), value.to_variant()))
+        .collect()
+}
+
+/// Converts all entries, failing on the first key or value that cannot be converted.
+fn dictionary_to_map<K, V, M>(dictionary: &Dictionary) -> Result<M, ConvertError>
+where
+    K: FromGodot,
+    V: FromGodot,
+    M: FromIterator<(K, V)>,
+{
+    dictionary
+        .iter_shared()
+        .map(|(key, value)| {
+            let converted_key = key.try_to::<K>().map_err(|err| {
+                ConvertError::with_error_value(
+                    format!("dictionary key {key:?} cannot be converted: {err}"),
+                    key.clone(),
+                )
+            })?;
+
+            let converted_value = value.try_to::<V>().map_err(|err| {
+                ConvertError::with_error_value(
+                    format!("dictionary value for key {key:?} cannot be converted: {err}"),
+                    value.clone(),
+                )
+            })?;
+
+            Ok((converted_key, converted_value))
+        })
+        .collect()
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Raw pointers
 
@@ -5,9 +5,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::{GString, StringName};
+use crate::builtin::{Dictionary, GString, StringName};
 use crate::global::{PropertyHint, PropertyUsageFlags};
-use crate::meta::{ArrayElement, ClassName, GodotType, PackedArrayElement};
+use crate::meta::{ArrayElement, ClassName, FromGodot, GodotType, PackedArrayElement};
 use crate::registry::property::{Export, Var};
 use crate::sys;
 use godot_ffi::VariantType;
@@ -130,6 +130,34 @@ impl PropertyInfo {
         }
     }
 
+    /// Creates a `PropertyInfo` from a dictionary, in the format returned by [`Object::get_property_list()`][crate::classes::Object::get_property_list].
+    ///
+    /// The keys `name`, `class_name`, `type`, `hint`, `hint_string` and `usage` are read. Missing keys yield default values (empty names,
+    /// type `NIL`, no hint and no usage flags). Hints that are unknown to this version of godot-rust are mapped to [`PropertyHint::NONE`].
+    pub fn from_dictionary(dict: &Dictionary) -> Self {
+        fn get<T: FromGodot>(dict: &Dictionary, key: &str) -> Option<T> {
+            dict.get(key).and_then(|value| value.try_to::<T>().ok())
+        }
+
+        // Strings can be stored as either String or StringName.
+        let get_string = |key: &str| {
+            dict.get(key)
+                .map(|value| value.stringify())
+                .unwrap_or_default()
+        };
+
+        Self {
+            variant_type: get(dict, "type").unwrap_or(VariantType::NIL),
+            class_name: ClassName::from_runtime_name(&get_string("class_name").to_string()),
+            property_name: StringName::from(&get_string("name")),
+            hint_info: PropertyHintInfo {
+                hint: get(dict, "hint").unwrap_or(PropertyHint::NONE),
+                hint_string: get_string("hint_string"),
+            },
+            usage: get(dict, "usage").unwrap_or(PropertyUsageFlags::NONE),
+        }
+    }
+
     /// Converts to the FFI type. Keep this object allocated while using that!
     pub fn property_sys(&self) -> sys::GDExtensionPropertyInfo {
         use crate::obj::EngineBitfield as _;
@@ -56,6 +56,8 @@ impl Sealed for u64 {}
 impl Sealed for u32 {}
 impl Sealed for u16 {}
 impl Sealed for u8 {}
+impl Sealed for isize {}
+impl Sealed for usize {}
 impl Sealed for f64 {}
 impl Sealed for f32 {}
 impl Sealed for () {}
@@ -205,3 +205,60 @@ impl PackedArrayElement for crate::builtin::Vector3 {}
 impl PackedArrayElement for crate::builtin::Vector4 {}
 impl PackedArrayElement for crate::builtin::Color {}
 impl PackedArrayElement for crate::builtin::GString {}
+
+/// Integer types that numeric variants can be converted to, with explicit handling of out-of-range values.
+///
+/// Used by [`Variant::to_saturating()`] and [`Variant::to_checked()`]. Implemented for all Rust integer types from 8 to 64 bits, as
+/// well as `isize` and `usize`.
+pub trait IntegerTarget: Copy + sealed::Sealed + 'static {
+    #[doc(hidden)]
+    const TYPE_NAME: &'static str;
+
+    /// Converts `value`, clamping it to the range of `Self`.
+    #[doc(hidden)]
+    fn saturating_from_i64(value: i64) -> Self;
+
+    /// Converts `value`, truncating towards zero and clamping it to the range of `Self`. Must not be NaN.
+    #[doc(hidden)]
+    fn saturating_from_f64(value: f64) -> Self;
+
+    /// Converts `value`, or returns `None` if it is outside the range of `Self`.
+    #[doc(hidden)]
+    fn checked_from_i64(value: i64) -> Option<Self>;
+
+    /// Converts `value`, or returns `None` if it is not an integer inside the range of `Self`.
+    #[doc(hidden)]
+    fn checked_from_f64(value: f64) -> Option<Self>;
+}
+
+macro_rules! impl_integer_target {
+    ($($T:ty),+ $(,)?) => {
+        $(
+            impl IntegerTarget for $T {
+                const TYPE_NAME: &'static str = stringify!($T);
+
+                fn saturating_from_i64(value: i64) -> Self {
+                    Self::try_from(value).unwrap_or(if value < 0 { Self::MIN } else { Self::MAX })
+                }
+
+                fn saturating_from_f64(value: f64) -> Self {
+                    // Float-to-int casts truncate and saturate.
+                    value as Self
+                }
+
+                fn checked_from_i64(value: i64) -> Option<Self> {
+                    Self::try_from(value).ok()
+                }
+
+                fn checked_from_f64(value: f64) -> Option<Self> {
+                    // `MAX as f64` may round up (e.g. for i64), so compare against the exclusive upper bound. NaN and infinity fail here.
+                    let in_range = value >= Self::MIN as f64 && value < Self::MAX as f64 + 1.0;
+
+                    (in_range && value.fract() == 0.0).then_some(value as Self)
+                }
+            }
+        )+
+    };
+}
+
+impl_integer_target!(i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
@@ -12,9 +12,9 @@ use godot_ffi as sys;
 
 use sys::{static_assert_eq_size_align, VariantType};
 
-use crate::builtin::{Callable, NodePath, StringName, Variant};
+use crate::builtin::{Callable, GString, NodePath, StringName, Variant};
 use crate::global::PropertyHint;
-use crate::meta::error::{ConvertError, FromFfiError};
+use crate::meta::error::{ConvertError, FromFfiError, PropertyError};
 use crate::meta::{
     ArrayElement, CallContext, ClassName, FromGodot, GodotConvert, GodotType, PropertyHintInfo,
     RefArg, ToGodot,
@@ -50,7 +50,9 @@ use crate::{classes, out};
 /// - **Manual**<br>
 ///   Objects inheriting from [`Object`] which are not `RefCounted` (or inherited) are **manually-managed**.
 ///   Their destructor is not automatically called (unless they are part of the scene tree). Creating a `Gd<T>` means that
-///   you are responsible for explicitly deallocating such objects using [`free()`][Self::free].<br><br>
+///   you are responsible for explicitly deallocating such objects using [`free()`][Self::free].<br>
+///   Dropping a `Gd<T>` never deallocates a manually-managed object; only `free()` does. When ownership is transferred to Godot (for
+///   example by adding a node to the tree), you can make this explicit with [`forget()`][Self::forget].<br><br>
 ///
 /// - **Dynamic**<br>
 ///   For `T=Object`, the memory strategy is determined **dynamically**. Due to polymorphism, a `Gd<Object>` can point to either
@@ -278,6 +280,36 @@ impl<T: GodotClass> Gd<T> {
         self.raw.is_instance_valid()
     }
 
+    /// Returns the class name of the object's dynamic (runtime) type.
+    ///
+    /// While [`T::class_name()`][GodotClass::class_name] is the static type of this pointer, this returns the most derived class of the
+    /// actual object. For example, a `Gd<Node>` pointing to a `Sprite2D` returns `"Sprite2D"`. For user-defined classes, this is the
+    /// name under which the class was registered.
+    ///
+    /// _Godot equivalent: `get_class()`, but returns an interned `StringName`._
+    ///
+    /// # Panics
+    /// If this object is no longer alive.
+    pub fn dynamic_class_name(&self) -> StringName {
+        let class: GString = self.raw.as_object().get_class();
+        StringName::from(&class)
+    }
+
+    /// Returns `true` if the object's dynamic class is `U` or inherits from `U`.
+    ///
+    /// This is the typed counterpart to `Object::is_class()`: the class is identified by its Rust type instead of a string, which
+    /// avoids allocating the class name. For example, a `Gd<Node>` pointing to a `Sprite2D` is an instance of `Sprite2D`, `Node2D` and
+    /// `Node`, but not of `Label`. Unlike [`try_cast()`][Self::try_cast], `U` need not be derived from `T`, and the pointer is not
+    /// consumed.
+    ///
+    /// Returns `false` if the object is no longer alive.
+    pub fn is_instance_of<U>(&self) -> bool
+    where
+        U: GodotClass,
+    {
+        self.raw.is_instance_of::<U>()
+    }
+
     /// **Upcast:** convert into a smart pointer to a base class. Always succeeds.
     ///
     /// Moves out of this value. If you want to create _another_ smart pointer instance,
@@ -438,6 +470,48 @@ impl<T: GodotClass> Gd<T> {
         Callable::from_object_method(self, method_name)
     }
 
+    /// Temporarily sets the property at `path` to `temp_value` while `f` runs, then restores the previous value.
+    ///
+    /// Accepts the same property paths as [`Object::try_get_indexed_as()`][classes::Object::try_get_indexed_as], e.g. `"modulate:a"`.
+    /// The previous value is restored when `f` returns, and also if it panics. If the object is freed inside `f`, nothing is restored.
+    ///
+    /// Returns an error without invoking `f`, if a (sub-)property in the path does not exist.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let mut node = Node2D::new_alloc();
+    /// let result = node.with_property_temp("visible", false, |node| {
+    ///     assert!(!node.is_visible());
+    /// });
+    ///
+    /// assert!(result.is_ok());
+    /// assert!(node.is_visible());
+    /// # node.free();
+    /// ```
+    pub fn with_property_temp<R>(
+        &mut self,
+        path: impl Into<NodePath>,
+        temp_value: impl ToGodot,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> Result<R, PropertyError>
+    where
+        T: Inherits<classes::Object>,
+    {
+        let path = path.into();
+        let mut object = self.clone().upcast::<classes::Object>();
+        let old_value = object.try_get_indexed_as::<Variant>(path.clone())?;
+
+        object.set_indexed(path.clone(), &temp_value.to_variant());
+        let _restore = PropertyRestoreGuard {
+            object,
+            path,
+            old_value,
+        };
+
+        Ok(f(self))
+    }
+
     pub(crate) unsafe fn from_obj_sys_or_none(
         ptr: sys::GDExtensionObjectPtr,
     ) -> Result<Self, ConvertError> {
@@ -585,6 +659,34 @@ where
         // TODO: this might leak associated data in Gd<T>, e.g. ClassName.
         std::mem::forget(self);
     }
+
+    /// Gives up this smart pointer, without affecting the manually-managed Godot object.
+    ///
+    /// Use this to make explicit that the object is now owned by someone else, e.g. after adding a node to the scene tree, or after
+    /// handing it to GDScript, which will free it later. Other `Gd` pointers to the object stay valid, and the object can be retrieved
+    /// again with [`Gd::from_instance_id()`].
+    ///
+    /// For manually-managed objects, this has the same effect as dropping the `Gd`. Neither frees the object: only [`free()`][Self::free]
+    /// (or the owner, like the scene tree) does that. However, `forget()` documents the intent and is checked at runtime.
+    ///
+    /// # Panics
+    /// When this is invoked on an upcast `Gd<Object>` that dynamically points to a reference-counted type. Forgetting such a pointer
+    /// would leak a reference, so that the object is never deallocated.
+    pub fn forget(self) {
+        // Runtime check in case of T=Object, no-op otherwise.
+        let ref_counted =
+            <<T as Bounds>::DynMemory as bounds::DynMemory>::is_ref_counted(&self.raw);
+
+        assert_ne!(
+            ref_counted,
+            Some(true),
+            "called forget() on Gd<Object> which points to a RefCounted dynamic type; forget() only supported for manually managed types\n\
+            Object: {self:?}"
+        );
+
+        // Dropping does not decrement any reference count for manually managed objects.
+        drop(self);
+    }
 }
 
 /// _The methods in this impl block are only available for objects `T` that are reference-counted,
@@ -850,12 +952,37 @@ impl<T: GodotClass> PartialEq for Gd<T> {
 
 impl<T: GodotClass> Eq for Gd<T> {}
 
+impl<T: GodotClass> PartialEq<InstanceId> for Gd<T> {
+    /// Returns whether this `Gd` points to the object with the given instance ID.
+    ///
+    /// Unlike `Gd == Gd`, this does not panic for dead objects: it compares the ID stored in the pointer, without accessing the object.
+    /// A dead `Gd` is thus still equal to the ID of the object it pointed to.
+    fn eq(&self, other: &InstanceId) -> bool {
+        self.instance_id_unchecked() == *other
+    }
+}
+
+impl<T: GodotClass> PartialEq<Gd<T>> for InstanceId {
+    /// Returns whether the given `Gd` points to the object with this instance ID.
+    ///
+    /// See `impl PartialEq<InstanceId> for Gd<T>`; does not panic for dead objects.
+    fn eq(&self, other: &Gd<T>) -> bool {
+        other == self
+    }
+}
+
+/// Uses Godot's `to_string()`, e.g. `PlayerSprite:<Sprite2D#1234>` for a node named `PlayerSprite`, or `<Object#1234>` for other objects.
+///
+/// A user class can customize this by overriding `to_string()` in its interface trait. Freed objects are displayed as `<Freed Object>`.
 impl<T: GodotClass> Display for Gd<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         classes::display_string(self, f)
     }
 }
 
+/// Shows instance ID and dynamic class, as well as the name of nodes, e.g. `Gd { id: 1234, class: Sprite2D, name: "PlayerSprite" }`.
+///
+/// Does not panic for freed objects, which are shown as `Gd { freed obj }`.
 impl<T: GodotClass> Debug for Gd<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         classes::debug_string(self, f, "Gd")
@@ -872,6 +999,21 @@ impl<T: GodotClass> std::hash::Hash for Gd<T> {
     }
 }
 
+/// Restores a property to its previous value when dropped; see [`Gd::with_property_temp()`].
+struct PropertyRestoreGuard {
+    object: Gd<classes::Object>,
+    path: NodePath,
+    old_value: Variant,
+}
+
+impl Drop for PropertyRestoreGuard {
+    fn drop(&mut self) {
+        if self.object.is_instance_valid() {
+            self.object.set_indexed(self.path.clone(), &self.old_value);
+        }
+    }
+}
+
 // Gd unwinding across panics does not invalidate any invariants;
 // its mutability is anyway present, in the Godot engine.
 impl<T: GodotClass> std::panic::UnwindSafe for Gd<T> {}
@@ -190,6 +190,22 @@ impl<T: GodotClass> RawGd<T> {
         sys::ptr_then(cast_object_ptr, |ptr| RawGd::from_obj_sys_weak(ptr))
     }
 
+    /// Returns `true` if the object is alive and its dynamic class is `U` or derived from it. Does not create a new pointer.
+    pub(super) fn is_instance_of<U>(&self) -> bool
+    where
+        U: GodotClass,
+    {
+        if !self.is_instance_valid() {
+            return false;
+        }
+
+        // SAFETY: The object is alive, and class tags stay valid while the class is registered.
+        unsafe {
+            let class_tag = interface_fn!(classdb_get_class_tag)(U::class_name().string_sys());
+            !interface_fn!(object_cast_to)(self.obj_sys(), class_tag).is_null()
+        }
+    }
+
     pub(crate) fn with_ref_counted<R>(&self, apply: impl Fn(&mut classes::RefCounted) -> R) -> R {
         // Note: this previously called Declarer::scoped_mut() - however, no need to go through bind() for changes in base RefCounted.
         // Any accesses to user objects (e.g. destruction if refc=0) would bind anyway.
@@ -561,6 +561,8 @@ pub mod cap {
         fn __register_constants();
         #[doc(hidden)]
         fn __register_rpcs(_: &mut dyn Any) {}
+        #[doc(hidden)]
+        fn __autoconnect_signals(_: &mut dyn Any) {}
     }
 
     pub trait ImplementsGodotExports: GodotClass {
@@ -9,7 +9,8 @@ pub use crate::gen::classes::class_macros;
 pub use crate::obj::rtti::ObjectRtti;
 pub use crate::registry::callbacks;
 pub use crate::registry::plugin::{
-    ClassPlugin, ErasedRegisterFn, ErasedRegisterRpcsFn, InherentImpl, PluginItem,
+    ClassPlugin, ErasedAutoconnectFn, ErasedRegisterFn, ErasedRegisterRpcsFn, InherentImpl,
+    PluginItem,
 };
 pub use crate::storage::{as_storage, Storage};
 pub use sys::out;
@@ -129,7 +130,6 @@ pub(crate) fn iterate_plugins(mut visitor: impl FnMut(&ClassPlugin)) {
     sys::plugin_foreach!(__GODOT_PLUGIN_REGISTRY; visitor);
 }
 
-#[cfg(feature = "codegen-full")] // Remove if used in other scenarios.
 pub(crate) fn find_inherent_impl(class_name: crate::meta::ClassName) -> Option<InherentImpl> {
     // We do this manually instead of using `iterate_plugins()` because we want to break as soon as we find a match.
     let plugins = __godot_rust_plugin___GODOT_PLUGIN_REGISTRY.lock().unwrap();
@@ -157,6 +157,16 @@ pub(crate) fn find_inherent_impl(class_name: crate::meta::ClassName) -> Option<I
 )]
 pub trait You_forgot_the_attribute__godot_api {}
 
+// If someone uses #[connect] without #[class(autoconnect)], this causes a compile error, rather than the signals silently not being
+// connected at runtime.
+#[allow(non_camel_case_types)]
+#[diagnostic::on_unimplemented(
+    message = "`#[connect]` requires the class to be declared with `#[class(autoconnect)]`",
+    label = "`#[connect]` used here, but `{Self}` does not have `#[class(autoconnect)]`",
+    note = "add `autoconnect` to the `#[class(...)]` attribute of the struct"
+)]
+pub trait You_forgot_the_attribute__class_autoconnect {}
+
 pub struct ClassConfig {
     pub is_tool: bool,
 }
@@ -176,11 +186,25 @@ pub unsafe fn has_virtual_script_method(
     sys::interface_fn!(object_has_script_method)(sys::to_const_ptr(object_ptr), method_sname) != 0
 }
 
+/// Formats one line of [`godot_dbg!`][crate::godot_dbg] output.
+pub fn format_dbg_line(
+    file: &str,
+    line: u32,
+    column: u32,
+    expr: &str,
+    value: &crate::builtin::Variant,
+) -> String {
+    format!("[{file}:{line}:{column}] {expr} = {}", value.stringify())
+}
+
 pub fn flush_stdout() {
     use std::io::Write;
     std::io::stdout().flush().expect("flush stdout");
 }
 
+/// Ensure `T` is declared with `#[class(autoconnect)]`.
+pub const fn is_autoconnect_class<T: You_forgot_the_attribute__class_autoconnect>() {}
+
 /// Ensure `T` is an editor plugin.
 pub const fn is_editor_plugin<T: crate::obj::Inherits<crate::classes::EditorPlugin>>() {}
 
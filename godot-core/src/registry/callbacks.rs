@@ -358,3 +358,7 @@ pub fn register_user_methods_constants<T: cap::ImplementsGodotApi>(_class_builde
 pub fn register_user_rpcs<T: cap::ImplementsGodotApi>(object: &mut dyn Any) {
     T::__register_rpcs(object);
 }
+
+pub fn autoconnect_user_signals<T: cap::ImplementsGodotApi>(object: &mut dyn Any) {
+    T::__autoconnect_signals(object);
+}
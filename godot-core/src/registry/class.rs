@@ -8,6 +8,8 @@
 use std::collections::HashMap;
 use std::ptr;
 
+use crate::builtin::{Callable, NodePath, StringName};
+use crate::classes::Node;
 use crate::init::InitLevel;
 use crate::meta::ClassName;
 use crate::obj::{cap, GodotClass};
@@ -212,6 +214,46 @@ pub fn auto_register_rpcs<T: GodotClass>(object: &mut T) {
     }
 }
 
+pub fn auto_connect_signals<T: GodotClass>(object: &mut T) {
+    // Find the element that matches our class, and call the closure if it exists.
+    if let Some(InherentImpl {
+        autoconnect_signals_fn: Some(closure),
+        ..
+    }) = crate::private::find_inherent_impl(T::class_name())
+    {
+        (closure.raw)(object);
+    }
+}
+
+/// Connects `signal` of the node at `child_path` (relative to `node`) to `callable`, for `#[connect]` methods.
+///
+/// If the child or its signal do not exist, an error is printed instead.
+pub fn connect_child_signal(
+    node: &Node,
+    child_path: &str,
+    signal: &str,
+    callable: Callable,
+    class_name: &str,
+    method_name: &str,
+) {
+    let Some(mut child) = node.get_node_or_null(NodePath::from(child_path)) else {
+        godot_error!(
+            "#[connect] on {class_name}::{method_name}: no child node at path '{child_path}'"
+        );
+        return;
+    };
+
+    if !child.has_signal(StringName::from(signal)) {
+        godot_error!(
+            "#[connect] on {class_name}::{method_name}: node '{child_path}' of class {child_class} has no signal '{signal}'",
+            child_class = child.get_class()
+        );
+        return;
+    }
+
+    child.connect(StringName::from(signal), callable);
+}
+
 fn global_loaded_classes() -> GlobalGuard<'static, HashMap<InitLevel, Vec<LoadedClass>>> {
     match LOADED_CLASSES.try_lock() {
         Ok(it) => it,
@@ -296,6 +338,7 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
         PluginItem::InherentImpl(InherentImpl {
             register_methods_constants_fn,
             register_rpcs_fn: _,
+            autoconnect_signals_fn: _,
             #[cfg(all(since_api = "4.3", feature = "docs"))]
                 docs: _,
         }) => {
@@ -10,7 +10,7 @@
 use crate::builtin::StringName;
 use crate::global::PropertyUsageFlags;
 use crate::meta::{ClassName, GodotConvert, GodotType, PropertyHintInfo, PropertyInfo};
-use crate::obj::GodotClass;
+use crate::obj::{GodotClass, Inherits, WithBaseField};
 use crate::registry::property::{Export, Var};
 use crate::{classes, sys};
 use godot_ffi::GodotFfi;
@@ -79,3 +79,16 @@ fn register_var_or_export_inner(
         );
     }
 }
+
+/// Emits the `changed` signal of a resource, after a setter generated by `#[var(on_change)]` has updated the field.
+pub fn emit_changed<C>(instance: &mut C)
+where
+    C: WithBaseField,
+    C::Base: Inherits<classes::Resource>,
+{
+    // Go through `base_mut()`, so that handlers of `changed` can access the instance again.
+    instance
+        .base_mut()
+        .upcast_mut::<classes::Resource>()
+        .emit_changed();
+}
@@ -55,6 +55,17 @@ impl fmt::Debug for ErasedRegisterRpcsFn {
     }
 }
 
+#[derive(Copy, Clone)]
+pub struct ErasedAutoconnectFn {
+    pub raw: fn(&mut dyn Any),
+}
+
+impl fmt::Debug for ErasedAutoconnectFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:0>16x}", self.raw as usize)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct InherentImpl {
     /// Callback to library-generated function which registers functions and constants in the `impl` block.
@@ -65,6 +76,11 @@ pub struct InherentImpl {
     ///
     /// This function is called in [`UserClass::__before_ready()`](crate::obj::UserClass::__before_ready) definitions generated by the `#[derive(GodotClass)]` macro.
     pub register_rpcs_fn: Option<ErasedRegisterRpcsFn>,
+    /// Callback to library-generated function which connects child signals to methods annotated with `#[connect]` on the `impl` block.
+    ///
+    /// This function is called in [`UserClass::__before_ready()`](crate::obj::UserClass::__before_ready) definitions generated by the
+    /// `#[derive(GodotClass)]` macro, if the class is declared with `#[class(autoconnect)]`.
+    pub autoconnect_signals_fn: Option<ErasedAutoconnectFn>,
     #[cfg(all(since_api = "4.3", feature = "docs"))]
     pub docs: InherentImplDocs,
 }
@@ -10,5 +10,7 @@ mod instance_storage;
 mod multi_threaded;
 #[cfg_attr(feature = "experimental-threads", allow(dead_code))]
 mod single_threaded;
+mod user_data;
 
 pub use instance_storage::*;
+pub(crate) use user_data::user_data_map;
@@ -0,0 +1,145 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Rust data attached to arbitrary objects, stored in a dedicated instance binding.
+//!
+//! Godot keeps one instance binding per object and token. godot-rust's class instances use the library token; user data uses a separate
+//! token, so it can be attached to engine and user objects alike. The binding is created on first write and dropped by Godot once the
+//! object is destroyed -- unless that happens on another thread than the one owning the data, in which case it is leaked.
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ptr;
+use std::rc::Rc;
+use std::thread::ThreadId;
+
+use godot_ffi as sys;
+use sys::interface_fn;
+
+/// Type-keyed values attached to one object.
+pub(crate) struct UserDataMap {
+    values: RefCell<HashMap<TypeId, Rc<dyn Any>>>,
+    thread_id: ThreadId,
+}
+
+impl UserDataMap {
+    fn new() -> Self {
+        Self {
+            values: RefCell::new(HashMap::new()),
+            thread_id: std::thread::current().id(),
+        }
+    }
+
+    pub fn insert<T: 'static>(&self, value: T) {
+        self.check_thread();
+        self.values
+            .borrow_mut()
+            .insert(TypeId::of::<T>(), Rc::new(value));
+    }
+
+    pub fn get<T: 'static>(&self) -> Option<Rc<T>> {
+        self.check_thread();
+        let value = self.values.borrow().get(&TypeId::of::<T>()).cloned()?;
+
+        value.downcast().ok()
+    }
+
+    pub fn remove<T: 'static>(&self) -> Option<Rc<T>> {
+        self.check_thread();
+        let value = self.values.borrow_mut().remove(&TypeId::of::<T>())?;
+
+        value.downcast().ok()
+    }
+
+    fn check_thread(&self) {
+        assert_eq!(
+            self.thread_id,
+            std::thread::current().id(),
+            "user data of an object must only be accessed on the thread where it was first attached"
+        );
+    }
+}
+
+/// Returns the user data of `object`, creating an empty map if `create` is true and the object has none yet.
+///
+/// # Safety
+/// `object` must point to a live object. The returned reference must not outlive it.
+pub(crate) unsafe fn user_data_map<'a>(
+    object: sys::GDExtensionObjectPtr,
+    create: bool,
+) -> Option<&'a UserDataMap> {
+    let callbacks = user_data_callbacks();
+    let callbacks_ptr = if create {
+        &callbacks as *const _
+    } else {
+        ptr::null()
+    };
+
+    let binding =
+        interface_fn!(object_get_instance_binding)(object, user_data_token(), callbacks_ptr);
+
+    // SAFETY: bindings with this token are only created by `create_callback()`, and stay valid until the object is destroyed.
+    (binding as *const UserDataMap).as_ref()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Callbacks
+
+/// Token distinguishing the user-data binding from the class instance binding. Only the address matters.
+static USER_DATA_TOKEN: u8 = 0;
+
+fn user_data_token() -> *mut std::ffi::c_void {
+    ptr::addr_of!(USER_DATA_TOKEN) as *mut std::ffi::c_void
+}
+
+fn user_data_callbacks() -> sys::GDExtensionInstanceBindingCallbacks {
+    sys::GDExtensionInstanceBindingCallbacks {
+        create_callback: Some(create_callback),
+        free_callback: Some(free_callback),
+        reference_callback: Some(reference_callback),
+    }
+}
+
+extern "C" fn create_callback(
+    _p_token: *mut std::os::raw::c_void,
+    _p_instance: *mut std::os::raw::c_void,
+) -> *mut std::os::raw::c_void {
+    // Must not be null, otherwise Godot would not remember the binding.
+    Box::into_raw(Box::new(UserDataMap::new())) as *mut std::os::raw::c_void
+}
+
+extern "C" fn free_callback(
+    _p_token: *mut std::os::raw::c_void,
+    _p_instance: *mut std::os::raw::c_void,
+    p_binding: *mut std::os::raw::c_void,
+) {
+    // SAFETY: the binding was created by `create_callback()`, and Godot frees it exactly once, when the object is destroyed.
+    let map = unsafe { Box::from_raw(p_binding as *mut UserDataMap) };
+
+    // Values are `Rc`s and may not be `Send`, so they must only be dropped on their owner thread. Objects destroyed elsewhere leak them.
+    if map.thread_id != std::thread::current().id() {
+        crate::godot_error!(
+            "user data of an object destroyed on another thread than where it was attached; leaking it instead of dropping"
+        );
+        std::mem::forget(map);
+        return;
+    }
+
+    // Destructors of user values must not unwind into Godot.
+    if std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(map))).is_err() {
+        crate::godot_error!("panic while dropping user data of a freed object");
+    }
+}
+
+extern "C" fn reference_callback(
+    _p_token: *mut std::os::raw::c_void,
+    _p_binding: *mut std::os::raw::c_void,
+    _p_reference: sys::GDExtensionBool,
+) -> sys::GDExtensionBool {
+    true as u8
+}
@@ -0,0 +1,82 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{StringName, Variant};
+use crate::classes::Object;
+use crate::meta::error::CallError;
+use crate::obj::{bounds, Bounds, Gd, GodotClass, Inherits};
+
+/// Fallback for calling methods by name from Rust, which a user class does not declare.
+///
+/// This is **not** dynamic dispatch in Godot: the engine has no hook for unknown methods on GDExtension classes, so the fallback is only
+/// reached through the Rust helper [`Gd::call_or_fallback()`]. GDScript calls such as `proxy.spawn_enemy(7)`, [`Object::call()`] and
+/// callables never reach it and fail as usual.
+///
+/// `call_or_fallback()` calls methods which the object has as usual, and passes all other names to
+/// [`call_fallback()`][Self::call_fallback]:
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::CallFallback;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Object)]
+/// struct RemoteProxy {
+///     forwarded: Vec<(StringName, Vec<Variant>)>,
+/// }
+///
+/// impl CallFallback for RemoteProxy {
+///     fn call_fallback(&mut self, method: StringName, args: &[Variant]) -> Variant {
+///         // Forward to a remote peer, a script VM, etc.
+///         self.forwarded.push((method, args.to_vec()));
+///         Variant::nil()
+///     }
+/// }
+///
+/// let mut proxy = RemoteProxy::new_alloc();
+/// proxy.call_or_fallback("spawn_enemy", &[7.to_variant()]).unwrap();
+/// # proxy.free();
+/// ```
+///
+/// # Precedence
+/// [`Gd::call_or_fallback()`] checks [`Object::has_method()`] first. Thus the following methods take precedence over the fallback, in
+/// this order:
+/// 1. Methods of a script attached to the object.
+/// 2. `#[func]` methods of the class and its Rust base classes.
+/// 3. Methods of the engine base classes, e.g. `get_class` of `Object`.
+///
+/// Only names not found there are passed to `call_fallback()`. To make the fallback accessible from GDScript, expose a `#[func]` which
+/// forwards to `call_or_fallback()`.
+pub trait CallFallback:
+    GodotClass + Bounds<Declarer = bounds::DeclUser> + Inherits<Object>
+{
+    /// Handles a call to `method`, which the object does not declare, with arguments `args`. Returns the result of the call.
+    ///
+    /// The object is mutably bound during this call, so it must not be accessed through other `Gd` pointers meanwhile.
+    fn call_fallback(&mut self, method: StringName, args: &[Variant]) -> Variant;
+}
+
+impl<T: CallFallback> Gd<T> {
+    /// Calls `method` with `args` on the object if it has a method of that name, or [`CallFallback::call_fallback()`] otherwise.
+    ///
+    /// Calls of existing methods behave like [`Object::try_call()`], including errors for wrong arguments. Calls reaching the fallback
+    /// always succeed. See [`CallFallback`] for the precedence, and for calls that bypass the fallback.
+    pub fn call_or_fallback(
+        &mut self,
+        method: impl Into<StringName>,
+        args: &[Variant],
+    ) -> Result<Variant, CallError> {
+        let method = method.into();
+        let object = self.upcast_mut::<Object>();
+
+        if object.has_method(method.clone()) {
+            object.try_call(method, args)
+        } else {
+            Ok(self.bind_mut().call_fallback(method, args))
+        }
+    }
+}
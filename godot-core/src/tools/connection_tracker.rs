@@ -0,0 +1,139 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Callable, StringName};
+use crate::classes::Object;
+use crate::meta::error::{ConnectError, ConnectErrorKind};
+use crate::obj::{Gd, Inherits, InstanceId};
+
+/// Records signal connections, and disconnects them all when dropped.
+///
+/// Godot removes a connection automatically when the object that a callable is bound to is freed. This does not apply to connections
+/// that a class makes on behalf of another object, nor to custom callables (such as Rust closures), which keep receiving signals after
+/// the class that connected them is gone. Store a `ConnectionTracker` as a field of the class, and connect through
+/// [`connect_tracked()`][Self::connect_tracked]: once the instance is freed and its fields are dropped, all tracked connections are
+/// removed again.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::tools::ConnectionTracker;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct HealthBar {
+///     connections: ConnectionTracker,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl HealthBar {
+///     #[func]
+///     fn observe(&mut self, player: Gd<Node>, label: Gd<Node>) {
+///         // Removed once the health bar is freed, even though the callable is bound to `label`.
+///         let callable = Callable::from_object_method(&label, "set_text");
+///         self.connections
+///             .connect_tracked(&player, "health_text_changed", callable)
+///             .expect("player has signal");
+///     }
+/// }
+/// ```
+///
+/// To remove the connections earlier, e.g. in `exit_tree()`, call [`disconnect_all()`][Self::disconnect_all]. Connections of objects
+/// that have been freed in the meantime are skipped.
+#[derive(Debug, Default)]
+pub struct ConnectionTracker {
+    connections: Vec<TrackedConnection>,
+}
+
+impl ConnectionTracker {
+    /// Creates a tracker without connections.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Connects `signal` of `source` to `callable`, and records the connection to be removed later.
+    ///
+    /// Returns an error if `source` has no such signal, or if Godot rejects the connection, e.g. because it already exists. Failed
+    /// connections are not recorded.
+    pub fn connect_tracked<T>(
+        &mut self,
+        source: &Gd<T>,
+        signal: impl Into<StringName>,
+        callable: Callable,
+    ) -> Result<(), ConnectError>
+    where
+        T: Inherits<Object>,
+    {
+        let signal = signal.into();
+        let mut source = source.clone().upcast::<Object>();
+        let source_name = source.to_string();
+        let error = |kind| ConnectError::for_object(kind, source_name.clone(), signal.to_string());
+
+        if !source.has_signal(signal.clone()) {
+            return Err(error(ConnectErrorKind::SignalNotFound));
+        }
+
+        source.try_connect(&signal, &callable, error)?;
+
+        self.connections.push(TrackedConnection {
+            source_id: source.instance_id(),
+            signal,
+            callable,
+        });
+        Ok(())
+    }
+
+    /// Removes all tracked connections, returning how many were still connected.
+    pub fn disconnect_all(&mut self) -> usize {
+        self.connections
+            .drain(..)
+            .filter(|connection| connection.disconnect())
+            .count()
+    }
+
+    /// Number of tracked connections, including those whose source has been freed since.
+    pub fn len(&self) -> usize {
+        self.connections.len()
+    }
+
+    /// Returns `true` if no connections are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.connections.is_empty()
+    }
+}
+
+impl Drop for ConnectionTracker {
+    fn drop(&mut self) {
+        self.disconnect_all();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+#[derive(Debug)]
+struct TrackedConnection {
+    source_id: InstanceId,
+    signal: StringName,
+    callable: Callable,
+}
+
+impl TrackedConnection {
+    /// Disconnects, if the source is still alive and connected. Returns whether a connection was removed.
+    fn disconnect(&self) -> bool {
+        let Ok(mut source) = Gd::<Object>::try_from_instance_id(self.source_id) else {
+            return false;
+        };
+
+        if !source.is_connected(self.signal.clone(), self.callable.clone()) {
+            return false;
+        }
+
+        source.disconnect(self.signal.clone(), self.callable.clone());
+        true
+    }
+}
@@ -0,0 +1,171 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// Tracks the remaining time of a cooldown, driven by frame times.
+///
+/// A lightweight alternative to a [`Timer`][crate::classes::Timer] node per ability or action: no node, no signal, just a number that is
+/// counted down. Feed the frame time from `process(delta)` to [`tick()`][Self::tick], and check [`is_ready()`][Self::is_ready] before
+/// triggering the action. A new cooldown is ready from the start.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::Cooldown;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     dash: Cooldown,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Player {
+///     fn process(&mut self, delta: f64) {
+///         self.dash.tick(delta);
+///     }
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[func]
+///     fn dash(&mut self) {
+///         if self.dash.is_ready() {
+///             // Dash...
+///             self.dash.start(1.5);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Cooldown {
+    remaining: f64,
+}
+
+impl Cooldown {
+    /// Creates a cooldown which is ready.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts counting down `seconds`, after which the cooldown is ready again.
+    ///
+    /// If the cooldown is still counting, it is restarted with the new duration. The remaining time is not added up.
+    ///
+    /// # Panics
+    /// If `seconds` is negative or not finite.
+    pub fn start(&mut self, seconds: f64) {
+        assert!(
+            seconds.is_finite() && seconds >= 0.0,
+            "cooldown must be non-negative and finite, got {seconds}"
+        );
+
+        self.remaining = seconds;
+    }
+
+    /// Advances the cooldown by `delta` seconds of elapsed time.
+    ///
+    /// Negative or NaN deltas are treated as zero.
+    pub fn tick(&mut self, delta: f64) {
+        if delta > 0.0 {
+            self.remaining = (self.remaining - delta).max(0.0);
+        }
+    }
+
+    /// Returns `true` if the cooldown has elapsed, or was never started.
+    pub fn is_ready(&self) -> bool {
+        self.remaining <= 0.0
+    }
+
+    /// Time left until the cooldown is ready, in seconds. Zero if it is ready.
+    pub fn remaining(&self) -> f32 {
+        self.remaining as f32
+    }
+
+    /// Makes the cooldown ready immediately.
+    pub fn reset(&mut self) {
+        self.remaining = 0.0;
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new_is_ready() {
+        let cooldown = Cooldown::new();
+
+        assert!(cooldown.is_ready());
+        assert_eq!(cooldown.remaining(), 0.0);
+    }
+
+    #[test]
+    fn ticks_until_ready() {
+        let mut cooldown = Cooldown::new();
+        cooldown.start(1.0);
+        assert!(!cooldown.is_ready());
+
+        cooldown.tick(0.25);
+        cooldown.tick(0.25);
+        assert!(!cooldown.is_ready());
+        assert_eq!(cooldown.remaining(), 0.5);
+
+        cooldown.tick(0.25);
+        assert!(!cooldown.is_ready());
+
+        cooldown.tick(0.25);
+        assert!(cooldown.is_ready());
+        assert_eq!(cooldown.remaining(), 0.0);
+
+        // Overshooting does not go below zero.
+        cooldown.tick(10.0);
+        assert_eq!(cooldown.remaining(), 0.0);
+    }
+
+    #[test]
+    fn start_while_counting_restarts() {
+        let mut cooldown = Cooldown::new();
+        cooldown.start(1.0);
+        cooldown.tick(0.75);
+
+        cooldown.start(0.5);
+        assert_eq!(cooldown.remaining(), 0.5);
+
+        cooldown.tick(0.25);
+        assert!(!cooldown.is_ready());
+        cooldown.tick(0.25);
+        assert!(cooldown.is_ready());
+    }
+
+    #[test]
+    fn reset_makes_ready() {
+        let mut cooldown = Cooldown::new();
+        cooldown.start(3.0);
+
+        cooldown.reset();
+        assert!(cooldown.is_ready());
+    }
+
+    #[test]
+    fn invalid_deltas_are_ignored() {
+        let mut cooldown = Cooldown::new();
+        cooldown.start(1.0);
+
+        cooldown.tick(-1.0);
+        cooldown.tick(f64::NAN);
+        assert_eq!(cooldown.remaining(), 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_start_panics() {
+        Cooldown::new().start(-1.0);
+    }
+}
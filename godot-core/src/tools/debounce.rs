@@ -0,0 +1,145 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+use crate::classes::Node;
+use crate::obj::{Gd, InstanceId};
+use crate::tools::{connect_delayed_callback, ProcessCallbackHandle};
+
+/// Coalesces repeated triggers into a single run of a closure on the next process frame.
+///
+/// Created with [`Node::debounce()`]. Useful for expensive updates which depend on state that changes in bursts, e.g. rebuilding a mesh
+/// after a series of inspector edits: [`trigger()`][Self::trigger] can be called any number of times, but the closure runs only once,
+/// on the next frame.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # fn rebuild_mesh() {}
+/// # let node: Gd<Node> = todo!();
+/// let rebuild = node.debounce(|| rebuild_mesh());
+///
+/// rebuild.trigger();
+/// rebuild.trigger(); // Coalesced with the first trigger.
+/// ```
+///
+/// The closure runs at most once per frame. Triggers from within the closure, or in later frames, schedule another run on the following
+/// frame. Like [`Node::after_frames()`], frames only count while the node is inside the scene tree and not paused.
+///
+/// The debouncer can be cloned; all clones share the same closure and pending run. Once the last clone is dropped or the node is freed,
+/// a pending run is cancelled.
+#[derive(Clone)]
+pub struct Debouncer {
+    inner: Rc<DebouncerInner>,
+}
+
+impl Debouncer {
+    pub(crate) fn new(node: &Gd<Node>, callback: impl FnMut() + 'static) -> Self {
+        Self {
+            inner: Rc::new(DebouncerInner {
+                node_id: node.instance_id(),
+                callback: RefCell::new(Box::new(callback)),
+                pending: RefCell::new(None),
+            }),
+        }
+    }
+
+    /// Schedules a run of the closure on the next frame, unless one is already pending.
+    ///
+    /// Has no effect if the node has been freed.
+    pub fn trigger(&self) {
+        if self.is_pending() {
+            return;
+        }
+
+        let Ok(node) = Gd::<Node>::try_from_instance_id(self.inner.node_id) else {
+            return;
+        };
+
+        let inner = Rc::downgrade(&self.inner);
+        let handle = connect_delayed_callback(&node, 1, move || run_pending(&inner));
+        *self.inner.pending.borrow_mut() = Some(handle);
+    }
+
+    /// Returns `true` if a run is scheduled for an upcoming frame.
+    pub fn is_pending(&self) -> bool {
+        self.inner
+            .pending
+            .borrow()
+            .as_ref()
+            .is_some_and(ProcessCallbackHandle::is_active)
+    }
+
+    /// Cancels a pending run. Has no effect if none is pending.
+    pub fn cancel(&self) {
+        if let Some(handle) = self.inner.pending.borrow_mut().take() {
+            handle.cancel();
+        }
+    }
+
+    /// Runs the closure immediately if a run is pending, instead of on the next frame.
+    ///
+    /// Returns `true` if the closure was run. Has no effect when called from within the closure.
+    pub fn flush(&self) -> bool {
+        if !self.is_pending() {
+            return false;
+        }
+
+        self.cancel();
+        self.inner.run()
+    }
+}
+
+impl fmt::Debug for Debouncer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Debouncer")
+            .field("node_id", &self.inner.node_id)
+            .field("is_pending", &self.is_pending())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct DebouncerInner {
+    node_id: InstanceId,
+    callback: RefCell<Box<dyn FnMut()>>,
+    pending: RefCell<Option<ProcessCallbackHandle>>,
+}
+
+impl DebouncerInner {
+    fn run(&self) -> bool {
+        // A debouncer which is flushed from within its own closure doesn't run recursively.
+        let Ok(mut callback) = self.callback.try_borrow_mut() else {
+            return false;
+        };
+
+        callback();
+        true
+    }
+}
+
+impl Drop for DebouncerInner {
+    fn drop(&mut self) {
+        if let Some(handle) = self.pending.get_mut().take() {
+            handle.cancel();
+        }
+    }
+}
+
+fn run_pending(inner: &Weak<DebouncerInner>) {
+    let Some(inner) = inner.upgrade() else {
+        return;
+    };
+
+    // Clear before running, so that triggers from within the closure schedule the next run.
+    inner.pending.borrow_mut().take();
+    inner.run();
+}
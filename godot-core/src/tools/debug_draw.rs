@@ -0,0 +1,330 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::real_consts::TAU;
+use crate::builtin::{real, Color, Vector3};
+
+/// Number of line segments approximating each of the three circles of a sphere.
+const SPHERE_SEGMENTS: usize = 24;
+
+/// Collects debug shapes during a frame, to be drawn as lines once per frame.
+///
+/// Useful for visualizing gizmos from `#[class(tool)]` classes or during gameplay, without dealing with mesh APIs. Shapes are queued
+/// with [`line()`][Self::line] and [`sphere()`][Self::sphere], and then drawn and cleared with [`flush()`][Self::flush], typically
+/// at the end of `process()`:
+///
+/// ```ignore
+/// # use godot::prelude::*;
+/// # use godot::tools::{DebugDraw, ImmediateMeshTarget};
+/// # struct Gizmos { draw: DebugDraw, target: ImmediateMeshTarget }
+/// # impl Gizmos {
+/// fn process(&mut self, _delta: f64) {
+///     self.draw.line(Vector3::ZERO, Vector3::UP, Color::RED);
+///     self.draw.sphere(Vector3::new(0.0, 2.0, 0.0), 0.5, Color::GREEN);
+///
+///     // Replaces the shapes drawn in the previous frame.
+///     self.draw.flush(&mut self.target);
+/// }
+/// # }
+/// ```
+///
+/// The drawing itself is done by a [`DebugDrawTarget`]. With the `codegen-full` feature, [`ImmediateMeshTarget`] renders into an
+/// `ImmediateMesh`. Custom targets can be used to route shapes elsewhere, or to inspect them in tests.
+#[derive(Clone, Debug, Default)]
+pub struct DebugDraw {
+    shapes: Vec<DebugShape>,
+}
+
+impl DebugDraw {
+    /// Creates an empty collection of shapes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a line from `from` to `to`.
+    pub fn line(&mut self, from: Vector3, to: Vector3, color: Color) {
+        self.shapes.push(DebugShape::Line { from, to, color });
+    }
+
+    /// Queues a wireframe sphere, drawn as three circles around the axes.
+    pub fn sphere(&mut self, center: Vector3, radius: real, color: Color) {
+        self.shapes.push(DebugShape::Sphere {
+            center,
+            radius,
+            color,
+        });
+    }
+
+    /// Shapes queued since the last flush, in order.
+    pub fn shapes(&self) -> &[DebugShape] {
+        &self.shapes
+    }
+
+    /// Discards all queued shapes without drawing them.
+    pub fn clear(&mut self) {
+        self.shapes.clear();
+    }
+
+    /// Draws all queued shapes to `target`, replacing what was drawn in the previous flush, and clears the queue.
+    ///
+    /// If no shapes were queued, the target is only cleared.
+    pub fn flush(&mut self, target: &mut impl DebugDrawTarget) {
+        target.begin_frame();
+        for shape in self.shapes.drain(..) {
+            shape.emit_lines(|from, to, color| target.add_line(from, to, color));
+        }
+        target.end_frame();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Shape queued in a [`DebugDraw`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum DebugShape {
+    /// Straight line between two points.
+    Line {
+        from: Vector3,
+        to: Vector3,
+        color: Color,
+    },
+
+    /// Wireframe sphere.
+    Sphere {
+        center: Vector3,
+        radius: real,
+        color: Color,
+    },
+}
+
+impl DebugShape {
+    /// Calls `add_line` for each line segment making up this shape.
+    fn emit_lines(&self, mut add_line: impl FnMut(Vector3, Vector3, Color)) {
+        match *self {
+            Self::Line { from, to, color } => add_line(from, to, color),
+            Self::Sphere {
+                center,
+                radius,
+                color,
+            } => {
+                let axes = [
+                    (Vector3::RIGHT, Vector3::UP),
+                    (Vector3::UP, Vector3::BACK),
+                    (Vector3::BACK, Vector3::RIGHT),
+                ];
+
+                for (u, v) in axes {
+                    let point = |i: usize| {
+                        let angle = TAU * i as real / SPHERE_SEGMENTS as real;
+                        center + (u * angle.cos() + v * angle.sin()) * radius
+                    };
+
+                    for i in 0..SPHERE_SEGMENTS {
+                        add_line(point(i), point(i + 1), color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Receives the lines of a [`DebugDraw`] when it is flushed.
+pub trait DebugDrawTarget {
+    /// Called at the start of each flush. Should discard everything drawn in the previous flush.
+    fn begin_frame(&mut self);
+
+    /// Adds a line segment to the current frame.
+    fn add_line(&mut self, from: Vector3, to: Vector3, color: Color);
+
+    /// Called at the end of each flush, after all lines have been added.
+    fn end_frame(&mut self);
+}
+
+/// [`DebugDrawTarget`] rendering lines into an `ImmediateMesh`, shown by a `MeshInstance3D`.
+///
+/// The mesh instance is added as an internal child of a 3D node, so it is not saved with the scene, and uses an unshaded material which
+/// takes the line colors from the vertices. Lines are drawn in the parent's local space.
+#[cfg(feature = "codegen-full")]
+pub struct ImmediateMeshTarget {
+    mesh: crate::obj::Gd<crate::classes::ImmediateMesh>,
+    material: crate::obj::Gd<crate::classes::StandardMaterial3D>,
+    instance: crate::obj::Gd<crate::classes::MeshInstance3D>,
+    lines: Vec<(Vector3, Vector3, Color)>,
+}
+
+#[cfg(feature = "codegen-full")]
+impl ImmediateMeshTarget {
+    /// Creates a mesh instance as internal child of `parent`, which displays the drawn lines.
+    pub fn new(parent: &mut crate::obj::Gd<crate::classes::Node3D>) -> Self {
+        use crate::classes::base_material_3d::{Flags, ShadingMode};
+        use crate::classes::node::InternalMode;
+        use crate::classes::{ImmediateMesh, MeshInstance3D, StandardMaterial3D};
+        use crate::obj::{NewAlloc, NewGd};
+
+        let mesh = ImmediateMesh::new_gd();
+
+        let mut material = StandardMaterial3D::new_gd();
+        material.set_shading_mode(ShadingMode::UNSHADED);
+        material.set_flag(Flags::ALBEDO_FROM_VERTEX_COLOR, true);
+
+        let mut instance = MeshInstance3D::new_alloc();
+        instance.set_mesh(&mesh);
+        parent
+            .add_child_ex(&instance)
+            .internal(InternalMode::BACK)
+            .done();
+
+        Self {
+            mesh,
+            material,
+            instance,
+            lines: Vec::new(),
+        }
+    }
+
+    /// The mesh instance displaying the lines. Is freed together with its parent.
+    pub fn mesh_instance(&self) -> &crate::obj::Gd<crate::classes::MeshInstance3D> {
+        &self.instance
+    }
+}
+
+#[cfg(feature = "codegen-full")]
+impl DebugDrawTarget for ImmediateMeshTarget {
+    fn begin_frame(&mut self) {
+        self.lines.clear();
+        self.mesh.clear_surfaces();
+    }
+
+    fn add_line(&mut self, from: Vector3, to: Vector3, color: Color) {
+        self.lines.push((from, to, color));
+    }
+
+    fn end_frame(&mut self) {
+        // Godot rejects surfaces without vertices.
+        if self.lines.is_empty() {
+            return;
+        }
+
+        self.mesh
+            .surface_begin_ex(crate::classes::mesh::PrimitiveType::LINES)
+            .material(&self.material)
+            .done();
+
+        for &(from, to, color) in &self.lines {
+            self.mesh.surface_set_color(color);
+            self.mesh.surface_add_vertex(from);
+            self.mesh.surface_add_vertex(to);
+        }
+
+        self.mesh.surface_end();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::builtin::math::ApproxEq;
+
+    /// Records frames of lines, instead of drawing them.
+    #[derive(Default)]
+    struct MockTarget {
+        frames: Vec<Vec<(Vector3, Vector3, Color)>>,
+        is_in_frame: bool,
+    }
+
+    impl DebugDrawTarget for MockTarget {
+        fn begin_frame(&mut self) {
+            assert!(!self.is_in_frame);
+            self.is_in_frame = true;
+            self.frames.push(Vec::new());
+        }
+
+        fn add_line(&mut self, from: Vector3, to: Vector3, color: Color) {
+            assert!(self.is_in_frame);
+            self.frames.last_mut().unwrap().push((from, to, color));
+        }
+
+        fn end_frame(&mut self) {
+            assert!(self.is_in_frame);
+            self.is_in_frame = false;
+        }
+    }
+
+    #[test]
+    fn shapes_accumulate_until_flush() {
+        let mut draw = DebugDraw::new();
+        draw.line(Vector3::ZERO, Vector3::UP, Color::RED);
+        draw.sphere(Vector3::ONE, 2.0, Color::GREEN);
+
+        assert_eq!(
+            draw.shapes(),
+            [
+                DebugShape::Line {
+                    from: Vector3::ZERO,
+                    to: Vector3::UP,
+                    color: Color::RED
+                },
+                DebugShape::Sphere {
+                    center: Vector3::ONE,
+                    radius: 2.0,
+                    color: Color::GREEN
+                },
+            ]
+        );
+
+        let mut target = MockTarget::default();
+        draw.flush(&mut target);
+
+        assert!(draw.shapes().is_empty());
+        assert_eq!(target.frames.len(), 1);
+
+        let lines = &target.frames[0];
+        assert_eq!(lines.len(), 1 + 3 * SPHERE_SEGMENTS);
+        assert_eq!(lines[0], (Vector3::ZERO, Vector3::UP, Color::RED));
+
+        // Sphere segments lie on the surface, and form closed circles.
+        for &(from, to, color) in &lines[1..] {
+            assert!((from.distance_to(Vector3::ONE) - 2.0).abs() < 1e-4);
+            assert!((to.distance_to(Vector3::ONE) - 2.0).abs() < 1e-4);
+            assert_eq!(color, Color::GREEN);
+        }
+        for circle in lines[1..].chunks(SPHERE_SEGMENTS) {
+            assert!(circle[0].0.approx_eq(&circle[SPHERE_SEGMENTS - 1].1));
+        }
+    }
+
+    #[test]
+    fn flush_clears_each_frame() {
+        let mut draw = DebugDraw::new();
+        let mut target = MockTarget::default();
+
+        draw.line(Vector3::ZERO, Vector3::RIGHT, Color::WHITE);
+        draw.flush(&mut target);
+
+        // Nothing queued: the next frame is empty.
+        draw.flush(&mut target);
+
+        draw.line(Vector3::ZERO, Vector3::BACK, Color::BLUE);
+        draw.line(Vector3::ZERO, Vector3::FORWARD, Color::BLUE);
+        draw.clear();
+        draw.line(Vector3::ONE, Vector3::ZERO, Color::BLACK);
+        draw.flush(&mut target);
+
+        assert_eq!(
+            target.frames,
+            [
+                vec![(Vector3::ZERO, Vector3::RIGHT, Color::WHITE)],
+                vec![],
+                vec![(Vector3::ONE, Vector3::ZERO, Color::BLACK)],
+            ]
+        );
+    }
+}
@@ -0,0 +1,121 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Dictionary, VariantArray};
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, GodotConvert, ToGodot};
+
+/// Difference between two dictionaries: the keys that were added, removed, or whose values changed.
+///
+/// Computed with [`compute()`][Self::compute], and applied to a copy of the old dictionary with [`apply()`][Self::apply], which then
+/// equals the new dictionary. This is useful for state synchronization in multiplayer games, where sending only the changes of a
+/// state dictionary saves bandwidth. A diff converts to and from a [`Dictionary`] of the form
+/// `{ "added": { ... }, "changed": { ... }, "removed": [ ... ] }`, so it can be sent over the network or stored.
+///
+/// # Nested structures
+/// The diff is shallow: values are compared with Godot's `==` operator, which compares nested dictionaries and arrays by content.
+/// If a nested container differs in any element, it is stored as a whole under its key in [`changed()`][Self::changed]. To sync
+/// large nested states efficiently, compute separate diffs for the nested dictionaries.
+#[derive(Clone, Debug, Default)]
+pub struct DictionaryDiff {
+    added: Dictionary,
+    changed: Dictionary,
+    removed: VariantArray,
+}
+
+impl DictionaryDiff {
+    /// Computes the changes that turn `old` into `new`.
+    pub fn compute(old: &Dictionary, new: &Dictionary) -> Self {
+        let mut diff = Self::default();
+
+        for (key, new_value) in new.iter_shared() {
+            match old.get(key.clone()) {
+                None => diff.added.set(key, new_value),
+                Some(old_value) if old_value != new_value => diff.changed.set(key, new_value),
+                Some(_) => {}
+            }
+        }
+
+        for key in old.keys_array().iter_shared() {
+            if !new.contains_key(key.clone()) {
+                diff.removed.push(&key);
+            }
+        }
+
+        diff
+    }
+
+    /// Applies the changes to `target`: sets added and changed keys to their new values, and removes removed keys.
+    ///
+    /// If `target` equals the `old` dictionary passed to [`compute()`][Self::compute], it equals `new` afterwards. On other
+    /// dictionaries, the changes are applied regardless, e.g. a changed key is inserted if missing.
+    pub fn apply(&self, target: &mut Dictionary) {
+        for key in self.removed.iter_shared() {
+            target.remove(key);
+        }
+
+        for (key, value) in self.added.iter_shared().chain(self.changed.iter_shared()) {
+            target.set(key, value);
+        }
+    }
+
+    /// Keys that exist only in the new dictionary, with their values.
+    pub fn added(&self) -> &Dictionary {
+        &self.added
+    }
+
+    /// Keys that exist in both dictionaries but with different values, with their new values.
+    pub fn changed(&self) -> &Dictionary {
+        &self.changed
+    }
+
+    /// Keys that exist only in the old dictionary.
+    pub fn removed(&self) -> &VariantArray {
+        &self.removed
+    }
+
+    /// Returns `true` if both dictionaries were equal.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+
+    /// Total number of added, changed and removed keys.
+    pub fn len(&self) -> usize {
+        self.added.len() + self.changed.len() + self.removed.len()
+    }
+}
+
+impl GodotConvert for DictionaryDiff {
+    type Via = Dictionary;
+}
+
+impl ToGodot for DictionaryDiff {
+    type ToVia<'v> = Dictionary;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        let mut dict = Dictionary::new();
+        dict.set("added", self.added.clone());
+        dict.set("changed", self.changed.clone());
+        dict.set("removed", self.removed.clone());
+        dict
+    }
+}
+
+impl FromGodot for DictionaryDiff {
+    fn try_from_godot(dict: Self::Via) -> Result<Self, ConvertError> {
+        let field = |key: &str| {
+            dict.get(key)
+                .ok_or_else(|| ConvertError::new(format!("dictionary diff is missing key '{key}'")))
+        };
+
+        Ok(Self {
+            added: field("added")?.try_to()?,
+            changed: field("changed")?.try_to()?,
+            removed: field("removed")?.try_to()?,
+        })
+    }
+}
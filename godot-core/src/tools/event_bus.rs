@@ -0,0 +1,150 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Array, Callable, Dictionary, StringName};
+use crate::meta::ToGodot;
+use crate::varray;
+
+/// Dispatches named events to subscribed callables, decoupling the code that emits events from the code reacting to them.
+///
+/// Subscribers are registered per event name with [`subscribe()`][Self::subscribe] and receive the payload of each
+/// [`emit()`][Self::emit] as their single argument. Dispatch is synchronous: all subscribers have run when `emit()` returns.
+///
+/// Unlike signals, events are not tied to an object, so neither side needs to know the other. A common setup is to store one bus in an
+/// autoload (singleton) class, which exposes it to the whole game. Callables that have become invalid, e.g. because their object was
+/// freed, are removed automatically during the next `emit()`.
+///
+/// Internally, subscribers are stored as a [`Dictionary`] mapping each event name to an `Array<Callable>`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::EventBus;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Events {
+///     bus: EventBus,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Events {
+///     #[func]
+///     fn subscribe(&mut self, event: StringName, callable: Callable) {
+///         self.bus.subscribe(event, callable);
+///     }
+///
+///     #[func]
+///     fn emit(&mut self, event: StringName, payload: Variant) {
+///         self.bus.emit(event, payload);
+///     }
+/// }
+/// ```
+pub struct EventBus {
+    subscribers: Dictionary,
+}
+
+impl EventBus {
+    /// Creates a new bus without any subscribers.
+    pub fn new() -> Self {
+        Self {
+            subscribers: Dictionary::new(),
+        }
+    }
+
+    /// Subscribes `callable` to the event `event`.
+    ///
+    /// Returns `false` if `callable` was already subscribed to this event, in which case it is not added a second time. Callables are
+    /// compared with `==`; see [`Callable`] for its equality semantics.
+    pub fn subscribe(&mut self, event: impl Into<StringName>, callable: Callable) -> bool {
+        let event = event.into();
+
+        let mut callables = self.callables(&event).unwrap_or_else(|| {
+            let callables = Array::new();
+            self.subscribers.set(event, callables.clone());
+            callables
+        });
+
+        if callables.contains(&callable) {
+            return false;
+        }
+
+        callables.push(callable);
+        true
+    }
+
+    /// Removes `callable` from the subscribers of `event`.
+    ///
+    /// Returns `false` if `callable` was not subscribed to this event.
+    pub fn unsubscribe(&mut self, event: impl Into<StringName>, callable: &Callable) -> bool {
+        let event = event.into();
+        let Some(mut callables) = self.callables(&event) else {
+            return false;
+        };
+
+        let Some(index) = callables.find(callable, None) else {
+            return false;
+        };
+
+        callables.remove(index);
+        if callables.is_empty() {
+            self.subscribers.remove(event);
+        }
+
+        true
+    }
+
+    /// Calls all subscribers of `event` with `payload` as argument, returning how many subscribers were called.
+    ///
+    /// Subscribers are called in the order they subscribed. Invalid callables are skipped and unsubscribed. Changes made to the
+    /// subscribers of `event` by the subscribers themselves take effect from the next `emit()` on.
+    pub fn emit(&mut self, event: impl Into<StringName>, payload: impl ToGodot) -> usize {
+        let event = event.into();
+        let Some(callables) = self.callables(&event) else {
+            return 0;
+        };
+
+        let arguments = varray![payload];
+        let mut call_count = 0;
+
+        // Iterate over a copy, so that subscribers can modify the bus while being called.
+        for callable in callables.duplicate_shallow().iter_shared() {
+            if callable.is_valid() {
+                callable.callv(&arguments);
+                call_count += 1;
+            } else {
+                self.unsubscribe(event.clone(), &callable);
+            }
+        }
+
+        call_count
+    }
+
+    /// Returns the number of callables subscribed to `event`, including ones which have become invalid since the last `emit()`.
+    pub fn subscriber_count(&self, event: impl Into<StringName>) -> usize {
+        self.callables(&event.into())
+            .map_or(0, |callables| callables.len())
+    }
+
+    /// Removes all subscribers of all events.
+    pub fn clear(&mut self) {
+        self.subscribers.clear();
+    }
+
+    fn callables(&self, event: &StringName) -> Option<Array<Callable>> {
+        self.subscribers
+            .get(event.clone())
+            .map(|callables| callables.to::<Array<Callable>>())
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
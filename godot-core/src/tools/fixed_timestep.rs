@@ -0,0 +1,213 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// Accumulates frame times and consumes them in fixed-size steps.
+///
+/// Useful for deterministic logic (simulations, lockstep networking, replays) that must advance at a fixed rate regardless of the
+/// framerate. Feed the frame time from `process(delta)` to [`accumulate()`][Self::accumulate], and run one logic step per yielded item.
+/// Time that doesn't amount to a full step is carried over to the next frame.
+///
+/// # Spiral of death
+/// If one logic step takes longer than a step's duration, each frame takes longer than the previous one and the backlog grows
+/// without bound. To prevent this, at most [`max_steps()`][Self::max_steps] steps are run per call; any further accumulated time
+/// is discarded, slowing down the simulation instead of freezing the game.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::FixedTimestep;
+///
+/// #[derive(GodotClass)]
+/// #[class(base=Node)]
+/// struct Simulation {
+///     timestep: FixedTimestep,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Simulation {
+///     fn init(base: Base<Node>) -> Self {
+///         Self {
+///             timestep: FixedTimestep::from_hz(60.0),
+///             base,
+///         }
+///     }
+///
+///     fn process(&mut self, delta: f64) {
+///         for () in self.timestep.accumulate(delta) {
+///             // Deterministic logic, advancing by `self.timestep.step()` seconds.
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct FixedTimestep {
+    step: f64,
+    max_steps: usize,
+    accumulated: f64,
+}
+
+impl FixedTimestep {
+    /// Maximum number of steps per [`accumulate()`][Self::accumulate] call, unless changed with [`with_max_steps()`][Self::with_max_steps].
+    pub const DEFAULT_MAX_STEPS: usize = 8;
+
+    /// Creates a timestep consuming `step` seconds per step.
+    ///
+    /// # Panics
+    /// If `step` is not a positive, finite number.
+    pub fn new(step: f64) -> Self {
+        assert!(
+            step.is_finite() && step > 0.0,
+            "fixed timestep must be positive and finite, got {step}"
+        );
+
+        Self {
+            step,
+            max_steps: Self::DEFAULT_MAX_STEPS,
+            accumulated: 0.0,
+        }
+    }
+
+    /// Creates a timestep running `hz` steps per second.
+    ///
+    /// # Panics
+    /// If `hz` is not a positive, finite number.
+    pub fn from_hz(hz: f64) -> Self {
+        assert!(
+            hz.is_finite() && hz > 0.0,
+            "fixed timestep frequency must be positive and finite, got {hz}"
+        );
+
+        Self::new(1.0 / hz)
+    }
+
+    /// Limits the number of steps run per [`accumulate()`][Self::accumulate] call.
+    ///
+    /// # Panics
+    /// If `max_steps` is 0.
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        assert!(max_steps > 0, "fixed timestep needs max_steps >= 1");
+
+        Self { max_steps, ..self }
+    }
+
+    /// Adds `delta` seconds of elapsed time, and returns an iterator yielding once per step consumed.
+    ///
+    /// The time is consumed eagerly, so the steps are accounted for even if the iterator is not exhausted. Negative or NaN deltas are
+    /// treated as zero.
+    pub fn accumulate(&mut self, delta: f64) -> impl Iterator<Item = ()> {
+        if delta > 0.0 {
+            self.accumulated += delta;
+        }
+
+        let available = (self.accumulated / self.step).floor();
+        let steps = if available >= self.max_steps as f64 {
+            // Drop the backlog (see "spiral of death"), keep only the fractional part of a step.
+            self.accumulated %= self.step;
+            self.max_steps
+        } else {
+            self.accumulated -= available * self.step;
+            available as usize
+        };
+
+        std::iter::repeat(()).take(steps)
+    }
+
+    /// Duration of a single step, in seconds.
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    /// Maximum number of steps per [`accumulate()`][Self::accumulate] call.
+    pub fn max_steps(&self) -> usize {
+        self.max_steps
+    }
+
+    /// Fraction of a step that has accumulated but not yet been consumed, in `[0, 1)`.
+    ///
+    /// Can be used to interpolate rendering between the last two logic states.
+    pub fn alpha(&self) -> f64 {
+        self.accumulated / self.step
+    }
+
+    /// Discards all accumulated time.
+    pub fn reset(&mut self) {
+        self.accumulated = 0.0;
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn small_deltas_accumulate() {
+        let mut timestep = FixedTimestep::new(0.25);
+
+        assert_eq!(timestep.accumulate(0.1).count(), 0);
+        assert_eq!(timestep.accumulate(0.1).count(), 0);
+        assert_eq!(timestep.accumulate(0.1).count(), 1);
+        assert!((timestep.alpha() - 0.2).abs() < 1e-9);
+
+        let total: usize = (0..100).map(|_| timestep.accumulate(0.05).count()).sum();
+        assert_eq!(total, 20);
+    }
+
+    #[test]
+    fn large_delta_runs_multiple_steps() {
+        let mut timestep = FixedTimestep::new(0.25);
+
+        assert_eq!(timestep.accumulate(1.0).count(), 4);
+        assert_eq!(timestep.alpha(), 0.0);
+
+        assert_eq!(timestep.accumulate(0.625).count(), 2);
+        assert_eq!(timestep.alpha(), 0.5);
+    }
+
+    #[test]
+    fn steps_are_capped() {
+        let mut timestep = FixedTimestep::new(0.25).with_max_steps(3);
+
+        // 10.125 s would be 40 steps; only 3 are run, the backlog is dropped.
+        assert_eq!(timestep.accumulate(10.125).count(), 3);
+        assert_eq!(timestep.alpha(), 0.5);
+        assert_eq!(timestep.accumulate(0.125).count(), 1);
+    }
+
+    #[test]
+    fn steps_are_consumed_without_iterating() {
+        let mut timestep = FixedTimestep::new(0.5);
+
+        drop(timestep.accumulate(1.25));
+        assert_eq!(timestep.alpha(), 0.5);
+        assert_eq!(timestep.accumulate(0.0).count(), 0);
+    }
+
+    #[test]
+    fn invalid_deltas_are_ignored() {
+        let mut timestep = FixedTimestep::new(0.5);
+
+        assert_eq!(timestep.accumulate(-1.0).count(), 0);
+        assert_eq!(timestep.accumulate(f64::NAN).count(), 0);
+        assert_eq!(timestep.alpha(), 0.0);
+    }
+
+    #[test]
+    fn from_hz() {
+        let timestep = FixedTimestep::from_hz(4.0);
+        assert_eq!(timestep.step(), 0.25);
+        assert_eq!(timestep.max_steps(), FixedTimestep::DEFAULT_MAX_STEPS);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_step_panics() {
+        FixedTimestep::new(0.0);
+    }
+}
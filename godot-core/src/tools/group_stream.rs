@@ -0,0 +1,166 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{Signal, StringName};
+use crate::classes::{Node, SceneTree};
+use crate::obj::{Gd, InstanceId};
+use crate::tools::local_callback::{
+    connect_local_callback, register_local_callback, remove_local_callback, LocalCallbackId,
+};
+
+/// Stream of nodes in a group, as they are added to the scene tree.
+///
+/// Created by [`SceneTree::nodes_added_to_group()`]. Nodes can be received one at a time, either by awaiting [`next()`][Self::next] or
+/// by polling [`try_next()`][Self::try_next], e.g. once per frame:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::tools::spawn_task;
+/// # fn register_enemy(_enemy: Gd<Node>) {}
+/// # fn setup(tree: Gd<SceneTree>) {
+/// let mut enemies = tree.nodes_added_to_group("enemies");
+///
+/// spawn_task(async move {
+///     loop {
+///         let enemy = enemies.next().await;
+///         register_enemy(enemy);
+///     }
+/// });
+/// # }
+/// ```
+///
+/// # Membership
+/// The stream starts with all nodes that are in the group at the time of its creation. After that, it yields each node that enters the
+/// tree while being in the group, based on the tree's `node_added` signal. This includes groups added in `enter_tree()`, or with
+/// [`Node::add_to_group()`] before adding the node as a child. Nodes that join the group while already inside the tree, e.g. in
+/// `ready()`, are not observed. A node that leaves and re-enters the tree is yielded again.
+///
+/// Nodes freed before they are received are skipped.
+///
+/// Dropping the stream disconnects it from the tree.
+pub struct GroupNodeStream {
+    state: Rc<RefCell<GroupStreamState>>,
+    group: StringName,
+    callback_id: LocalCallbackId,
+}
+
+impl GroupNodeStream {
+    pub(crate) fn new(tree: &Gd<SceneTree>, group: StringName) -> Self {
+        let pending = tree
+            .get_nodes_in_group(group.clone())
+            .iter_shared()
+            .map(|node| node.instance_id())
+            .collect();
+
+        let state = Rc::new(RefCell::new(GroupStreamState {
+            pending,
+            waker: None,
+        }));
+
+        let callback_id = {
+            let state = Rc::clone(&state);
+            let group = group.clone();
+
+            register_local_callback(move |args| {
+                let Some(node) = args.first().and_then(|arg| arg.try_to::<Gd<Node>>().ok()) else {
+                    return;
+                };
+
+                if node.is_in_group(group.clone()) {
+                    let waker = {
+                        let mut state = state.borrow_mut();
+                        state.pending.push_back(node.instance_id());
+                        state.waker.take()
+                    };
+
+                    // Wake outside the borrow, in case the executor polls synchronously.
+                    if let Some(waker) = waker {
+                        waker.wake();
+                    }
+                }
+            })
+        };
+
+        connect_local_callback(
+            callback_id,
+            Signal::from_object_signal(tree, "node_added"),
+            "GroupNodeStream::node_added",
+        );
+
+        Self {
+            state,
+            group,
+            callback_id,
+        }
+    }
+
+    /// The observed group.
+    pub fn group(&self) -> &StringName {
+        &self.group
+    }
+
+    /// Returns the next node that has not yet been received, or `None` if there is currently none.
+    pub fn try_next(&mut self) -> Option<Gd<Node>> {
+        let mut state = self.state.borrow_mut();
+
+        while let Some(id) = state.pending.pop_front() {
+            if let Ok(node) = Gd::try_from_instance_id(id) {
+                return Some(node);
+            }
+        }
+
+        None
+    }
+
+    /// Waits for the next node that has not yet been received.
+    ///
+    /// Resolves immediately if nodes are already pending. See [`try_next()`][Self::try_next] for a non-blocking alternative.
+    pub fn next(&mut self) -> NextGroupNode<'_> {
+        NextGroupNode { stream: self }
+    }
+}
+
+impl Drop for GroupNodeStream {
+    fn drop(&mut self) {
+        remove_local_callback(self.callback_id);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Future returned by [`GroupNodeStream::next()`].
+pub struct NextGroupNode<'a> {
+    stream: &'a mut GroupNodeStream,
+}
+
+impl Future for NextGroupNode<'_> {
+    type Output = Gd<Node>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(node) = self.stream.try_next() {
+            return Poll::Ready(node);
+        }
+
+        self.stream.state.borrow_mut().waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct GroupStreamState {
+    pending: VecDeque<InstanceId>,
+    waker: Option<Waker>,
+}
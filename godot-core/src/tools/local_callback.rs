@@ -0,0 +1,125 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Registry of Rust closures which are invoked from Godot signals, but stay on the thread that registered them.
+//!
+//! Callables created with [`Callable::from_fn()`] must be `Send + Sync`. Closures which capture `Gd` pointers, `Rc`s or other
+//! thread-bound state are therefore stored in a thread-local registry, and the callable only refers to them by ID.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::builtin::{Callable, Signal, Variant};
+
+/// Identifies a closure in the registry of the current thread.
+pub(crate) type LocalCallbackId = u64;
+
+type LocalCallback = Rc<RefCell<Box<dyn FnMut(&[&Variant])>>>;
+
+thread_local! {
+    static CALLBACKS: RefCell<HashMap<LocalCallbackId, LocalEntry>> = RefCell::new(HashMap::new());
+    static NEXT_ID: Cell<LocalCallbackId> = const { Cell::new(0) };
+}
+
+struct LocalEntry {
+    callback: LocalCallback,
+    connections: Vec<(Signal, Callable)>,
+}
+
+/// Registers `callback` on the current thread, and returns its ID.
+pub(crate) fn register_local_callback(
+    callback: impl FnMut(&[&Variant]) + 'static,
+) -> LocalCallbackId {
+    register_local_callback_with_id(|_id| callback)
+}
+
+/// Like [`register_local_callback()`], but passes the new ID to `make_callback`, e.g. so the callback can remove itself.
+pub(crate) fn register_local_callback_with_id<F>(
+    make_callback: impl FnOnce(LocalCallbackId) -> F,
+) -> LocalCallbackId
+where
+    F: FnMut(&[&Variant]) + 'static,
+{
+    let id = NEXT_ID.with(|next_id| next_id.replace(next_id.get() + 1));
+    let entry = LocalEntry {
+        callback: Rc::new(RefCell::new(Box::new(make_callback(id)))),
+        connections: Vec::new(),
+    };
+
+    CALLBACKS.with(|callbacks| callbacks.borrow_mut().insert(id, entry));
+    id
+}
+
+/// Connects `signal` to the callback with the given ID, through a callable named `name`.
+///
+/// The connection is removed together with the callback.
+pub(crate) fn connect_local_callback(id: LocalCallbackId, signal: Signal, name: &str) {
+    let callable = Callable::from_fn(name, move |args| {
+        call_local_callback(id, args);
+        Ok(Variant::nil())
+    });
+
+    // Connect outside the registry borrow, as Godot may emit signals during the connection.
+    signal.connect(callable.clone(), 0);
+
+    CALLBACKS.with(|callbacks| {
+        if let Some(entry) = callbacks.borrow_mut().get_mut(&id) {
+            entry.connections.push((signal, callable));
+        }
+    });
+}
+
+/// Runs the callback with the given ID. Returns `false` if it is not registered, or is already running further up the stack.
+pub(crate) fn call_local_callback(id: LocalCallbackId, args: &[&Variant]) -> bool {
+    // Release the registry before calling, so the callback can register or remove callbacks (including itself).
+    let Some(callback) = CALLBACKS.with(|callbacks| {
+        callbacks
+            .borrow()
+            .get(&id)
+            .map(|entry| Rc::clone(&entry.callback))
+    }) else {
+        return false;
+    };
+
+    let Ok(mut callback) = callback.try_borrow_mut() else {
+        return false;
+    };
+
+    callback(args);
+    true
+}
+
+/// Returns `true` if a callback with the given ID is registered on the current thread.
+pub(crate) fn is_local_callback_registered(id: LocalCallbackId) -> bool {
+    CALLBACKS
+        .try_with(|callbacks| callbacks.borrow().contains_key(&id))
+        .unwrap_or(false)
+}
+
+/// Unregisters the callback with the given ID and disconnects its signals. Has no effect if it is not registered.
+///
+/// May also be called from within the callback itself, and during thread shutdown.
+pub(crate) fn remove_local_callback(id: LocalCallbackId) {
+    let entry = CALLBACKS
+        .try_with(|callbacks| callbacks.borrow_mut().remove(&id))
+        .ok()
+        .flatten();
+
+    // Disconnect and drop the callback outside the borrow, as its destructor may interact with the registry.
+    let Some(entry) = entry else {
+        return;
+    };
+
+    for (signal, callable) in entry.connections {
+        // Connections of freed objects are already gone, e.g. for nodes being freed or the scene tree during shutdown.
+        let is_alive = signal.object_id().is_some_and(|id| id.lookup_validity());
+        if is_alive && signal.is_connected(callable.clone()) {
+            signal.disconnect(callable);
+        }
+    }
+}
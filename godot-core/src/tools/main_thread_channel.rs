@@ -0,0 +1,144 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::mpsc;
+use std::thread::ThreadId;
+
+use crate::builtin::Variant;
+use crate::meta::ToGodot;
+
+/// Queue of work that other threads hand over to the main thread.
+///
+/// Most of the Godot API may only be used from the main thread. Background threads (e.g. a tokio runtime or a thread pool) can post
+/// closures and values through a [`MainThreadSender`], and the main thread executes them when it calls [`drain()`][Self::drain] --
+/// typically once per frame from `process()`.
+///
+/// The channel must be created on the main thread, and it can only be drained on the thread that created it.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::MainThreadChannel;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Worker {
+///     channel: MainThreadChannel,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Worker {
+///     fn ready(&mut self) {
+///         let sender = self.channel.sender();
+///         std::thread::spawn(move || {
+///             let result = 6 * 7; // Expensive computation.
+///             sender.post_value(result);
+///         });
+///     }
+///
+///     fn process(&mut self, _delta: f64) {
+///         for value in self.channel.drain() {
+///             godot_print!("Received from worker: {value}");
+///         }
+///     }
+/// }
+/// ```
+pub struct MainThreadChannel {
+    sender: mpsc::Sender<Message>,
+    receiver: mpsc::Receiver<Message>,
+    thread_id: ThreadId,
+}
+
+impl MainThreadChannel {
+    /// Creates a new channel, bound to the current thread.
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        Self {
+            sender,
+            receiver,
+            thread_id: std::thread::current().id(),
+        }
+    }
+
+    /// Returns a sender, which can be moved to other threads to post work.
+    pub fn sender(&self) -> MainThreadSender {
+        MainThreadSender {
+            sender: self.sender.clone(),
+        }
+    }
+
+    /// Runs all queued closures and returns all queued values, in the order they were posted.
+    ///
+    /// Work posted while draining (e.g. by one of the drained closures) is processed in the same call.
+    ///
+    /// # Panics
+    /// If called on a different thread than the one which created the channel.
+    pub fn drain(&self) -> Vec<Variant> {
+        assert_eq!(
+            self.thread_id,
+            std::thread::current().id(),
+            "MainThreadChannel::drain() must be called on the thread that created the channel"
+        );
+
+        let mut values = Vec::new();
+        for message in self.receiver.try_iter() {
+            match message {
+                Message::Call(function) => function(),
+                Message::Value(to_variant) => values.push(to_variant()),
+            }
+        }
+
+        values
+    }
+}
+
+impl Default for MainThreadChannel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Sending half of a [`MainThreadChannel`].
+///
+/// Can be cloned and sent to other threads.
+#[derive(Clone)]
+pub struct MainThreadSender {
+    sender: mpsc::Sender<Message>,
+}
+
+impl MainThreadSender {
+    /// Posts a closure, to be run on the main thread during the next [`MainThreadChannel::drain()`].
+    ///
+    /// Returns `false` if the channel has been dropped, in which case the closure is discarded.
+    pub fn post(&self, function: impl FnOnce() + Send + 'static) -> bool {
+        self.sender.send(Message::Call(Box::new(function))).is_ok()
+    }
+
+    /// Posts a value, to be returned as `Variant` by the next [`MainThreadChannel::drain()`].
+    ///
+    /// Since [`Variant`] itself can't be sent across threads, the value is only converted on the main thread.
+    ///
+    /// Returns `false` if the channel has been dropped, in which case the value is discarded.
+    pub fn post_value<T>(&self, value: T) -> bool
+    where
+        T: ToGodot + Send + 'static,
+    {
+        let to_variant = move || value.to_variant();
+        self.sender
+            .send(Message::Value(Box::new(to_variant)))
+            .is_ok()
+    }
+}
+
+enum Message {
+    Call(Box<dyn FnOnce() + Send>),
+    Value(Box<dyn FnOnce() -> Variant + Send>),
+}
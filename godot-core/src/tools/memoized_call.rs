@@ -0,0 +1,98 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use crate::builtin::{StringName, Variant, VariantArray, VariantKey};
+use crate::classes::{Engine, Object};
+use crate::obj::{Gd, Inherits};
+
+/// Caches the results of dynamic method calls on an object, keyed by method name and arguments.
+///
+/// Reflection-heavy code, such as inspectors or serializers, often calls the same methods with the same arguments over and over, e.g.
+/// `get_class` or getters of constant data. [`call()`][Self::call] only invokes a method the first time for given arguments, and
+/// returns the cached result afterwards. Arguments are compared like [`VariantKey`]s, i.e. `1` and `1.0` are different arguments.
+///
+/// Caching is opt-in and only correct for _pure_ methods, whose result depends on nothing but the arguments. To still pick up changes
+/// of slowly changing state, the cache is cleared automatically whenever a new process frame has started, unless this is disabled with
+/// [`with_frame_invalidation(false)`][Self::with_frame_invalidation]. Call [`invalidate()`][Self::invalidate] to clear it on demand.
+///
+/// The object is kept alive by the wrapper (if reference-counted). Calling methods after a manually managed object has been freed
+/// panics, like any other call on a freed object.
+#[derive(Debug)]
+pub struct MemoizedCall {
+    object: Gd<Object>,
+    cache: HashMap<(StringName, VariantKey), Variant>,
+    invalidate_per_frame: bool,
+    cached_frame: u64,
+}
+
+impl MemoizedCall {
+    /// Creates an empty cache for calls on `object`, which is cleared each frame.
+    pub fn new<T>(object: &Gd<T>) -> Self
+    where
+        T: Inherits<Object>,
+    {
+        Self {
+            object: object.clone().upcast(),
+            cache: HashMap::new(),
+            invalidate_per_frame: true,
+            cached_frame: current_frame(),
+        }
+    }
+
+    /// Enables or disables clearing the cache when a new process frame starts. Enabled by default.
+    pub fn with_frame_invalidation(self, enabled: bool) -> Self {
+        Self {
+            invalidate_per_frame: enabled,
+            ..self
+        }
+    }
+
+    /// Calls `method` with `args`, or returns the cached result of a previous call with equal arguments.
+    pub fn call(&mut self, method: impl Into<StringName>, args: &[Variant]) -> Variant {
+        if self.invalidate_per_frame {
+            let frame = current_frame();
+            if frame != self.cached_frame {
+                self.cache.clear();
+                self.cached_frame = frame;
+            }
+        }
+
+        let method = method.into();
+        let args_key = VariantKey::new(args.iter().cloned().collect::<VariantArray>());
+
+        self.cache
+            .entry((method, args_key))
+            .or_insert_with_key(|(method, _)| self.object.call(method.clone(), args))
+            .clone()
+    }
+
+    /// Discards all cached results, so that the next calls invoke the methods again.
+    pub fn invalidate(&mut self) {
+        self.cache.clear();
+    }
+
+    /// Number of cached results.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns `true` if no results are cached.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// The object on which methods are called.
+    pub fn object(&self) -> &Gd<Object> {
+        &self.object
+    }
+}
+
+fn current_frame() -> u64 {
+    Engine::singleton().get_process_frames()
+}
@@ -10,10 +10,110 @@
 //! Contains functionality that extends existing Godot classes and functions, to make them more versatile
 //! or better integrated with Rust.
 
+mod call_fallback;
+mod connection_tracker;
+mod cooldown;
+#[cfg(since_api = "4.2")]
+mod debounce;
+mod debug_draw;
+mod dictionary_diff;
+mod event_bus;
+mod fixed_timestep;
 mod gfile;
+#[cfg(since_api = "4.2")]
+mod group_stream;
+#[cfg(since_api = "4.2")]
+mod local_callback;
+mod main_thread_channel;
+mod memoized_call;
+#[cfg(since_api = "4.2")]
+mod node_cache;
+#[cfg(since_api = "4.2")]
+mod process_callback;
+mod process_toggles;
+#[cfg(feature = "codegen-full")]
+mod raycast;
+mod reactive;
+#[cfg(since_api = "4.2")]
+mod rust_box;
 mod save_load;
+mod schema;
+#[cfg(since_api = "4.2")]
+mod script_iter;
+mod serialize;
+mod server_resource_guard;
+mod shared_resource;
+#[cfg(since_api = "4.2")]
+mod signal_future;
+mod smooth_follow;
+mod sticky_signal;
+#[cfg(since_api = "4.2")]
+mod task;
 mod translate;
+#[cfg(since_api = "4.2")]
+mod tree_guard;
+#[cfg(since_api = "4.2")]
+mod tree_observer;
+mod tree_transaction;
+mod typed_method;
+#[cfg(feature = "codegen-full")]
+mod undo_action;
+mod update_scheduler;
+mod variant_stream;
 
+pub use call_fallback::*;
+pub use connection_tracker::*;
+pub use cooldown::*;
+#[cfg(since_api = "4.2")]
+pub use debounce::*;
+pub use debug_draw::*;
+pub use dictionary_diff::*;
+pub use event_bus::*;
+pub use fixed_timestep::*;
 pub use gfile::*;
+#[cfg(since_api = "4.2")]
+pub use group_stream::*;
+pub use main_thread_channel::*;
+pub use memoized_call::*;
+#[cfg(since_api = "4.2")]
+pub use node_cache::*;
+#[cfg(since_api = "4.2")]
+pub use process_callback::ProcessCallbackHandle;
+#[cfg(since_api = "4.2")]
+pub(crate) use process_callback::{connect_delayed_callback, connect_process_callback};
+pub use process_toggles::*;
+#[cfg(feature = "codegen-full")]
+pub(crate) use raycast::cast_ray_3d;
+#[cfg(feature = "codegen-full")]
+pub use raycast::RaycastHit;
+pub use reactive::*;
+#[cfg(since_api = "4.2")]
+pub use rust_box::*;
 pub use save_load::*;
+pub use schema::*;
+#[cfg(since_api = "4.2")]
+pub use script_iter::*;
+pub use serialize::*;
+pub use server_resource_guard::*;
+pub use shared_resource::*;
+#[cfg(since_api = "4.2")]
+pub use signal_future::*;
+pub use smooth_follow::*;
+pub use sticky_signal::*;
+#[cfg(since_api = "4.2")]
+pub(crate) use task::spawn_node_task;
+#[cfg(since_api = "4.2")]
+pub use task::{spawn_task, TaskHandle};
 pub use translate::*;
+#[cfg(since_api = "4.2")]
+pub use tree_guard::*;
+#[cfg(since_api = "4.2")]
+pub use tree_observer::*;
+pub use tree_transaction::*;
+pub use typed_method::*;
+#[cfg(feature = "codegen-full")]
+pub use undo_action::UndoAction;
+#[cfg(feature = "codegen-full")]
+pub(crate) use undo_action::UndoManager;
+pub use update_scheduler::*;
+pub use variant_stream::*;
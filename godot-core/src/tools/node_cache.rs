@@ -0,0 +1,188 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+use crate::builtin::{NodePath, Signal};
+use crate::classes::Node;
+use crate::obj::{Gd, Inherits};
+use crate::tools::local_callback::{
+    connect_local_callback, register_local_callback, remove_local_callback, LocalCallbackId,
+};
+
+/// Caches the node at a path, so that repeated lookups don't traverse the scene tree.
+///
+/// [`Node::get_node_as()`] resolves its path on each call. For nodes which are accessed often, e.g. every frame, store a `NodeCache`
+/// as a field of your class instead. The first access resolves the path relative to `owner`; later accesses return the cached node,
+/// until it is invalidated:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::classes::Label;
+/// use godot::tools::NodeCache;
+///
+/// #[derive(GodotClass)]
+/// #[class(base=Node)]
+/// struct Hud {
+///     health_label: NodeCache<Label>,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Hud {
+///     fn init(base: Base<Node>) -> Self {
+///         Self {
+///             health_label: NodeCache::new("Panel/HealthLabel"),
+///             base,
+///         }
+///     }
+///
+///     fn process(&mut self, _delta: f64) {
+///         let mut label = self.health_label.get(&self.base());
+///         label.set_text("100".into());
+///     }
+/// }
+/// ```
+///
+/// # Invalidation
+/// The cache is invalidated when the cached node emits `tree_exiting` (it is removed from the tree, moved to another parent, or freed
+/// inside the tree) or `renamed`. The next access then resolves the path again, which finds a node that has replaced the previous one.
+/// A cached node that has been freed is never returned.
+///
+/// Since `tree_exiting` is only emitted inside the scene tree, removals from a subtree outside the tree are not observed; call
+/// [`invalidate()`][Self::invalidate] in that case. The cache must always be accessed with the same `owner`.
+pub struct NodeCache<T: Inherits<Node>> {
+    path: NodePath,
+    cached: RefCell<Option<CachedNode<T>>>,
+}
+
+impl<T: Inherits<Node>> NodeCache<T> {
+    /// Creates a cache for the node at `path`. The path is resolved on first access.
+    pub fn new(path: impl Into<NodePath>) -> Self {
+        Self {
+            path: path.into(),
+            cached: RefCell::new(None),
+        }
+    }
+
+    /// ⚠️ Returns the node at the cached path, resolving it relative to `owner` if necessary.
+    ///
+    /// # Panics
+    /// If the node is not found, or if it does not have type `T` or inherited.
+    pub fn get(&self, owner: &Node) -> Gd<T> {
+        self.try_get(owner).unwrap_or_else(|| {
+            panic!(
+                "There is no node of type {ty} at path `{path}`",
+                ty = T::class_name(),
+                path = self.path
+            )
+        })
+    }
+
+    /// Returns the node at the cached path, resolving it relative to `owner` if necessary (fallible).
+    ///
+    /// If the node is not found, or if it does not have type `T` or inherited, `None` is returned and nothing is cached.
+    pub fn try_get(&self, owner: &Node) -> Option<Gd<T>> {
+        if let Some(cached) = self.cached.borrow().as_ref() {
+            if cached.is_valid() {
+                return Some(cached.node.clone());
+            }
+        }
+
+        self.invalidate();
+
+        let node = owner.try_get_node_as::<T>(self.path.clone())?;
+        *self.cached.borrow_mut() = Some(CachedNode::new(node.clone()));
+
+        Some(node)
+    }
+
+    /// Discards the cached node, so that the next access resolves the path again.
+    pub fn invalidate(&self) {
+        // Disconnect outside the borrow, in case Godot emits further signals from within.
+        let cached = self.cached.borrow_mut().take();
+        if let Some(cached) = cached {
+            cached.disconnect();
+        }
+    }
+
+    /// Returns `true` if a node is cached and still valid, i.e. the next access does not traverse the tree.
+    pub fn is_cached(&self) -> bool {
+        self.cached
+            .borrow()
+            .as_ref()
+            .is_some_and(CachedNode::is_valid)
+    }
+
+    /// The path of the cached node, relative to its owner.
+    pub fn path(&self) -> &NodePath {
+        &self.path
+    }
+}
+
+impl<T: Inherits<Node>> Drop for NodeCache<T> {
+    fn drop(&mut self) {
+        self.invalidate();
+    }
+}
+
+impl<T: Inherits<Node>> fmt::Debug for NodeCache<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeCache")
+            .field("path", &self.path)
+            .field("is_cached", &self.is_cached())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+const INVALIDATING_SIGNALS: [&str; 2] = ["tree_exiting", "renamed"];
+
+struct CachedNode<T: Inherits<Node>> {
+    node: Gd<T>,
+
+    /// Cleared by the connected callback.
+    valid: Rc<Cell<bool>>,
+
+    callback_id: LocalCallbackId,
+}
+
+impl<T: Inherits<Node>> CachedNode<T> {
+    fn new(node: Gd<T>) -> Self {
+        let valid = Rc::new(Cell::new(true));
+
+        let flag = Rc::clone(&valid);
+        let callback_id = register_local_callback(move |_args| flag.set(false));
+
+        for signal in INVALIDATING_SIGNALS {
+            connect_local_callback(
+                callback_id,
+                Signal::from_object_signal(&node, signal),
+                "NodeCache::invalidate",
+            );
+        }
+
+        Self {
+            node,
+            valid,
+            callback_id,
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.valid.get() && self.node.is_instance_valid()
+    }
+
+    fn disconnect(self) {
+        // Connections of a freed node are already gone, which removing the callback takes into account.
+        remove_local_callback(self.callback_id);
+    }
+}
@@ -0,0 +1,122 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::builtin::{Signal, Variant};
+use crate::classes::{Engine, Node, SceneTree};
+use crate::obj::{Gd, InstanceId};
+use crate::tools::local_callback::{
+    connect_local_callback, is_local_callback_registered, register_local_callback_with_id,
+    remove_local_callback, LocalCallbackId,
+};
+
+/// Handle to a per-frame callback registered with [`Node::on_process()`], or a delayed one registered with [`Node::after_frames()`].
+///
+/// Can be cloned; all clones refer to the same callback. Dropping the handle does not remove the callback.
+#[derive(Clone, Debug)]
+pub struct ProcessCallbackHandle {
+    id: LocalCallbackId,
+    _not_send: PhantomData<Rc<()>>,
+}
+
+impl ProcessCallbackHandle {
+    /// Removes the callback, so it is no longer run. Has no effect if it has already been removed.
+    ///
+    /// May also be called from within the callback itself.
+    pub fn cancel(&self) {
+        remove_local_callback(self.id);
+    }
+
+    /// Returns `true` if the callback is still registered, i.e. neither cancelled nor removed because its node was freed.
+    ///
+    /// Delayed callbacks are removed after they have run.
+    pub fn is_active(&self) -> bool {
+        is_local_callback_registered(self.id)
+    }
+}
+
+/// Registers `callback` to be run on every process frame, while `node` is inside the tree and can process.
+pub(crate) fn connect_process_callback(
+    node: &Gd<Node>,
+    mut callback: impl FnMut(f64) + 'static,
+) -> ProcessCallbackHandle {
+    connect_callback(node, move |delta| {
+        callback(delta);
+        true
+    })
+}
+
+/// Registers `callback` to be run once, on the `frames`-th process frame in which `node` is inside the tree and can process.
+///
+/// A delay of 0 frames is treated like 1, i.e. the callback runs on the next frame.
+pub(crate) fn connect_delayed_callback(
+    node: &Gd<Node>,
+    frames: u32,
+    callback: impl FnOnce() + 'static,
+) -> ProcessCallbackHandle {
+    let mut remaining = frames.max(1);
+    let mut callback = Some(callback);
+
+    connect_callback(node, move |_delta| {
+        remaining -= 1;
+        if remaining > 0 {
+            return true;
+        }
+
+        if let Some(callback) = callback.take() {
+            callback();
+        }
+        false
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Registers `callback` to be run on every process frame; it returns `false` once it should be removed.
+fn connect_callback(
+    node: &Gd<Node>,
+    mut callback: impl FnMut(f64) -> bool + 'static,
+) -> ProcessCallbackHandle {
+    let tree = Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        .expect("process callbacks require the main loop to be a SceneTree");
+
+    let node_id = node.instance_id();
+    let id = register_local_callback_with_id(|id| {
+        // Typed explicitly: the signature is not inferred for closures returned from closures.
+        move |_args: &[&Variant]| run_callback(id, node_id, &mut callback)
+    });
+
+    let signal = Signal::from_object_signal(&tree, "process_frame");
+    connect_local_callback(id, signal, "Node::on_process");
+
+    ProcessCallbackHandle {
+        id,
+        _not_send: PhantomData,
+    }
+}
+
+fn run_callback(id: LocalCallbackId, node_id: InstanceId, callback: &mut dyn FnMut(f64) -> bool) {
+    let Ok(node) = Gd::<Node>::try_from_instance_id(node_id) else {
+        remove_local_callback(id);
+        return;
+    };
+
+    // Like `process()`, skip frames in which the node is outside the tree or paused.
+    if !node.is_inside_tree() || !node.can_process() {
+        return;
+    }
+
+    let keep = callback(node.get_process_delta_time());
+    if !keep {
+        remove_local_callback(id);
+    }
+}
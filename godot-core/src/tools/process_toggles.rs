@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// Which per-frame and input callbacks of a node are enabled.
+///
+/// Used with [`Node::set_processing()`][crate::classes::Node::set_processing] and [`Node::processing()`][crate::classes::Node::processing]
+/// to configure all toggles at once, instead of calling `set_process()`, `set_physics_process()`, `set_process_input()` etc. one by one.
+/// Each field corresponds to one of these setters.
+///
+/// These toggles are independent of pausing via [`ProcessMode`][crate::classes::node::ProcessMode]: a callback only runs if it is enabled
+/// here _and_ the node can process.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ProcessToggles {
+    /// `process()`, see `Node::set_process()`.
+    pub process: bool,
+
+    /// `physics_process()`, see `Node::set_physics_process()`.
+    pub physics_process: bool,
+
+    /// `input()`, see `Node::set_process_input()`.
+    pub input: bool,
+
+    /// `shortcut_input()`, see `Node::set_process_shortcut_input()`.
+    pub shortcut_input: bool,
+
+    /// `unhandled_input()`, see `Node::set_process_unhandled_input()`.
+    pub unhandled_input: bool,
+
+    /// `unhandled_key_input()`, see `Node::set_process_unhandled_key_input()`.
+    pub unhandled_key_input: bool,
+}
+
+impl ProcessToggles {
+    /// All callbacks disabled.
+    pub const NONE: Self = Self::splat(false);
+
+    /// All callbacks enabled.
+    pub const ALL: Self = Self::splat(true);
+
+    /// Sets all toggles to `enabled`.
+    pub const fn splat(enabled: bool) -> Self {
+        Self {
+            process: enabled,
+            physics_process: enabled,
+            input: enabled,
+            shortcut_input: enabled,
+            unhandled_input: enabled,
+            unhandled_key_input: enabled,
+        }
+    }
+}
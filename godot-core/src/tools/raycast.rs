@@ -0,0 +1,76 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Array, Dictionary, Rid, Vector3};
+use crate::classes::{Object, PhysicsDirectSpaceState3D, PhysicsRayQueryParameters3D};
+use crate::meta::FromGodot;
+use crate::obj::{Gd, InstanceId};
+
+/// Result of a 3D physics ray cast that hit something.
+///
+/// Typed counterpart to the dictionary returned by [`PhysicsDirectSpaceState3D::intersect_ray()`]. Returned by
+/// [`Node3D::raycast_forward()`][crate::classes::Node3D::raycast_forward].
+#[derive(Clone, Debug)]
+pub struct RaycastHit {
+    /// Point of intersection, in global coordinates.
+    pub position: Vector3,
+
+    /// Surface normal of the collider at the point of intersection.
+    ///
+    /// Zero if the ray started inside the shape and [`hit_from_inside`][PhysicsRayQueryParameters3D::set_hit_from_inside] was enabled.
+    pub normal: Vector3,
+
+    /// Object that was hit, or `None` if it has been freed since the ray cast.
+    pub collider: Option<Gd<Object>>,
+
+    /// Instance ID of the object that was hit.
+    pub collider_id: Option<InstanceId>,
+
+    /// Physics server resource ID of the object that was hit.
+    pub rid: Rid,
+
+    /// Index of the collider's shape that was hit.
+    pub shape: i32,
+}
+
+impl RaycastHit {
+    /// Converts the result dictionary of `intersect_ray()`, which is empty if nothing was hit.
+    fn from_dictionary(result: &Dictionary) -> Option<Self> {
+        if result.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            position: entry(result, "position")?,
+            normal: entry(result, "normal").unwrap_or(Vector3::ZERO),
+            collider: entry(result, "collider"),
+            collider_id: entry::<i64>(result, "collider_id").and_then(InstanceId::try_from_i64),
+            rid: entry(result, "rid").unwrap_or(Rid::Invalid),
+            shape: entry(result, "shape").unwrap_or(0),
+        })
+    }
+}
+
+/// Casts a ray from `from` to `to` in `space_state`, against colliders in `collision_mask` that are not in `exclude`.
+pub(crate) fn cast_ray_3d(
+    mut space_state: Gd<PhysicsDirectSpaceState3D>,
+    from: Vector3,
+    to: Vector3,
+    collision_mask: u32,
+    exclude: Array<Rid>,
+) -> Option<RaycastHit> {
+    let mut query = PhysicsRayQueryParameters3D::create(from, to)?;
+    query.set_collision_mask(collision_mask);
+    query.set_exclude(&exclude);
+
+    let result = space_state.intersect_ray(&query);
+    RaycastHit::from_dictionary(&result)
+}
+
+fn entry<T: FromGodot>(dict: &Dictionary, key: &str) -> Option<T> {
+    dict.get(key).and_then(|value| value.try_to::<T>().ok())
+}
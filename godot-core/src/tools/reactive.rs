@@ -0,0 +1,139 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::StringName;
+use crate::classes::Object;
+use crate::meta::ToGodot;
+use crate::obj::{Gd, Inherits};
+
+/// Value which notifies observers whenever it changes.
+///
+/// Changing the value through [`set()`][Self::set] invokes all callbacks registered with [`on_changed()`][Self::on_changed], but
+/// only if the new value differs from the old one (according to `PartialEq`). Assigning the same value again is a no-op. This replaces
+/// manual dirty flags, e.g. for UI code that should only refresh when the displayed state actually changes.
+///
+/// To inform GDScript or other objects, use [`emit_on_change()`][Self::emit_on_change], which emits a signal such as `changed` with
+/// the new value as argument.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::Reactive;
+///
+/// #[derive(GodotClass)]
+/// #[class(base=Node)]
+/// struct Health {
+///     current: Reactive<i64>,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Health {
+///     fn init(base: Base<Node>) -> Self {
+///         Self { current: Reactive::new(100), base }
+///     }
+///
+///     fn ready(&mut self) {
+///         let this = self.to_gd();
+///         self.current.emit_on_change(&this, "changed");
+///     }
+/// }
+///
+/// #[godot_api]
+/// impl Health {
+///     #[signal]
+///     fn changed(current: i64);
+///
+///     #[func]
+///     fn take_damage(&mut self, amount: i64) {
+///         let current = (*self.current.get() - amount).max(0);
+///         self.current.set(current);
+///     }
+/// }
+/// ```
+pub struct Reactive<T> {
+    value: T,
+    callbacks: Vec<Box<dyn FnMut(&T)>>,
+}
+
+impl<T: PartialEq> Reactive<T> {
+    /// Creates a reactive value holding `value`, without any callbacks.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            callbacks: Vec::new(),
+        }
+    }
+
+    /// Returns the current value.
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Stores `value` and invokes the change callbacks, unless it is equal to the current value.
+    ///
+    /// Callbacks are invoked in the order in which they were registered, after the new value has been stored. Returns whether the value
+    /// changed.
+    pub fn set(&mut self, value: T) -> bool {
+        if self.value == value {
+            return false;
+        }
+
+        self.value = value;
+        for callback in self.callbacks.iter_mut() {
+            callback(&self.value);
+        }
+
+        true
+    }
+
+    /// Registers `callback` to be invoked with the new value each time the value changes.
+    pub fn on_changed(&mut self, callback: impl FnMut(&T) + 'static) {
+        self.callbacks.push(Box::new(callback));
+    }
+
+    /// Emits `signal` on `object` with the new value as its only argument, each time the value changes.
+    ///
+    /// The signal must be declared with one parameter, e.g. `#[signal] fn changed(value: T);`. Only the instance ID of `object` is
+    /// stored; once it is freed, no more signals are emitted.
+    pub fn emit_on_change<O>(&mut self, object: &Gd<O>, signal: impl Into<StringName>)
+    where
+        T: ToGodot,
+        O: Inherits<Object>,
+    {
+        let instance_id = object.instance_id();
+        let signal = signal.into();
+
+        self.on_changed(move |value| {
+            if let Ok(mut object) = Gd::<Object>::try_from_instance_id(instance_id) {
+                object.emit_signal(signal.clone(), &[value.to_variant()]);
+            }
+        });
+    }
+
+    /// Removes all change callbacks, including the signal emissions registered with [`emit_on_change()`][Self::emit_on_change].
+    pub fn clear_callbacks(&mut self) {
+        self.callbacks.clear();
+    }
+}
+
+impl<T: PartialEq + Default> Default for Reactive<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Reactive<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reactive")
+            .field("value", &self.value)
+            .field("callbacks", &self.callbacks.len())
+            .finish()
+    }
+}
@@ -0,0 +1,216 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use godot_ffi as sys;
+use sys::Global;
+
+use crate::builtin::{Callable, RustCallable, Variant};
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, GodotConvert, ToGodot};
+
+/// Values of all live boxes, by box ID.
+static VALUES: Global<HashMap<u64, BoxEntry>> = Global::default();
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+const DISPLAY_PREFIX: &str = "RustBox#";
+
+/// Opaque handle to a Rust value, which can be stored in a [`Variant`].
+///
+/// Godot containers, object metadata and signals can only hold Godot types. `RustBox` wraps any `T: Send + Sync + 'static` so that it can
+/// be passed through them, and retrieved on the Rust side with [`downcast()`][Self::downcast]. GDScript can store and pass around the
+/// box, but not access its contents.
+///
+/// Like [`Box<dyn Any>`][Box], `RustBox` is not generic; the stored type is checked at runtime. Clones refer to the same value. The
+/// value is dropped once the last reference to it is gone, both in Rust and in Godot.
+///
+/// In Godot, a box is represented as a [`Callable`] which cannot be called.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::RustBox;
+///
+/// #[derive(Clone, PartialEq, Debug)]
+/// struct Inventory {
+///     items: Vec<String>,
+/// }
+///
+/// let inventory = Inventory { items: vec!["sword".to_string()] };
+/// let variant = RustBox::new(inventory.clone()).to_variant();
+///
+/// let boxed = variant.to::<RustBox>();
+/// assert_eq!(boxed.downcast::<Inventory>(), Some(inventory));
+/// assert_eq!(boxed.downcast::<String>(), None);
+/// ```
+#[derive(Clone)]
+pub struct RustBox {
+    id: u64,
+    callable: Callable,
+}
+
+impl RustBox {
+    /// Moves `value` into a new box.
+    pub fn new<T: Any + Send + Sync>(value: T) -> Self {
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let callable = Callable::from_custom(BoxHandle {
+            id,
+            owns_value: true,
+        });
+
+        let entry = BoxEntry {
+            value: Arc::new(value),
+            hash: callable.hash(),
+        };
+        VALUES.lock().insert(id, entry);
+
+        Self { id, callable }
+    }
+
+    /// Returns a copy of the stored value, if it is of type `T`.
+    pub fn downcast<T: Any + Clone>(&self) -> Option<T> {
+        // Cloned after releasing the lock, as `T::clone()` may itself access boxes.
+        let value = Arc::clone(&VALUES.lock().get(&self.id)?.value);
+
+        value.downcast_ref::<T>().cloned()
+    }
+
+    /// Returns `true` if the stored value is of type `T`.
+    pub fn is<T: Any>(&self) -> bool {
+        VALUES
+            .lock()
+            .get(&self.id)
+            .is_some_and(|entry| (*entry.value).type_id() == TypeId::of::<T>())
+    }
+}
+
+impl PartialEq for RustBox {
+    /// Boxes are equal if they refer to the same value, i.e. one is a clone of the other.
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for RustBox {}
+
+impl fmt::Debug for RustBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RustBox").field("id", &self.id).finish()
+    }
+}
+
+impl GodotConvert for RustBox {
+    type Via = Callable;
+}
+
+impl ToGodot for RustBox {
+    type ToVia<'v> = Callable;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        self.callable.clone()
+    }
+}
+
+impl FromGodot for RustBox {
+    fn try_from_godot(callable: Self::Via) -> Result<Self, ConvertError> {
+        let id = if callable.is_custom() {
+            find_box_id(&callable)
+        } else {
+            None
+        };
+
+        match id {
+            Some(id) => Ok(Self { id, callable }),
+            None => Err(ConvertError::new(format!(
+                "callable {callable} does not hold a RustBox"
+            ))),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct BoxEntry {
+    value: Arc<dyn Any + Send + Sync>,
+
+    /// Hash of the box's callable, to narrow down candidates in `find_box_id()`.
+    hash: u32,
+}
+
+/// Returns the ID of the box that `callable` belongs to, or `None` if it is not a box.
+///
+/// Godot compares custom callables with the `PartialEq` impl of their Rust type, and only if both are of the same type. So a callable
+/// is a box exactly if it equals a `BoxHandle` with the same ID. The callable keeps its value alive, so the ID is still registered.
+fn find_box_id(callable: &Callable) -> Option<u64> {
+    let hash = callable.hash();
+    let candidates: Vec<u64> = VALUES
+        .lock()
+        .iter()
+        .filter(|(_, entry)| entry.hash == hash)
+        .map(|(&id, _)| id)
+        .collect();
+
+    candidates.into_iter().find(|&id| {
+        let probe = Callable::from_custom(BoxHandle {
+            id,
+            owns_value: false,
+        });
+
+        probe == *callable
+    })
+}
+
+/// Owns the boxed value on the Godot side. Freed by Godot once the last `Callable` referring to it is gone.
+///
+/// Handles created by `find_box_id()` only serve for comparison, and do not own the value.
+struct BoxHandle {
+    id: u64,
+    owns_value: bool,
+}
+
+impl PartialEq for BoxHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Hash for BoxHandle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl RustCallable for BoxHandle {
+    fn invoke(&mut self, _args: &[&Variant]) -> Result<Variant, ()> {
+        Err(())
+    }
+}
+
+impl fmt::Display for BoxHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{DISPLAY_PREFIX}{}", self.id)
+    }
+}
+
+impl Drop for BoxHandle {
+    fn drop(&mut self) {
+        if !self.owns_value {
+            return;
+        }
+
+        // Drop the value after releasing the lock, as its destructor may itself drop boxes.
+        let entry = VALUES.lock().remove(&self.id);
+        drop(entry);
+    }
+}
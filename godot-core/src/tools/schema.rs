@@ -0,0 +1,151 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use crate::builtin::{Dictionary, VariantType};
+use crate::meta::error::SchemaError;
+
+/// Declares the fields a dictionary is expected to have, to validate data such as loaded configuration files.
+///
+/// Each field has a name and an expected [`VariantType`], and is either required or optional. [`validate()`][Self::validate] checks a
+/// dictionary against all declared fields and reports _every_ violation, not just the first, so that tools can show complete error
+/// messages for hand-written data.
+///
+/// Dictionary keys may be `String` or `StringName`. A value must have exactly the declared type; for example, an `int` is not
+/// accepted for a `FLOAT` field. Declare a field with `VariantType::NIL` to accept values of any type.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::Schema;
+///
+/// let schema = Schema::new()
+///     .required("title", VariantType::STRING)
+///     .required("resolution", VariantType::VECTOR2I)
+///     .optional("volume", VariantType::FLOAT)
+///     .deny_unknown_fields();
+///
+/// let config = dict! { "title": "My Game", "resolution": 1080 };
+/// if let Err(errors) = schema.validate(&config) {
+///     for error in errors {
+///         godot_error!("Invalid config: {error}");
+///     }
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct Schema {
+    fields: Vec<SchemaField>,
+    deny_unknown: bool,
+}
+
+impl Schema {
+    /// Creates a schema without fields, which accepts all dictionaries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares a field `name` of type `variant_type`, which must be present.
+    ///
+    /// Declaring a field again replaces the previous declaration.
+    pub fn required(self, name: impl Into<String>, variant_type: VariantType) -> Self {
+        self.with_field(name.into(), variant_type, true)
+    }
+
+    /// Declares a field `name` of type `variant_type`, which may be absent.
+    ///
+    /// Declaring a field again replaces the previous declaration.
+    pub fn optional(self, name: impl Into<String>, variant_type: VariantType) -> Self {
+        self.with_field(name.into(), variant_type, false)
+    }
+
+    /// Reports fields which are not declared in the schema as errors. By default, they are ignored.
+    ///
+    /// Entries whose keys are not strings count as unknown fields.
+    pub fn deny_unknown_fields(self) -> Self {
+        Self {
+            deny_unknown: true,
+            ..self
+        }
+    }
+
+    /// Checks `dict` against the schema, returning all violations if there are any.
+    ///
+    /// Errors for declared fields come first, in declaration order; unknown fields follow in the dictionary's order.
+    pub fn validate(&self, dict: &Dictionary) -> Result<(), Vec<SchemaError>> {
+        let mut values = HashMap::new();
+        let mut unknown = Vec::new();
+        for (key, value) in dict.iter_shared() {
+            let key_type = key.get_type();
+            let is_string = key_type == VariantType::STRING || key_type == VariantType::STRING_NAME;
+            let name = key.stringify().to_string();
+
+            if is_string && self.field(&name).is_some() {
+                values.insert(name, value);
+            } else if self.deny_unknown {
+                unknown.push(SchemaError::unknown(name));
+            }
+        }
+
+        let mut errors = Vec::new();
+        for field in &self.fields {
+            let Some(value) = values.get(&field.name) else {
+                if field.required {
+                    errors.push(SchemaError::missing(&field.name));
+                }
+                continue;
+            };
+
+            let actual = value.get_type();
+            if field.variant_type != VariantType::NIL && actual != field.variant_type {
+                errors.push(SchemaError::wrong_type(
+                    &field.name,
+                    field.variant_type,
+                    actual,
+                ));
+            }
+        }
+        errors.extend(unknown);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn with_field(mut self, name: String, variant_type: VariantType, required: bool) -> Self {
+        let field = SchemaField {
+            name,
+            variant_type,
+            required,
+        };
+
+        match self
+            .fields
+            .iter_mut()
+            .find(|existing| existing.name == field.name)
+        {
+            Some(existing) => *existing = field,
+            None => self.fields.push(field),
+        }
+        self
+    }
+
+    fn field(&self, name: &str) -> Option<&SchemaField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct SchemaField {
+    name: String,
+    variant_type: VariantType,
+    required: bool,
+}
@@ -0,0 +1,152 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::thread::ThreadId;
+
+use crate::builtin::{Callable, Variant, VariantArray};
+use crate::meta::ToGodot;
+use crate::varray;
+
+/// Implements GDScript's iterator protocol on top of Rust iterators.
+///
+/// GDScript can iterate over any object (`for item in object:`) that provides the methods `_iter_init`, `_iter_next` and `_iter_get`.
+/// Usually, these are generated by an `#[iter]` method inside `#[godot_api]`, which returns the Rust iterator:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Inventory {
+///     items: Vec<GString>,
+/// }
+///
+/// #[godot_api]
+/// impl Inventory {
+///     // GDScript: `for item in inventory: print(item)`
+///     #[iter]
+///     fn items(&self) -> impl Iterator<Item = GString> + 'static {
+///         self.items.clone().into_iter()
+///     }
+/// }
+/// ```
+///
+/// The functions of this type can also be called from hand-written `#[func]` methods with the names above.
+///
+/// Each loop gets its own Rust iterator, so nested loops over the same object work. As the iterator lives on after the method that
+/// created it returns, it must not borrow from the object (`'static`). It is stored in the loop's state, and dropped once the loop
+/// finishes or is exited early (e.g. with `break`). Loops must run on the thread which started them.
+pub struct ScriptIterator {
+    _private: (),
+}
+
+impl ScriptIterator {
+    /// Implementation of `_iter_init(state: Array) -> bool`.
+    ///
+    /// Starts iterating over `iter`, storing the iteration state in `state`. Returns `false` if `iter` has no elements, which ends the
+    /// loop before its first iteration.
+    pub fn init<I>(mut state: VariantArray, iter: I) -> bool
+    where
+        I: IntoIterator,
+        I::IntoIter: 'static,
+        I::Item: ToGodot,
+    {
+        let mut cursor = Cursor {
+            iter: Box::new(iter.into_iter().map(|item| item.to_variant())),
+            current: Variant::nil(),
+            thread_id: std::thread::current().id(),
+        };
+
+        if !cursor.advance() {
+            return false;
+        }
+
+        let callable = Callable::from_fn("ScriptIterator::cursor", move |args| {
+            if cursor.thread_id != std::thread::current().id() {
+                crate::godot_error!(
+                    "GDScript loop over Rust iterator must stay on the thread which started it"
+                );
+                return Err(());
+            }
+
+            // `true` advances the iterator, `false` returns the current element.
+            match args.first().map(|arg| arg.try_to::<bool>()) {
+                Some(Ok(true)) => Ok(cursor.advance().to_variant()),
+                Some(Ok(false)) => Ok(cursor.current.clone()),
+                _ => Err(()),
+            }
+        });
+
+        set_state(&mut state, callable.to_variant());
+        true
+    }
+
+    /// Implementation of `_iter_next(state: Array) -> bool`.
+    ///
+    /// Advances the iterator stored in `state`. Returns `false` once it is exhausted, which ends the loop and releases the iterator.
+    pub fn next(mut state: VariantArray) -> bool {
+        let Some(cursor) = state
+            .get(0)
+            .and_then(|cursor| cursor.try_to::<Callable>().ok())
+        else {
+            return false;
+        };
+
+        let has_next = cursor.callv(&varray![true]).booleanize();
+        if !has_next {
+            set_state(&mut state, Variant::nil());
+        }
+
+        has_next
+    }
+
+    /// Implementation of `_iter_get(state: Variant) -> Variant`.
+    ///
+    /// Returns the current element of the iterator stored in `state`, or nil if there is none.
+    pub fn get(state: &Variant) -> Variant {
+        match state.try_to::<Callable>() {
+            Ok(cursor) => cursor.callv(&varray![false]),
+            Err(_) => Variant::nil(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct Cursor {
+    iter: Box<dyn Iterator<Item = Variant>>,
+    current: Variant,
+    thread_id: ThreadId,
+}
+
+// SAFETY: The iterator and its elements may not be thread-safe. They are only accessed on the creating thread, which the callable checks
+// before each access. They are dropped together with the loop state, which is owned by the loop running on that thread.
+unsafe impl Send for Cursor {}
+unsafe impl Sync for Cursor {}
+
+impl Cursor {
+    fn advance(&mut self) -> bool {
+        match self.iter.next() {
+            Some(item) => {
+                self.current = item;
+                true
+            }
+            None => {
+                self.current = Variant::nil();
+                false
+            }
+        }
+    }
+}
+
+fn set_state(state: &mut VariantArray, value: Variant) {
+    if state.is_empty() {
+        state.push(&value);
+    } else {
+        state.set(0, &value);
+    }
+}
@@ -0,0 +1,232 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Dictionary, PackedByteArray, Variant, VariantArray, VariantType};
+use crate::classes::Object;
+use crate::global::{bytes_to_var, var_to_bytes};
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, ToGodot};
+use crate::obj::{Gd, GodotClass, InstanceId};
+
+/// Dictionary key under which object references are stored.
+const INSTANCE_ID_KEY: &str = "$instance_id";
+
+/// Dictionary key under which dictionaries are stored, if they could otherwise be mistaken for an object reference.
+const ESCAPED_DICTIONARY_KEY: &str = "$dictionary";
+
+/// Serializes the `#[var]` and `#[export]` fields of a class, independently of Godot's scene format.
+///
+/// This trait is usually derived with `#[derive(GodotSerialize)]`, alongside `#[derive(GodotClass)]`. Each field annotated with `#[var]`
+/// or `#[export]` is stored in a [`Dictionary`] under its property name, i.e. the name given with `#[var(rename = ...)]` or else the field
+/// name. Field types must implement [`ToGodot`] and [`FromGodot`]. The dictionary can be encoded to bytes using
+/// [`serialize_to_bytes()`][Self::serialize_to_bytes], which uses Godot's binary `var_to_bytes()` format.
+///
+/// # Object references
+/// Fields holding objects (`Gd<T>`, `Option<Gd<T>>`) are not serialized by value, but as a reference to their instance ID, in the form
+/// of a dictionary `{ "$instance_id": id }`. On deserialization, a _resolver_ maps each ID back to an object. This can be
+/// [`resolve_live_instance`], if the referenced objects are still alive, or a custom function which looks up objects recreated by a save
+/// system. If the resolver returns `None`, `Option<Gd<T>>` fields become `None`, and `Gd<T>` fields cause an error.
+///
+/// Objects inside untyped containers ([`VariantArray`], [`Dictionary`] keys and values, also nested) are stored as references as well;
+/// unresolved ones become nil. Typed arrays of objects, such as `Array<Gd<Node>>`, cannot hold references in place of their elements and
+/// are not supported; use `VariantArray` for such fields.
+///
+/// Dictionary fields with a single `"$instance_id"` or `"$dictionary"` key are escaped as `{ "$dictionary": value }`, so they are
+/// restored as-is and never confused with references.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::{resolve_live_instance, GodotSerialize};
+///
+/// #[derive(GodotClass, GodotSerialize)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[var]
+///     health: i32,
+///     #[export]
+///     name: GString,
+///     base: Base<Node>,
+/// }
+///
+/// fn save_and_load(player: &Gd<Player>, other: &mut Gd<Player>) {
+///     let bytes = player.bind().serialize_to_bytes();
+///
+///     other
+///         .bind_mut()
+///         .deserialize_from_bytes(&bytes, &mut resolve_live_instance)
+///         .expect("valid save data");
+/// }
+/// ```
+pub trait GodotSerialize: GodotClass {
+    /// Returns all serialized fields, keyed by field name.
+    fn serialize_fields(&self) -> Dictionary;
+
+    /// Assigns fields from a dictionary previously created by [`serialize_fields()`][Self::serialize_fields].
+    ///
+    /// Fields that are absent from `fields` keep their current value. Object references are looked up with `resolver`.
+    fn deserialize_fields(
+        &mut self,
+        fields: &Dictionary,
+        resolver: &mut dyn FnMut(InstanceId) -> Option<Gd<Object>>,
+    ) -> Result<(), ConvertError>;
+
+    /// Serializes all fields to Godot's binary format.
+    fn serialize_to_bytes(&self) -> PackedByteArray {
+        var_to_bytes(&self.serialize_fields().to_variant())
+    }
+
+    /// Deserializes all fields from bytes previously created by [`serialize_to_bytes()`][Self::serialize_to_bytes].
+    fn deserialize_from_bytes(
+        &mut self,
+        bytes: &PackedByteArray,
+        resolver: &mut dyn FnMut(InstanceId) -> Option<Gd<Object>>,
+    ) -> Result<(), ConvertError> {
+        let fields: Dictionary = bytes_to_var(bytes).try_to()?;
+        self.deserialize_fields(&fields, resolver)
+    }
+}
+
+/// How [`Object::to_dictionary()`] handles properties whose values are objects.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ObjectProperties {
+    /// Object-typed properties are not included in the dictionary.
+    Skip,
+
+    /// Objects are stored as a reference to their instance ID, in the same format as with [`GodotSerialize`].
+    ///
+    /// When restoring, references are mapped back to objects by a resolver, such as [`resolve_live_instance`].
+    ByReference,
+}
+
+/// Resolver for [`GodotSerialize`], which looks up objects that are still alive by their instance ID.
+pub fn resolve_live_instance(instance_id: InstanceId) -> Option<Gd<Object>> {
+    Gd::try_from_instance_id(instance_id).ok()
+}
+
+#[doc(hidden)]
+pub fn __serialize_field<T: ToGodot>(value: &T) -> Variant {
+    serialize_variant(value.to_variant())
+}
+
+#[doc(hidden)]
+pub fn __deserialize_field<T: FromGodot>(
+    value: Variant,
+    resolver: &mut dyn FnMut(InstanceId) -> Option<Gd<Object>>,
+) -> Result<T, ConvertError> {
+    deserialize_variant(value, resolver).try_to()
+}
+
+/// Replaces objects with references, also inside untyped arrays and dictionaries.
+fn serialize_variant(variant: Variant) -> Variant {
+    match variant.get_type() {
+        VariantType::OBJECT => match variant.try_to::<Option<Gd<Object>>>() {
+            Ok(Some(object)) => {
+                let mut reference = Dictionary::new();
+                reference.set(INSTANCE_ID_KEY, object.instance_id().to_i64());
+                reference.to_variant()
+            }
+            _ => Variant::nil(),
+        },
+
+        // Typed arrays cannot hold references in place of their elements, so they are stored as-is.
+        VariantType::ARRAY => match variant.try_to::<VariantArray>() {
+            Ok(array) => array
+                .iter_shared()
+                .map(serialize_variant)
+                .collect::<VariantArray>()
+                .to_variant(),
+            Err(_) => variant,
+        },
+
+        VariantType::DICTIONARY => {
+            let dictionary = variant.to::<Dictionary>();
+            let entries = dictionary
+                .iter_shared()
+                .map(|(key, value)| (serialize_variant(key), serialize_variant(value)))
+                .collect::<Dictionary>();
+
+            if is_reserved_dictionary(&dictionary) {
+                let mut escaped = Dictionary::new();
+                escaped.set(ESCAPED_DICTIONARY_KEY, entries);
+                escaped.to_variant()
+            } else {
+                entries.to_variant()
+            }
+        }
+
+        _ => variant,
+    }
+}
+
+/// Reverses [`serialize_variant()`], resolving references with `resolver`.
+fn deserialize_variant(
+    value: Variant,
+    resolver: &mut dyn FnMut(InstanceId) -> Option<Gd<Object>>,
+) -> Variant {
+    match value.get_type() {
+        VariantType::ARRAY => match value.try_to::<VariantArray>() {
+            Ok(array) => {
+                let mut elements = VariantArray::new();
+                for element in array.iter_shared() {
+                    elements.push(&deserialize_variant(element, resolver));
+                }
+                elements.to_variant()
+            }
+            Err(_) => value,
+        },
+
+        VariantType::DICTIONARY => {
+            let dictionary = value.to::<Dictionary>();
+            if dictionary.len() == 1 {
+                if let Some(escaped) = dictionary.get(ESCAPED_DICTIONARY_KEY) {
+                    return match escaped.try_to::<Dictionary>() {
+                        Ok(entries) => deserialize_entries(&entries, resolver),
+                        Err(_) => escaped,
+                    };
+                }
+
+                if let Some(instance_id) = as_instance_reference(&dictionary) {
+                    return match resolver(instance_id) {
+                        Some(object) => object.to_variant(),
+                        None => Variant::nil(),
+                    };
+                }
+            }
+
+            deserialize_entries(&dictionary, resolver)
+        }
+
+        _ => value,
+    }
+}
+
+fn deserialize_entries(
+    dictionary: &Dictionary,
+    resolver: &mut dyn FnMut(InstanceId) -> Option<Gd<Object>>,
+) -> Variant {
+    let mut entries = Dictionary::new();
+    for (key, value) in dictionary.iter_shared() {
+        entries.set(
+            deserialize_variant(key, resolver),
+            deserialize_variant(value, resolver),
+        );
+    }
+    entries.to_variant()
+}
+
+/// Whether `dictionary` has the shape of an encoded reference or escaped dictionary, and must thus be escaped itself.
+fn is_reserved_dictionary(dictionary: &Dictionary) -> bool {
+    dictionary.len() == 1
+        && (dictionary.contains_key(INSTANCE_ID_KEY)
+            || dictionary.contains_key(ESCAPED_DICTIONARY_KEY))
+}
+
+fn as_instance_reference(reference: &Dictionary) -> Option<InstanceId> {
+    let id = reference.get(INSTANCE_ID_KEY)?.try_to::<i64>().ok()?;
+    InstanceId::try_from_i64(id)
+}
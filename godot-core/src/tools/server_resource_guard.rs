@@ -0,0 +1,191 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::Rid;
+use crate::classes::RenderingServer;
+
+/// Server which owns a resource ID, and is responsible for freeing it.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RidServer {
+    /// [`RenderingServer`], e.g. for canvas items, meshes or instances.
+    Rendering,
+
+    /// [`PhysicsServer2D`][crate::classes::PhysicsServer2D], e.g. for bodies, shapes or spaces.
+    #[cfg(feature = "codegen-full")]
+    Physics2D,
+
+    /// [`PhysicsServer3D`][crate::classes::PhysicsServer3D], e.g. for bodies, shapes or spaces.
+    #[cfg(feature = "codegen-full")]
+    Physics3D,
+}
+
+impl RidServer {
+    fn free_rid(self, rid: Rid) {
+        match self {
+            Self::Rendering => RenderingServer::singleton().free_rid(rid),
+            #[cfg(feature = "codegen-full")]
+            Self::Physics2D => crate::classes::PhysicsServer2D::singleton().free_rid(rid),
+            #[cfg(feature = "codegen-full")]
+            Self::Physics3D => crate::classes::PhysicsServer3D::singleton().free_rid(rid),
+        }
+    }
+}
+
+/// Frees server resources ([`Rid`]s) when dropped.
+///
+/// Code which uses servers directly, e.g. to draw canvas items without nodes, must free each allocated `Rid` on the same server, or else
+/// the resource leaks. Store a guard as a field of your class and [track][Self::track] resources as you create them. They are freed
+/// once the guard is dropped, which happens when the object is freed. To release them already when a node leaves the tree, call
+/// [`free_all()`][Self::free_all] in `exit_tree()`:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::classes::RenderingServer;
+/// use godot::tools::{RidServer, ServerResourceGuard};
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D)]
+/// struct Sparkles {
+///     resources: ServerResourceGuard,
+///     base: Base<Node2D>,
+/// }
+///
+/// #[godot_api]
+/// impl INode2D for Sparkles {
+///     fn enter_tree(&mut self) {
+///         let parent = self.base().get_canvas_item();
+///         let mut server = RenderingServer::singleton();
+///
+///         let item = self.resources.track(RidServer::Rendering, server.canvas_item_create());
+///         server.canvas_item_set_parent(item, parent);
+///     }
+///
+///     fn exit_tree(&mut self) {
+///         self.resources.free_all();
+///     }
+/// }
+/// ```
+///
+/// Resources are freed in reverse order of tracking, so that resources created later (which may depend on earlier ones) are freed first.
+#[derive(Default)]
+pub struct ServerResourceGuard {
+    tracked: Vec<(Rid, Freer)>,
+}
+
+impl ServerResourceGuard {
+    /// Creates a guard which tracks no resources.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tracks `rid`, to be freed on `server`. Returns `rid`, so allocations can be wrapped directly.
+    ///
+    /// Invalid RIDs are not tracked.
+    pub fn track(&mut self, server: RidServer, rid: Rid) -> Rid {
+        self.track_freer(rid, Freer::Server(server))
+    }
+
+    /// Tracks `rid`, to be freed by calling `free`. Returns `rid`.
+    ///
+    /// Useful for servers not covered by [`RidServer`], such as `NavigationServer3D`. Invalid RIDs are not tracked.
+    pub fn track_with(&mut self, rid: Rid, free: impl FnOnce(Rid) + 'static) -> Rid {
+        self.track_freer(rid, Freer::Custom(Box::new(free)))
+    }
+
+    /// Stops tracking `rid` without freeing it, e.g. because it was freed manually or ownership moved elsewhere.
+    ///
+    /// Returns `true` if `rid` was tracked.
+    pub fn untrack(&mut self, rid: Rid) -> bool {
+        let Some(index) = self.tracked.iter().position(|(tracked, _)| *tracked == rid) else {
+            return false;
+        };
+
+        self.tracked.remove(index);
+        true
+    }
+
+    /// Frees `rid` now, if it is tracked. Returns `true` if it was tracked.
+    pub fn free(&mut self, rid: Rid) -> bool {
+        let Some(index) = self.tracked.iter().position(|(tracked, _)| *tracked == rid) else {
+            return false;
+        };
+
+        let (rid, freer) = self.tracked.remove(index);
+        freer.free(rid);
+        true
+    }
+
+    /// Frees all tracked resources, in reverse order of tracking. Returns how many were freed.
+    ///
+    /// The guard can track new resources afterwards.
+    pub fn free_all(&mut self) -> usize {
+        let tracked = std::mem::take(&mut self.tracked);
+        let count = tracked.len();
+
+        for (rid, freer) in tracked.into_iter().rev() {
+            freer.free(rid);
+        }
+
+        count
+    }
+
+    /// Returns `true` if `rid` is tracked.
+    pub fn contains(&self, rid: Rid) -> bool {
+        self.tracked.iter().any(|(tracked, _)| *tracked == rid)
+    }
+
+    /// Number of tracked resources.
+    pub fn len(&self) -> usize {
+        self.tracked.len()
+    }
+
+    /// Returns `true` if no resources are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.tracked.is_empty()
+    }
+
+    fn track_freer(&mut self, rid: Rid, freer: Freer) -> Rid {
+        if rid.is_valid() {
+            self.tracked.push((rid, freer));
+        }
+
+        rid
+    }
+}
+
+impl Drop for ServerResourceGuard {
+    fn drop(&mut self) {
+        self.free_all();
+    }
+}
+
+impl fmt::Debug for ServerResourceGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.tracked.iter().map(|(rid, _)| rid))
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+enum Freer {
+    Server(RidServer),
+    Custom(Box<dyn FnOnce(Rid)>),
+}
+
+impl Freer {
+    fn free(self, rid: Rid) {
+        match self {
+            Self::Server(server) => server.free_rid(rid),
+            Self::Custom(free) => free(rid),
+        }
+    }
+}
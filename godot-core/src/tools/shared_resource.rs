@@ -0,0 +1,122 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::Callable;
+use crate::classes::{Node, Resource};
+use crate::meta::error::ConnectError;
+use crate::obj::{Gd, Inherits, InstanceId};
+
+/// Resource shared by several nodes, which keeps track of its users and notifies subscribers when it changes.
+///
+/// Godot cannot tell which nodes reference a resource, so users are registered explicitly with [`add_user()`][Self::add_user]. Only
+/// their instance IDs are stored; freed nodes are no longer reported by [`users()`][Self::users].
+///
+/// Subscribers are callables connected to the resource's `changed` signal with [`subscribe()`][Self::subscribe]. Godot emits it when
+/// built-in properties are modified (depending on the resource class), or when
+/// [`emit_changed()`][crate::classes::Resource::emit_changed] is called. The signal has no arguments; subscribers typically query the
+/// users to update them, e.g. to refresh all nodes sharing a material.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::SharedResource;
+///
+/// fn share(resource: Gd<Resource>, nodes: &[Gd<Node>], on_changed: Callable) -> SharedResource<Resource> {
+///     let mut shared = SharedResource::new(resource);
+///     for node in nodes {
+///         shared.add_user(node);
+///     }
+///
+///     shared.subscribe(on_changed);
+///     shared
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SharedResource<R: Inherits<Resource>> {
+    resource: Gd<R>,
+    users: Vec<InstanceId>,
+}
+
+impl<R: Inherits<Resource>> SharedResource<R> {
+    /// Starts tracking `resource`, without any users.
+    pub fn new(resource: Gd<R>) -> Self {
+        Self {
+            resource,
+            users: Vec::new(),
+        }
+    }
+
+    /// Returns the shared resource.
+    pub fn resource(&self) -> &Gd<R> {
+        &self.resource
+    }
+
+    /// Registers `node` as a user of the resource.
+    ///
+    /// Returns `false` if `node` is already registered.
+    pub fn add_user<T>(&mut self, node: &Gd<T>) -> bool
+    where
+        T: Inherits<Node>,
+    {
+        let id = node.instance_id();
+        if self.users.contains(&id) {
+            return false;
+        }
+
+        self.users.push(id);
+        true
+    }
+
+    /// Unregisters `node` as a user of the resource.
+    ///
+    /// Returns `false` if `node` was not registered.
+    pub fn remove_user<T>(&mut self, node: &Gd<T>) -> bool
+    where
+        T: Inherits<Node>,
+    {
+        let id = node.instance_id();
+        let len = self.users.len();
+        self.users.retain(|&user| user != id);
+
+        self.users.len() != len
+    }
+
+    /// Returns the users of the resource which are still alive, in order of registration.
+    pub fn users(&self) -> Vec<Gd<Node>> {
+        self.users
+            .iter()
+            .filter_map(|&id| Gd::try_from_instance_id(id).ok())
+            .collect()
+    }
+
+    /// Connects `callable` to the `changed` signal of the resource.
+    ///
+    /// Returns `false` if `callable` is already subscribed.
+    pub fn subscribe(&mut self, callable: Callable) -> bool {
+        let mut resource = self.resource.clone().upcast::<Resource>();
+        let source = resource.to_string();
+
+        resource
+            .try_connect(&"changed".into(), &callable, |kind| {
+                ConnectError::for_object(kind, source, "changed".to_string())
+            })
+            .is_ok()
+    }
+
+    /// Disconnects `callable` from the `changed` signal of the resource.
+    ///
+    /// Returns `false` if `callable` was not subscribed.
+    pub fn unsubscribe(&mut self, callable: &Callable) -> bool {
+        let mut resource = self.resource.clone().upcast::<Resource>();
+        if !resource.is_connected("changed".into(), callable.clone()) {
+            return false;
+        }
+
+        resource.disconnect("changed".into(), callable.clone());
+        true
+    }
+}
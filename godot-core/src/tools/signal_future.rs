@@ -0,0 +1,1049 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread::ThreadId;
+
+use crate::builtin::{Callable, Signal, Variant};
+#[cfg(feature = "codegen-full")]
+use crate::builtin::{GString, PackedByteArray, PackedStringArray};
+#[cfg(feature = "codegen-full")]
+use crate::classes::http_request::Result as HttpRequestResult;
+use crate::classes::object::ConnectFlags;
+use crate::classes::{AudioStreamPlayer, Engine, Node, Object, SceneTree, SceneTreeTimer};
+#[cfg(feature = "codegen-full")]
+use crate::global::Error as GodotError;
+#[cfg(feature = "codegen-full")]
+use crate::meta::error::{HttpRequestError, HttpRequestErrorKind};
+use crate::obj::{EngineBitfield, Gd, InstanceId};
+
+/// Future that resolves when a signal is emitted, or `None` once a timeout elapses first.
+///
+/// Created by [`Signal::to_signal_timeout()`]. Awaiting it yields `Some(args)` with the arguments of the signal emission, or `None` if the
+/// timeout won the race.
+///
+/// The timeout is measured by a [`SceneTreeTimer`], so it advances with the scene tree's process frames (and respects pausing).
+/// As soon as either side wins, or the future is dropped, both the signal connection and the timer connection are removed, and the
+/// timer is expired so that the engine releases it on the next frame.
+///
+/// # Executor
+/// This is a plain [`Future`]; it must be polled on the main thread, by an executor that runs alongside the Godot main loop.
+/// Signal handlers are connected as one-shot connections and wake the future when invoked.
+pub struct SignalTimeoutFuture {
+    state: Arc<Mutex<SignalFutureState<Outcome>>>,
+    signal: Signal,
+    signal_callable: Callable,
+    timer: Gd<SceneTreeTimer>,
+    timer_signal: Signal,
+    timer_callable: Callable,
+}
+
+impl SignalTimeoutFuture {
+    pub(crate) fn new(signal: Signal, timeout_seconds: f64) -> Self {
+        let state = Arc::new(Mutex::new(SignalFutureState::new()));
+
+        let timer = main_scene_tree("awaiting a signal with timeout")
+            .create_timer(timeout_seconds)
+            .expect("SceneTree::create_timer() returned null");
+
+        let signal_callable =
+            SignalFutureState::make_callable(&state, "SignalTimeoutFuture::signal", |args| {
+                Some(Outcome::Emitted(
+                    args.iter().map(|&arg| arg.clone()).collect(),
+                ))
+            });
+        let timer_callable =
+            SignalFutureState::make_callable(&state, "SignalTimeoutFuture::timeout", |_args| {
+                Some(Outcome::TimedOut)
+            });
+
+        let timer_signal = Signal::from_object_signal(&timer, "timeout");
+        let one_shot = ConnectFlags::ONE_SHOT.ord() as i64;
+        signal.connect(signal_callable.clone(), one_shot);
+        timer_signal.connect(timer_callable.clone(), one_shot);
+
+        Self {
+            state,
+            signal,
+            signal_callable,
+            timer,
+            timer_signal,
+            timer_callable,
+        }
+    }
+
+    /// The timer measuring the timeout.
+    ///
+    /// Can be used to query the remaining time via [`SceneTreeTimer::get_time_left()`].
+    pub fn timer(&self) -> &Gd<SceneTreeTimer> {
+        &self.timer
+    }
+
+    /// Removes both connections and lets the timer expire.
+    fn cleanup(&mut self) {
+        if self.signal.is_connected(self.signal_callable.clone()) {
+            self.signal.disconnect(self.signal_callable.clone());
+        }
+
+        if self.timer.is_instance_valid() {
+            if self.timer_signal.is_connected(self.timer_callable.clone()) {
+                self.timer_signal.disconnect(self.timer_callable.clone());
+            }
+
+            // The tree only drops its reference once the timer fires; make that happen on the next frame.
+            self.timer.set_time_left(0.0);
+        }
+    }
+}
+
+impl Future for SignalTimeoutFuture {
+    type Output = Option<Vec<Variant>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Some(outcome) = self.state.lock().unwrap().take_outcome(cx.waker()) else {
+            return Poll::Pending;
+        };
+
+        self.cleanup();
+
+        match outcome {
+            Outcome::Emitted(args) => Poll::Ready(Some(args)),
+            Outcome::TimedOut => Poll::Ready(None),
+        }
+    }
+}
+
+impl Drop for SignalTimeoutFuture {
+    fn drop(&mut self) {
+        self.cleanup();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// How an awaited audio playback ended.
+///
+/// Returned by [`AudioPlaybackFuture`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum PlaybackEnd {
+    /// The stream was played until its end, and the player emitted `finished`.
+    Finished,
+
+    /// Playback ended before the stream's end, e.g. due to [`AudioStreamPlayer::stop()`] or because the player was freed.
+    Stopped,
+}
+
+/// Future that resolves once an [`AudioStreamPlayer`] stops playing.
+///
+/// Created by [`AudioStreamPlayer::play_and_await()`].
+///
+/// Besides the `finished` signal, the future checks [`is_playing()`][AudioStreamPlayer::is_playing] on every process frame of the scene
+/// tree. This detects playback that is stopped early, which Godot does not signal. Pausing the stream via `stream_paused` does not count
+/// as stopping.
+///
+/// Looping streams never emit `finished`; for them, the future only resolves once playback is stopped, with [`PlaybackEnd::Stopped`].
+///
+/// # Executor
+/// Like [`SignalTimeoutFuture`], this must be polled on the main thread, by an executor that runs alongside the Godot main loop.
+pub struct AudioPlaybackFuture {
+    state: Arc<Mutex<SignalFutureState<PlaybackEnd>>>,
+    player: Gd<AudioStreamPlayer>,
+    finished_signal: Signal,
+    finished_callable: Callable,
+    frame_signal: Signal,
+    frame_callable: Callable,
+}
+
+impl AudioPlaybackFuture {
+    /// Observes `player`, which is expected to start playing right after this call.
+    pub(crate) fn new(player: Gd<AudioStreamPlayer>) -> Self {
+        let state = Arc::new(Mutex::new(SignalFutureState::new()));
+        let tree = main_scene_tree("awaiting audio playback");
+
+        let finished_callable =
+            SignalFutureState::make_callable(&state, "AudioPlaybackFuture::finished", |_args| {
+                Some(PlaybackEnd::Finished)
+            });
+
+        let player_id = player.instance_id();
+        let is_stop_pending = AtomicBool::new(false);
+        let frame_callable =
+            SignalFutureState::make_callable(&state, "AudioPlaybackFuture::frame", move |_args| {
+                let is_active = Gd::<AudioStreamPlayer>::try_from_instance_id(player_id)
+                    .map_or(false, |player| {
+                        player.is_playing() || player.get_stream_paused()
+                    });
+
+                if is_active {
+                    is_stop_pending.store(false, Ordering::Relaxed);
+                    return None;
+                }
+
+                // `process_frame` is emitted before nodes are processed, and the player only emits `finished` during its own processing.
+                // Wait for one more frame, so that a stream reaching its end is not mistaken for an early stop.
+                if is_stop_pending.swap(true, Ordering::Relaxed) {
+                    Some(PlaybackEnd::Stopped)
+                } else {
+                    None
+                }
+            });
+
+        let finished_signal = Signal::from_object_signal(&player, "finished");
+        let frame_signal = Signal::from_object_signal(&tree, "process_frame");
+        finished_signal.connect(
+            finished_callable.clone(),
+            ConnectFlags::ONE_SHOT.ord() as i64,
+        );
+        frame_signal.connect(frame_callable.clone(), 0);
+
+        Self {
+            state,
+            player,
+            finished_signal,
+            finished_callable,
+            frame_signal,
+            frame_callable,
+        }
+    }
+
+    /// Removes both connections.
+    fn cleanup(&mut self) {
+        if self.player.is_instance_valid()
+            && self
+                .finished_signal
+                .is_connected(self.finished_callable.clone())
+        {
+            self.finished_signal
+                .disconnect(self.finished_callable.clone());
+        }
+
+        if self.frame_signal.is_connected(self.frame_callable.clone()) {
+            self.frame_signal.disconnect(self.frame_callable.clone());
+        }
+    }
+}
+
+impl Future for AudioPlaybackFuture {
+    type Output = PlaybackEnd;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Some(end) = self.state.lock().unwrap().take_outcome(cx.waker()) else {
+            return Poll::Pending;
+        };
+
+        self.cleanup();
+        Poll::Ready(end)
+    }
+}
+
+impl Drop for AudioPlaybackFuture {
+    fn drop(&mut self) {
+        self.cleanup();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Future that resolves once a node is ready, i.e. after its `ready()` callback has run.
+///
+/// Created by [`Node::await_ready()`]. If the node is already ready at creation time, the future resolves on the first poll.
+/// Otherwise, it waits for the node's `ready` signal, which is emitted after `ready()` of the node and all its children. At that point,
+/// all `OnReady` fields of the node are initialized.
+///
+/// # Executor
+/// Like [`SignalTimeoutFuture`], this must be polled on the main thread, by an executor that runs alongside the Godot main loop.
+pub struct NodeReadyFuture {
+    state: Arc<Mutex<SignalFutureState<()>>>,
+    node: Gd<Node>,
+
+    /// `None` if the node was already ready.
+    connection: Option<(Signal, Callable)>,
+}
+
+impl NodeReadyFuture {
+    pub(crate) fn new(node: Gd<Node>) -> Self {
+        let state = Arc::new(Mutex::new(SignalFutureState::new()));
+
+        let connection = if node.is_node_ready() {
+            state.lock().unwrap().outcome = Some(());
+            None
+        } else {
+            let callable = SignalFutureState::make_callable(
+                &state,
+                "NodeReadyFuture::ready",
+                |_args| Some(()),
+            );
+
+            let signal = Signal::from_object_signal(&node, "ready");
+            signal.connect(callable.clone(), ConnectFlags::ONE_SHOT.ord() as i64);
+            Some((signal, callable))
+        };
+
+        Self {
+            state,
+            node,
+            connection,
+        }
+    }
+
+    /// Removes the connection, if still present.
+    fn cleanup(&mut self) {
+        let Some((signal, callable)) = self.connection.take() else {
+            return;
+        };
+
+        if self.node.is_instance_valid() && signal.is_connected(callable.clone()) {
+            signal.disconnect(callable);
+        }
+    }
+}
+
+impl Future for NodeReadyFuture {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self
+            .state
+            .lock()
+            .unwrap()
+            .take_outcome(cx.waker())
+            .is_none()
+        {
+            return Poll::Pending;
+        }
+
+        self.cleanup();
+        Poll::Ready(())
+    }
+}
+
+impl Drop for NodeReadyFuture {
+    fn drop(&mut self) {
+        self.cleanup();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Future that resolves once all nodes of a set are ready, i.e. after each of their `ready()` callbacks has run.
+///
+/// Created by [`Node::await_siblings_ready()`] and [`SceneTree::await_scene_ready()`]. The set of nodes is fixed when the future is
+/// created. Nodes which are already ready count as done; for the others, the future waits for their `ready` signals. Nodes that are
+/// freed before becoming ready are no longer waited for, which is detected on the scene tree's next process frame (or the next poll).
+///
+/// This acts as a barrier in scenes where nodes depend on each other: Godot runs `ready()` of siblings in child order, so a node cannot
+/// rely on later siblings being ready in its own `ready()`. Awaiting this future, e.g. in a task spawned from `ready()`, removes such
+/// ordering assumptions.
+///
+/// # Executor
+/// Like [`SignalTimeoutFuture`], this must be polled on the main thread, by an executor that runs alongside the Godot main loop.
+pub struct ReadyBarrierFuture {
+    state: Arc<Mutex<SignalFutureState<()>>>,
+    pending: Arc<Vec<InstanceId>>,
+
+    /// Callable connected to the signals, each with the ID of the signal's object. Empty if all nodes were ready from the start.
+    connections: Vec<(InstanceId, Signal, Callable)>,
+}
+
+impl ReadyBarrierFuture {
+    pub(crate) fn new(nodes: impl IntoIterator<Item = Gd<Node>>) -> Self {
+        let state = Arc::new(Mutex::new(SignalFutureState::new()));
+
+        let pending: Vec<Gd<Node>> = nodes
+            .into_iter()
+            .filter(|node| !node.is_node_ready())
+            .collect();
+        let pending_ids = Arc::new(pending.iter().map(|node| node.instance_id()).collect());
+
+        if pending.is_empty() {
+            state.lock().unwrap().outcome = Some(());
+
+            return Self {
+                state,
+                pending: pending_ids,
+                connections: Vec::new(),
+            };
+        }
+
+        let tree = main_scene_tree("awaiting nodes to be ready");
+        let ids = Arc::clone(&pending_ids);
+        let callable =
+            SignalFutureState::make_callable(&state, "ReadyBarrierFuture::check", move |_args| {
+                all_ready(&ids).then_some(())
+            });
+
+        let mut connections = Vec::with_capacity(pending.len() + 1);
+        for node in &pending {
+            let signal = Signal::from_object_signal(node, "ready");
+            signal.connect(callable.clone(), ConnectFlags::ONE_SHOT.ord() as i64);
+            connections.push((node.instance_id(), signal, callable.clone()));
+        }
+
+        let frame_signal = Signal::from_object_signal(&tree, "process_frame");
+        frame_signal.connect(callable.clone(), 0);
+        connections.push((tree.instance_id(), frame_signal, callable));
+
+        Self {
+            state,
+            pending: pending_ids,
+            connections,
+        }
+    }
+
+    /// Removes all connections still present.
+    fn cleanup(&mut self) {
+        for (object_id, signal, callable) in self.connections.drain(..) {
+            if object_id.lookup_validity() && signal.is_connected(callable.clone()) {
+                signal.disconnect(callable);
+            }
+        }
+    }
+}
+
+impl Future for ReadyBarrierFuture {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let outcome = {
+            let mut state = self.state.lock().unwrap();
+
+            // Don't wait for the next frame if the remaining nodes have been freed.
+            if state.outcome.is_none() && all_ready(&self.pending) {
+                state.outcome = Some(());
+            }
+
+            state.take_outcome(cx.waker())
+        };
+
+        if outcome.is_none() {
+            return Poll::Pending;
+        }
+
+        self.cleanup();
+        Poll::Ready(())
+    }
+}
+
+impl Drop for ReadyBarrierFuture {
+    fn drop(&mut self) {
+        self.cleanup();
+    }
+}
+
+/// Returns `true` if every node is either ready or freed.
+fn all_ready(node_ids: &[InstanceId]) -> bool {
+    node_ids
+        .iter()
+        .all(|&id| match Gd::<Node>::try_from_instance_id(id) {
+            Ok(node) => node.is_node_ready(),
+            Err(_) => true,
+        })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Future that resolves on the next emission of a signal, looked up by name.
+///
+/// Created by [`Object::await_signal()`][crate::classes::Object::await_signal]. Awaiting it yields `Some(args)` with the arguments of the
+/// emission, or `None` if the object is freed before the signal is emitted.
+///
+/// Freeing is detected on the scene tree's next process frame (or the next poll, whichever comes first). Once the future resolves or is
+/// dropped, all its connections are removed.
+///
+/// # Executor
+/// Like [`SignalTimeoutFuture`], this must be polled on the main thread, by an executor that runs alongside the Godot main loop.
+pub struct SignalFuture {
+    state: Arc<Mutex<SignalFutureState<Option<Vec<Variant>>>>>,
+    object_id: InstanceId,
+    signal: Signal,
+    signal_callable: Callable,
+    frame_signal: Signal,
+    frame_callable: Callable,
+}
+
+impl SignalFuture {
+    pub(crate) fn new(object: Gd<Object>, signal_name: &str) -> Self {
+        assert!(
+            object.has_signal(signal_name.into()),
+            "Object::await_signal(): class {} has no signal named '{signal_name}'",
+            object.get_class()
+        );
+
+        let state = Arc::new(Mutex::new(SignalFutureState::new()));
+        let tree = main_scene_tree("awaiting a signal");
+        let object_id = object.instance_id();
+
+        let signal_callable =
+            SignalFutureState::make_callable(&state, "SignalFuture::signal", |args| {
+                Some(Some(args.iter().map(|&arg| arg.clone()).collect()))
+            });
+        let frame_callable =
+            SignalFutureState::make_callable(&state, "SignalFuture::frame", move |_args| {
+                (!object_id.lookup_validity()).then_some(None)
+            });
+
+        let signal = Signal::from_object_signal(&object, signal_name);
+        let frame_signal = Signal::from_object_signal(&tree, "process_frame");
+        signal.connect(signal_callable.clone(), ConnectFlags::ONE_SHOT.ord() as i64);
+        frame_signal.connect(frame_callable.clone(), 0);
+
+        Self {
+            state,
+            object_id,
+            signal,
+            signal_callable,
+            frame_signal,
+            frame_callable,
+        }
+    }
+
+    /// Removes both connections.
+    fn cleanup(&mut self) {
+        if self.object_id.lookup_validity()
+            && self.signal.is_connected(self.signal_callable.clone())
+        {
+            self.signal.disconnect(self.signal_callable.clone());
+        }
+
+        if self.frame_signal.is_connected(self.frame_callable.clone()) {
+            self.frame_signal.disconnect(self.frame_callable.clone());
+        }
+    }
+}
+
+impl Future for SignalFuture {
+    type Output = Option<Vec<Variant>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let outcome = {
+            let mut state = self.state.lock().unwrap();
+
+            // Don't wait for the next frame if the object is already gone.
+            if state.outcome.is_none() && !self.object_id.lookup_validity() {
+                state.outcome = Some(None);
+            }
+
+            state.take_outcome(cx.waker())
+        };
+
+        let Some(args) = outcome else {
+            return Poll::Pending;
+        };
+
+        self.cleanup();
+        Poll::Ready(args)
+    }
+}
+
+impl Drop for SignalFuture {
+    fn drop(&mut self) {
+        self.cleanup();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// How an awaited tween ended.
+///
+/// Returned by [`TweenFuture`].
+#[cfg(feature = "codegen-full")]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TweenEnd {
+    /// All tweeners ran to completion, and the tween emitted `finished`.
+    Finished,
+
+    /// The tween was stopped before completion, because the animated node was freed or the tween was killed.
+    Cancelled,
+}
+
+/// Future that resolves once a [`Tween`][crate::classes::Tween] has finished.
+///
+/// Created by [`Node2D::tween_to()`][crate::classes::Node2D::tween_to]. Besides the tween's `finished` signal, the future checks on every
+/// process frame of the scene tree whether the animated node still exists and the tween is still valid. If not, it resolves with
+/// [`TweenEnd::Cancelled`] and kills the tween, so that it no longer touches the node.
+///
+/// # Executor
+/// Like [`SignalTimeoutFuture`], this must be polled on the main thread, by an executor that runs alongside the Godot main loop.
+#[cfg(feature = "codegen-full")]
+pub struct TweenFuture {
+    state: Arc<Mutex<SignalFutureState<TweenEnd>>>,
+    tween: Gd<crate::classes::Tween>,
+    node_id: InstanceId,
+    finished_signal: Signal,
+    finished_callable: Callable,
+    frame_signal: Signal,
+    frame_callable: Callable,
+}
+
+#[cfg(feature = "codegen-full")]
+impl TweenFuture {
+    /// Observes `tween`, which animates the node with ID `node_id`.
+    pub(crate) fn new(tween: Gd<crate::classes::Tween>, node_id: InstanceId) -> Self {
+        let state = Arc::new(Mutex::new(SignalFutureState::new()));
+        let tree = main_scene_tree("awaiting a tween");
+
+        let finished_callable =
+            SignalFutureState::make_callable(&state, "TweenFuture::finished", |_args| {
+                Some(TweenEnd::Finished)
+            });
+
+        let tween_id = tween.instance_id();
+        let frame_callable =
+            SignalFutureState::make_callable(&state, "TweenFuture::frame", move |_args| {
+                let is_tween_valid = Gd::<crate::classes::Tween>::try_from_instance_id(tween_id)
+                    .map_or(false, |tween| tween.is_valid());
+
+                (!is_tween_valid || !node_id.lookup_validity()).then_some(TweenEnd::Cancelled)
+            });
+
+        let finished_signal = Signal::from_object_signal(&tween, "finished");
+        let frame_signal = Signal::from_object_signal(&tree, "process_frame");
+        finished_signal.connect(
+            finished_callable.clone(),
+            ConnectFlags::ONE_SHOT.ord() as i64,
+        );
+        frame_signal.connect(frame_callable.clone(), 0);
+
+        Self {
+            state,
+            tween,
+            node_id,
+            finished_signal,
+            finished_callable,
+            frame_signal,
+            frame_callable,
+        }
+    }
+
+    /// The tween being awaited.
+    ///
+    /// Can be used to adjust it further, e.g. with [`Tween::set_speed_scale()`][crate::classes::Tween::set_speed_scale].
+    pub fn tween(&self) -> &Gd<crate::classes::Tween> {
+        &self.tween
+    }
+
+    /// Removes both connections, and kills the tween if its node is gone.
+    fn cleanup(&mut self) {
+        if self
+            .finished_signal
+            .is_connected(self.finished_callable.clone())
+        {
+            self.finished_signal
+                .disconnect(self.finished_callable.clone());
+        }
+
+        if self.frame_signal.is_connected(self.frame_callable.clone()) {
+            self.frame_signal.disconnect(self.frame_callable.clone());
+        }
+
+        if !self.node_id.lookup_validity() {
+            self.tween.kill();
+        }
+    }
+}
+
+#[cfg(feature = "codegen-full")]
+impl Future for TweenFuture {
+    type Output = TweenEnd;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Some(end) = self.state.lock().unwrap().take_outcome(cx.waker()) else {
+            return Poll::Pending;
+        };
+
+        self.cleanup();
+        Poll::Ready(end)
+    }
+}
+
+#[cfg(feature = "codegen-full")]
+impl Drop for TweenFuture {
+    fn drop(&mut self) {
+        self.cleanup();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Options for [`HttpRequest::request_async()`][crate::classes::HttpRequest::request_async].
+///
+/// The default is a `GET` request without additional headers and body, using the node's configured timeout.
+#[cfg(feature = "codegen-full")]
+#[derive(Clone, Debug)]
+pub struct HttpRequestOptions {
+    /// The HTTP method.
+    pub method: crate::classes::http_client::Method,
+
+    /// Additional request headers, each in the form `"Name: value"`.
+    pub headers: Vec<String>,
+
+    /// The request body, sent as-is.
+    pub body: PackedByteArray,
+
+    /// Seconds after which the request fails with a timeout, or `None` to keep the node's `timeout` property (by default no timeout).
+    ///
+    /// If set, the node's `timeout` property is changed accordingly.
+    pub timeout_seconds: Option<f64>,
+}
+
+#[cfg(feature = "codegen-full")]
+impl Default for HttpRequestOptions {
+    fn default() -> Self {
+        Self {
+            method: crate::classes::http_client::Method::GET,
+            headers: Vec::new(),
+            body: PackedByteArray::new(),
+            timeout_seconds: None,
+        }
+    }
+}
+
+/// Response to a completed HTTP request.
+///
+/// Returned by [`HttpRequestFuture`]. Every response received from the server is returned, including those with an HTTP error status;
+/// check [`status_code`][Self::status_code] or [`is_success()`][Self::is_success].
+#[cfg(feature = "codegen-full")]
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    /// The HTTP status code, e.g. 200 or 404.
+    pub status_code: i32,
+
+    /// The response headers, each in the form `"Name: value"`.
+    pub headers: Vec<String>,
+
+    /// The response body.
+    pub body: PackedByteArray,
+}
+
+#[cfg(feature = "codegen-full")]
+impl HttpResponse {
+    /// Returns `true` if the status code is in the `2xx` range.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status_code)
+    }
+
+    /// Returns the value of the first header named `name`, compared case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find_map(|header| {
+            let (header_name, value) = header.split_once(':')?;
+            header_name
+                .trim()
+                .eq_ignore_ascii_case(name)
+                .then(|| value.trim())
+        })
+    }
+
+    /// Returns the body decoded as UTF-8, with invalid sequences replaced by `U+FFFD`.
+    pub fn body_text(&self) -> String {
+        String::from_utf8_lossy(self.body.as_slice()).into_owned()
+    }
+
+    fn from_signal_args(args: &[&Variant], url: &str) -> Result<Self, HttpRequestError> {
+        let arg = |index: usize| args.get(index).copied();
+
+        let result = arg(0)
+            .and_then(|result| result.try_to::<HttpRequestResult>().ok())
+            .unwrap_or(HttpRequestResult::REQUEST_FAILED);
+
+        if result != HttpRequestResult::SUCCESS {
+            return Err(HttpRequestError::new(
+                HttpRequestErrorKind::Failed(result),
+                url,
+            ));
+        }
+
+        let status_code = arg(1)
+            .and_then(|code| code.try_to::<i32>().ok())
+            .unwrap_or(0);
+        let headers = arg(2)
+            .and_then(|headers| headers.try_to::<PackedStringArray>().ok())
+            .map(|headers| headers.as_slice().iter().map(GString::to_string).collect())
+            .unwrap_or_default();
+        let body = arg(3)
+            .and_then(|body| body.try_to::<PackedByteArray>().ok())
+            .unwrap_or_default();
+
+        Ok(Self {
+            status_code,
+            headers,
+            body,
+        })
+    }
+}
+
+/// Future that resolves once an [`HttpRequest`][crate::classes::HttpRequest] completes.
+///
+/// Created by [`HttpRequest::request_async()`][crate::classes::HttpRequest::request_async]. Awaiting it is the equivalent of
+/// `await http.request_completed` in GDScript, yielding `Ok(response)` once a response was received. It yields an error if the request
+/// could not be started, failed (e.g. due to a network error or timeout), or if the node was freed before completion. Freeing is
+/// detected on the scene tree's next process frame (or the next poll, whichever comes first).
+///
+/// Dropping the future before it resolves cancels the request.
+///
+/// # Executor
+/// Like [`SignalTimeoutFuture`], this must be polled on the main thread, by an executor that runs alongside the Godot main loop.
+#[cfg(feature = "codegen-full")]
+pub struct HttpRequestFuture {
+    state: Arc<Mutex<SignalFutureState<Result<HttpResponse, HttpRequestError>>>>,
+    request_id: InstanceId,
+    url: String,
+
+    /// Empty if the request could not be started.
+    connections: Vec<(Signal, Callable)>,
+}
+
+#[cfg(feature = "codegen-full")]
+impl HttpRequestFuture {
+    pub(crate) fn new(
+        mut request: Gd<crate::classes::HttpRequest>,
+        url: GString,
+        options: HttpRequestOptions,
+    ) -> Self {
+        let state = Arc::new(Mutex::new(SignalFutureState::new()));
+        let tree = main_scene_tree("awaiting an HTTP request");
+        let request_id = request.instance_id();
+        let url_string = url.to_string();
+
+        let mut future = Self {
+            state,
+            request_id,
+            url: url_string.clone(),
+            connections: Vec::new(),
+        };
+
+        // Outside the tree, Godot would print an error; report it through the future instead.
+        let result = if request.is_inside_tree() {
+            if let Some(timeout) = options.timeout_seconds {
+                request.set_timeout(timeout);
+            }
+
+            let headers: PackedStringArray = options.headers.iter().map(GString::from).collect();
+            request
+                .request_raw_ex(url)
+                .custom_headers(&headers)
+                .method(options.method)
+                .request_data_raw(&options.body)
+                .done()
+        } else {
+            GodotError::ERR_UNCONFIGURED
+        };
+
+        if result != GodotError::OK {
+            let error = HttpRequestError::new(HttpRequestErrorKind::Start(result), url_string);
+            future.state.lock().unwrap().outcome = Some(Err(error));
+            return future;
+        }
+
+        let completed_callable = {
+            let url = url_string.clone();
+            SignalFutureState::make_callable(
+                &future.state,
+                "HttpRequestFuture::completed",
+                move |args| Some(HttpResponse::from_signal_args(args, &url)),
+            )
+        };
+        let frame_callable = SignalFutureState::make_callable(
+            &future.state,
+            "HttpRequestFuture::frame",
+            move |_args| {
+                (!request_id.lookup_validity()).then(|| {
+                    Err(HttpRequestError::new(
+                        HttpRequestErrorKind::Cancelled,
+                        url_string.clone(),
+                    ))
+                })
+            },
+        );
+
+        let completed_signal = Signal::from_object_signal(&request, "request_completed");
+        let frame_signal = Signal::from_object_signal(&tree, "process_frame");
+        completed_signal.connect(
+            completed_callable.clone(),
+            ConnectFlags::ONE_SHOT.ord() as i64,
+        );
+        frame_signal.connect(frame_callable.clone(), 0);
+
+        future.connections = vec![
+            (completed_signal, completed_callable),
+            (frame_signal, frame_callable),
+        ];
+        future
+    }
+
+    /// Removes all connections.
+    fn cleanup(&mut self) {
+        for (signal, callable) in self.connections.drain(..) {
+            let is_object_alive = signal.object_id().is_some_and(|id| id.lookup_validity());
+
+            if is_object_alive && signal.is_connected(callable.clone()) {
+                signal.disconnect(callable);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "codegen-full")]
+impl Future for HttpRequestFuture {
+    type Output = Result<HttpResponse, HttpRequestError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let outcome = {
+            let mut state = self.state.lock().unwrap();
+
+            // Don't wait for the next frame if the node is already gone.
+            if state.outcome.is_none() && !self.request_id.lookup_validity() {
+                let error = HttpRequestError::new(HttpRequestErrorKind::Cancelled, &self.url);
+                state.outcome = Some(Err(error));
+            }
+
+            state.take_outcome(cx.waker())
+        };
+
+        let Some(result) = outcome else {
+            return Poll::Pending;
+        };
+
+        self.cleanup();
+        Poll::Ready(result)
+    }
+}
+
+#[cfg(feature = "codegen-full")]
+impl Drop for HttpRequestFuture {
+    fn drop(&mut self) {
+        let is_pending = {
+            let state = self.state.lock().unwrap();
+            state.outcome.is_none() && !state.is_consumed
+        };
+
+        self.cleanup();
+
+        if is_pending {
+            if let Ok(mut request) =
+                Gd::<crate::classes::HttpRequest>::try_from_instance_id(self.request_id)
+            {
+                request.cancel_request();
+            }
+        }
+    }
+}
+
+fn main_scene_tree(purpose: &str) -> Gd<SceneTree> {
+    Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        .unwrap_or_else(|| panic!("{purpose} requires the main loop to be a SceneTree"))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Shared state between future and connected callables
+
+enum Outcome {
+    Emitted(Vec<Variant>),
+    TimedOut,
+}
+
+struct SignalFutureState<O> {
+    outcome: Option<O>,
+    is_consumed: bool,
+    waker: Option<Waker>,
+    thread_id: ThreadId,
+}
+
+// SAFETY: The only non-`Send` data are the Godot values in `outcome`, such as `Variant`s. They are only written and read after checking
+// that the accessing thread is the one which created the future (see `check_thread()`), and never dropped on another thread (see `Drop`).
+unsafe impl<O> Send for SignalFutureState<O> {}
+
+impl<O> Drop for SignalFutureState<O> {
+    fn drop(&mut self) {
+        // The last reference may be held by a callable that Godot destroys on another thread. The outcome must not be dropped there, so
+        // it is leaked instead.
+        if let Some(outcome) = self.outcome.take() {
+            if self.thread_id != std::thread::current().id() {
+                std::mem::forget(outcome);
+            }
+        }
+    }
+}
+
+impl<O> SignalFutureState<O> {
+    fn new() -> Self {
+        Self {
+            outcome: None,
+            is_consumed: false,
+            waker: None,
+            thread_id: std::thread::current().id(),
+        }
+    }
+
+    /// Creates a callable which resolves the future with the outcome computed by `make_outcome`, unless it is already resolved.
+    ///
+    /// If `make_outcome` returns `None`, the invocation is ignored and the future stays pending.
+    fn make_callable(
+        state: &Arc<Mutex<Self>>,
+        name: &str,
+        make_outcome: impl Fn(&[&Variant]) -> Option<O> + Send + Sync + 'static,
+    ) -> Callable
+    where
+        O: 'static,
+    {
+        let state = Arc::clone(state);
+
+        Callable::from_fn(name, move |args| {
+            let waker = {
+                let mut state = state.lock().unwrap();
+                state.check_thread();
+
+                if state.outcome.is_some() || state.is_consumed {
+                    return Ok(Variant::nil());
+                }
+
+                state.outcome = make_outcome(args);
+                if state.outcome.is_none() {
+                    return Ok(Variant::nil());
+                }
+                state.waker.take()
+            };
+
+            // Wake outside the lock, in case the executor polls synchronously.
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+
+            Ok(Variant::nil())
+        })
+    }
+
+    /// Returns the outcome if available, otherwise registers the waker.
+    fn take_outcome(&mut self, waker: &Waker) -> Option<O> {
+        self.check_thread();
+        assert!(!self.is_consumed, "signal future polled after completion");
+
+        match self.outcome.take() {
+            Some(outcome) => {
+                self.is_consumed = true;
+                Some(outcome)
+            }
+            None => {
+                self.waker = Some(waker.clone());
+                None
+            }
+        }
+    }
+
+    fn check_thread(&self) {
+        assert_eq!(
+            self.thread_id,
+            std::thread::current().id(),
+            "signal futures must be polled and resolved on the thread that created them"
+        );
+    }
+}
@@ -0,0 +1,207 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Add;
+
+use crate::builtin::{real, Vector2, Vector3};
+use crate::classes::{Node, Node2D, Node3D};
+use crate::obj::{Gd, Inherits, InstanceId};
+
+/// Moves a node smoothly towards a target node, e.g. for a camera following the player.
+///
+/// Call [`update()`][Self::update] with the frame time from `process(delta)`. Each update moves the follower's global position towards
+/// the target's global position (plus an optional [offset][Self::with_offset]) by exponential damping: after `t` seconds, the remaining
+/// distance has shrunk by the factor `exp(-smoothing * t)`. Unlike a fixed `lerp()` weight per frame, this leads to the same motion
+/// regardless of the framerate. Higher `smoothing` values follow the target more tightly; `f64::INFINITY` snaps to it.
+///
+/// Works with 2D and 3D nodes, see [`FollowNode`]. Only the instance ID of the target is stored. Once the target is freed, the follower
+/// stays where it is, and [`update()`][Self::update] returns `false`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::SmoothFollow;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D)]
+/// struct FollowCamera {
+///     follow: Option<SmoothFollow<Node2D>>,
+///     base: Base<Node2D>,
+/// }
+///
+/// #[godot_api]
+/// impl INode2D for FollowCamera {
+///     fn process(&mut self, delta: f64) {
+///         let mut this = self.to_gd().upcast::<Node2D>();
+///         let active = match &self.follow {
+///             Some(follow) => follow.update(&mut this, delta),
+///             None => false,
+///         };
+///
+///         if !active {
+///             self.follow = None; // Target was freed.
+///         }
+///     }
+/// }
+/// ```
+pub struct SmoothFollow<T: FollowNode> {
+    target_id: InstanceId,
+    smoothing: f64,
+    offset: T::Position,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: FollowNode> SmoothFollow<T> {
+    /// Follows `target` with damping rate `smoothing`, in 1/s.
+    ///
+    /// # Panics
+    /// If `smoothing` is negative or NaN.
+    pub fn new<U>(target: &Gd<U>, smoothing: f64) -> Self
+    where
+        U: Inherits<T>,
+    {
+        assert!(
+            smoothing >= 0.0,
+            "smoothing must be non-negative, got {smoothing}"
+        );
+
+        Self {
+            target_id: target.instance_id(),
+            smoothing,
+            offset: T::__zero(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Follows the position at `offset` from the target (in global coordinates), instead of the target's position itself.
+    pub fn with_offset(self, offset: T::Position) -> Self {
+        Self { offset, ..self }
+    }
+
+    /// Moves `follower` towards the target, for `delta` seconds of elapsed time.
+    ///
+    /// Returns `false` if the target has been freed, in which case the follower is not moved. If the follower or the target is not
+    /// inside the scene tree, or if `delta` is not positive, nothing is moved either, but `true` is returned.
+    pub fn update<F>(&self, follower: &mut Gd<F>, delta: f64) -> bool
+    where
+        F: Inherits<T>,
+    {
+        let Some(target) = self.target() else {
+            return false;
+        };
+
+        let mut follower = follower.clone().upcast::<T>();
+        if delta.is_nan()
+            || delta <= 0.0
+            || !follower.upcast_ref::<Node>().is_inside_tree()
+            || !target.upcast_ref::<Node>().is_inside_tree()
+        {
+            return true;
+        }
+
+        let weight = 1.0 - (-self.smoothing * delta).exp();
+
+        let from = T::__global_position(&follower);
+        let to = T::__global_position(&target) + self.offset;
+        T::__set_global_position(&mut follower, T::__lerp(from, to, weight as real));
+        true
+    }
+
+    /// Returns the target, or `None` if it has been freed.
+    pub fn target(&self) -> Option<Gd<T>> {
+        Gd::try_from_instance_id(self.target_id).ok()
+    }
+
+    /// Returns `true` if the target still exists.
+    pub fn is_following(&self) -> bool {
+        self.target_id.lookup_validity()
+    }
+
+    /// Damping rate in 1/s, as passed to [`new()`][Self::new].
+    pub fn smoothing(&self) -> f64 {
+        self.smoothing
+    }
+}
+
+impl<T: FollowNode> fmt::Debug for SmoothFollow<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmoothFollow")
+            .field("target_id", &self.target_id)
+            .field("smoothing", &self.smoothing)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+/// Node class whose global position can be followed with [`SmoothFollow`]: [`Node2D`] or [`Node3D`].
+///
+/// Derived classes (such as `Camera2D`) are supported by using the base class as type parameter, e.g. `SmoothFollow<Node2D>`.
+pub trait FollowNode: Inherits<Node> + private::Sealed {
+    /// Type of the position: [`Vector2`] or [`Vector3`].
+    type Position: Copy + Add<Output = Self::Position> + fmt::Debug;
+
+    #[doc(hidden)]
+    fn __zero() -> Self::Position;
+
+    #[doc(hidden)]
+    fn __global_position(node: &Gd<Self>) -> Self::Position;
+
+    #[doc(hidden)]
+    fn __set_global_position(node: &mut Gd<Self>, position: Self::Position);
+
+    #[doc(hidden)]
+    fn __lerp(from: Self::Position, to: Self::Position, weight: real) -> Self::Position;
+}
+
+impl FollowNode for Node2D {
+    type Position = Vector2;
+
+    fn __zero() -> Vector2 {
+        Vector2::ZERO
+    }
+
+    fn __global_position(node: &Gd<Self>) -> Vector2 {
+        node.get_global_position()
+    }
+
+    fn __set_global_position(node: &mut Gd<Self>, position: Vector2) {
+        node.set_global_position(position);
+    }
+
+    fn __lerp(from: Vector2, to: Vector2, weight: real) -> Vector2 {
+        from.lerp(to, weight)
+    }
+}
+
+impl FollowNode for Node3D {
+    type Position = Vector3;
+
+    fn __zero() -> Vector3 {
+        Vector3::ZERO
+    }
+
+    fn __global_position(node: &Gd<Self>) -> Vector3 {
+        node.get_global_position()
+    }
+
+    fn __set_global_position(node: &mut Gd<Self>, position: Vector3) {
+        node.set_global_position(position);
+    }
+
+    fn __lerp(from: Vector3, to: Vector3, weight: real) -> Vector3 {
+        from.lerp(to, weight)
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for crate::classes::Node2D {}
+    impl Sealed for crate::classes::Node3D {}
+}
@@ -0,0 +1,114 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Callable, GString, StringName, Variant, VariantArray};
+use crate::classes::Object;
+use crate::meta::error::{ConnectError, ConnectErrorKind};
+use crate::obj::{Gd, Inherits, InstanceId};
+
+/// Signal which remembers its last emission, and replays it to handlers connected later.
+///
+/// Handlers connected through [`connect()`][Self::connect] are immediately called with the arguments of the most recent
+/// [`emit()`][Self::emit], and then receive all further emissions like regular signal handlers. This suits signals which represent
+/// state, such as `health_changed` or `level_loaded`: subscribers that connect after the initial emission still learn the current
+/// state, without a separate getter. If the signal has not been emitted yet, nothing is replayed.
+///
+/// The signal itself is a regular Godot signal of the object, which can be declared with `#[signal]`; otherwise it is added as a user
+/// signal. Only emissions through [`emit()`][Self::emit] are remembered, not those made with `emit_signal()` directly. Only the instance
+/// ID of the object is stored; once it is freed, emissions are no longer forwarded.
+#[derive(Debug)]
+pub struct StickySignal {
+    instance_id: InstanceId,
+    signal: StringName,
+    last_args: Option<Vec<Variant>>,
+}
+
+impl StickySignal {
+    /// Wraps the signal `signal` of `object`, adding it as a user signal if the object does not have it.
+    pub fn new<O>(object: &Gd<O>, signal: impl Into<StringName>) -> Self
+    where
+        O: Inherits<Object>,
+    {
+        let signal = signal.into();
+
+        let mut object = object.clone().upcast::<Object>();
+        if !object.has_signal(signal.clone()) {
+            object.add_user_signal(GString::from(&signal));
+        }
+
+        Self {
+            instance_id: object.instance_id(),
+            signal,
+            last_args: None,
+        }
+    }
+
+    /// Emits the signal with `args`, and remembers them for handlers connected later.
+    pub fn emit(&mut self, args: &[Variant]) {
+        self.last_args = Some(args.to_vec());
+
+        if let Some(mut object) = self.object() {
+            object.emit_signal(self.signal.clone(), args);
+        }
+    }
+
+    /// Connects `callable` to the signal, and immediately calls it with the last emitted arguments, if any.
+    ///
+    /// Returns an error without calling `callable` if the object has been freed, or if Godot rejects the connection, e.g. because
+    /// `callable` is already connected.
+    pub fn connect(&self, callable: Callable) -> Result<(), ConnectError> {
+        let error =
+            |kind, source: String| ConnectError::for_object(kind, source, self.signal.to_string());
+
+        let Some(mut object) = self.object() else {
+            return Err(error(
+                ConnectErrorKind::ObjectFreed,
+                format!("with instance ID {}", self.instance_id),
+            ));
+        };
+
+        let source = object.to_string();
+        object.try_connect(&self.signal, &callable, |kind| error(kind, source))?;
+
+        if let Some(args) = &self.last_args {
+            let args: VariantArray = args.iter().cloned().collect();
+            callable.callv(&args);
+        }
+
+        Ok(())
+    }
+
+    /// Disconnects `callable` from the signal. Returns `false` if it was not connected.
+    pub fn disconnect(&self, callable: &Callable) -> bool {
+        match self.object() {
+            Some(mut object) if object.is_connected(self.signal.clone(), callable.clone()) => {
+                object.disconnect(self.signal.clone(), callable.clone());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The arguments of the last emission, or `None` if the signal has not been emitted since creation or [`clear()`][Self::clear].
+    pub fn last_args(&self) -> Option<&[Variant]> {
+        self.last_args.as_deref()
+    }
+
+    /// Forgets the last emission, so that handlers connected afterwards are not called until the next one.
+    pub fn clear(&mut self) {
+        self.last_args = None;
+    }
+
+    /// The name of the signal.
+    pub fn signal(&self) -> &StringName {
+        &self.signal
+    }
+
+    fn object(&self) -> Option<Gd<Object>> {
+        Gd::try_from_instance_id(self.instance_id).ok()
+    }
+}
@@ -0,0 +1,313 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::ThreadId;
+
+use crate::builtin::{Callable, Signal, Variant};
+use crate::classes::object::ConnectFlags;
+use crate::classes::{Engine, Node, SceneTree};
+use crate::obj::{EngineBitfield, Gd, InstanceId};
+
+/// Runs `future` as a task on the current thread, which should be the main thread.
+///
+/// The task is polled for the first time right away, until it awaits something that is not ready yet. From then on, it is polled
+/// again whenever it is woken, typically by a signal emission (see [`Signal::to_signal_timeout()`]). Tasks are executed by a simple
+/// executor built into godot-rust; no external async runtime is needed.
+///
+/// Returns a handle which can be used to cancel the task. Dropping the handle does not cancel the task.
+///
+/// Wake-ups from other threads are processed on the next process frame of the scene tree.
+///
+/// To tie a task to the lifetime of a node, use [`Node::spawn_task()`] instead.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::spawn_task;
+///
+/// fn spawn_greeter(tree: Gd<SceneTree>) {
+///     spawn_task(async move {
+///         let frame = Signal::from_object_signal(&tree, "process_frame");
+///         frame.to_signal_timeout(1.0).await;
+///
+///         godot_print!("One frame later!");
+///     });
+/// }
+/// ```
+pub fn spawn_task(future: impl Future<Output = ()> + 'static) -> TaskHandle {
+    EXECUTOR.with(|executor| {
+        let id = executor.reserve_id();
+        executor.spawn(id, Box::pin(future))
+    })
+}
+
+/// Spawns a task which is cancelled once `node` exits the scene tree or is freed.
+pub(crate) fn spawn_node_task(
+    node: Gd<Node>,
+    future: impl Future<Output = ()> + 'static,
+) -> TaskHandle {
+    EXECUTOR.with(|executor| {
+        let id = executor.reserve_id();
+
+        let callable = Callable::from_fn("Node::spawn_task::tree_exited", move |_args| {
+            cancel_task(id);
+            Ok(Variant::nil())
+        });
+
+        let signal = Signal::from_object_signal(&node, "tree_exited");
+        signal.connect(callable.clone(), ConnectFlags::ONE_SHOT.ord() as i64);
+
+        let task = NodeBoundTask {
+            node_id: node.instance_id(),
+            connection: Some((signal, callable)),
+            future: Box::pin(future),
+        };
+
+        executor.spawn(id, Box::pin(task))
+    })
+}
+
+/// Handle to a task started with [`spawn_task()`] or [`Node::spawn_task()`].
+///
+/// Can be cloned; all clones refer to the same task.
+#[derive(Clone, Debug)]
+pub struct TaskHandle {
+    id: TaskId,
+    finished: Rc<Cell<bool>>,
+}
+
+impl TaskHandle {
+    /// Cancels the task, dropping its future. Has no effect if the task is already finished.
+    ///
+    /// If the task is currently running (i.e. it cancels itself), it is dropped as soon as it yields.
+    pub fn cancel(&self) {
+        cancel_task(self.id);
+    }
+
+    /// Returns `true` if the task has run to completion or has been cancelled.
+    pub fn is_finished(&self) -> bool {
+        self.finished.get()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Executor
+
+type TaskId = u64;
+
+thread_local! {
+    static EXECUTOR: Executor = Executor::new();
+}
+
+struct Task {
+    future: Pin<Box<dyn Future<Output = ()>>>,
+    finished: Rc<Cell<bool>>,
+}
+
+struct Executor {
+    tasks: RefCell<HashMap<TaskId, Task>>,
+    next_id: Cell<TaskId>,
+
+    /// Tasks to be polled; shared with wakers, which may live on other threads.
+    ready_queue: Arc<Mutex<VecDeque<TaskId>>>,
+
+    /// Task currently being polled, and whether it has cancelled itself meanwhile.
+    polled_task: Cell<Option<TaskId>>,
+    is_polled_task_cancelled: Cell<bool>,
+
+    is_frame_hook_connected: Cell<bool>,
+}
+
+impl Executor {
+    fn new() -> Self {
+        Self {
+            tasks: RefCell::new(HashMap::new()),
+            next_id: Cell::new(0),
+            ready_queue: Arc::new(Mutex::new(VecDeque::new())),
+            polled_task: Cell::new(None),
+            is_polled_task_cancelled: Cell::new(false),
+            is_frame_hook_connected: Cell::new(false),
+        }
+    }
+
+    fn reserve_id(&self) -> TaskId {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        id
+    }
+
+    fn spawn(&self, id: TaskId, future: Pin<Box<dyn Future<Output = ()>>>) -> TaskHandle {
+        self.connect_frame_hook();
+
+        let finished = Rc::new(Cell::new(false));
+        let task = Task {
+            future,
+            finished: Rc::clone(&finished),
+        };
+
+        self.tasks.borrow_mut().insert(id, task);
+        self.ready_queue.lock().unwrap().push_back(id);
+        self.run_ready();
+
+        TaskHandle { id, finished }
+    }
+
+    /// Polls all woken tasks, including ones that are woken during the process.
+    fn run_ready(&self) {
+        // Tasks woken while another task is polled are picked up by the outer loop.
+        if self.polled_task.get().is_some() {
+            return;
+        }
+
+        loop {
+            let Some(id) = self.ready_queue.lock().unwrap().pop_front() else {
+                break;
+            };
+
+            // Finished and cancelled tasks are no longer in the map.
+            let Some(mut task) = self.tasks.borrow_mut().remove(&id) else {
+                continue;
+            };
+
+            let waker = Waker::from(Arc::new(TaskWaker {
+                id,
+                ready_queue: Arc::clone(&self.ready_queue),
+                thread_id: std::thread::current().id(),
+            }));
+
+            let poll = {
+                let _guard = PolledTaskGuard::new(self, id);
+                task.future.as_mut().poll(&mut Context::from_waker(&waker))
+            };
+
+            if poll.is_ready() || self.is_polled_task_cancelled.replace(false) {
+                task.finished.set(true);
+            } else {
+                self.tasks.borrow_mut().insert(id, task);
+            }
+        }
+    }
+
+    fn cancel(&self, id: TaskId) {
+        if self.polled_task.get() == Some(id) {
+            self.is_polled_task_cancelled.set(true);
+            return;
+        }
+
+        // Drop the future outside the borrow, as its destructor may interact with the executor.
+        let task = self.tasks.borrow_mut().remove(&id);
+        if let Some(task) = task {
+            task.finished.set(true);
+        }
+    }
+
+    /// Makes sure that wake-ups from other threads are processed once per frame.
+    fn connect_frame_hook(&self) {
+        if self.is_frame_hook_connected.replace(true) {
+            return;
+        }
+
+        let Some(tree) = Engine::singleton()
+            .get_main_loop()
+            .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        else {
+            return;
+        };
+
+        let callable = Callable::from_fn("spawn_task::process_frame", |_args| {
+            EXECUTOR.with(|executor| executor.run_ready());
+            Ok(Variant::nil())
+        });
+
+        Signal::from_object_signal(&tree, "process_frame").connect(callable, 0);
+    }
+}
+
+fn cancel_task(id: TaskId) {
+    let _ = EXECUTOR.try_with(|executor| executor.cancel(id));
+}
+
+/// Marks a task as being polled; resets on drop, also when the task panics.
+struct PolledTaskGuard<'a> {
+    executor: &'a Executor,
+}
+
+impl<'a> PolledTaskGuard<'a> {
+    fn new(executor: &'a Executor, id: TaskId) -> Self {
+        executor.polled_task.set(Some(id));
+        Self { executor }
+    }
+}
+
+impl Drop for PolledTaskGuard<'_> {
+    fn drop(&mut self) {
+        self.executor.polled_task.set(None);
+    }
+}
+
+struct TaskWaker {
+    id: TaskId,
+    ready_queue: Arc<Mutex<VecDeque<TaskId>>>,
+    thread_id: ThreadId,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.ready_queue.lock().unwrap().push_back(self.id);
+
+        // On the executor's thread, poll right away. Other threads rely on the frame hook.
+        if std::thread::current().id() == self.thread_id {
+            let _ = EXECUTOR.try_with(|executor| executor.run_ready());
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Future which completes early if its node has been freed, and disconnects from `tree_exited` when dropped.
+struct NodeBoundTask<F> {
+    node_id: InstanceId,
+    connection: Option<(Signal, Callable)>,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future<Output = ()>> Future for NodeBoundTask<F> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Gd::<Node>::try_from_instance_id(self.node_id).is_err() {
+            return Poll::Ready(());
+        }
+
+        self.future.as_mut().poll(cx)
+    }
+}
+
+impl<F> Drop for NodeBoundTask<F> {
+    fn drop(&mut self) {
+        let Some((signal, callable)) = self.connection.take() else {
+            return;
+        };
+
+        if Gd::<Node>::try_from_instance_id(self.node_id).is_ok()
+            && signal.is_connected(callable.clone())
+        {
+            signal.disconnect(callable);
+        }
+    }
+}
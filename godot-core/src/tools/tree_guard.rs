@@ -0,0 +1,112 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::builtin::Signal;
+use crate::classes::Node;
+use crate::obj::{Gd, Inherits, InstanceId};
+use crate::tools::local_callback::{
+    connect_local_callback, is_local_callback_registered, register_local_callback,
+    remove_local_callback, LocalCallbackId,
+};
+
+/// Runs closures whenever a node enters or exits the scene tree, for as long as the guard is alive.
+///
+/// This is a declarative alternative to overriding `enter_tree()` and `exit_tree()`, for nodes that acquire resources while inside
+/// the tree and release them afterwards. Typically, the guard is stored as a field of a user class and created in `init()`:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::tools::EnterTreeGuard;
+///
+/// #[derive(GodotClass)]
+/// #[class(base=Node)]
+/// struct Spawner {
+///     guard: EnterTreeGuard,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Spawner {
+///     fn init(base: Base<Node>) -> Self {
+///         let guard = EnterTreeGuard::new(
+///             &base.to_gd(),
+///             || godot_print!("register with global systems"),
+///             || godot_print!("unregister again"),
+///         );
+///
+///         Self { guard, base }
+///     }
+/// }
+/// ```
+///
+/// # Ordering
+/// The closures are run from the node's `tree_entered` and `tree_exiting` signals. This means:
+/// - `on_enter` runs after `NOTIFICATION_ENTER_TREE` and `enter_tree()` of the node, but before `ready()`. Parents enter before children.
+/// - `on_exit` runs after `exit_tree()` of the node, but before `NOTIFICATION_EXIT_TREE`. Children exit before parents. The node is
+///   still inside the tree at that point.
+///
+/// Only transitions after the guard's creation are observed: if the node is already inside the tree, `on_enter` is not run for that.
+/// Freeing a node inside the tree runs `on_exit`.
+///
+/// Dropping the guard disconnects both closures. If its node is freed, the guard becomes inactive.
+#[derive(Debug)]
+pub struct EnterTreeGuard {
+    /// Callbacks for entering and exiting.
+    ids: [LocalCallbackId; 2],
+    node_id: InstanceId,
+    _not_send: PhantomData<Rc<()>>,
+}
+
+impl EnterTreeGuard {
+    /// Starts observing `node`, running `on_enter` each time it enters the tree, and `on_exit` each time it is about to exit.
+    pub fn new<T>(
+        node: &Gd<T>,
+        mut on_enter: impl FnMut() + 'static,
+        mut on_exit: impl FnMut() + 'static,
+    ) -> Self
+    where
+        T: Inherits<Node>,
+    {
+        let enter_id = register_local_callback(move |_args| on_enter());
+        let exit_id = register_local_callback(move |_args| on_exit());
+
+        connect_local_callback(
+            enter_id,
+            Signal::from_object_signal(node, "tree_entered"),
+            "EnterTreeGuard::enter",
+        );
+        connect_local_callback(
+            exit_id,
+            Signal::from_object_signal(node, "tree_exiting"),
+            "EnterTreeGuard::exit",
+        );
+
+        Self {
+            ids: [enter_id, exit_id],
+            node_id: node.instance_id(),
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Returns `true` if the observed node still exists, i.e. the closures can still be run.
+    pub fn is_active(&self) -> bool {
+        is_local_callback_registered(self.ids[0]) && self.node_id.lookup_validity()
+    }
+}
+
+impl Drop for EnterTreeGuard {
+    fn drop(&mut self) {
+        // Guards are often fields of the node's own instance, which is dropped while the node is being freed. Its connections are
+        // gone by then, which removing the callbacks takes into account.
+        for id in self.ids {
+            remove_local_callback(id);
+        }
+    }
+}
@@ -0,0 +1,221 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::builtin::{Signal, Variant};
+use crate::classes::{Engine, Node, SceneTree};
+use crate::obj::Gd;
+use crate::tools::local_callback::{
+    call_local_callback, connect_local_callback, register_local_callback, remove_local_callback,
+    LocalCallbackId,
+};
+
+/// Scene tree signal which carries the affected node.
+///
+/// Used with [`SceneTreeObserver::subscribe()`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TreeNodeEvent {
+    /// A node entered the tree (`node_added` signal).
+    NodeAdded,
+
+    /// A node is about to exit the tree (`node_removed` signal).
+    NodeRemoved,
+
+    /// A node inside the tree was renamed (`node_renamed` signal).
+    NodeRenamed,
+}
+
+impl TreeNodeEvent {
+    /// Name of the `SceneTree` signal.
+    pub fn signal_name(self) -> &'static str {
+        match self {
+            Self::NodeAdded => "node_added",
+            Self::NodeRemoved => "node_removed",
+            Self::NodeRenamed => "node_renamed",
+        }
+    }
+}
+
+/// Shares one connection to a scene tree signal between any number of Rust subscribers.
+///
+/// Signals such as `node_added` are emitted for every node entering the tree. If many systems connect to them individually, each
+/// emission goes through the engine's dispatch once per connection, with a `Callable` invocation each time.
+/// The observer instead connects once per [`TreeNodeEvent`], and forwards each emission to all subscribed closures directly in Rust.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::tools::{SceneTreeObserver, TreeNodeEvent};
+///
+/// let subscription = SceneTreeObserver::subscribe(TreeNodeEvent::NodeAdded, |node| {
+///     if node.is_in_group("enemies".into()) {
+///         godot_print!("enemy spawned: {node}");
+///     }
+/// });
+///
+/// // Later: dropping the subscription unsubscribes.
+/// drop(subscription);
+/// ```
+///
+/// The connection is made with the first subscriber of an event, and removed once the last one is dropped. Subscribers are called in
+/// the order they subscribed. A closure can subscribe or unsubscribe others while being called; changes take effect with the next
+/// emission (except that unsubscribed closures are no longer called). If an emission happens while a subscriber is already running,
+/// e.g. because it adds a node to the tree, that subscriber is not called again recursively.
+///
+/// Subscriptions are bound to the main thread, where the scene tree emits its signals.
+pub struct SceneTreeObserver {
+    _private: (),
+}
+
+impl SceneTreeObserver {
+    /// Calls `callback` with the affected node, each time the scene tree emits the signal of `event`.
+    ///
+    /// # Panics
+    /// If the engine's main loop is not a [`SceneTree`].
+    pub fn subscribe(
+        event: TreeNodeEvent,
+        mut callback: impl FnMut(Gd<Node>) + 'static,
+    ) -> TreeSubscription {
+        let id = register_local_callback(move |args| {
+            if let Some(node) = args.first().and_then(|arg| arg.try_to::<Gd<Node>>().ok()) {
+                callback(node);
+            }
+        });
+
+        let is_connected = CHANNELS.with(|channels| {
+            let mut channels = channels.borrow_mut();
+            if let Some(channel) = channels.get_mut(&event) {
+                channel.subscribers.push(id);
+                true
+            } else {
+                false
+            }
+        });
+
+        // Connect outside the channel borrow, as Godot may emit signals during the connection.
+        if !is_connected {
+            let dispatcher = connect_channel(event);
+            CHANNELS.with(|channels| {
+                channels.borrow_mut().insert(
+                    event,
+                    Channel {
+                        dispatcher,
+                        subscribers: vec![id],
+                    },
+                )
+            });
+        }
+
+        TreeSubscription {
+            event,
+            id,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Number of closures currently subscribed to `event`.
+    pub fn subscriber_count(event: TreeNodeEvent) -> usize {
+        CHANNELS
+            .try_with(|channels| {
+                channels
+                    .borrow()
+                    .get(&event)
+                    .map_or(0, |channel| channel.subscribers.len())
+            })
+            .unwrap_or(0)
+    }
+}
+
+/// Subscription created by [`SceneTreeObserver::subscribe()`]. Dropping it unsubscribes the closure.
+#[must_use = "dropping the subscription unsubscribes immediately"]
+#[derive(Debug)]
+pub struct TreeSubscription {
+    event: TreeNodeEvent,
+    id: LocalCallbackId,
+    _not_send: PhantomData<Rc<()>>,
+}
+
+impl TreeSubscription {
+    /// The observed event.
+    pub fn event(&self) -> TreeNodeEvent {
+        self.event
+    }
+
+    /// Unsubscribes the closure. Equivalent to dropping the subscription.
+    pub fn unsubscribe(self) {}
+}
+
+impl Drop for TreeSubscription {
+    fn drop(&mut self) {
+        let empty_channel = CHANNELS
+            .try_with(|channels| {
+                let mut channels = channels.borrow_mut();
+                let channel = channels.get_mut(&self.event)?;
+                channel.subscribers.retain(|id| *id != self.id);
+
+                if channel.subscribers.is_empty() {
+                    channels.remove(&self.event)
+                } else {
+                    None
+                }
+            })
+            .ok()
+            .flatten();
+
+        remove_local_callback(self.id);
+        if let Some(channel) = empty_channel {
+            remove_local_callback(channel.dispatcher);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+thread_local! {
+    static CHANNELS: RefCell<HashMap<TreeNodeEvent, Channel>> = RefCell::new(HashMap::new());
+}
+
+struct Channel {
+    /// Callback connected to the scene tree signal.
+    dispatcher: LocalCallbackId,
+
+    /// Subscribed callbacks, in order of subscription.
+    subscribers: Vec<LocalCallbackId>,
+}
+
+fn connect_channel(event: TreeNodeEvent) -> LocalCallbackId {
+    let tree = Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        .expect("SceneTreeObserver requires the main loop to be a SceneTree");
+
+    let dispatcher = register_local_callback(move |args| dispatch(event, args));
+    let signal = Signal::from_object_signal(&tree, event.signal_name());
+    connect_local_callback(dispatcher, signal, "SceneTreeObserver::dispatch");
+
+    dispatcher
+}
+
+fn dispatch(event: TreeNodeEvent, args: &[&Variant]) {
+    // Release the channel before calling, so subscribers can subscribe or unsubscribe.
+    let snapshot = CHANNELS.with(|channels| {
+        channels
+            .borrow()
+            .get(&event)
+            .map(|channel| channel.subscribers.clone())
+            .unwrap_or_default()
+    });
+
+    // Unsubscribed callbacks are no longer registered, and subscribers already running further up the stack are skipped.
+    for id in snapshot {
+        call_local_callback(id, args);
+    }
+}
@@ -0,0 +1,320 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::{HashMap, HashSet};
+
+use crate::classes::Node;
+use crate::meta::error::{TreeTransactionError, TreeTransactionErrorKind};
+use crate::obj::{Gd, Inherits, InstanceId};
+
+/// Records scene-tree changes in `build`, and applies them as one batch.
+///
+/// Shorthand for creating a [`TreeTransaction`], recording operations on it, and calling [`commit()`][TreeTransaction::commit]. See
+/// there for what is (and isn't) guaranteed.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::tree_transaction;
+///
+/// fn regroup(level: &Gd<Node>, enemies: &[Gd<Node>]) {
+///     let group = Node::new_alloc();
+///
+///     tree_transaction(|tx| {
+///         tx.add_child(level, &group);
+///         for enemy in enemies {
+///             tx.reparent(enemy, &group);
+///         }
+///     })
+///     .expect("valid restructuring");
+/// }
+/// ```
+pub fn tree_transaction(
+    build: impl FnOnce(&mut TreeTransaction),
+) -> Result<(), TreeTransactionError> {
+    let mut transaction = TreeTransaction::new();
+    build(&mut transaction);
+    transaction.commit()
+}
+
+/// Batch of scene-tree changes, which is validated as a whole before being applied.
+///
+/// Procedural scene construction often consists of many dependent steps: create a container, move existing nodes into it, remove
+/// others. Performed one by one, a mistake in a late step (e.g. adding a node that already has a parent) leaves the tree half
+/// restructured, and Godot only prints an error. A transaction records the operations first. [`commit()`][Self::commit] then checks
+/// the complete batch against the tree as it _would_ look after each step, and only applies the operations if all of them are valid.
+/// Otherwise, it returns an error describing the first invalid operation, and the tree is left unchanged.
+///
+/// Operations are applied in the order they were recorded.
+///
+/// # Signals and notifications
+/// Godot has no way to postpone the side effects of tree changes. While committing, each operation emits its signals and
+/// notifications right away, exactly as if it was called directly:
+/// - `child_entered_tree`, `child_exiting_tree` and `child_order_changed` on the parent,
+/// - `tree_entered`, `tree_exiting` and `tree_exited` on the moved nodes and their descendants, as well as `ready` on first entry,
+/// - `node_added`, `node_removed` and `tree_changed` on the `SceneTree`.
+///
+/// What the transaction does avoid is running these handlers on batches that are then aborted midway. If a handler itself changes the
+/// tree so that a later operation becomes impossible, committing stops at that operation; see [`TreeTransactionError::applied()`].
+///
+/// Like the underlying `add_child()`, committing fails in Godot while a parent is busy setting up its children, e.g. within `ready()`
+/// of one of them. Commit from a deferred call in such cases.
+#[derive(Debug, Default)]
+pub struct TreeTransaction {
+    operations: Vec<TreeOperation>,
+}
+
+impl TreeTransaction {
+    /// Creates an empty transaction.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records adding `child` to `parent`. The child must not have a parent at this point of the transaction.
+    pub fn add_child<P, C>(&mut self, parent: &Gd<P>, child: &Gd<C>)
+    where
+        P: Inherits<Node>,
+        C: Inherits<Node>,
+    {
+        self.operations.push(TreeOperation::AddChild {
+            parent: node_of(parent),
+            child: node_of(child),
+        });
+    }
+
+    /// Records removing `child` from `parent`. The child must be a child of `parent` at this point of the transaction.
+    ///
+    /// The removed node is not freed, and remains in the responsibility of the caller.
+    pub fn remove_child<P, C>(&mut self, parent: &Gd<P>, child: &Gd<C>)
+    where
+        P: Inherits<Node>,
+        C: Inherits<Node>,
+    {
+        self.operations.push(TreeOperation::RemoveChild {
+            parent: node_of(parent),
+            child: node_of(child),
+        });
+    }
+
+    /// Records moving `child` from its current parent (if any) to `new_parent`, as its last child.
+    ///
+    /// Unlike `Node::reparent()`, the local transform is kept, not the global one.
+    pub fn reparent<C, P>(&mut self, child: &Gd<C>, new_parent: &Gd<P>)
+    where
+        C: Inherits<Node>,
+        P: Inherits<Node>,
+    {
+        self.operations.push(TreeOperation::Reparent {
+            child: node_of(child),
+            new_parent: node_of(new_parent),
+        });
+    }
+
+    /// Records removing `node` from its parent (if any), and queuing it for deletion.
+    ///
+    /// The node and its descendants are freed at the end of the frame. Descendants which are moved elsewhere by later operations of the
+    /// transaction are not freed. The node itself may not be used in later operations.
+    pub fn queue_free<T>(&mut self, node: &Gd<T>)
+    where
+        T: Inherits<Node>,
+    {
+        self.operations.push(TreeOperation::QueueFree {
+            node: node_of(node),
+        });
+    }
+
+    /// Number of recorded operations.
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns `true` if no operations have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Validates all recorded operations, and applies them if they are valid.
+    ///
+    /// Returns an error for the first operation which cannot be performed: because a node has been freed, already has a parent, is not
+    /// a child of the parent it is removed from, or would be added below itself.
+    pub fn commit(self) -> Result<(), TreeTransactionError> {
+        let mut simulation = Simulation::default();
+        for (index, operation) in self.operations.iter().enumerate() {
+            simulation
+                .check(operation)
+                .map_err(|(kind, node)| operation.error(kind, index, node))?;
+            simulation.record(operation);
+        }
+
+        for (index, operation) in self.operations.iter().enumerate() {
+            // Signal handlers of earlier operations may have changed the tree; check again against the actual state.
+            Simulation::default()
+                .check(operation)
+                .map_err(|(kind, node)| operation.error(kind, index, node).with_applied(index))?;
+            operation.apply();
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+#[derive(Debug)]
+enum TreeOperation {
+    AddChild {
+        parent: Gd<Node>,
+        child: Gd<Node>,
+    },
+    RemoveChild {
+        parent: Gd<Node>,
+        child: Gd<Node>,
+    },
+    Reparent {
+        child: Gd<Node>,
+        new_parent: Gd<Node>,
+    },
+    QueueFree {
+        node: Gd<Node>,
+    },
+}
+
+impl TreeOperation {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::AddChild { .. } => "add_child",
+            Self::RemoveChild { .. } => "remove_child",
+            Self::Reparent { .. } => "reparent",
+            Self::QueueFree { .. } => "queue_free",
+        }
+    }
+
+    fn error(
+        &self,
+        kind: TreeTransactionErrorKind,
+        index: usize,
+        node: &Gd<Node>,
+    ) -> TreeTransactionError {
+        TreeTransactionError::new(kind, self.name(), index, node.to_string())
+    }
+
+    fn apply(&self) {
+        match self {
+            Self::AddChild { parent, child } => {
+                parent.clone().add_child(child);
+            }
+            Self::RemoveChild { parent, child } => {
+                parent.clone().remove_child(child);
+            }
+            Self::Reparent { child, new_parent } => {
+                if let Some(mut old_parent) = child.get_parent() {
+                    old_parent.remove_child(child);
+                }
+                new_parent.clone().add_child(child);
+            }
+            Self::QueueFree { node } => {
+                if let Some(mut parent) = node.get_parent() {
+                    parent.remove_child(node);
+                }
+                node.clone().queue_free();
+            }
+        }
+    }
+}
+
+/// Tree state after the operations recorded so far. Nodes without entries are unaffected, so their actual parent applies.
+#[derive(Default)]
+struct Simulation {
+    parents: HashMap<InstanceId, Option<Gd<Node>>>,
+    freed: HashSet<InstanceId>,
+}
+
+type Failure<'a> = (TreeTransactionErrorKind, &'a Gd<Node>);
+
+impl Simulation {
+    fn check<'a>(&self, operation: &'a TreeOperation) -> Result<(), Failure<'a>> {
+        match operation {
+            TreeOperation::AddChild { parent, child } => {
+                self.check_alive(parent)?;
+                self.check_alive(child)?;
+                if self.parent(child).is_some() {
+                    return Err((TreeTransactionErrorKind::AlreadyParented, child));
+                }
+                self.check_acyclic(parent, child)
+            }
+            TreeOperation::RemoveChild { parent, child } => {
+                self.check_alive(parent)?;
+                self.check_alive(child)?;
+                if self.parent(child).as_ref() != Some(parent) {
+                    return Err((TreeTransactionErrorKind::NotChild, child));
+                }
+                Ok(())
+            }
+            TreeOperation::Reparent { child, new_parent } => {
+                self.check_alive(new_parent)?;
+                self.check_alive(child)?;
+                self.check_acyclic(new_parent, child)
+            }
+            TreeOperation::QueueFree { node } => self.check_alive(node),
+        }
+    }
+
+    fn record(&mut self, operation: &TreeOperation) {
+        match operation {
+            TreeOperation::AddChild { parent, child }
+            | TreeOperation::Reparent {
+                child,
+                new_parent: parent,
+            } => {
+                self.parents
+                    .insert(child.instance_id(), Some(parent.clone()));
+            }
+            TreeOperation::RemoveChild { child, .. } => {
+                self.parents.insert(child.instance_id(), None);
+            }
+            TreeOperation::QueueFree { node } => {
+                self.parents.insert(node.instance_id(), None);
+                self.freed.insert(node.instance_id());
+            }
+        }
+    }
+
+    fn parent(&self, node: &Gd<Node>) -> Option<Gd<Node>> {
+        match self.parents.get(&node.instance_id()) {
+            Some(parent) => parent.clone(),
+            None => node.get_parent(),
+        }
+    }
+
+    fn check_alive<'a>(&self, node: &'a Gd<Node>) -> Result<(), Failure<'a>> {
+        if node.is_instance_valid() && !self.freed.contains(&node.instance_id()) {
+            Ok(())
+        } else {
+            Err((TreeTransactionErrorKind::Freed, node))
+        }
+    }
+
+    /// Fails if `parent` is `child` itself or one of its descendants.
+    fn check_acyclic<'a>(&self, parent: &Gd<Node>, child: &'a Gd<Node>) -> Result<(), Failure<'a>> {
+        let mut ancestor = Some(parent.clone());
+        while let Some(node) = ancestor {
+            if node == *child {
+                return Err((TreeTransactionErrorKind::Cycle, child));
+            }
+            ancestor = self.parent(&node);
+        }
+        Ok(())
+    }
+}
+
+fn node_of<T>(node: &Gd<T>) -> Gd<Node>
+where
+    T: Inherits<Node>,
+{
+    node.clone().upcast()
+}
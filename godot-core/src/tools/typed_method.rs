@@ -0,0 +1,303 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::TypeId;
+use std::fmt;
+use std::marker::PhantomData;
+
+use godot_ffi::GodotFfi;
+
+use crate::builtin::{Dictionary, StringName, Variant, VariantArray, VariantType};
+use crate::classes::{ClassDb, Object};
+use crate::global::MethodFlags;
+use crate::meta::error::{ConvertError, SignatureError, SignatureErrorKind};
+use crate::meta::{FromGodot, GodotConvert, GodotType, PropertyInfo, ToGodot};
+use crate::obj::{EngineBitfield, Gd, Inherits};
+
+/// Method of a Godot class, looked up once by name and validated against the Rust signature `fn(Args) -> Ret`.
+///
+/// Methods called dynamically with [`Object::call()`] are only checked when called: a wrong name or parameter type is reported by Godot
+/// at that point. `TypedMethod` validates the method upfront instead, which is useful when calls are configured at runtime (e.g. from a
+/// scripting VM or a data-driven system):
+/// - On creation, the method is looked up in `ClassDB`, and its parameter and return types are checked against `Args` and `Ret`.
+/// - Calls then accept Rust values directly, and reuse the method name.
+///
+/// Calls are dispatched like with [`Object::call()`], i.e. the method is looked up by name and arguments are passed as variants each
+/// time. `TypedMethod` does not make calls faster: binding the method directly would require its hash, which `ClassDB` does not expose.
+///
+/// `Args` is a tuple of parameter types, such as `(i64, GString)`, or `()` for no parameters. Parameters declared as `Variant` in
+/// Godot accept any Rust type, and a `Variant` in the Rust signature accepts any Godot type. For methods with default parameters,
+/// `Args` may omit trailing parameters.
+///
+/// Only methods registered in `ClassDB` can be found, i.e. those of engine classes and `#[func]` methods of Rust classes. Methods
+/// defined in scripts are not supported.
+///
+/// # Hot reloading
+/// A `TypedMethod` refers to the class and method by name, so it does not dangle if the GDExtension library is reloaded. However, the
+/// validation is not repeated: if a method's signature changes across a reload, calls may fail. Do not keep `TypedMethod`s in
+/// `static`s or other state that survives a reload; create them again after the library is initialized.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::TypedMethod;
+///
+/// let set_name = TypedMethod::<(StringName,), ()>::new("Node", "set_name")
+///     .expect("signature matches");
+///
+/// let mut nodes = Vec::new();
+/// for i in 0..100 {
+///     let mut node = Node::new_alloc();
+///     set_name.call(&mut node, (StringName::from(format!("Node{i}")),));
+///     nodes.push(node);
+/// }
+/// # for node in nodes { node.free(); }
+/// ```
+pub struct TypedMethod<Args, Ret> {
+    class_name: StringName,
+    method_name: StringName,
+    _signature: PhantomData<fn(Args) -> Ret>,
+}
+
+impl<Args, Ret> TypedMethod<Args, Ret>
+where
+    Args: MethodArgs,
+    Ret: FromGodot + 'static,
+{
+    /// Looks up method `method_name` in class `class_name` (or one of its base classes) and validates its signature.
+    ///
+    /// Returns an error if the class or method does not exist, or if parameter count or types don't match `Args` and `Ret`.
+    pub fn new(
+        class_name: impl Into<StringName>,
+        method_name: impl Into<StringName>,
+    ) -> Result<Self, SignatureError> {
+        let class_name = class_name.into();
+        let method_name = method_name.into();
+        let method_name_str = method_name.to_string();
+        let error =
+            |kind| SignatureError::new(kind, class_name.to_string(), method_name.to_string());
+
+        let class_db = ClassDb::singleton();
+        if !class_db.class_exists(class_name.clone()) {
+            return Err(error(SignatureErrorKind::ClassNotFound));
+        }
+
+        let method_info = class_db
+            .class_get_method_list(class_name.clone())
+            .iter_shared()
+            .find(|method| {
+                method
+                    .get("name")
+                    .is_some_and(|name| name.stringify().to_string() == method_name_str)
+            })
+            .ok_or_else(|| error(SignatureErrorKind::MethodNotFound))?;
+
+        validate_signature::<Args, Ret>(&method_info).map_err(error)?;
+
+        Ok(Self {
+            class_name,
+            method_name,
+            _signature: PhantomData,
+        })
+    }
+
+    /// ⚠️ Calls the method on `object`, panicking if the call fails.
+    ///
+    /// # Panics
+    /// If the return value cannot be converted to `Ret`. In Debug mode, also if `object` is not an instance of the class that this
+    /// method was looked up in.
+    pub fn call<T>(&self, object: &mut Gd<T>, args: Args) -> Ret
+    where
+        T: Inherits<Object>,
+    {
+        self.try_call(object, args)
+            .unwrap_or_else(|err| panic!("TypedMethod::call() failed for {self}: {err}"))
+    }
+
+    /// Calls the method on `object` (fallible).
+    ///
+    /// Returns an error if the return value cannot be converted to `Ret`.
+    ///
+    /// # Panics
+    /// In Debug mode, if `object` is not an instance of the class that this method was looked up in.
+    pub fn try_call<T>(&self, object: &mut Gd<T>, args: Args) -> Result<Ret, ConvertError>
+    where
+        T: Inherits<Object>,
+    {
+        let object = object.upcast_mut::<Object>();
+
+        #[cfg(debug_assertions)]
+        assert!(
+            object.is_class(self.class_name.to_string().into()),
+            "TypedMethod {self} called on object of class {}",
+            object.get_class()
+        );
+
+        let result = object.call(self.method_name.clone(), &args.to_variants());
+
+        if is_unit::<Ret>() {
+            Ret::try_from_variant(&Variant::nil())
+        } else {
+            result.try_to()
+        }
+    }
+
+    /// Name of the class in which the method was looked up.
+    pub fn class_name(&self) -> &StringName {
+        &self.class_name
+    }
+
+    /// Name of the method.
+    pub fn method_name(&self) -> &StringName {
+        &self.method_name
+    }
+}
+
+impl<Args, Ret> Clone for TypedMethod<Args, Ret> {
+    fn clone(&self) -> Self {
+        Self {
+            class_name: self.class_name.clone(),
+            method_name: self.method_name.clone(),
+            _signature: PhantomData,
+        }
+    }
+}
+
+impl<Args, Ret> fmt::Display for TypedMethod<Args, Ret> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}", self.class_name, self.method_name)
+    }
+}
+
+impl<Args, Ret> fmt::Debug for TypedMethod<Args, Ret> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TypedMethod({self})")
+    }
+}
+
+fn validate_signature<Args, Ret>(method_info: &Dictionary) -> Result<(), SignatureErrorKind>
+where
+    Args: MethodArgs,
+    Ret: FromGodot + 'static,
+{
+    let params: Vec<PropertyInfo> = method_info
+        .get("args")
+        .and_then(|args| args.try_to::<VariantArray>().ok())
+        .map(|args| {
+            args.iter_shared()
+                .filter_map(|arg| arg.try_to::<Dictionary>().ok())
+                .map(|arg| PropertyInfo::from_dictionary(&arg))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let default_count = method_info
+        .get("default_args")
+        .and_then(|defaults| defaults.try_to::<VariantArray>().ok())
+        .map_or(0, |defaults| defaults.len());
+
+    let flags = method_info
+        .get("flags")
+        .and_then(|flags| flags.try_to::<u64>().ok())
+        .unwrap_or(0);
+    let is_vararg = flags & MethodFlags::VARARG.ord() != 0;
+
+    // Parameter count: trailing parameters with defaults may be omitted; varargs methods accept any number of extra arguments.
+    let rust_types = Args::param_types();
+    let min = params.len().saturating_sub(default_count);
+    let max = (!is_vararg).then_some(params.len());
+
+    if rust_types.len() < min || max.is_some_and(|max| rust_types.len() > max) {
+        return Err(SignatureErrorKind::ParamCount {
+            min,
+            max,
+            actual: rust_types.len(),
+        });
+    }
+
+    for (index, (param, &actual)) in params.iter().zip(&rust_types).enumerate() {
+        let expected = param.variant_type;
+        if !types_compatible(expected, actual) {
+            return Err(SignatureErrorKind::ParamType {
+                index,
+                expected,
+                actual,
+            });
+        }
+    }
+
+    // Return type: `()` requires a method without return value, while `Variant` accepts any return value.
+    let expected = method_info
+        .get("return")
+        .and_then(|ret| ret.try_to::<Dictionary>().ok())
+        .map_or(VariantType::NIL, |ret| {
+            PropertyInfo::from_dictionary(&ret).variant_type
+        });
+    let actual = variant_type_of::<Ret>();
+
+    let is_compatible = if is_unit::<Ret>() {
+        expected == VariantType::NIL
+    } else {
+        actual == VariantType::NIL || expected == actual
+    };
+
+    if !is_compatible {
+        return Err(SignatureErrorKind::ReturnType { expected, actual });
+    }
+
+    Ok(())
+}
+
+fn is_unit<T: 'static>() -> bool {
+    TypeId::of::<T>() == TypeId::of::<()>()
+}
+
+/// `NIL` stands for `Variant` on both sides, which accepts any type.
+fn types_compatible(expected: VariantType, actual: VariantType) -> bool {
+    expected == VariantType::NIL || actual == VariantType::NIL || expected == actual
+}
+
+fn variant_type_of<T: GodotConvert>() -> VariantType {
+    <<T as GodotConvert>::Via as GodotType>::Ffi::variant_type()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Parameter list of a [`TypedMethod`], implemented for tuples of up to 8 [`ToGodot`] types.
+pub trait MethodArgs {
+    /// Variant types of the parameters, in order.
+    #[doc(hidden)]
+    fn param_types() -> Vec<VariantType>;
+
+    /// Converts the arguments to variants, in order.
+    #[doc(hidden)]
+    fn to_variants(&self) -> Vec<Variant>;
+}
+
+macro_rules! impl_method_args {
+    ($($P:ident: $n:tt),*) => {
+        impl<$($P: ToGodot),*> MethodArgs for ($($P,)*) {
+            fn param_types() -> Vec<VariantType> {
+                vec![$(variant_type_of::<$P>()),*]
+            }
+
+            fn to_variants(&self) -> Vec<Variant> {
+                vec![$(self.$n.to_variant()),*]
+            }
+        }
+    };
+}
+
+impl_method_args!();
+impl_method_args!(P0: 0);
+impl_method_args!(P0: 0, P1: 1);
+impl_method_args!(P0: 0, P1: 1, P2: 2);
+impl_method_args!(P0: 0, P1: 1, P2: 2, P3: 3);
+impl_method_args!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4);
+impl_method_args!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5);
+impl_method_args!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6);
+impl_method_args!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6, P7: 7);
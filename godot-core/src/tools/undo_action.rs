@@ -0,0 +1,227 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::{Callable, GString, StringName, Variant, VariantArray};
+use crate::classes::{EditorUndoRedoManager, Object, UndoRedo};
+use crate::meta::ToGodot;
+use crate::obj::{Gd, Inherits};
+
+/// Undoable action, recorded by [`EditorUndoRedoManager::with_undo()`] and [`UndoRedo::with_undo()`].
+///
+/// Register the changes of the action with the `add_*` methods. They are only passed to the undo/redo manager once the closure which
+/// records them returns successfully, to be executed as one action. If it fails (returns `Err` or panics), nothing is registered, so
+/// that the manager is never left with a half-built action.
+///
+/// Like in Godot, both do- and undo-operations run in the order in which they were added.
+#[derive(Default)]
+pub struct UndoAction {
+    operations: Vec<Operation>,
+}
+
+impl UndoAction {
+    /// Sets `property` of `object` to `value`, and restores its current value when undone.
+    ///
+    /// This is the common case of an undoable property change, and equivalent to calling [`add_do_property()`][Self::add_do_property]
+    /// with `value` and [`add_undo_property()`][Self::add_undo_property] with the current value.
+    pub fn set_property<T>(
+        &mut self,
+        object: &Gd<T>,
+        property: impl Into<StringName>,
+        value: impl ToGodot,
+    ) where
+        T: Inherits<Object>,
+    {
+        let object = object.clone().upcast::<Object>();
+        let property = property.into();
+        let old_value = object.get(property.clone());
+
+        self.push(
+            Step::Do,
+            Change::Property(object.clone(), property.clone(), value.to_variant()),
+        );
+        self.push(Step::Undo, Change::Property(object, property, old_value));
+    }
+
+    /// Sets `property` of `object` to `value` when the action is done or redone.
+    pub fn add_do_property<T>(
+        &mut self,
+        object: &Gd<T>,
+        property: impl Into<StringName>,
+        value: impl ToGodot,
+    ) where
+        T: Inherits<Object>,
+    {
+        let change = Change::Property(object.clone().upcast(), property.into(), value.to_variant());
+        self.push(Step::Do, change);
+    }
+
+    /// Sets `property` of `object` to `value` when the action is undone.
+    pub fn add_undo_property<T>(
+        &mut self,
+        object: &Gd<T>,
+        property: impl Into<StringName>,
+        value: impl ToGodot,
+    ) where
+        T: Inherits<Object>,
+    {
+        let change = Change::Property(object.clone().upcast(), property.into(), value.to_variant());
+        self.push(Step::Undo, change);
+    }
+
+    /// Calls `method` on `object` with `args` when the action is done or redone.
+    pub fn add_do_method<T>(
+        &mut self,
+        object: &Gd<T>,
+        method: impl Into<StringName>,
+        args: &[Variant],
+    ) where
+        T: Inherits<Object>,
+    {
+        let change = Change::Method(object.clone().upcast(), method.into(), args.to_vec());
+        self.push(Step::Do, change);
+    }
+
+    /// Calls `method` on `object` with `args` when the action is undone.
+    pub fn add_undo_method<T>(
+        &mut self,
+        object: &Gd<T>,
+        method: impl Into<StringName>,
+        args: &[Variant],
+    ) where
+        T: Inherits<Object>,
+    {
+        let change = Change::Method(object.clone().upcast(), method.into(), args.to_vec());
+        self.push(Step::Undo, change);
+    }
+
+    /// Number of registered do- and undo-operations.
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns `true` if no operations have been registered.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    fn push(&mut self, step: Step, change: Change) {
+        self.operations.push(Operation { step, change });
+    }
+
+    /// Registers all operations as one action named `name`, and executes it. Empty actions are not registered.
+    pub(crate) fn commit_to(self, manager: &mut dyn UndoManager, name: GString) {
+        if self.is_empty() {
+            return;
+        }
+
+        manager.begin_action(name);
+        for Operation { step, change } in self.operations {
+            manager.add_change(step, change);
+        }
+        manager.finish_action();
+    }
+}
+
+impl fmt::Debug for UndoAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UndoAction")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct Operation {
+    step: Step,
+    change: Change,
+}
+
+#[derive(Copy, Clone)]
+pub(crate) enum Step {
+    Do,
+    Undo,
+}
+
+pub(crate) enum Change {
+    Property(Gd<Object>, StringName, Variant),
+    Method(Gd<Object>, StringName, Vec<Variant>),
+}
+
+/// Common operations of `UndoRedo` and `EditorUndoRedoManager`, which are unrelated classes.
+pub(crate) trait UndoManager {
+    fn begin_action(&mut self, name: GString);
+    fn add_change(&mut self, step: Step, change: Change);
+    fn finish_action(&mut self);
+}
+
+impl UndoManager for UndoRedo {
+    fn begin_action(&mut self, name: GString) {
+        UndoRedo::create_action(self, name);
+    }
+
+    fn add_change(&mut self, step: Step, change: Change) {
+        match (step, change) {
+            (Step::Do, Change::Property(object, property, value)) => {
+                self.add_do_property(&object, property, &value)
+            }
+            (Step::Undo, Change::Property(object, property, value)) => {
+                self.add_undo_property(&object, property, &value)
+            }
+            // UndoRedo takes callables instead of object and method name.
+            (Step::Do, Change::Method(object, method, args)) => {
+                self.add_do_method(bound_method(&object, method, args))
+            }
+            (Step::Undo, Change::Method(object, method, args)) => {
+                self.add_undo_method(bound_method(&object, method, args))
+            }
+        }
+    }
+
+    fn finish_action(&mut self) {
+        UndoRedo::commit_action(self);
+    }
+}
+
+impl UndoManager for EditorUndoRedoManager {
+    fn begin_action(&mut self, name: GString) {
+        EditorUndoRedoManager::create_action(self, name);
+    }
+
+    fn add_change(&mut self, step: Step, change: Change) {
+        match (step, change) {
+            (Step::Do, Change::Property(object, property, value)) => {
+                self.add_do_property(&object, property, &value)
+            }
+            (Step::Undo, Change::Property(object, property, value)) => {
+                self.add_undo_property(&object, property, &value)
+            }
+            (Step::Do, Change::Method(object, method, args)) => {
+                self.add_do_method(&object, method, &args)
+            }
+            (Step::Undo, Change::Method(object, method, args)) => {
+                self.add_undo_method(&object, method, &args)
+            }
+        }
+    }
+
+    fn finish_action(&mut self) {
+        EditorUndoRedoManager::commit_action(self);
+    }
+}
+
+fn bound_method(object: &Gd<Object>, method: StringName, args: Vec<Variant>) -> Callable {
+    let callable = Callable::from_object_method(object, method);
+    if args.is_empty() {
+        callable
+    } else {
+        callable.bindv(&args.into_iter().collect::<VariantArray>())
+    }
+}
@@ -0,0 +1,165 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::classes::Node;
+use crate::obj::{Gd, Inherits, InstanceId};
+
+/// Runs per-node update functions in an explicit order, independent of the scene tree.
+///
+/// Godot processes nodes in tree order, which makes the order of updates depend on the scene layout. Systems registered with
+/// [`register()`][Self::register] instead run in ascending order of their priority, each time [`run()`][Self::run] is called.
+/// Systems with equal priority run in the order in which they were registered. Typically, a single root node owns the scheduler
+/// and calls `run()` from its `process(delta)`.
+///
+/// Each system belongs to a node:
+/// - While the node is outside the scene tree, its systems are skipped.
+/// - Once the node is freed, its systems are removed.
+///
+/// Systems can also be removed explicitly with [`unregister()`][Self::unregister] or [`unregister_node()`][Self::unregister_node].
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::UpdateScheduler;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct World {
+///     scheduler: UpdateScheduler,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for World {
+///     fn ready(&mut self) {
+///         let player = self.base().get_node_as::<Node2D>("Player");
+///         self.scheduler.register(&player, 0, |mut player, delta| {
+///             let position = player.get_position() + Vector2::RIGHT * delta as f32;
+///             player.set_position(position);
+///         });
+///     }
+///
+///     fn process(&mut self, delta: f64) {
+///         self.scheduler.run(delta);
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct UpdateScheduler {
+    /// Sorted by priority; systems with equal priority in order of registration.
+    systems: Vec<System>,
+    next_id: u64,
+}
+
+impl UpdateScheduler {
+    /// Creates a scheduler without any systems.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `update` to be called with `node` and the frame time on each [`run()`][Self::run].
+    ///
+    /// Systems with lower `priority` run first. Returns an ID to [unregister][Self::unregister] the system.
+    pub fn register<T>(
+        &mut self,
+        node: &Gd<T>,
+        priority: i32,
+        mut update: impl FnMut(Gd<T>, f64) + 'static,
+    ) -> SystemId
+    where
+        T: Inherits<Node>,
+    {
+        let id = SystemId(self.next_id);
+        self.next_id += 1;
+
+        let system = System {
+            id,
+            priority,
+            node_id: node.instance_id(),
+            update: Box::new(move |node, delta| update(node.cast::<T>(), delta)),
+        };
+
+        let index = self
+            .systems
+            .partition_point(|system| system.priority <= priority);
+        self.systems.insert(index, system);
+
+        id
+    }
+
+    /// Removes the system with the given ID.
+    ///
+    /// Returns `false` if no such system is registered, e.g. because it has been removed already.
+    pub fn unregister(&mut self, id: SystemId) -> bool {
+        let len = self.systems.len();
+        self.systems.retain(|system| system.id != id);
+
+        self.systems.len() != len
+    }
+
+    /// Removes all systems of `node`, returning how many were removed.
+    pub fn unregister_node<T>(&mut self, node: &Gd<T>) -> usize
+    where
+        T: Inherits<Node>,
+    {
+        let node_id = node.instance_id();
+        let len = self.systems.len();
+        self.systems.retain(|system| system.node_id != node_id);
+
+        len - self.systems.len()
+    }
+
+    /// Runs all systems in order of their priority, passing `delta` as frame time.
+    ///
+    /// Systems of nodes outside the scene tree are skipped, and systems of freed nodes removed.
+    pub fn run(&mut self, delta: f64) {
+        self.systems.retain_mut(|system| {
+            let Ok(node) = Gd::<Node>::try_from_instance_id(system.node_id) else {
+                return false;
+            };
+
+            if node.is_inside_tree() {
+                (system.update)(node, delta);
+            }
+            true
+        });
+    }
+
+    /// Number of registered systems, including ones of nodes freed since the last [`run()`][Self::run].
+    pub fn len(&self) -> usize {
+        self.systems.len()
+    }
+
+    /// Returns `true` if no systems are registered.
+    pub fn is_empty(&self) -> bool {
+        self.systems.is_empty()
+    }
+}
+
+impl fmt::Debug for UpdateScheduler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UpdateScheduler")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// Identifies a system registered in an [`UpdateScheduler`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SystemId(u64);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct System {
+    id: SystemId,
+    priority: i32,
+    node_id: InstanceId,
+    update: Box<dyn FnMut(Gd<Node>, f64)>,
+}
@@ -0,0 +1,226 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::{self, ErrorKind, Read, Write};
+
+use crate::builtin::{PackedByteArray, Variant, VariantArray};
+use crate::global::{bytes_to_var, var_to_bytes};
+use crate::meta::ToGodot;
+
+/// Bytes at the start of every variant stream.
+const MAGIC: &[u8; 4] = b"GDVS";
+
+/// Version of the framing, stored after [`MAGIC`].
+const VERSION: u8 = 1;
+
+/// Writes a sequence of variants to a byte stream, one at a time.
+///
+/// Encoding a large array with `var_to_bytes()` needs the whole array and its encoded form in memory at once. With `VariantWriter`, each
+/// value is encoded and written on its own, so that large data sets (e.g. thousands of entities in a save file) can be produced
+/// incrementally. The stream can be any [`Write`] implementation, for example a [`GFile`][crate::tools::GFile] or a `Vec<u8>`.
+/// Wrap unbuffered writers in a [`BufWriter`][std::io::BufWriter], as each value causes several small writes.
+///
+/// Read the stream back with [`VariantReader`].
+///
+/// # Format
+/// The stream starts with a header of 5 bytes: the ASCII characters `GDVS`, followed by the format version `1` as a single byte.
+///
+/// Each value is stored as a record: its length in bytes as a little-endian `u32`, followed by the value encoded with Godot's
+/// `var_to_bytes()`. Objects are not encoded. There is no terminator; the stream ends after the last record.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::classes::file_access::ModeFlags;
+/// use godot::tools::{GFile, VariantWriter};
+/// use std::io::BufWriter;
+///
+/// fn save_entities(entities: impl Iterator<Item = Dictionary>) -> std::io::Result<()> {
+///     let file = GFile::open("user://entities.dat", ModeFlags::WRITE)?;
+///     let mut writer = VariantWriter::new(BufWriter::new(file))?;
+///
+///     for entity in entities {
+///         writer.write(&entity)?;
+///     }
+///
+///     writer.into_inner()?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct VariantWriter<W: Write> {
+    inner: W,
+    count: usize,
+}
+
+impl<W: Write> VariantWriter<W> {
+    /// Starts a new stream on `inner`, writing the header.
+    pub fn new(mut inner: W) -> io::Result<Self> {
+        inner.write_all(MAGIC)?;
+        inner.write_all(&[VERSION])?;
+
+        Ok(Self { inner, count: 0 })
+    }
+
+    /// Encodes `value` and writes it as the next record.
+    pub fn write<T: ToGodot>(&mut self, value: &T) -> io::Result<()> {
+        let bytes = var_to_bytes(&value.to_variant());
+        let len = u32::try_from(bytes.len()).map_err(|_| {
+            io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("encoded variant too large ({} bytes)", bytes.len()),
+            )
+        })?;
+
+        self.inner.write_all(&len.to_le_bytes())?;
+        self.inner.write_all(bytes.as_slice())?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Writes each element of `array` as a separate record.
+    ///
+    /// The array itself is not stored, so the elements can be read back one by one, or in chunks of any size.
+    pub fn write_elements(&mut self, array: &VariantArray) -> io::Result<()> {
+        for element in array.iter_shared() {
+            self.write(&element)?;
+        }
+        Ok(())
+    }
+
+    /// Number of records written so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Flushes the underlying stream.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// Flushes and returns the underlying stream.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+/// Reads a sequence of variants from a byte stream, one at a time.
+///
+/// Counterpart to [`VariantWriter`], see there for the format. Only one record is held in memory at a time. Besides reading single
+/// values with [`read()`][Self::read], the reader can load [chunks][Self::read_chunk] of several values, and is an [`Iterator`] over all
+/// remaining values.
+///
+/// Wrap unbuffered readers in a [`BufReader`][std::io::BufReader], as each value causes several small reads.
+#[derive(Debug)]
+pub struct VariantReader<R: Read> {
+    inner: R,
+    count: usize,
+}
+
+impl<R: Read> VariantReader<R> {
+    /// Starts reading a stream from `inner`, checking the header.
+    ///
+    /// Returns an error of kind [`InvalidData`][ErrorKind::InvalidData] if the stream was not written by [`VariantWriter`], or with
+    /// another format version.
+    pub fn new(mut inner: R) -> io::Result<Self> {
+        let mut header = [0u8; 5];
+        inner.read_exact(&mut header)?;
+
+        if &header[..4] != MAGIC {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "not a variant stream (header mismatch)",
+            ));
+        }
+        if header[4] != VERSION {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                format!("unsupported variant stream version {}", header[4]),
+            ));
+        }
+
+        Ok(Self { inner, count: 0 })
+    }
+
+    /// Reads the next value, or returns `None` at the end of the stream.
+    ///
+    /// Returns an error of kind [`UnexpectedEof`][ErrorKind::UnexpectedEof] if the stream ends in the middle of a record.
+    pub fn read(&mut self) -> io::Result<Option<Variant>> {
+        let mut len_bytes = [0u8; 4];
+        if !self.read_len(&mut len_bytes)? {
+            return Ok(None);
+        }
+
+        let len = u32::from_le_bytes(len_bytes) as u64;
+
+        // Don't allocate the length upfront, to not trust corrupted data.
+        let mut bytes = Vec::new();
+        self.inner.by_ref().take(len).read_to_end(&mut bytes)?;
+        if (bytes.len() as u64) < len {
+            return Err(io::Error::new(
+                ErrorKind::UnexpectedEof,
+                format!("variant record truncated ({} of {len} bytes)", bytes.len()),
+            ));
+        }
+
+        self.count += 1;
+        Ok(Some(bytes_to_var(&PackedByteArray::from(bytes))))
+    }
+
+    /// Reads up to `max_len` values into an array.
+    ///
+    /// The array is shorter than `max_len` only at the end of the stream, and empty once the stream has been fully read.
+    pub fn read_chunk(&mut self, max_len: usize) -> io::Result<VariantArray> {
+        let mut chunk = VariantArray::new();
+        while chunk.len() < max_len {
+            match self.read()? {
+                Some(value) => chunk.push(&value),
+                None => break,
+            }
+        }
+        Ok(chunk)
+    }
+
+    /// Number of records read so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the underlying stream.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Fills `buf` with the length prefix of the next record. Returns `false` if the stream ends before the record.
+    fn read_len(&mut self, buf: &mut [u8; 4]) -> io::Result<bool> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            match self.inner.read(&mut buf[filled..]) {
+                Ok(0) if filled == 0 => return Ok(false),
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "variant record length truncated",
+                    ))
+                }
+                Ok(n) => filled += n,
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl<R: Read> Iterator for VariantReader<R> {
+    type Item = io::Result<Variant>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
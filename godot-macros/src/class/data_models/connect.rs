@@ -0,0 +1,136 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::util::KvParser;
+use crate::{util, ParseResult};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+
+/// Holds information from a `#[connect(from = "Child", signal = "name")]` attribute on a `#[func]` method.
+pub struct ConnectDefinition {
+    /// Name of the method, as registered in Godot.
+    pub godot_method_name: String,
+
+    /// Expression evaluating to the child's node path (relative to the object).
+    pub from: TokenStream,
+
+    /// Expression evaluating to the signal's name.
+    pub signal: TokenStream,
+
+    /// Span of the `#[connect]` attribute, for errors if the class is not declared with `#[class(autoconnect)]`.
+    pub span: Span,
+
+    /// The method's `#[cfg]` attributes.
+    pub cfg_attrs: Vec<venial::Attribute>,
+}
+
+/// Removes all `#[connect]` attributes from `function` and returns their spans and parsed `(from, signal)` keys.
+pub fn extract_connect_attributes(
+    function: &mut venial::Function,
+) -> ParseResult<Vec<(Span, TokenStream, TokenStream)>> {
+    let mut connects = vec![];
+    let mut index = 0;
+
+    while let Some(attr) = function.attributes.get(index) {
+        if attr
+            .get_single_path_segment()
+            .map_or(true, |name| name != "connect")
+        {
+            index += 1;
+            continue;
+        }
+
+        // Safe unwrap since #[connect] is present at this position.
+        let mut parser = KvParser::parse(std::slice::from_ref(attr), "connect")?.unwrap();
+        let span = parser.span();
+        let from = parser.handle_expr_required("from")?;
+        let signal = parser.handle_expr_required("signal")?;
+        parser.finish()?;
+
+        connects.push((span, from, signal));
+        function.attributes.remove(index);
+    }
+
+    Ok(connects)
+}
+
+pub fn make_autoconnect_fn(class_name: &Ident, connects: &[ConnectDefinition]) -> TokenStream {
+    // Like for RPCs, the class might not implement `WithBaseField` or `Inherits<Node>`, so only emit code if needed.
+    if connects.is_empty() {
+        return TokenStream::new();
+    }
+
+    let class_name_str = class_name.to_string();
+    let connections = connects.iter().map(|connect| {
+        let ConnectDefinition {
+            godot_method_name,
+            from,
+            signal,
+            span,
+            cfg_attrs,
+        } = connect;
+
+        // The struct is declared in another macro invocation, so whether it has `#[class(autoconnect)]` is checked by the compiler.
+        // The class name is re-spanned, so the error points to the `#[connect]` attribute.
+        let class_name = Ident::new(&class_name.to_string(), *span);
+        let autoconnect_check = quote_spanned! {*span=>
+            const _: () = ::godot::private::is_autoconnect_class::<#class_name>();
+        };
+
+        quote! {
+            #(#cfg_attrs)*
+            #autoconnect_check
+
+            #(#cfg_attrs)*
+            ::godot::register::private::connect_child_signal(
+                node,
+                #from,
+                #signal,
+                gd.callable(#godot_method_name),
+                #class_name_str,
+                #godot_method_name,
+            );
+        }
+    });
+
+    quote! {
+        fn __autoconnect_signals(object: &mut dyn ::std::any::Any) {
+            use ::godot::classes::Node;
+            use ::godot::obj::WithBaseField;
+
+            let gd = object
+                .downcast_mut::<#class_name>()
+                .expect("bad type erasure when connecting signals")
+                .to_gd();
+
+            let node = gd.upcast_ref::<Node>();
+            #( #connections )*
+        }
+    }
+}
+
+pub fn make_connect_definitions(
+    godot_method_name: String,
+    function_attributes: &[venial::Attribute],
+    connects: Vec<(Span, TokenStream, TokenStream)>,
+) -> Vec<ConnectDefinition> {
+    let cfg_attrs: Vec<venial::Attribute> = util::extract_cfg_attrs(function_attributes)
+        .into_iter()
+        .cloned()
+        .collect();
+
+    connects
+        .into_iter()
+        .map(|(span, from, signal)| ConnectDefinition {
+            godot_method_name: godot_method_name.clone(),
+            from,
+            signal,
+            span,
+            cfg_attrs: cfg_attrs.clone(),
+        })
+        .collect()
+}
@@ -12,7 +12,7 @@ use crate::class::{
     into_signature_info, make_existence_check, make_method_registration, Field, FieldHint,
     FuncDefinition,
 };
-use crate::util::KvParser;
+use crate::util::{bail, KvParser};
 use crate::{util, ParseResult};
 
 /// Store info from `#[var]` attribute.
@@ -22,6 +22,12 @@ pub struct FieldVar {
     pub setter: GetterSetter,
     pub hint: FieldHint,
     pub usage_flags: UsageFlags,
+
+    /// Whether the generated setter emits `Resource::changed`.
+    pub on_change: bool,
+
+    /// Name of the property in Godot, if different from the field name.
+    pub rename: Option<Ident>,
 }
 
 impl FieldVar {
@@ -33,6 +39,8 @@ impl FieldVar {
     /// - `hint = ident`
     /// - `hint_string = expr`
     /// - `usage_flags =
+    /// - `on_change`
+    /// - `rename = ident`
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let mut getter = GetterSetter::parse(parser, "get")?;
         let mut setter = GetterSetter::parse(parser, "set")?;
@@ -42,6 +50,17 @@ impl FieldVar {
             setter = GetterSetter::Generated;
         }
 
+        let on_change = parser.handle_alone_with_span("on_change")?;
+        if let Some(span) = &on_change {
+            if setter != GetterSetter::Generated {
+                return bail!(
+                    span,
+                    "#[var(on_change)] requires a generated setter; call `emit_changed()` in custom setters instead"
+                );
+            }
+        }
+
+        let rename = parser.handle_ident("rename")?;
         let hint = parser.handle_ident("hint")?;
 
         let hint = if let Some(hint) = hint {
@@ -71,6 +90,8 @@ impl FieldVar {
             setter,
             hint,
             usage_flags,
+            on_change: on_change.is_some(),
+            rename,
         })
     }
 }
@@ -113,11 +134,12 @@ impl GetterSetter {
         class_name: &Ident,
         kind: GetSet,
         field: &Field,
+        on_change: bool,
     ) -> Option<GetterSetterImpl> {
         match self {
             GetterSetter::Omitted => None,
             GetterSetter::Generated => Some(GetterSetterImpl::from_generated_impl(
-                class_name, kind, field,
+                class_name, kind, field, on_change,
             )),
             GetterSetter::Custom(function_name) => {
                 Some(GetterSetterImpl::from_custom_impl(function_name))
@@ -154,7 +176,12 @@ pub struct GetterSetterImpl {
 }
 
 impl GetterSetterImpl {
-    fn from_generated_impl(class_name: &Ident, kind: GetSet, field: &Field) -> Self {
+    fn from_generated_impl(
+        class_name: &Ident,
+        kind: GetSet,
+        field: &Field,
+        on_change: bool,
+    ) -> Self {
         let Field {
             name: field_name,
             ty: field_type,
@@ -179,8 +206,13 @@ impl GetterSetterImpl {
                 signature = quote! {
                     fn #function_name(&mut self, #field_name: <#field_type as ::godot::meta::GodotConvert>::Via)
                 };
+                let emit_changed = on_change.then(|| {
+                    quote! { ::godot::register::private::emit_changed(self); }
+                });
+
                 function_body = quote! {
                     <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, #field_name);
+                    #emit_changed
                 };
             }
         }
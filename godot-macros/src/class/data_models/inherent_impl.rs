@@ -5,8 +5,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 use crate::class::{
-    into_signature_info, make_constant_registration, make_method_registration,
-    make_signal_registrations, ConstDefinition, FuncDefinition, RpcAttr, RpcMode, SignalDefinition,
+    extract_connect_attributes, into_signature_info, make_autoconnect_fn, make_connect_definitions,
+    make_constant_registration, make_method_registration, make_signal_registrations,
+    ConnectDefinition, ConstDefinition, FuncDefinition, RpcAttr, RpcMode, SignalDefinition,
     SignatureInfo, TransferMode,
 };
 use crate::util::{bail, ident, require_api_version, KvParser};
@@ -21,6 +22,7 @@ enum ItemAttrType {
     Func(FuncAttr, Option<RpcAttr>),
     Signal(venial::AttributeValue),
     Const(#[allow(dead_code)] venial::AttributeValue),
+    Iter,
 }
 
 struct ItemAttr {
@@ -40,6 +42,7 @@ enum AttrParseResult {
     FuncRpc(FuncAttr, RpcAttr),
     Signal(venial::AttributeValue),
     Const(#[allow(dead_code)] venial::AttributeValue),
+    Iter,
 }
 
 impl AttrParseResult {
@@ -51,6 +54,7 @@ impl AttrParseResult {
             AttrParseResult::FuncRpc(func, rpc) => ItemAttrType::Func(func, Some(rpc)),
             AttrParseResult::Signal(signal) => ItemAttrType::Signal(signal),
             AttrParseResult::Const(constant) => ItemAttrType::Const(constant),
+            AttrParseResult::Iter => ItemAttrType::Iter,
         }
     }
 }
@@ -71,7 +75,7 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
     let prv = quote! { ::godot::private };
 
     // Can add extra functions to the end of the impl block.
-    let (funcs, signals) = process_godot_fns(&class_name, &mut impl_block)?;
+    let (funcs, signals, connects) = process_godot_fns(&class_name, &mut impl_block)?;
     let consts = process_godot_constants(&mut impl_block)?;
 
     #[cfg(all(feature = "docs", since_api = "4.3"))]
@@ -86,6 +90,8 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
     #[cfg(not(feature = "codegen-full"))]
     let rpc_registrations = TokenStream::new();
 
+    let autoconnect_fn = make_autoconnect_fn(&class_name, &connects);
+
     let method_registrations: Vec<TokenStream> = funcs
         .into_iter()
         .map(|func_def| make_method_registration(&class_name, func_def))
@@ -107,6 +113,7 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
             }
 
             #rpc_registrations
+            #autoconnect_fn
         }
 
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
@@ -118,6 +125,9 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
                 register_rpcs_fn: Some(#prv::ErasedRegisterRpcsFn {
                     raw: #prv::callbacks::register_user_rpcs::<#class_name>,
                 }),
+                autoconnect_signals_fn: Some(#prv::ErasedAutoconnectFn {
+                    raw: #prv::callbacks::autoconnect_user_signals::<#class_name>,
+                }),
                 #docs
             }),
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
@@ -130,10 +140,16 @@ pub fn transform_inherent_impl(mut impl_block: venial::Impl) -> ParseResult<Toke
 fn process_godot_fns(
     class_name: &Ident,
     impl_block: &mut venial::Impl,
-) -> ParseResult<(Vec<FuncDefinition>, Vec<SignalDefinition>)> {
+) -> ParseResult<(
+    Vec<FuncDefinition>,
+    Vec<SignalDefinition>,
+    Vec<ConnectDefinition>,
+)> {
     let mut func_definitions = vec![];
     let mut signal_definitions = vec![];
+    let mut connect_definitions = vec![];
     let mut virtual_functions = vec![];
+    let mut iter_functions = vec![];
 
     let mut removed_indexes = vec![];
     for (index, item) in impl_block.body_items.iter_mut().enumerate() {
@@ -141,7 +157,16 @@ fn process_godot_fns(
             continue;
         };
 
+        // #[connect] can appear multiple times, so it's processed separately from the other attributes.
+        let connects = extract_connect_attributes(function)?;
+
         let Some(attr) = extract_attributes(function)? else {
+            if !connects.is_empty() {
+                return bail!(
+                    &function.name,
+                    "#[connect] can only be used on #[func] methods"
+                );
+            }
             continue;
         };
 
@@ -163,6 +188,16 @@ fn process_godot_fns(
             ItemAttrType::Func(func, rpc_info) => {
                 let external_attributes = function.attributes.clone();
 
+                let godot_method_name = func
+                    .rename
+                    .clone()
+                    .unwrap_or_else(|| function.name.to_string());
+                connect_definitions.extend(make_connect_definitions(
+                    godot_method_name,
+                    &external_attributes,
+                    connects,
+                ));
+
                 // Signatures are the same thing without body.
                 let mut signature = util::reduce_to_signature(function);
                 let gd_self_parameter = if func.has_gd_self {
@@ -216,6 +251,10 @@ fn process_godot_fns(
                 });
             }
             ItemAttrType::Signal(ref _attr_val) => {
+                if !connects.is_empty() {
+                    return attr.bail("#[connect] can only be used on #[func] methods", function);
+                }
+
                 if function.return_ty.is_some() {
                     return attr.bail("return types are not supported", function);
                 }
@@ -236,6 +275,34 @@ fn process_godot_fns(
                     function,
                 )
             }
+            ItemAttrType::Iter => {
+                if !connects.is_empty() {
+                    return attr.bail("#[connect] can only be used on #[func] methods", function);
+                }
+
+                if !iter_functions.is_empty() {
+                    return attr.bail("only one #[iter] method is allowed per class", function);
+                }
+
+                let is_ref_receiver = matches!(
+                    function.params.inner.first(),
+                    Some((venial::FnParam::Receiver(recv), _)) if recv.tk_ref.is_some() && recv.tk_mut.is_none()
+                );
+                if !is_ref_receiver || function.params.len() != 1 {
+                    return attr.bail(
+                        "the method must take `&self` as its only parameter",
+                        function,
+                    );
+                }
+
+                if function.return_ty.is_none() {
+                    return attr.bail("the method must return an iterator", function);
+                }
+
+                let (functions, definitions) = make_iter_protocol(function, class_name);
+                iter_functions.extend(functions);
+                func_definitions.extend(definitions);
+            }
         }
     }
 
@@ -251,7 +318,13 @@ fn process_godot_fns(
         impl_block.body_items.push(member);
     }
 
-    Ok((func_definitions, signal_definitions))
+    // Same for the methods implementing the GDScript iterator protocol.
+    for f in iter_functions.into_iter() {
+        let member = venial::ImplMember::AssocFunction(f);
+        impl_block.body_items.push(member);
+    }
+
+    Ok((func_definitions, signal_definitions, connect_definitions))
 }
 
 fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefinition>> {
@@ -270,6 +343,9 @@ fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefi
                 ItemAttrType::Signal(_) => {
                     return bail!(constant, "#[signal] can only be used on functions")
                 }
+                ItemAttrType::Iter => {
+                    return bail!(constant, "#[iter] can only be used on functions")
+                }
                 ItemAttrType::Const(_) => {
                     if constant.initializer.is_none() {
                         return bail!(constant, "exported constant must have initializer");
@@ -362,6 +438,73 @@ fn add_virtual_script_call(
     virtual_functions.push(early_bound_function);
 }
 
+/// Generates `_iter_init`, `_iter_next` and `_iter_get`, which iterate over the iterator returned by the `#[iter]` method `function`.
+fn make_iter_protocol(
+    function: &venial::Function,
+    class_name: &Ident,
+) -> (Vec<venial::Function>, Vec<FuncDefinition>) {
+    // Only #[cfg] attributes are transported; docs of the iterator method don't apply to the protocol methods.
+    let cfg_attrs: Vec<venial::Attribute> = util::extract_cfg_attrs(&function.attributes)
+        .into_iter()
+        .cloned()
+        .collect();
+
+    let iter_fn = &function.name;
+    let protocol = [
+        (
+            "_iter_init",
+            quote! {
+                #[doc(hidden)]
+                fn __godot_iter_init(&self, state: ::godot::builtin::VariantArray) -> bool {
+                    ::godot::tools::ScriptIterator::init(state, Self::#iter_fn(self))
+                }
+            },
+        ),
+        (
+            "_iter_next",
+            quote! {
+                #[doc(hidden)]
+                fn __godot_iter_next(&self, state: ::godot::builtin::VariantArray) -> bool {
+                    ::godot::tools::ScriptIterator::next(state)
+                }
+            },
+        ),
+        (
+            "_iter_get",
+            quote! {
+                #[doc(hidden)]
+                fn __godot_iter_get(&self, state: ::godot::builtin::Variant) -> ::godot::builtin::Variant {
+                    ::godot::tools::ScriptIterator::get(&state)
+                }
+            },
+        ),
+    ];
+
+    let mut functions = Vec::with_capacity(protocol.len());
+    let mut definitions = Vec::with_capacity(protocol.len());
+    for (godot_name, code) in protocol {
+        let mut generated = venial::parse_item(code)
+            .unwrap()
+            .as_function()
+            .unwrap()
+            .clone();
+
+        let signature = util::reduce_to_signature(&generated);
+        definitions.push(FuncDefinition {
+            signature_info: into_signature_info(signature, class_name, false),
+            external_attributes: cfg_attrs.clone(),
+            rename: Some(godot_name.to_string()),
+            is_script_virtual: false,
+            rpc_info: None,
+        });
+
+        generated.attributes.splice(0..0, cfg_attrs.iter().cloned());
+        functions.push(generated);
+    }
+
+    (functions, definitions)
+}
+
 fn extract_attributes<T>(item: &mut T) -> ParseResult<Option<ItemAttr>>
 where
     for<'a> &'a T: Spanned,
@@ -470,6 +613,12 @@ where
             // #[constant]
             name if name == "constant" => AttrParseResult::Const(attr.value.clone()),
 
+            // #[iter]
+            name if name == "iter" => {
+                require_api_version!("4.2", attr_name, "#[iter]")?;
+                AttrParseResult::Iter
+            }
+
             // Ignore unknown attributes.
             _ => continue,
         };
@@ -63,15 +63,17 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             continue;
         };
 
-        let field_name = field_ident.to_string();
-
         let FieldVar {
             getter,
             setter,
             hint,
             mut usage_flags,
+            on_change,
+            rename,
         } = var;
 
+        let field_name = rename.as_ref().unwrap_or(field_ident).to_string();
+
         let export_hint;
         let registration_fn;
 
@@ -134,12 +136,12 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         };
 
         let getter_name = make_getter_setter(
-            getter.to_impl(class_name, GetSet::Get, field),
+            getter.to_impl(class_name, GetSet::Get, field, false),
             &mut getter_setter_impls,
             &mut export_tokens,
         );
         let setter_name = make_getter_setter(
-            setter.to_impl(class_name, GetSet::Set, field),
+            setter.to_impl(class_name, GetSet::Set, field, on_change),
             &mut getter_setter_impls,
             &mut export_tokens,
         );
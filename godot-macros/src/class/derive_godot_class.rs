@@ -77,8 +77,12 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         TokenStream::new()
     };
 
-    let (user_class_impl, has_default_virtual) =
-        make_user_class_impl(class_name, struct_cfg.is_tool, &fields.all_fields);
+    let (user_class_impl, has_default_virtual) = make_user_class_impl(
+        class_name,
+        struct_cfg.is_tool,
+        struct_cfg.is_autoconnect,
+        &fields.all_fields,
+    );
 
     let mut init_expecter = TokenStream::new();
     let mut godot_init_impl = TokenStream::new();
@@ -211,6 +215,7 @@ struct ClassAttributes {
     init_strategy: InitStrategy,
     is_tool: bool,
     is_internal: bool,
+    is_autoconnect: bool,
     rename: Option<Ident>,
     deprecations: Vec<TokenStream>,
 }
@@ -253,6 +258,7 @@ fn make_godot_init_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
 fn make_user_class_impl(
     class_name: &Ident,
     is_tool: bool,
+    is_autoconnect: bool,
     all_fields: &[Field],
 ) -> (TokenStream, bool) {
     #[cfg(feature = "codegen-full")]
@@ -285,7 +291,16 @@ fn make_user_class_impl(
         }
     };
 
-    let default_virtual_fn = if all_fields.iter().any(|field| field.is_onready) {
+    let (autoconnect, autoconnect_marker) = if is_autoconnect {
+        (
+            quote! { ::godot::register::private::auto_connect_signals::<#class_name>(self); },
+            quote! { impl ::godot::private::You_forgot_the_attribute__class_autoconnect for #class_name {} },
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+
+    let default_virtual_fn = if is_autoconnect || all_fields.iter().any(|field| field.is_onready) {
         let tool_check = util::make_virtual_tool_check();
         let signature_info = SignatureInfo::fn_ready();
 
@@ -318,10 +333,13 @@ fn make_user_class_impl(
             fn __before_ready(&mut self) {
                 #rpc_registrations
                 #onready_inits
+                #autoconnect
             }
 
             #default_virtual_fn
         }
+
+        #autoconnect_marker
     };
 
     (user_class_impl, default_virtual_fn.is_some())
@@ -333,6 +351,7 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
     let mut init_strategy = InitStrategy::UserDefined;
     let mut is_tool = false;
     let mut is_internal = false;
+    let mut is_autoconnect = false;
     let mut rename: Option<Ident> = None;
     let mut deprecations = vec![];
 
@@ -382,6 +401,11 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             });
         }
 
+        // #[class(autoconnect)]
+        if parser.handle_alone("autoconnect")? {
+            is_autoconnect = true;
+        }
+
         parser.finish()?;
     }
 
@@ -392,6 +416,7 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         init_strategy,
         is_tool,
         is_internal,
+        is_autoconnect,
         rename,
         deprecations,
     })
@@ -8,6 +8,7 @@
 mod derive_godot_class;
 mod godot_api;
 mod data_models {
+    pub mod connect;
     pub mod constant;
     pub mod field;
     pub mod field_export;
@@ -21,6 +22,7 @@ mod data_models {
     pub mod signal;
 }
 
+pub(crate) use data_models::connect::*;
 pub(crate) use data_models::constant::*;
 pub(crate) use data_models::field::*;
 pub(crate) use data_models::field_export::*;
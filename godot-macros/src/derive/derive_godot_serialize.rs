@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::util::{bail, path_is_single, KvParser};
+use crate::ParseResult;
+
+/// Derives `GodotSerialize` for the given class, serializing all `#[var]` and `#[export]` fields under their property names.
+pub fn derive_godot_serialize(item: venial::Item) -> ParseResult<TokenStream> {
+    let venial::Item::Struct(class) = &item else {
+        return bail!(
+            &item,
+            "#[derive(GodotSerialize)] is only supported for structs"
+        );
+    };
+
+    let venial::Fields::Named(named_fields) = &class.fields else {
+        return bail!(
+            &class.fields,
+            "#[derive(GodotSerialize)] is only supported for structs with named fields"
+        );
+    };
+
+    let mut field_names = Vec::new();
+    let mut field_strs = Vec::new();
+    for (field, _punct) in named_fields.fields.iter() {
+        let var = KvParser::parse(&field.attributes, "var")?;
+        let is_export = field
+            .attributes
+            .iter()
+            .any(|attr| path_is_single(&attr.path, "export"));
+        if var.is_none() && !is_export {
+            continue;
+        }
+
+        // Stored under the property name that Godot sees. The other #[var] keys are validated by #[derive(GodotClass)].
+        let rename = match var {
+            Some(mut parser) => parser.handle_ident("rename")?,
+            None => None,
+        };
+
+        field_strs.push(rename.as_ref().unwrap_or(&field.name).to_string());
+        field_names.push(&field.name);
+    }
+
+    let field_strs_2 = field_strs.clone();
+    let class_name = &class.name;
+
+    Ok(quote! {
+        impl ::godot::tools::GodotSerialize for #class_name {
+            fn serialize_fields(&self) -> ::godot::builtin::Dictionary {
+                let mut fields = ::godot::builtin::Dictionary::new();
+                #(
+                    fields.set(#field_strs, ::godot::tools::__serialize_field(&self.#field_names));
+                )*
+                fields
+            }
+
+            fn deserialize_fields(
+                &mut self,
+                fields: &::godot::builtin::Dictionary,
+                resolver: &mut dyn FnMut(::godot::obj::InstanceId) -> Option<::godot::obj::Gd<::godot::classes::Object>>,
+            ) -> Result<(), ::godot::meta::error::ConvertError> {
+                #(
+                    if let Some(value) = fields.get(#field_strs_2) {
+                        self.#field_names = ::godot::tools::__deserialize_field(value, resolver)?;
+                    }
+                )*
+                Ok(())
+            }
+        }
+    })
+}
@@ -219,6 +219,46 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// To register the property under a different name than the field, use `rename`. The generated getter and setter are still named
+/// after the field:
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     // Accessible as `my_struct.speed` in GDScript.
+///     #[var(rename = speed)]
+///     max_speed: f64,
+/// }
+/// ```
+///
+/// ## Change notifications
+///
+/// Resources are expected to emit their `changed` signal when a property is modified, so that the editor and other users of the
+/// resource can refresh. With `#[var(on_change)]`, the generated setter emits `changed` after updating the field:
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Resource)]
+/// struct Stats {
+///     #[export]
+///     #[var(on_change)]
+///     strength: i64,
+///
+///     base: Base<Resource>,
+/// }
+/// ```
+///
+/// This requires the class to inherit `Resource` and have a `Base<T>` field. It cannot be combined with a custom setter (`set = ...`);
+/// call `self.base_mut().emit_changed()` there instead. Initial values from `init()` or field defaults don't go through the setter,
+/// and thus don't emit `changed`.
+///
+/// Godot has no way to tell loading apart from editing, so values it assigns through the setter emit `changed` as well. This includes
+/// stored properties set by `ResourceLoader::load()` or when instantiating a `PackedScene`, as well as `duplicate()`. Handlers that
+/// should only see edits are best connected after the resource is loaded.
+///
 /// ## Property exports
 ///
 /// For exporting properties to the editor, you can use the `#[export]` attribute:
@@ -348,6 +388,50 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// ## Auto-connecting child signals
+///
+/// With `#[class(autoconnect)]`, methods annotated with `#[connect(from = "path/to/Child", signal = "signal_name")]` are connected to the
+/// signals of child nodes, right before `ready()` runs. This is similar to connecting signals in the editor, and avoids manual `connect()`
+/// calls. The class must inherit `Node`, and the methods must be `#[func]`. A method can have multiple `#[connect]` attributes.
+///
+/// If the child node or its signal do not exist at that time, an error is printed.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, autoconnect)]
+/// struct Menu {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Menu {
+///     #[func]
+///     #[connect(from = "StartButton", signal = "pressed")]
+///     fn on_start_pressed(&mut self) {
+///         godot_print!("Start!");
+///     }
+/// }
+/// ```
+///
+/// Using `#[connect]` on a class without `#[class(autoconnect)]` is a compile error, instead of never connecting the signal:
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Menu {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Menu {
+///     #[func]
+///     #[connect(from = "StartButton", signal = "pressed")] // Error: requires #[class(autoconnect)].
+///     fn on_start_pressed(&mut self) {}
+/// }
+/// ```
+///
 /// # Further class customization
 ///
 /// ## Running code in the editor
@@ -676,6 +760,34 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///
 /// Make sure you understand the limitations in the [tutorial](https://godot-rust.github.io/book/register/virtual-functions.html).
 ///
+/// ## Iteration from GDScript
+///
+/// A method with the `#[iter]` attribute makes objects of the class iterable in GDScript (`for item in obj:`). It takes `&self` and
+/// returns an iterator, whose items are converted to variants. The method itself is not registered with Godot; instead, GDScript's
+/// iterator protocol (`_iter_init`, `_iter_next`, `_iter_get`) is implemented on top of it.
+/// See [`ScriptIterator`](../tools/struct.ScriptIterator.html) for details.
+///
+/// ```no_run
+/// # #[cfg(since_api = "4.2")]
+/// # mod conditional {
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Deck {
+///     cards: Vec<i64>,
+/// }
+///
+/// #[godot_api]
+/// impl Deck {
+///     // The iterator must not borrow from `self`.
+///     #[iter]
+///     fn cards(&self) -> impl Iterator<Item = i64> + 'static {
+///         self.cards.clone().into_iter()
+///     }
+/// }
+/// # }
+/// ```
+///
 /// # Constants and signals
 ///
 /// Please refer to [the book](https://godot-rust.github.io/book/register/constants.html).
@@ -815,6 +927,14 @@ pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
 }
 
+/// Derive macro for [`GodotSerialize`](../tools/trait.GodotSerialize.html) on classes.
+///
+/// Serializes all fields annotated with `#[var]` or `#[export]`. Must be used together with `#[derive(GodotClass)]`.
+#[proc_macro_derive(GodotSerialize)]
+pub fn derive_godot_serialize(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_godot_serialize)
+}
+
 /// Derive macro for [`Var`](../register/property/trait.Var.html) on enums.
 ///
 /// This expects a derived [`GodotConvert`](../builtin/meta/trait.GodotConvert.html) implementation, using a manual
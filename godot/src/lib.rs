@@ -173,13 +173,14 @@ pub mod init {
 /// Register/export Rust symbols to Godot: classes, methods, enums...
 pub mod register {
     pub use godot_core::registry::property;
-    pub use godot_macros::{godot_api, Export, GodotClass, GodotConvert, Var};
+    pub use godot_macros::{godot_api, Export, GodotClass, GodotConvert, GodotSerialize, Var};
 
     /// Re-exports used by proc-macro API.
     #[doc(hidden)]
     pub mod private {
         #[cfg(feature = "__codegen-full")]
         pub use godot_core::registry::class::auto_register_rpcs;
+        pub use godot_core::registry::class::{auto_connect_signals, connect_child_signal};
         pub use godot_core::registry::godot_register_wrappers::*;
         pub use godot_core::registry::{constant, method};
     }
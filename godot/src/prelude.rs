@@ -8,7 +8,7 @@
 pub use super::register::property::{Export, Var};
 
 // Re-export macros.
-pub use super::register::{godot_api, Export, GodotClass, GodotConvert, Var};
+pub use super::register::{godot_api, Export, GodotClass, GodotConvert, GodotSerialize, Var};
 
 pub use super::builtin::__prelude_reexport::*;
 pub use super::builtin::math::FloatExt as _;
@@ -21,7 +21,8 @@ pub use super::classes::{
     Node2D, Node3D, Object, PackedScene, RefCounted, Resource, SceneTree,
 };
 pub use super::global::{
-    godot_error, godot_print, godot_print_rich, godot_script_error, godot_warn,
+    godot_dbg, godot_error, godot_print, godot_print_rich, godot_print_variants,
+    godot_script_error, godot_warn,
 };
 pub use super::tools::{load, save, try_load, try_save, GFile};
 
@@ -26,6 +26,12 @@ func test_to_string():
 	
 	assert_eq(str(ffi), "VirtualMethodTest[integer=0]")
 
+func test_to_string_in_format():
+	var ffi = VirtualMethodTest.new()
+	
+	assert_eq("<%s>" % ffi, "<VirtualMethodTest[integer=0]>")
+	assert_eq("%s" % [[ffi]], "[VirtualMethodTest[integer=0]]")
+
 func test_export():
 	var obj = HasProperty.new()
 
@@ -360,3 +366,29 @@ func test_get_set():
 	assert_eq(obj.set_get, 1000)
 	assert(obj.is_set_called())
 	assert(obj.is_get_called())
+
+func test_iter_for_loop():
+	# Class is only registered in Godot 4.2+.
+	if not ClassDB.class_exists(&"IterObj"):
+		return
+
+	var obj = ClassDB.instantiate(&"IterObj")
+	obj.set_values(PackedInt64Array([1, 2, 3]))
+
+	var items := []
+	for item in obj:
+		items.append(item)
+	assert_eq(items, ["#1", "#2", "#3"])
+
+	# Nested loops over the same object, with early exit.
+	var pairs := []
+	for outer in obj:
+		for inner in obj:
+			if inner == "#2":
+				break
+			pairs.append(outer + inner)
+	assert_eq(pairs, ["#1#1", "#2#1", "#3#1"])
+
+	obj.set_values(PackedInt64Array())
+	for item in obj:
+		assert(false, "Empty iterator should not run loop body")
@@ -52,3 +52,260 @@ func test_collision_object_2d_input_event():
 
 	window.queue_free()
 
+
+# Test that `Object::try_set_deferred()` only assigns the property once the current frame is done.
+func test_object_try_set_deferred():
+	var node := Node.new()
+	node.name = "Before"
+
+	assert_that(DeferredPropertySetter.schedule(node, "name", "After"), "Property should exist")
+	assert_eq(node.name, &"Before", "Property should not be set immediately")
+
+	await Engine.get_main_loop().process_frame
+
+	assert_eq(node.name, &"After", "Property should be set after the frame")
+	node.free()
+
+
+# Test that `notify_deferred()` only sends the notification once the current frame is done.
+func test_object_notify_deferred():
+	var node := NotificationTest.new()
+	node.notify_custom_deferred()
+	assert_eq(node.custom_notification_count(), 0, "Notification should not be sent immediately")
+
+	await Engine.get_main_loop().process_frame
+
+	assert_eq(node.custom_notification_count(), 1, "Notification should be sent after the frame")
+	node.free()
+
+
+# Test that `Node::queue_free_all()` frees all nodes once the current frame is done.
+func test_node_queue_free_all():
+	var parent := Node.new()
+	var child := Node.new()
+	parent.add_child(child)
+	var other := Node.new()
+
+	var nodes: Array[Node] = [parent, child, other, parent]
+	assert_eq(NodeBatchFreer.free_all(nodes), 3, "Duplicates should be queued once")
+	assert_that(is_instance_valid(parent), "Nodes should not be freed immediately")
+
+	await Engine.get_main_loop().process_frame
+
+	assert_that(not is_instance_valid(parent), "Parent should be freed after the frame")
+	assert_that(not is_instance_valid(child), "Child should be freed after the frame")
+	assert_that(not is_instance_valid(other), "Other node should be freed after the frame")
+
+
+# Test that `Node::add_children_deferred()` adds all children in order once the current frame is done.
+func test_node_add_children_deferred():
+	var parent := Node.new()
+	Engine.get_main_loop().root.add_child(parent)
+
+	var nodes: Array[Node] = []
+	for i in 4:
+		var node := Node.new()
+		node.name = "Child%d" % i
+		nodes.append(node)
+
+	assert_eq(NodeBatchAdder.add_all(parent, nodes), 4, "All children should be queued")
+	assert_eq(parent.get_child_count(), 0, "Children should not be added immediately")
+
+	await Engine.get_main_loop().process_frame
+
+	assert_eq(parent.get_children(), nodes, "Children should be added in order")
+	parent.free()
+
+
+# Test that `SceneTree::call_group_deferred_typed()` only calls the method on the group once the current frame is done.
+func test_scene_tree_call_group_deferred_typed():
+	var root: Node = Engine.get_main_loop().root
+	var receiver := GroupCallReceiver.new()
+	receiver.add_to_group(&"deferred_receivers")
+	root.add_child(receiver)
+
+	GroupCallScheduler.call_deferred_with(root.get_tree(), &"deferred_receivers", 3)
+	assert_eq(receiver.received_values(), PackedInt64Array(), "Method should not be called immediately")
+
+	await Engine.get_main_loop().process_frame
+
+	assert_eq(receiver.received_values(), PackedInt64Array([3]), "Method should be called after the frame")
+	receiver.free()
+
+
+# Test that a task spawned with `Node::spawn_task()` keeps running across frames, and stops once its node is freed.
+func test_node_spawn_task_stops_when_freed():
+	var node := Node.new()
+	Engine.get_main_loop().root.add_child(node)
+
+	# Class is only registered in Godot 4.2+.
+	if not ClassDB.class_exists(&"FrameCountingTask"):
+		node.free()
+		return
+
+	var task = ClassDB.instantiate(&"FrameCountingTask")
+	task.start(node)
+
+	await Engine.get_main_loop().process_frame
+	await Engine.get_main_loop().process_frame
+	assert_that(task.frames() >= 1, "Task should count frames while node is alive")
+	assert_that(not task.is_finished(), "Task should still be running")
+
+	node.free()
+	assert_that(task.is_finished(), "Task should be cancelled when node exits the tree")
+
+	var frames_after_free := task.frames()
+	await Engine.get_main_loop().process_frame
+	await Engine.get_main_loop().process_frame
+	assert_eq(task.frames(), frames_after_free, "Task should not run after node is freed")
+
+
+# Test that `Object::call_on_main()` and `call_on_main_blocking()` perform calls from other threads on the main thread.
+func test_call_on_main_from_thread():
+	# Class is only registered with the `experimental-threads` feature.
+	if not ClassDB.class_exists(&"MainThreadCaller"):
+		return
+
+	var node := Node.new()
+	node.name = "Before"
+	Engine.get_main_loop().root.add_child(node)
+
+	var caller = ClassDB.instantiate(&"MainThreadCaller")
+	caller.rename_from_thread(node, &"After")
+
+	# The spawned thread is not joined, so wait until it has queued the call.
+	for i in 10:
+		await Engine.get_main_loop().process_frame
+		if node.name == &"After":
+			break
+	assert_eq(node.name, &"After", "Name should be set on the main thread")
+
+	# Blocking variant is only available in Godot 4.2+.
+	if caller.has_method(&"read_name_from_thread"):
+		caller.read_name_from_thread(node)
+		for i in 10:
+			await Engine.get_main_loop().process_frame
+			if caller.blocking_result() != null:
+				break
+		assert_eq(caller.blocking_result(), &"After", "Blocking call should return the result")
+
+	node.free()
+
+
+# Test that a callback registered with `Node::on_process()` runs once per frame, and is removed once its node is freed.
+func test_node_on_process_counts_frames():
+	# Class is only registered in Godot 4.2+.
+	if not ClassDB.class_exists(&"ProcessCounter"):
+		return
+
+	var node := Node.new()
+	Engine.get_main_loop().root.add_child(node)
+
+	var counter = ClassDB.instantiate(&"ProcessCounter")
+	counter.start(node)
+
+	for i in 3:
+		await Engine.get_main_loop().process_frame
+	assert_eq(counter.frames(), 3, "Callback should run once per frame")
+	assert_that(counter.is_active(), "Callback should be active while node is alive")
+
+	node.free()
+	await Engine.get_main_loop().process_frame
+	assert_that(not counter.is_active(), "Callback should be removed once node is freed")
+	assert_eq(counter.frames(), 3, "Callback should not run after node is freed")
+
+
+# Test that `Area3D::overlapping_bodies_typed()` and `is_overlapping()` report a body inside the area, after physics frames passed.
+func test_area3d_reports_overlapping_body():
+	# Class is only registered with the `codegen-full` feature.
+	if not ClassDB.class_exists(&"AreaOverlapChecker"):
+		return
+
+	var parent := Node3D.new()
+	Engine.get_main_loop().root.add_child(parent)
+
+	var checker = ClassDB.instantiate(&"AreaOverlapChecker")
+	var nodes: Array = checker.spawn_overlapping(parent)
+	var area: Area3D = nodes[0]
+	var body: StaticBody3D = nodes[1]
+
+	for i in 5:
+		await Engine.get_main_loop().physics_frame
+		if checker.static_body_count(area) > 0:
+			break
+
+	assert_eq(checker.static_body_count(area), 1, "Body inside area should be reported")
+	assert_eq(checker.character_body_count(area), 0, "Bodies of other classes should be skipped")
+	assert_that(checker.is_overlapping(area, body), "Body should overlap area")
+	assert_that(not checker.is_overlapping(area, parent), "Non-physics node should not overlap")
+
+	area.monitoring = false
+	assert_eq(checker.static_body_count(area), 0, "Area without monitoring should report no bodies")
+
+	parent.free()
+
+
+# Test that `Node3D::raycast_forward()` hits a body in front of the node, after physics frames passed.
+func test_node3d_raycast_forward_hits_body():
+	# Class is only registered with the `codegen-full` feature.
+	if not ClassDB.class_exists(&"RaycastChecker"):
+		return
+
+	var parent := Node3D.new()
+	Engine.get_main_loop().root.add_child(parent)
+
+	var checker = ClassDB.instantiate(&"RaycastChecker")
+	var nodes: Array = checker.spawn_scene(parent)
+	var eye: Node3D = nodes[0]
+	var body: StaticBody3D = nodes[1]
+
+	var hit: Array = []
+	for i in 5:
+		await Engine.get_main_loop().physics_frame
+		hit = checker.raycast(eye, 10.0, 0xFFFFFFFF)
+		if not hit.is_empty():
+			break
+
+	assert_eq(hit.size(), 2, "Body in front should be hit")
+	assert_that(hit[0].is_equal_approx(Vector3(0, 0, -4.5)), "Ray should hit the front face of the box")
+	assert_eq(hit[1], body, "Collider should be the body")
+
+	assert_that(checker.raycast(eye, 3.0, 0xFFFFFFFF).is_empty(), "Body beyond distance should not be hit")
+	assert_that(checker.raycast(eye, 10.0, 2).is_empty(), "Body outside collision mask should not be hit")
+
+	eye.rotate_y(PI)
+	assert_that(checker.raycast(eye, 10.0, 0xFFFFFFFF).is_empty(), "Body behind should not be hit")
+
+	parent.free()
+
+
+# Test that `SceneTree::change_scene_and_await_ready()` switches scenes, and resolves once the new scene is current and ready.
+func test_change_scene_and_await_ready():
+	# Class is only registered in Godot 4.2+.
+	if not ClassDB.class_exists(&"SceneChangeAwaiter"):
+		return
+
+	var tree: SceneTree = Engine.get_main_loop()
+	var runner := tree.current_scene
+
+	# Detach the test runner from the current scene, so that the scene change does not free it.
+	tree.current_scene = null
+
+	var awaiter = ClassDB.instantiate(&"SceneChangeAwaiter")
+	awaiter.start()
+	assert_eq(awaiter.root(), null, "Scene should only change at the end of the frame")
+
+	for i in 5:
+		await tree.process_frame
+		if awaiter.root() != null:
+			break
+
+	var root: Node = awaiter.root()
+	assert_that(root != null, "Future should resolve once the new scene is ready")
+	assert_eq(root.name, &"AwaitedScene", "Root of the saved scene should be loaded")
+	assert_eq(tree.current_scene, root, "New scene should be current")
+	assert_that(root.is_node_ready(), "New scene should be ready")
+	assert_that(runner.is_inside_tree(), "Runner should stay in the tree")
+
+	root.free()
+	tree.current_scene = runner
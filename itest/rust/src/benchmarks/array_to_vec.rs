@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Each benchmark creates its array first, so the creation cost is included. It is small compared to the element-wise
+// conversions, which makes the bulk and element-wise variants comparable with each other.
+//
+// The `to_vec()` docs quote no speedup, as these benchmarks have not been measured yet. Numbers added there should come from release
+// builds of both Rust and Godot; the runner warns if either uses a debug build.
+
+use std::hint::black_box;
+
+use godot::builtin::{Array, PackedFloat32Array, PackedInt32Array};
+
+use crate::framework::bench;
+
+const LEN: usize = 100_000;
+
+fn packed_ints() -> PackedInt32Array {
+    (0..LEN as i32).collect()
+}
+
+fn packed_floats() -> PackedFloat32Array {
+    (0..LEN).map(|i| i as f32).collect()
+}
+
+fn typed_array() -> Array<i64> {
+    (0..LEN as i64).collect()
+}
+
+#[bench(repeat = 1)]
+fn packed_int32_to_vec_bulk() -> Vec<i32> {
+    black_box(packed_ints()).to_vec()
+}
+
+#[bench(repeat = 1)]
+fn packed_int32_to_vec_per_element() -> Vec<i32> {
+    let array = black_box(packed_ints());
+    (0..array.len()).map(|i| array.get(i).unwrap()).collect()
+}
+
+#[bench(repeat = 1)]
+fn packed_float32_to_vec_bulk() -> Vec<f32> {
+    black_box(packed_floats()).to_vec()
+}
+
+#[bench(repeat = 1)]
+fn packed_float32_to_vec_per_element() -> Vec<f32> {
+    let array = black_box(packed_floats());
+    (0..array.len()).map(|i| array.get(i).unwrap()).collect()
+}
+
+#[bench(repeat = 1)]
+fn array_to_vec_bulk() -> Vec<i64> {
+    black_box(typed_array()).to_vec()
+}
+
+#[bench(repeat = 1)]
+fn array_to_vec_per_element() -> Vec<i64> {
+    black_box(typed_array()).iter_shared().collect()
+}
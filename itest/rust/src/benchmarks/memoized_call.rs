@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Compares repeated dynamic calls of the same methods with and without MemoizedCall.
+// Creating the cache is included in the measurement, amortized over all calls.
+
+use godot::builtin::{StringName, Variant};
+use godot::classes::Node3D;
+use godot::obj::NewAlloc;
+use godot::tools::MemoizedCall;
+
+use crate::framework::bench;
+
+const CALL_COUNT: usize = 100;
+
+#[bench]
+fn memoized_call_cached() -> usize {
+    let node = Node3D::new_alloc();
+    let mut memo = MemoizedCall::new(&node);
+    let args = [Variant::from("Node")];
+
+    let count = (0..CALL_COUNT)
+        .filter(|_| memo.call("is_class", &args).to::<bool>())
+        .count();
+
+    node.free();
+    count
+}
+
+#[bench]
+fn memoized_call_uncached() -> usize {
+    let mut node = Node3D::new_alloc();
+    let method = StringName::from("is_class");
+    let args = [Variant::from("Node")];
+
+    let count = (0..CALL_COUNT)
+        .filter(|_| node.call(method.clone(), &args).to::<bool>())
+        .count();
+
+    node.free();
+    count
+}
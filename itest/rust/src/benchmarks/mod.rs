@@ -17,7 +17,19 @@ use godot::register::GodotClass;
 
 use crate::framework::bench;
 
+mod array_to_vec;
 mod color;
+mod memoized_call;
+#[cfg(feature = "codegen-full")]
+mod multi_mesh;
+#[cfg(since_api = "4.2")]
+mod node_cache;
+#[cfg(feature = "codegen-full")]
+mod skeleton;
+#[cfg(since_api = "4.2")]
+mod tree_observer;
+mod typed_method;
+mod variant_scalar;
 
 #[bench]
 fn builtin_string_ctor() -> GString {
@@ -0,0 +1,49 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Compares bulk upload of instance transforms against setting them one by one. Both benchmarks include the same setup.
+
+use godot::builtin::{real, Basis, Transform3D, Vector3};
+use godot::classes::multi_mesh::TransformFormat;
+use godot::classes::MultiMesh;
+use godot::obj::{Gd, NewGd};
+
+use crate::framework::bench;
+
+const INSTANCE_COUNT: usize = 1000;
+
+fn make_multi_mesh() -> Gd<MultiMesh> {
+    let mut multi_mesh = MultiMesh::new_gd();
+    multi_mesh.set_transform_format(TransformFormat::TRANSFORM_3D);
+    multi_mesh.set_instance_count(INSTANCE_COUNT as i32);
+    multi_mesh
+}
+
+fn make_transforms() -> Vec<Transform3D> {
+    (0..INSTANCE_COUNT)
+        .map(|i| {
+            let offset = i as real;
+            Transform3D::new(Basis::IDENTITY, Vector3::new(offset, -offset, 0.5 * offset))
+        })
+        .collect()
+}
+
+#[bench(repeat = 10)]
+fn multi_mesh_transforms_bulk() -> Gd<MultiMesh> {
+    let mut multi_mesh = make_multi_mesh();
+    multi_mesh.set_transforms(&make_transforms());
+    multi_mesh
+}
+
+#[bench(repeat = 10)]
+fn multi_mesh_transforms_per_instance() -> Gd<MultiMesh> {
+    let mut multi_mesh = make_multi_mesh();
+    for (i, transform) in make_transforms().into_iter().enumerate() {
+        multi_mesh.set_instance_transform(i as i32, transform);
+    }
+    multi_mesh
+}
@@ -0,0 +1,77 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Compares repeated lookups of a nested node through NodeCache against repeated get_node_as() calls.
+// Building the node hierarchy and the first lookup are included in the measurement, amortized over all accesses.
+
+use godot::builtin::NodePath;
+use godot::classes::{Node, Node3D};
+use godot::obj::{Gd, NewAlloc};
+use godot::tools::NodeCache;
+
+use crate::framework::bench;
+
+const ACCESS_COUNT: usize = 100;
+const TARGET_PATH: &str = "Level/Enemies/Boss/Target";
+
+#[bench]
+fn node_cache_get() -> usize {
+    let owner = create_hierarchy();
+    let cache = NodeCache::<Node3D>::new(TARGET_PATH);
+
+    let count = (0..ACCESS_COUNT)
+        .filter(|_| cache.get(&owner).is_instance_valid())
+        .count();
+
+    owner.free();
+    count
+}
+
+#[bench]
+fn node_cache_repeated_get_node() -> usize {
+    let owner = create_hierarchy();
+    let path = NodePath::from(TARGET_PATH);
+
+    let count = (0..ACCESS_COUNT)
+        .filter(|_| {
+            owner
+                .get_node_as::<Node3D>(path.clone())
+                .is_instance_valid()
+        })
+        .count();
+
+    owner.free();
+    count
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers for benchmarks above
+
+/// Creates an owner with a chain of children along `TARGET_PATH`, each with a few siblings.
+fn create_hierarchy() -> Gd<Node> {
+    let owner = Node::new_alloc();
+    let mut parent = owner.clone();
+
+    for name in TARGET_PATH.split('/') {
+        for i in 0..3 {
+            let mut sibling = Node::new_alloc();
+            sibling.set_name(format!("Sibling{i}").into());
+            parent.add_child(&sibling);
+        }
+
+        let mut child: Gd<Node> = if name == "Target" {
+            Node3D::new_alloc().upcast()
+        } else {
+            Node::new_alloc()
+        };
+        child.set_name(name.into());
+        parent.add_child(&child);
+        parent = child;
+    }
+
+    owner
+}
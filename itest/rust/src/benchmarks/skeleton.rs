@@ -0,0 +1,69 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Compares setting all bone poses from a buffer against calling `set_bone_pose()` for each bone, to show the cost of decoding the buffer.
+// Both benchmarks include the same setup.
+
+use godot::builtin::{real, Basis, PackedFloat32Array, RealConv, Transform3D, Vector3};
+use godot::classes::Skeleton3D;
+use godot::obj::{Gd, NewAlloc};
+
+use crate::framework::bench;
+
+const BONE_COUNT: usize = 100;
+
+fn make_skeleton() -> Gd<Skeleton3D> {
+    let mut skeleton = Skeleton3D::new_alloc();
+    for i in 0..BONE_COUNT {
+        skeleton.add_bone(format!("bone_{i}").into());
+    }
+    skeleton
+}
+
+fn make_poses() -> Vec<Transform3D> {
+    (0..BONE_COUNT)
+        .map(|i| {
+            let offset = i as real;
+            let basis = Basis::from_axis_angle(Vector3::UP, 0.01 * offset);
+            Transform3D::new(basis, Vector3::new(offset, -offset, 0.5 * offset))
+        })
+        .collect()
+}
+
+fn make_pose_buffer() -> PackedFloat32Array {
+    let mut buffer = PackedFloat32Array::new();
+    for pose in make_poses() {
+        let origin = [pose.origin.x, pose.origin.y, pose.origin.z];
+        for (row, origin) in pose.basis.rows.iter().zip(origin) {
+            buffer.extend([row.x, row.y, row.z, origin].map(RealConv::as_f32));
+        }
+    }
+    buffer
+}
+
+#[bench(repeat = 10)]
+fn skeleton_bone_poses_bulk() -> i32 {
+    let mut skeleton = make_skeleton();
+    skeleton.set_bone_poses(&make_pose_buffer());
+
+    let count = skeleton.get_bone_count();
+    skeleton.free();
+    count
+}
+
+#[cfg(since_api = "4.3")]
+#[bench(repeat = 10)]
+fn skeleton_bone_poses_per_bone() -> i32 {
+    let mut skeleton = make_skeleton();
+    for (bone, pose) in make_poses().into_iter().enumerate() {
+        skeleton.set_bone_pose(bone as i32, pose);
+    }
+
+    let count = skeleton.get_bone_count();
+    skeleton.free();
+    count
+}
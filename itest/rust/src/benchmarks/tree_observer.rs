@@ -0,0 +1,96 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Compares many connections to a scene tree signal against a single shared connection through SceneTreeObserver.
+// Connecting and disconnecting is included in the measurement, amortized over all emissions.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use godot::builtin::{Callable, Signal, Variant};
+use godot::classes::{Engine, Node, SceneTree};
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc};
+use godot::tools::{SceneTreeObserver, TreeNodeEvent};
+
+use crate::framework::bench;
+
+const SUBSCRIBER_COUNT: usize = 50;
+const EMIT_COUNT: usize = 10;
+
+#[bench]
+fn tree_signal_direct_connections() -> usize {
+    let tree = scene_tree();
+    let signal = Signal::from_object_signal(&tree, "node_added");
+    let received = Arc::new(AtomicUsize::new(0));
+
+    let callables: Vec<Callable> = (0..SUBSCRIBER_COUNT)
+        .map(|_| {
+            let received = Arc::clone(&received);
+            Callable::from_fn("bench_direct", move |_args| {
+                received.fetch_add(1, Ordering::Relaxed);
+                Ok(Variant::nil())
+            })
+        })
+        .collect();
+
+    for callable in &callables {
+        signal.connect(callable.clone(), 0);
+    }
+
+    emit_node_added(tree);
+
+    for callable in callables {
+        signal.disconnect(callable);
+    }
+
+    received.load(Ordering::Relaxed)
+}
+
+#[bench]
+fn tree_signal_shared_observer() -> usize {
+    let tree = scene_tree();
+    let received = Rc::new(Cell::new(0));
+
+    let subscriptions: Vec<_> = (0..SUBSCRIBER_COUNT)
+        .map(|_| {
+            let received = Rc::clone(&received);
+            SceneTreeObserver::subscribe(TreeNodeEvent::NodeAdded, move |_node| {
+                received.set(received.get() + 1);
+            })
+        })
+        .collect();
+
+    emit_node_added(tree);
+    drop(subscriptions);
+
+    received.get()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers for benchmarks above
+
+fn scene_tree() -> Gd<SceneTree> {
+    Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        .expect("benchmarks run inside a SceneTree")
+}
+
+/// Emits `node_added` directly, so that only the dispatch is measured, not adding a node to the tree.
+fn emit_node_added(mut tree: Gd<SceneTree>) {
+    let node = Node::new_alloc();
+    let args = [node.to_variant()];
+
+    for _ in 0..EMIT_COUNT {
+        tree.emit_signal("node_added".into(), &args);
+    }
+
+    node.free();
+}
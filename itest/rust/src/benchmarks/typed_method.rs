@@ -0,0 +1,44 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Compares repeated calls through a TypedMethod against dynamic calls by name and against the generated API.
+// The TypedMethod lookup is included in the measurement, amortized over all calls.
+
+use godot::builtin::StringName;
+use godot::classes::RefCounted;
+use godot::obj::NewGd;
+use godot::tools::TypedMethod;
+
+use crate::framework::bench;
+
+const CALL_COUNT: usize = 100;
+
+#[bench]
+fn typed_method_call() -> i32 {
+    let mut object = RefCounted::new_gd();
+    let method = TypedMethod::<(), i32>::new("RefCounted", "get_reference_count")
+        .expect("signature matches");
+
+    (0..CALL_COUNT).map(|_| method.call(&mut object, ())).sum()
+}
+
+#[bench]
+fn typed_method_dynamic_call() -> i32 {
+    let mut object = RefCounted::new_gd();
+    let method = StringName::from("get_reference_count");
+
+    (0..CALL_COUNT)
+        .map(|_| object.call(method.clone(), &[]).to::<i32>())
+        .sum()
+}
+
+#[bench]
+fn typed_method_generated_call() -> i32 {
+    let object = RefCounted::new_gd();
+
+    (0..CALL_COUNT).map(|_| object.get_reference_count()).sum()
+}
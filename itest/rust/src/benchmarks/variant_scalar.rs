@@ -0,0 +1,57 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Scalars (bool, integers, floats) are converted to and from variants through the cached converter function pointers. The `Vector2i`
+// benchmark is a reference for a conversion of a larger builtin type.
+
+use std::hint::black_box;
+
+use godot::builtin::{Variant, Vector2i};
+use godot::meta::ToGodot;
+
+use crate::framework::bench;
+
+const COUNT: i64 = 1_000_000;
+
+#[bench(repeat = 1)]
+fn variant_int_to_variant() -> Variant {
+    let mut variant = Variant::nil();
+    for i in 0..COUNT {
+        variant = black_box(i).to_variant();
+    }
+    variant
+}
+
+#[bench(repeat = 1)]
+fn variant_int_roundtrip() -> i64 {
+    let mut sum = 0;
+    for i in 0..COUNT {
+        let variant = black_box(i).to_variant();
+        sum += black_box(&variant).to::<i64>();
+    }
+    sum
+}
+
+#[bench(repeat = 1)]
+fn variant_float_roundtrip() -> f64 {
+    let mut sum = 0.0;
+    for i in 0..COUNT {
+        let variant = black_box(i as f64).to_variant();
+        sum += black_box(&variant).to::<f64>();
+    }
+    sum
+}
+
+#[bench(repeat = 1)]
+fn variant_vector2i_roundtrip_ffi() -> i64 {
+    let mut sum = 0;
+    for i in 0..COUNT {
+        let variant: Variant = black_box(Vector2i::new(i as i32, 0)).to_variant();
+        sum += black_box(&variant).to::<Vector2i>().x as i64;
+    }
+    sum
+}
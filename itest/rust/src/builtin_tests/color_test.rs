@@ -6,6 +6,7 @@
  */
 
 use crate::framework::itest;
+use godot::builtin::inner::InnerColor;
 use godot::builtin::math::assert_eq_approx;
 use godot::builtin::{Color, ColorChannelOrder, ColorHsv};
 
@@ -97,6 +98,22 @@ fn color_blend() {
     );
 }
 
+#[itest]
+fn color_srgb_linear_matches_engine() {
+    let colors = [
+        Color::from_rgba(0.0, 0.02, 0.04045, 1.0),
+        Color::from_rgba(0.0031308, 0.25, 0.5, 0.5),
+        Color::from_rgba(0.75, 1.0, 1.5, 0.0),
+    ];
+
+    for color in colors {
+        let inner = InnerColor::from_outer(&color);
+
+        assert_eq_approx!(color.srgb_to_linear(), inner.srgb_to_linear());
+        assert_eq_approx!(color.linear_to_srgb(), inner.linear_to_srgb());
+    }
+}
+
 #[itest]
 fn color_to_u32() {
     let c = Color::from_html("#01020304").unwrap();
@@ -84,6 +84,114 @@ fn array_try_into_vec() {
     assert_eq!(result, Ok(vec![1, 2]));
 }
 
+#[itest]
+fn array_to_vec() {
+    let array = array![1, 2, 3];
+    assert_eq!(array.to_vec(), vec![1, 2, 3]);
+    assert_eq!(Vec::from(&array), vec![1, 2, 3]);
+
+    let empty = Array::<i64>::new();
+    assert_eq!(empty.to_vec(), Vec::<i64>::new());
+    assert_eq!(Vec::from(&empty), Vec::<i64>::new());
+
+    let strings = array![GString::from("a"), GString::from("b")];
+    assert_eq!(
+        strings.to_vec(),
+        vec![GString::from("a"), GString::from("b")]
+    );
+}
+
+#[itest]
+fn array_map_godot() {
+    let numbers = array![1, 2, 3];
+    let strings: Array<GString> = numbers.map_godot(|n| GString::from(format!("#{n}")));
+
+    assert_eq!(
+        strings,
+        array![
+            GString::from("#1"),
+            GString::from("#2"),
+            GString::from("#3")
+        ]
+    );
+    assert_eq!(numbers, array![1, 2, 3], "source array is unchanged");
+
+    // Result is a typed array of the target type.
+    let variant = strings.to_variant();
+    assert!(variant.try_to::<Array<GString>>().is_ok());
+    assert!(variant.try_to::<Array<i64>>().is_err());
+
+    let empty = Array::<i64>::new().map_godot(|n| n as f64);
+    assert!(empty.is_empty());
+}
+
+#[itest]
+fn array_filter_godot() {
+    let numbers = array![1, 2, 3, 4, 5, 6];
+    let even = numbers.filter_godot(|n| n % 2 == 0);
+
+    assert_eq!(even, array![2, 4, 6]);
+    assert_eq!(numbers.len(), 6, "source array is unchanged");
+
+    assert!(numbers.filter_godot(|_| false).is_empty());
+    assert_eq!(numbers.filter_godot(|_| true), numbers);
+
+    let strings = array![
+        GString::from("apple"),
+        GString::from("kiwi"),
+        GString::from("avocado")
+    ];
+    let a_words = strings
+        .filter_godot(|s| s.to_string().starts_with('a'))
+        .map_godot(|s| s.len() as i64);
+    assert_eq!(a_words, array![5, 7]);
+}
+
+#[itest]
+fn array_map_filter_godot_closure_modifies_alias() {
+    let numbers = array![1, 2, 3];
+
+    // Pushing many elements to a shared clone reallocates the common storage during iteration.
+    let mut alias = numbers.clone();
+    let doubled = numbers.map_godot(|n| {
+        alias.extend_array(&Array::from(&[0; 100][..]));
+        n * 2
+    });
+    assert_eq!(doubled, array![2, 4, 6]);
+    assert_eq!(numbers.len(), 303);
+
+    let mut alias = numbers.clone();
+    let mut visited = 0;
+    let positive = numbers.filter_godot(|&n| {
+        alias.push(-1);
+        visited += 1;
+        n > 0
+    });
+    assert_eq!(positive, array![1, 2, 3]);
+    assert_eq!(visited, 303, "pushed elements are not visited");
+    assert_eq!(numbers.len(), 606);
+}
+
+#[itest]
+fn array_untyped_to_typed() {
+    let untyped = varray![1, 2, 3];
+    let typed: Array<i64> = untyped.to_typed().expect("all elements are ints");
+    assert_eq!(typed, array![1, 2, 3]);
+
+    let node = Node::new_alloc();
+    let untyped = varray![node.clone()];
+    let typed = untyped
+        .to_typed::<Gd<Node>>()
+        .expect("all elements are nodes");
+    assert_eq!(typed.at(0), node);
+    node.free();
+
+    let mixed = varray![1, "two"];
+    assert!(mixed.to_typed::<i64>().is_err());
+    let empty = VariantArray::new().to_typed::<GString>();
+    assert_eq!(empty.expect("empty array converts"), Array::new());
+}
+
 #[itest]
 fn array_iter_shared() {
     let array = array![1, 2];
@@ -172,6 +280,9 @@ fn array_get() {
     expect_panic("Array index 2 out of bounds: length is 2", || {
         array.at(2);
     });
+    expect_panic("Array index beyond i64::MAX", || {
+        array.at(usize::MAX);
+    });
 }
 
 #[itest]
@@ -181,6 +292,42 @@ fn array_try_get() {
     assert_eq!(array.get(0), Some(1));
     assert_eq!(array.get(1), Some(2));
     assert_eq!(array.get(2), None);
+    assert_eq!(array.get(usize::MAX), None);
+
+    assert_eq!(Array::<i64>::new().get(0), None);
+
+    let array = varray![1, "two"];
+    assert_eq!(array.get(1), Some("two".to_variant()));
+    assert_eq!(array.get(2), None);
+}
+
+#[itest]
+fn array_read_only() {
+    let mut array = array![1, 2];
+    assert!(!array.is_read_only());
+
+    array.make_read_only();
+    assert!(array.is_read_only());
+    assert!(array.clone().is_read_only());
+
+    expect_panic("push() on read-only array", || {
+        array.push(3);
+    });
+    expect_panic("set() on read-only array", || {
+        array.set(0, 5);
+    });
+    expect_panic("clear() on read-only array", || {
+        array.clear();
+    });
+    expect_panic("rotate_left() on read-only array", || {
+        array.rotate_left(1);
+    });
+    assert_eq!(array, array![1, 2]);
+
+    let mut copy = array.duplicate_shallow();
+    assert!(!copy.is_read_only());
+    copy.push(3);
+    assert_eq!(copy, array![1, 2, 3]);
 }
 
 #[itest]
@@ -207,6 +354,61 @@ fn array_binary_search() {
     assert_eq!(array.bsearch(&4), 2);
 }
 
+#[itest]
+fn array_binary_search_by() {
+    let array = array![1, 3, 5, 7];
+
+    assert_eq!(array.bsearch_by(|e| e.cmp(&5)), Ok(2));
+    assert_eq!(array.bsearch_by(|e| e.cmp(&1)), Ok(0));
+    assert_eq!(array.bsearch_by(|e| e.cmp(&0)), Err(0));
+    assert_eq!(array.bsearch_by(|e| e.cmp(&4)), Err(2));
+    assert_eq!(array.bsearch_by(|e| e.cmp(&8)), Err(4));
+
+    // Descending order, by reversing the comparison.
+    let descending = array![9, 6, 3];
+    assert_eq!(descending.bsearch_by(|e| 6.cmp(e)), Ok(1));
+    assert_eq!(descending.bsearch_by(|e| 4.cmp(e)), Err(2));
+
+    assert_eq!(Array::<i64>::new().bsearch_by(|e| e.cmp(&1)), Err(0));
+}
+
+#[itest]
+fn array_insert_sorted() {
+    let mut array = Array::<i64>::new();
+
+    for value in [5, 1, 4, 1, 9, 0, 5] {
+        array.insert_sorted(value, i64::cmp);
+    }
+    assert_eq!(array, array![0, 1, 1, 4, 5, 5, 9]);
+
+    assert_eq!(array.insert_sorted(3, i64::cmp), 3);
+    assert_eq!(array.insert_sorted(-1, i64::cmp), 0);
+    assert_eq!(array.insert_sorted(10, i64::cmp), 9);
+    assert_eq!(array, array![-1, 0, 1, 1, 3, 4, 5, 5, 9, 10]);
+}
+
+#[itest]
+fn array_insert_sorted_duplicates_after() {
+    // Priority queue ordered by priority only; equal priorities keep insertion order.
+    let mut queue = Array::<Vector2i>::new();
+    let by_priority = |a: &Vector2i, b: &Vector2i| a.x.cmp(&b.x);
+
+    queue.insert_sorted(Vector2i::new(2, 0), by_priority);
+    queue.insert_sorted(Vector2i::new(1, 1), by_priority);
+    assert_eq!(queue.insert_sorted(Vector2i::new(2, 2), by_priority), 2);
+    assert_eq!(queue.insert_sorted(Vector2i::new(1, 3), by_priority), 1);
+
+    assert_eq!(
+        queue,
+        array![
+            Vector2i::new(1, 1),
+            Vector2i::new(1, 3),
+            Vector2i::new(2, 0),
+            Vector2i::new(2, 2),
+        ]
+    );
+}
+
 #[itest]
 fn array_find() {
     let array = array![1, 2, 1];
@@ -311,6 +513,43 @@ fn array_reverse() {
     assert_eq!(array, array![2, 1]);
 }
 
+#[itest]
+fn array_rotate() {
+    let mut array = array![1, 2, 3, 4, 5];
+
+    array.rotate_left(2);
+    assert_eq!(array, array![3, 4, 5, 1, 2]);
+
+    array.rotate_right(2);
+    assert_eq!(array, array![1, 2, 3, 4, 5]);
+
+    // Rotations wrap around the length.
+    array.rotate_left(5);
+    assert_eq!(array, array![1, 2, 3, 4, 5]);
+    array.rotate_right(7);
+    assert_eq!(array, array![4, 5, 1, 2, 3]);
+
+    let mut empty = Array::<i64>::new();
+    empty.rotate_left(3);
+    empty.rotate_right(1);
+    assert!(empty.is_empty());
+}
+
+#[itest]
+fn array_rotate_shared() {
+    let mut array = array![1, 2, 3];
+    let copy = array.duplicate_shallow();
+
+    array.rotate_left(1);
+    assert_eq!(array, array![2, 3, 1]);
+    assert_eq!(copy, array![1, 2, 3]);
+
+    // Other handles to the same array see the change.
+    let mut shared = array.clone();
+    shared.rotate_right(1);
+    assert_eq!(array, array![1, 2, 3]);
+}
+
 #[itest]
 fn array_shuffle() {
     let mut array = array![1];
@@ -557,3 +796,34 @@ impl ArrayTest {
         (1..(n + 1)).collect()
     }
 }
+
+#[itest]
+fn array_element_type() {
+    let ints = Array::from(vec![1, 2, 3]);
+    assert!(ints.is_typed());
+    assert_eq!(ints.element_type(), VariantType::INT);
+    assert_eq!(ints.element_class_name(), None);
+
+    // Godot itself sees the typed array, e.g. when passing it to a typed GDScript parameter.
+    let variant = ints.to_variant();
+    assert_eq!(variant.call("is_typed", &[]), true.to_variant());
+    assert_eq!(
+        variant.call("get_typed_builtin", &[]),
+        (VariantType::INT.ord() as i64).to_variant()
+    );
+
+    // Conversions of Vec<T> produce typed arrays, too.
+    let strings = vec![GString::from("a"), GString::from("b")].to_variant();
+    assert_eq!(
+        strings.call("get_typed_builtin", &[]),
+        (VariantType::STRING.ord() as i64).to_variant()
+    );
+
+    let nodes: Array<Gd<Node>> = Array::new();
+    assert_eq!(nodes.element_type(), VariantType::OBJECT);
+    assert_eq!(nodes.element_class_name(), Some(StringName::from("Node")));
+
+    let untyped = varray![1, "two"];
+    assert!(!untyped.is_typed());
+    assert_eq!(untyped.element_type(), VariantType::NIL);
+}
@@ -65,6 +65,22 @@ fn callable_hash() {
     assert_ne!(obj.callable("foo").hash(), obj.callable("bar").hash());
 }
 
+#[itest]
+fn callable_equality() {
+    let obj = CallableTestObj::new_gd();
+    let other = CallableTestObj::new_gd();
+
+    assert_eq!(obj.callable("foo"), obj.callable("foo"));
+    assert_eq!(
+        obj.callable("foo"),
+        Callable::from_object_method(&obj, "foo")
+    );
+    assert_ne!(obj.callable("foo"), obj.callable("bar"));
+    assert_ne!(obj.callable("foo"), other.callable("foo"));
+    assert_ne!(obj.callable("foo"), Callable::invalid());
+    assert_eq!(Callable::invalid(), Callable::invalid());
+}
+
 #[itest]
 fn callable_object_method() {
     let obj = CallableTestObj::new_gd();
@@ -200,6 +216,23 @@ pub mod custom_callable {
         assert_ne!(a, c, "same function, different instance -> not equal");
     }
 
+    #[itest]
+    fn callable_from_fn_introspection() {
+        let obj = CallableTestObj::new_gd();
+        let closure = Callable::from_fn("my_closure", |_args| Ok(Variant::nil()));
+
+        assert!(closure.is_valid());
+        assert!(!closure.is_null());
+        assert!(closure.is_custom());
+        assert_eq!(closure.object(), None);
+        assert_eq!(closure.object_id(), None);
+        assert_eq!(closure.to_string(), "my_closure");
+
+        assert_eq!(closure, closure.clone());
+        assert_ne!(closure, obj.callable("foo"));
+        assert_ne!(closure, Callable::invalid());
+    }
+
     fn sum(args: &[&Variant]) -> Result<Variant, ()> {
         let sum: i32 = args.iter().map(|arg| arg.to::<i32>()).sum();
         Ok(sum.to_variant())
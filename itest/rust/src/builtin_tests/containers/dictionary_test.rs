@@ -7,9 +7,10 @@
 
 use std::collections::{HashMap, HashSet};
 
-use godot::builtin::{dict, varray, Dictionary, Variant};
+use godot::builtin::{dict, varray, Dictionary, Variant, VariantType, Vector2, Vector2i};
 use godot::meta::{FromGodot, ToGodot};
 use godot::sys::GdextBuild;
+use godot::tools::{DictionaryDiff, Schema};
 
 use crate::framework::{expect_panic, itest};
 
@@ -324,6 +325,30 @@ fn dictionary_clear() {
     assert!(dictionary.is_empty());
 }
 
+#[itest]
+fn dictionary_read_only() {
+    let mut dictionary = dict! {
+        "foo": 0,
+    };
+    assert!(!dictionary.is_read_only());
+
+    dictionary.make_read_only();
+    assert!(dictionary.is_read_only());
+
+    expect_panic("set() on read-only dictionary", || {
+        dictionary.set("bar", 1);
+    });
+    expect_panic("remove() on read-only dictionary", || {
+        dictionary.remove("foo");
+    });
+    assert_eq!(dictionary, dict! { "foo": 0 });
+
+    let mut copy = dictionary.duplicate_deep();
+    assert!(!copy.is_read_only());
+    copy.set("bar", 1);
+    assert_eq!(copy.len(), 2);
+}
+
 #[itest]
 fn dictionary_find_key() {
     let dictionary = dict! {
@@ -685,3 +710,114 @@ fn dictionary_should_format_with_display() {
     };
     assert_eq!(format!("{d}"), "{ one: 1, two: true, three: <null> }")
 }
+
+#[itest]
+fn dictionary_diff_roundtrip() {
+    let old = dict! {
+        "hp": 100,
+        "name": "knight",
+        "position": Vector2::new(1.0, 2.0),
+        "inventory": dict! { "sword": 1, "potion": 3 },
+        "buff": "haste",
+    };
+    let new = dict! {
+        "hp": 80,
+        "name": "knight",
+        "position": Vector2::new(1.0, 2.0),
+        "inventory": dict! { "sword": 1, "potion": 2 },
+        "shield": true,
+    };
+
+    let diff = DictionaryDiff::compute(&old, &new);
+    assert_eq!(diff.added(), &dict! { "shield": true });
+    assert_eq!(diff.removed(), &varray!["buff"]);
+    assert_eq!(diff.len(), 4);
+
+    // Nested dictionaries are compared by content and replaced as a whole.
+    let changed = diff.changed();
+    assert_eq!(changed.len(), 2);
+    assert_eq!(changed.get("hp"), Some(80.to_variant()));
+    assert_eq!(
+        changed.get("inventory"),
+        Some(dict! { "sword": 1, "potion": 2 }.to_variant())
+    );
+
+    let mut target = old.duplicate_deep();
+    diff.apply(&mut target);
+    assert_eq!(target, new);
+
+    // Through the dictionary representation, as when sent over the network.
+    let restored = DictionaryDiff::from_variant(&diff.to_variant());
+    let mut target = old.duplicate_deep();
+    restored.apply(&mut target);
+    assert_eq!(target, new);
+}
+
+#[itest]
+fn dictionary_diff_equal() {
+    let dict = dict! { "a": 1, "b": varray![1, 2] };
+
+    let diff = DictionaryDiff::compute(&dict, &dict.duplicate_deep());
+    assert!(diff.is_empty());
+    assert_eq!(diff.len(), 0);
+
+    let diff = DictionaryDiff::compute(&Dictionary::new(), &dict);
+    assert_eq!(diff.added(), &dict);
+    assert!(diff.changed().is_empty());
+    assert!(diff.removed().is_empty());
+
+    let err = DictionaryDiff::try_from_variant(&dict! { "added": Dictionary::new() }.to_variant());
+    assert!(err.is_err());
+}
+
+#[itest]
+fn dictionary_schema_collects_all_errors() {
+    let schema = Schema::new()
+        .required("title", VariantType::STRING)
+        .required("resolution", VariantType::VECTOR2I)
+        .required("fullscreen", VariantType::BOOL)
+        .optional("volume", VariantType::FLOAT)
+        .optional("extra", VariantType::NIL)
+        .deny_unknown_fields();
+
+    let valid = dict! {
+        "title": "My Game",
+        "resolution": Vector2i::new(1920, 1080),
+        "fullscreen": true,
+        "extra": varray![1, 2],
+    };
+    assert_eq!(schema.validate(&valid), Ok(()));
+
+    let invalid = dict! {
+        "title": "My Game",
+        "resolution": 1080,
+        "volume": 1,
+        "colour": "red",
+    };
+    let errors = schema.validate(&invalid).expect_err("four violations");
+    assert_eq!(errors.len(), 4);
+
+    assert_eq!(errors[0].field(), "resolution");
+    assert_eq!(
+        errors[0].type_mismatch(),
+        Some((VariantType::VECTOR2I, VariantType::INT))
+    );
+
+    assert_eq!(errors[1].field(), "fullscreen");
+    assert!(errors[1].is_missing());
+
+    // No implicit conversion from int to float.
+    assert_eq!(errors[2].field(), "volume");
+    assert!(errors[2].is_wrong_type());
+
+    assert_eq!(errors[3].field(), "colour");
+    assert!(errors[3].is_unknown());
+    assert_eq!(
+        errors[3].to_string(),
+        "field 'colour' is not part of the schema"
+    );
+
+    // Unknown fields are ignored by default.
+    let lenient = Schema::new().optional("title", VariantType::STRING);
+    assert_eq!(lenient.validate(&invalid), Ok(()));
+}
@@ -7,8 +7,9 @@
 
 use crate::framework::{expect_panic, itest};
 use godot::builtin::{
-    Color, PackedByteArray, PackedColorArray, PackedFloat32Array, PackedInt32Array,
-    PackedStringArray,
+    Color, GString, PackedByteArray, PackedColorArray, PackedFloat32Array, PackedFloat64Array,
+    PackedInt32Array, PackedInt64Array, PackedStringArray, PackedVector2Array, PackedVector3Array,
+    Vector2, Vector3,
 };
 
 #[itest]
@@ -102,6 +103,13 @@ fn packed_array_to_vec() {
     assert_eq!(array.to_vec(), Vec::<u8>::new());
     let array = PackedByteArray::from(&[1, 2]);
     assert_eq!(array.to_vec(), vec![1, 2]);
+    assert_eq!(Vec::from(&array), vec![1, 2]);
+
+    let array = PackedStringArray::from(&[GString::from("a"), GString::from("b")]);
+    assert_eq!(array.to_vec(), vec![GString::from("a"), GString::from("b")]);
+
+    let array = PackedVector2Array::new();
+    assert_eq!(Vec::from(&array), Vec::<Vector2>::new());
 }
 
 /*
@@ -224,6 +232,47 @@ fn packed_array_get() {
     assert_eq!(array.get(2), None);
 }
 
+#[itest]
+fn packed_array_get_bounds() {
+    macro_rules! check_bounds {
+        ($PackedArray:ty, $first:expr, $last:expr) => {{
+            let array = <$PackedArray>::from_iter([$first, $last]);
+            let name = stringify!($PackedArray);
+
+            assert_eq!(array.get(0), Some($first), "{name}: first");
+            assert_eq!(array.get(1), Some($last), "{name}: last");
+            assert_eq!(array.get(2), None, "{name}: len");
+            assert_eq!(array.get(usize::MAX), None, "{name}: max");
+            assert_eq!(<$PackedArray>::new().get(0), None, "{name}: empty");
+        }};
+    }
+
+    check_bounds!(PackedByteArray, 1, 2);
+    check_bounds!(PackedInt32Array, -1, i32::MAX);
+    check_bounds!(PackedInt64Array, -1, i64::MAX);
+    check_bounds!(PackedFloat32Array, 0.5, -1.5);
+    check_bounds!(PackedFloat64Array, 0.5, -1.5);
+    check_bounds!(PackedStringArray, GString::from("a"), GString::from("b"));
+    check_bounds!(PackedVector2Array, Vector2::ONE, Vector2::new(1.0, 2.0));
+    check_bounds!(
+        PackedVector3Array,
+        Vector3::ONE,
+        Vector3::new(1.0, 2.0, 3.0)
+    );
+    #[cfg(since_api = "4.3")]
+    check_bounds!(
+        godot::builtin::PackedVector4Array,
+        godot::builtin::Vector4::ONE,
+        godot::builtin::Vector4::new(1.0, 2.0, 3.0, 4.0)
+    );
+    check_bounds!(PackedColorArray, Color::RED, Color::BLUE);
+
+    let array = PackedByteArray::from(&[1, 2]);
+    expect_panic("index beyond i64::MAX", || {
+        let _ = array[usize::MAX];
+    });
+}
+
 #[itest]
 fn packed_array_binary_search() {
     let array = PackedByteArray::from(&[1, 3]);
@@ -304,3 +353,119 @@ fn packed_array_format() {
     let a = PackedByteArray::new();
     assert_eq!(format!("{a}"), "[]");
 }
+
+#[itest]
+fn packed_byte_array_encode_decode() {
+    macro_rules! check_round_trip {
+        ($encode_le:ident, $encode_be:ident, $decode_le:ident, $decode_be:ident, $value:expr) => {{
+            let value = $value;
+            let size = std::mem::size_of_val(&value);
+
+            // Offset 1 checks that unaligned access works, and that neighboring bytes stay untouched.
+            let mut array = PackedByteArray::from(vec![0xAA; size + 2]);
+            array.$encode_le(1, value).unwrap();
+            assert_eq!(&array.as_slice()[1..=size], &value.to_le_bytes());
+            assert_eq!(array.$decode_le(1).unwrap(), value);
+
+            array.$encode_be(1, value).unwrap();
+            assert_eq!(&array.as_slice()[1..=size], &value.to_be_bytes());
+            assert_eq!(array.$decode_be(1).unwrap(), value);
+
+            assert_eq!(array[0], 0xAA);
+            assert_eq!(array[size + 1], 0xAA);
+        }};
+    }
+
+    check_round_trip!(
+        encode_u16_le,
+        encode_u16_be,
+        decode_u16_le,
+        decode_u16_be,
+        0xBEEFu16
+    );
+    check_round_trip!(
+        encode_i16_le,
+        encode_i16_be,
+        decode_i16_le,
+        decode_i16_be,
+        -12345i16
+    );
+    check_round_trip!(
+        encode_u32_le,
+        encode_u32_be,
+        decode_u32_le,
+        decode_u32_be,
+        0xDEADBEEFu32
+    );
+    check_round_trip!(
+        encode_i32_le,
+        encode_i32_be,
+        decode_i32_le,
+        decode_i32_be,
+        i32::MIN
+    );
+    check_round_trip!(
+        encode_u64_le,
+        encode_u64_be,
+        decode_u64_le,
+        decode_u64_be,
+        u64::MAX - 1
+    );
+    check_round_trip!(
+        encode_i64_le,
+        encode_i64_be,
+        decode_i64_le,
+        decode_i64_be,
+        -1234567890123i64
+    );
+    check_round_trip!(
+        encode_f32_le,
+        encode_f32_be,
+        decode_f32_le,
+        decode_f32_be,
+        -1.5f32
+    );
+    check_round_trip!(
+        encode_f64_le,
+        encode_f64_be,
+        decode_f64_le,
+        decode_f64_be,
+        std::f64::consts::PI
+    );
+}
+
+#[itest]
+fn packed_byte_array_encode_decode_byte_order() {
+    let array = PackedByteArray::from(&[0x12, 0x34, 0x56, 0x78]);
+    assert_eq!(array.decode_u32_le(0), Ok(0x78563412));
+    assert_eq!(array.decode_u32_be(0), Ok(0x12345678));
+    assert_eq!(array.decode_u16_be(2), Ok(0x5678));
+}
+
+#[itest]
+fn packed_byte_array_encode_decode_out_of_bounds() {
+    let mut array = PackedByteArray::from(&[1, 2, 3, 4, 5, 6]);
+
+    // Value would end exactly at the array's end: fine.
+    array.encode_u16_le(4, 0).unwrap();
+    assert_eq!(array.decode_u32_be(2), Ok(0x03040000));
+
+    let err = array.decode_u32_le(3).expect_err("exceeds by one byte");
+    assert_eq!((err.offset(), err.size(), err.array_len()), (3, 4, 6));
+    assert!(array.decode_f64_be(0).is_err());
+    assert!(array.decode_u16_le(usize::MAX).is_err());
+
+    // Failed writes don't change or resize the array.
+    array.encode_i32_be(4, -1).expect_err("exceeds array");
+    array
+        .encode_u64_le(usize::MAX - 2, 0)
+        .expect_err("overflowing offset");
+    assert_eq!(array.as_slice(), &[1, 2, 3, 4, 0, 0]);
+
+    let empty = PackedByteArray::new();
+    let err = empty.decode_i16_le(0).expect_err("empty array");
+    assert_eq!(
+        err.to_string(),
+        "cannot access 2 bytes at offset 0 in byte array of length 0"
+    );
+}
@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{Callable, GString, Signal, StringName, Variant};
+use godot::builtin::{emit_signal, signal_args, Callable, GString, Signal, StringName, Variant};
 use godot::meta::ToGodot;
 use godot::register::{godot_api, GodotClass};
 use std::cell::Cell;
@@ -129,6 +129,70 @@ fn emit_signal() {
     receiver.free();
 }
 
+#[itest]
+fn emit_signal_macro() {
+    let mut emitter = Emitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+
+    emitter.connect("signal_2_arg".into(), receiver.callable("receive_2_arg"));
+    let err = emit_signal!(emitter, "signal_2_arg", receiver.clone(), SIGNAL_ARG_STRING);
+
+    assert_eq!(err, godot::global::Error::OK);
+    assert!(receiver.bind().used[2].get());
+
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signal_args_macro() {
+    let source = Object::new_alloc();
+    let args = [250.to_variant(), source.to_variant()];
+
+    let (amount, object): (i64, Gd<Object>) = signal_args!(args).expect("matching args");
+    assert_eq!(amount, 250);
+    assert_eq!(object, source);
+
+    // Callables created with `Callable::from_fn()` receive `&[&Variant]`.
+    let arg_refs: Vec<&Variant> = args.iter().collect();
+    let (amount, _object): (i32, Gd<Object>) = signal_args!(arg_refs).expect("matching args");
+    assert_eq!(amount, 250);
+
+    let () = signal_args!(Vec::<Variant>::new()).expect("no args");
+
+    source.free();
+}
+
+#[itest]
+fn signal_args_macro_errors() {
+    let args = [7.to_variant(), "seven".to_variant()];
+
+    let err = signal_args!(args)
+        .map(|(_,): (i64,)| ())
+        .expect_err("too many args");
+    assert_eq!(
+        err.to_string(),
+        "wrong number of signal arguments: expected 1, got 2"
+    );
+
+    let err = signal_args!(args)
+        .map(|(_, _, _): (i64, GString, i64)| ())
+        .expect_err("too few args");
+    assert_eq!(
+        err.to_string(),
+        "wrong number of signal arguments: expected 3, got 2"
+    );
+
+    let err = signal_args!(args)
+        .map(|(_, _): (i64, i64)| ())
+        .expect_err("wrong type");
+    assert!(
+        err.to_string()
+            .starts_with("signal argument 1 has wrong type"),
+        "unexpected message: {err}"
+    );
+}
+
 #[itest]
 fn connect_signal() {
     let mut object = RefCounted::new_gd();
@@ -300,3 +364,173 @@ mod custom_callable {
         Callable::from_custom(PanicCallable(received))
     }
 }
+
+#[cfg(since_api = "4.2")]
+mod signal_timeout {
+    use std::task::Poll;
+
+    use godot::builtin::Signal;
+    use godot::classes::{RefCounted, SceneTreeTimer};
+    use godot::meta::ToGodot;
+    use godot::obj::{Gd, NewGd};
+
+    use crate::framework::{itest, poll_once};
+
+    // Signals don't keep their object alive, so it's returned alongside.
+    fn make_signal() -> (Gd<RefCounted>, Signal) {
+        let mut object = RefCounted::new_gd();
+        object.add_user_signal("test_signal".into());
+
+        let signal = Signal::from_object_signal(&object, "test_signal");
+        (object, signal)
+    }
+
+    fn timeout_connections(timer: &Gd<SceneTreeTimer>) -> usize {
+        Signal::from_object_signal(timer, "timeout")
+            .connections()
+            .len()
+    }
+
+    #[itest]
+    fn signal_timeout_signal_wins() {
+        let (_object, signal) = make_signal();
+        let mut future = signal.to_signal_timeout(60.0);
+
+        assert_eq!(poll_once(&mut future), Poll::Pending);
+        assert_eq!(signal.connections().len(), 1);
+
+        signal.emit(&[123.to_variant(), "hello".to_variant()]);
+
+        let Poll::Ready(result) = poll_once(&mut future) else {
+            panic!("future should be ready after signal emission");
+        };
+        assert_eq!(result, Some(vec![123.to_variant(), "hello".to_variant()]));
+
+        let timer = future.timer().clone();
+        assert_eq!(signal.connections().len(), 0);
+        assert_eq!(timeout_connections(&timer), 0);
+        assert_eq!(timer.get_time_left(), 0.0);
+    }
+
+    #[itest]
+    fn signal_timeout_timeout_wins() {
+        let (_object, signal) = make_signal();
+        let mut future = signal.to_signal_timeout(60.0);
+        let mut timer = future.timer().clone();
+
+        assert_eq!(poll_once(&mut future), Poll::Pending);
+
+        // Simulate the timer elapsing.
+        timer.emit_signal("timeout".into(), &[]);
+        assert_eq!(poll_once(&mut future), Poll::Ready(None));
+        assert_eq!(signal.connections().len(), 0);
+
+        // Emitting afterwards has no effect.
+        signal.emit(&[]);
+    }
+
+    #[itest]
+    fn signal_timeout_dropped_disconnects() {
+        let (_object, signal) = make_signal();
+        let future = signal.to_signal_timeout(60.0);
+        let timer = future.timer().clone();
+        assert_eq!(signal.connections().len(), 1);
+
+        drop(future);
+        assert_eq!(signal.connections().len(), 0);
+        assert_eq!(timeout_connections(&timer), 0);
+    }
+}
+
+#[cfg(since_api = "4.2")]
+mod await_signal {
+    use std::task::Poll;
+
+    use godot::builtin::Signal;
+    use godot::classes::{Node, Object, Timer};
+    use godot::meta::ToGodot;
+    use godot::obj::{Gd, NewAlloc};
+
+    use crate::framework::{expect_panic, itest, poll_once, TestContext};
+
+    #[itest]
+    fn await_signal_timer_timeout(ctx: &TestContext) {
+        let mut timer = Timer::new_alloc();
+        let mut parent = ctx.scene_tree.clone();
+        parent.add_child(&timer);
+
+        let mut future = timer.await_signal("timeout");
+        assert_eq!(poll_once(&mut future), Poll::Pending);
+
+        let timeout = Signal::from_object_signal(&timer, "timeout");
+        assert_eq!(timeout.connections().len(), 1);
+
+        // Simulate the timer elapsing.
+        timer.emit_signal("timeout".into(), &[]);
+        assert_eq!(poll_once(&mut future), Poll::Ready(Some(vec![])));
+        assert_eq!(timeout.connections().len(), 0);
+
+        parent.remove_child(&timer);
+        timer.free();
+    }
+
+    #[itest]
+    fn await_signal_user_signal_args() {
+        let mut object = Object::new_alloc();
+        object.add_user_signal("test_signal".into());
+
+        let mut future = object.await_signal("test_signal");
+        assert_eq!(poll_once(&mut future), Poll::Pending);
+
+        object.emit_signal(
+            "test_signal".into(),
+            &[7.to_variant(), "seven".to_variant()],
+        );
+        assert_eq!(
+            poll_once(&mut future),
+            Poll::Ready(Some(vec![7.to_variant(), "seven".to_variant()]))
+        );
+
+        object.free();
+    }
+
+    #[itest]
+    fn await_signal_object_freed(ctx: &TestContext) {
+        let node = Node::new_alloc();
+
+        let mut future = node.await_signal("renamed");
+        assert_eq!(poll_once(&mut future), Poll::Pending);
+
+        node.free();
+
+        // Freeing is detected on the next frame, which resolves the future.
+        let mut tree = ctx.scene_tree.get_tree().unwrap();
+        tree.emit_signal("process_frame".into(), &[]);
+        assert_eq!(poll_once(&mut future), Poll::Ready(None));
+    }
+
+    #[itest]
+    fn await_signal_dropped_disconnects() {
+        let object = Object::new_alloc();
+        let future = object.await_signal("script_changed");
+
+        let signal = Signal::from_object_signal(&object, "script_changed");
+        assert_eq!(signal.connections().len(), 1);
+
+        drop(future);
+        assert_eq!(signal.connections().len(), 0);
+
+        object.free();
+    }
+
+    #[itest]
+    fn await_signal_unknown_panics() {
+        let object: Gd<Object> = Object::new_alloc();
+
+        expect_panic("signal does not exist", || {
+            let _future = object.await_signal("no_such_signal");
+        });
+
+        object.free();
+    }
+}
@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{dict, varray, Dictionary, GString, Variant, VariantArray};
+use godot::meta::ToGodot;
+
+use crate::framework::itest;
+
+#[itest]
+fn variant_json_dictionary_roundtrip() {
+    let dict = dict! {
+        "name": "Godot",
+        "version": 4.5,
+        "stable": true,
+        "tags": varray!["engine", "open-source"],
+    };
+
+    let json = dict.to_variant().to_json_string();
+    assert_eq!(
+        json,
+        GString::from(
+            r#"{"name":"Godot","stable":true,"tags":["engine","open-source"],"version":4.5}"#
+        )
+    );
+
+    let parsed = Variant::from_json_string(&json.to_string()).expect("valid JSON");
+    assert_eq!(parsed.to::<Dictionary>(), dict);
+}
+
+#[itest]
+fn variant_json_array_roundtrip() {
+    // JSON has no integer type: numbers are parsed as floats.
+    let array = varray![1.5, "two", Variant::nil(), varray![3.5]];
+
+    let json = array.to_variant().to_json_string();
+    assert_eq!(json, GString::from(r#"[1.5,"two",null,[3.5]]"#));
+
+    let parsed = Variant::from_json_string(&json.to_string()).expect("valid JSON");
+    assert_eq!(parsed.to::<VariantArray>(), array);
+}
+
+#[itest]
+fn variant_json_indented() {
+    let value = dict! { "b": 1, "a": varray![true] }.to_variant();
+
+    assert_eq!(
+        value.to_json_string_indented("  "),
+        GString::from("{\n  \"a\": [\n    true\n  ],\n  \"b\": 1\n}")
+    );
+    assert_eq!(value.to_json_string_indented(""), value.to_json_string());
+}
+
+#[itest]
+fn variant_json_parse_error() {
+    let err = Variant::from_json_string("{\n\"key\": [1, 2\n}").expect_err("invalid JSON");
+    let message = err.to_string();
+
+    assert!(
+        message.ends_with("(line 3)"),
+        "unexpected message: {message}"
+    );
+    assert!(
+        message.len() > "(line 3)".len(),
+        "message should not be empty"
+    );
+}
@@ -6,13 +6,17 @@
  */
 
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fmt::Display;
 
+use godot::builtin::real_consts::TAU;
 use godot::builtin::{
-    array, dict, varray, Array, GString, NodePath, Signal, StringName, Variant, Vector2, Vector3,
+    array, dict, match_variant, varray, Array, Color, GString, NodePath, Rect2i, Signal,
+    StringName, Transform3D, Variant, Vector2, Vector2i, Vector3,
 };
-use godot::builtin::{Basis, Dictionary, VariantArray, VariantOperator, VariantType};
-use godot::classes::{Node, Node2D};
+use godot::builtin::{Basis, Dictionary, VariantArray, VariantKey, VariantOperator, VariantType};
+use godot::classes::{Node, Node2D, Node3D, Object};
+use godot::global;
 use godot::meta::{FromGodot, ToGodot};
 use godot::obj::{Gd, InstanceId, NewAlloc};
 use godot::sys::GodotFfi;
@@ -74,6 +78,49 @@ fn variant_conversions() {
     roundtrip(Signal::invalid());
 }
 
+// Check that the engine agrees with scalar conversions in both directions.
+#[itest]
+fn variant_scalar_conversions_match_engine() {
+    let ints = [0, 1, -1, i64::MAX, i64::MIN, 0x0102_0304_0506_0708];
+    for value in ints {
+        let variant = value.to_variant();
+        assert_eq!(variant.get_type(), VariantType::INT);
+        assert_eq!(variant.stringify(), GString::from(value.to_string()));
+
+        // `i64::MIN` has no literal in Godot's expression syntax.
+        if value != i64::MIN {
+            let engine_variant = global::str_to_var(value.to_string().into());
+            assert_eq!(engine_variant.get_type(), VariantType::INT);
+            assert_eq!(engine_variant.to::<i64>(), value);
+            assert_eq!(variant, engine_variant);
+        }
+    }
+
+    for value in [0.0, -0.0, 1.5, -1234.5678, f64::MAX, f64::MIN_POSITIVE] {
+        let variant = value.to_variant();
+        assert_eq!(variant.get_type(), VariantType::FLOAT);
+        assert_eq!(variant.to::<f64>().to_bits(), value.to_bits());
+
+        // Engine arithmetic on a converted variant.
+        let doubled = Variant::evaluate(&variant, &2.0.to_variant(), VariantOperator::MULTIPLY);
+        assert_eq!(doubled, Some((value * 2.0).to_variant()));
+    }
+    assert!(f64::NAN.to_variant().to::<f64>().is_nan());
+
+    for value in [true, false] {
+        let engine_variant = global::str_to_var(value.to_string().into());
+        assert_eq!(engine_variant.to::<bool>(), value);
+        assert_eq!(value.to_variant(), engine_variant);
+        assert_eq!(value.to_variant().booleanize(), value);
+    }
+
+    // Mismatched types still fail instead of being reinterpreted.
+    assert!(1.5.to_variant().try_to::<i64>().is_err());
+    assert!(7.to_variant().try_to::<f64>().is_err());
+    assert!(1.to_variant().try_to::<bool>().is_err());
+    assert!(Variant::nil().try_to::<i64>().is_err());
+}
+
 #[itest]
 fn variant_bad_integer_conversions() {
     truncate_bad::<i8>(128);
@@ -97,6 +144,343 @@ fn variant_bad_integer_conversions() {
     truncate_bad::<u64>(-1);
 }
 
+#[itest]
+fn variant_get_component() {
+    let vector = Vector2::new(1.5, -2.0).to_variant();
+    assert_eq!(vector.get_component("x").unwrap(), 1.5.to_variant());
+    assert_eq!(vector.get_component("y").unwrap(), (-2.0).to_variant());
+
+    let color = Color::from_rgba(0.25, 0.5, 0.75, 1.0).to_variant();
+    assert_eq!(color.get_component("r").unwrap(), 0.25.to_variant());
+    assert_eq!(color.get_component("a").unwrap(), 1.0.to_variant());
+
+    let err = vector.get_component("z").expect_err("Vector2 has no z");
+    assert!(err.is_not_found());
+    color.get_component("x").expect_err("Color has no x");
+    42.to_variant()
+        .get_component("x")
+        .expect_err("int has no components");
+
+    let node = Node::new_alloc();
+    node.to_variant()
+        .get_component("name")
+        .expect_err("objects not supported");
+    node.free();
+}
+
+#[itest]
+fn variant_set_component() {
+    let mut vector = Vector2::new(1.5, -2.0).to_variant();
+    vector.set_component("y", &4.0.to_variant()).unwrap();
+    assert_eq!(vector, Vector2::new(1.5, 4.0).to_variant());
+
+    // Ints are accepted for float components.
+    let mut color = Color::BLACK.to_variant();
+    color.set_component("g", &1.to_variant()).unwrap();
+    assert_eq!(color, Color::from_rgb(0.0, 1.0, 0.0).to_variant());
+
+    vector
+        .set_component("w", &1.0.to_variant())
+        .expect_err("Vector2 has no w");
+    vector
+        .set_component("x", &"text".to_variant())
+        .expect_err("string is not a float");
+    assert_eq!(vector, Vector2::new(1.5, 4.0).to_variant());
+}
+
+#[itest]
+fn variant_to_gd() {
+    let node = Node2D::new_alloc();
+    let variant = node.to_variant();
+
+    let typed = variant.to_gd::<Node2D>().expect("same class");
+    assert_eq!(typed, node);
+
+    let base = variant.to_gd::<Node>().expect("base class");
+    assert_eq!(base.instance_id(), node.instance_id());
+
+    let err = variant.to_gd::<Node3D>().expect_err("unrelated class");
+    assert!(
+        err.to_string().contains("expected class Node3D"),
+        "unexpected message: {err}"
+    );
+
+    let err = Variant::nil().to_gd::<Node>().expect_err("nil");
+    assert!(
+        err.to_string().contains("expected type OBJECT, got NIL"),
+        "unexpected message: {err}"
+    );
+
+    let err = 42.to_variant().to_gd::<Node>().expect_err("int");
+    assert!(
+        err.to_string().contains("expected type OBJECT, got INT"),
+        "unexpected message: {err}"
+    );
+
+    node.free();
+}
+
+#[itest]
+fn variant_is_shared_with() {
+    let array = varray![1, 2].to_variant();
+    let alias = array.clone();
+    let copy = varray![1, 2].to_variant();
+    assert!(array.is_shared_with(&alias));
+    assert!(!array.is_shared_with(&copy));
+
+    let dictionary = dict! { "key": 1 }.to_variant();
+    assert!(dictionary.is_shared_with(&dictionary.clone()));
+    assert!(!dictionary.is_shared_with(&array));
+
+    // Value types are never shared, even if equal.
+    let string = "text".to_variant();
+    assert!(!string.is_shared_with(&string.clone()));
+}
+
+#[itest]
+fn variant_ensure_unique() {
+    let inner = varray![1];
+    let original = varray![inner.clone(), 2];
+
+    let mut variant = original.to_variant();
+    let alias = variant.clone();
+    variant.ensure_unique();
+    assert!(!variant.is_shared_with(&alias));
+    assert_eq!(variant, alias);
+
+    // Deep copy: modifying the nested array of the copy does not affect the original.
+    let mut nested_copy = variant.to::<VariantArray>().at(0).to::<VariantArray>();
+    nested_copy.push(&10.to_variant());
+    assert_eq!(inner, varray![1]);
+    assert_eq!(original, varray![varray![1], 2]);
+
+    // Typed arrays keep their element type.
+    let mut typed = array![1, 2, 3].to_variant();
+    typed.ensure_unique();
+    assert_eq!(typed.try_to::<Array<i64>>().unwrap(), array![1, 2, 3]);
+
+    let mut dictionary = dict! { "key": 1 }.to_variant();
+    let dictionary_alias = dictionary.clone();
+    dictionary.ensure_unique();
+    assert!(!dictionary.is_shared_with(&dictionary_alias));
+
+    let mut number = 5.to_variant();
+    number.ensure_unique();
+    assert_eq!(number, 5.to_variant());
+}
+
+#[itest]
+fn variant_typed_bytes_roundtrip() {
+    let values = [
+        (-1234567890123_i64).to_variant(),
+        2.5.to_variant(),
+        Vector2i::new(-3, 7).to_variant(),
+        Vector3::new(1.0, -2.5, 3.25).to_variant(),
+        Rect2i::new(Vector2i::new(1, 2), Vector2i::new(30, 40)).to_variant(),
+        Color::from_rgba(0.25, 0.5, 0.75, 1.0).to_variant(),
+        Transform3D::new(TEST_BASIS, Vector3::new(10.0, 11.0, 12.0)).to_variant(),
+    ];
+
+    for value in values {
+        let variant_type = value.get_type();
+        let bytes = value.to_typed_bytes().expect("fixed-size type");
+
+        let decoded = Variant::from_typed_bytes(variant_type, &bytes).expect("valid bytes");
+        assert_eq!(decoded, value, "round-trip of {variant_type:?}");
+    }
+}
+
+#[itest]
+fn variant_typed_bytes_layout() {
+    // Components are always f32, independent of `double-precision`.
+    let components: [f32; 3] = [1.0, 2.0, 3.0];
+    let mut bytes = Vec::new();
+    for component in components {
+        bytes.extend_from_slice(&component.to_le_bytes());
+    }
+    assert_eq!(bytes.len(), 12);
+
+    let variant = Variant::from_typed_bytes(VariantType::VECTOR3, &bytes).unwrap();
+    assert_eq!(variant, Vector3::new(1.0, 2.0, 3.0).to_variant());
+    assert_eq!(variant.to_typed_bytes().unwrap(), bytes);
+}
+
+#[itest]
+fn variant_typed_bytes_errors() {
+    let err = Variant::from_typed_bytes(VariantType::VECTOR2I, &[0; 7]).expect_err("too short");
+    assert!(err.is_length_mismatch());
+    assert_eq!(err.variant_type(), VariantType::VECTOR2I);
+    assert_eq!(err.to_string(), "type VECTOR2I requires 8 bytes, got 7");
+
+    let err = Variant::from_typed_bytes(VariantType::INT, &[0; 9]).expect_err("too long");
+    assert!(err.is_length_mismatch());
+
+    let err = Variant::from_typed_bytes(VariantType::STRING, b"text").expect_err("not fixed-size");
+    assert!(err.is_unsupported_type());
+
+    let err = "text"
+        .to_variant()
+        .to_typed_bytes()
+        .expect_err("not fixed-size");
+    assert!(err.is_unsupported_type());
+    assert_eq!(err.variant_type(), VariantType::STRING);
+}
+
+#[itest]
+fn variant_saturating_integer_conversions() {
+    let max = i64::MAX.to_variant();
+    let min = i64::MIN.to_variant();
+
+    assert_eq!(max.to_saturating::<i32>().unwrap(), i32::MAX);
+    assert_eq!(min.to_saturating::<i32>().unwrap(), i32::MIN);
+    assert_eq!(max.to_saturating::<i8>().unwrap(), i8::MAX);
+    assert_eq!(min.to_saturating::<u8>().unwrap(), 0);
+    assert_eq!(max.to_saturating::<i16>().unwrap(), i16::MAX);
+    assert_eq!(min.to_saturating::<u16>().unwrap(), 0);
+    assert_eq!(max.to_saturating::<u32>().unwrap(), u32::MAX);
+    assert_eq!(max.to_saturating::<i64>().unwrap(), i64::MAX);
+    assert_eq!(max.to_saturating::<u64>().unwrap(), i64::MAX as u64);
+    assert_eq!(min.to_saturating::<u64>().unwrap(), 0);
+
+    // Values in range are unchanged, values just outside are clamped.
+    assert_eq!(200.to_variant().to_saturating::<u8>().unwrap(), 200);
+    assert_eq!((-128).to_variant().to_saturating::<i8>().unwrap(), -128);
+    assert_eq!((-129).to_variant().to_saturating::<i8>().unwrap(), i8::MIN);
+
+    // Floats are truncated, then clamped.
+    assert_eq!(3.9.to_variant().to_saturating::<i32>().unwrap(), 3);
+    assert_eq!((-3.9).to_variant().to_saturating::<i32>().unwrap(), -3);
+    assert_eq!(1e30.to_variant().to_saturating::<i64>().unwrap(), i64::MAX);
+    assert_eq!(
+        f64::NEG_INFINITY
+            .to_variant()
+            .to_saturating::<u32>()
+            .unwrap(),
+        0
+    );
+
+    f64::NAN
+        .to_variant()
+        .to_saturating::<i32>()
+        .expect_err("NaN");
+    "12".to_variant()
+        .to_saturating::<i32>()
+        .expect_err("string");
+    Variant::nil().to_saturating::<i32>().expect_err("nil");
+}
+
+#[itest]
+fn variant_checked_integer_conversions() {
+    let max = i64::MAX.to_variant();
+    let err = max
+        .to_checked::<i32>()
+        .expect_err("i64::MAX does not fit into i32");
+    assert!(
+        err.to_string().contains("out of range for `i32`"),
+        "unexpected message: {err}"
+    );
+
+    assert_eq!(
+        2147483647.to_variant().to_checked::<i32>().unwrap(),
+        i32::MAX
+    );
+    2147483648i64
+        .to_variant()
+        .to_checked::<i32>()
+        .expect_err("i32::MAX + 1");
+    assert_eq!(
+        (-2147483648i64).to_variant().to_checked::<i32>().unwrap(),
+        i32::MIN
+    );
+    (-2147483649i64)
+        .to_variant()
+        .to_checked::<i32>()
+        .expect_err("i32::MIN - 1");
+
+    assert_eq!(255.to_variant().to_checked::<u8>().unwrap(), 255);
+    256.to_variant()
+        .to_checked::<u8>()
+        .expect_err("u8::MAX + 1");
+    (-1).to_variant()
+        .to_checked::<u8>()
+        .expect_err("negative u8");
+    (-1).to_variant()
+        .to_checked::<u16>()
+        .expect_err("negative u16");
+    (-1).to_variant()
+        .to_checked::<u32>()
+        .expect_err("negative u32");
+    (-1).to_variant()
+        .to_checked::<u64>()
+        .expect_err("negative u64");
+    128.to_variant()
+        .to_checked::<i8>()
+        .expect_err("i8::MAX + 1");
+    32768
+        .to_variant()
+        .to_checked::<i16>()
+        .expect_err("i16::MAX + 1");
+    assert_eq!(max.to_checked::<i64>().unwrap(), i64::MAX);
+    assert_eq!(max.to_checked::<u64>().unwrap(), i64::MAX as u64);
+
+    // Floats must be integral and in range.
+    assert_eq!(3.0.to_variant().to_checked::<u8>().unwrap(), 3);
+    3.5.to_variant().to_checked::<u8>().expect_err("fraction");
+    (i64::MAX as f64)
+        .to_variant()
+        .to_checked::<i64>()
+        .expect_err("2^63");
+    f64::INFINITY
+        .to_variant()
+        .to_checked::<i64>()
+        .expect_err("infinity");
+    f64::NAN.to_variant().to_checked::<i64>().expect_err("NaN");
+
+    true.to_variant().to_checked::<i32>().expect_err("bool");
+}
+
+#[itest]
+fn variant_pointer_sized_integer_conversions() {
+    let max = i64::MAX.to_variant();
+    let min = i64::MIN.to_variant();
+
+    // Saturation depends on the platform's pointer width, so compare against the conversion from i64.
+    let usize_max = usize::try_from(i64::MAX).unwrap_or(usize::MAX);
+    let isize_max = isize::try_from(i64::MAX).unwrap_or(isize::MAX);
+    let isize_min = isize::try_from(i64::MIN).unwrap_or(isize::MIN);
+    assert_eq!(max.to_saturating::<usize>().unwrap(), usize_max);
+    assert_eq!(min.to_saturating::<usize>().unwrap(), 0);
+    assert_eq!(max.to_saturating::<isize>().unwrap(), isize_max);
+    assert_eq!(min.to_saturating::<isize>().unwrap(), isize_min);
+    assert_eq!((-3.9).to_variant().to_saturating::<isize>().unwrap(), -3);
+    assert_eq!((-3.9).to_variant().to_saturating::<usize>().unwrap(), 0);
+
+    assert_eq!(1234.to_variant().to_checked::<usize>().unwrap(), 1234);
+    assert_eq!((-1234).to_variant().to_checked::<isize>().unwrap(), -1234);
+    assert_eq!(
+        max.to_checked::<usize>().ok(),
+        usize::try_from(i64::MAX).ok()
+    );
+    assert_eq!(
+        min.to_checked::<isize>().ok(),
+        isize::try_from(i64::MIN).ok()
+    );
+    assert_eq!(3.0.to_variant().to_checked::<usize>().unwrap(), 3);
+
+    let err = (-1)
+        .to_variant()
+        .to_checked::<usize>()
+        .expect_err("negative usize");
+    assert!(
+        err.to_string().contains("out of range for `usize`"),
+        "unexpected message: {err}"
+    );
+    (-1.0)
+        .to_variant()
+        .to_checked::<usize>()
+        .expect_err("negative float usize");
+}
+
 #[itest]
 fn variant_bad_conversions() {
     fn assert_convert_err<T: ToGodot, U: FromGodot + std::fmt::Debug>(value: T) {
@@ -302,6 +686,212 @@ fn variant_evaluate_total_order() {
     total_order(gstr("hello"), gstr("hell"), Ordering::Greater);
 }
 
+#[itest]
+fn variant_partial_cmp() {
+    fn cmp<T: ToGodot, U: ToGodot>(lhs: T, rhs: U) -> Option<Ordering> {
+        lhs.to_variant().partial_cmp(&rhs.to_variant())
+    }
+
+    assert_eq!(cmp(3, 7), Some(Ordering::Less));
+    assert_eq!(cmp(7, 7), Some(Ordering::Equal));
+    assert_eq!(cmp(-2.5, -3.5), Some(Ordering::Greater));
+
+    // Numbers of different types are compared by value.
+    assert_eq!(cmp(3, 7.5), Some(Ordering::Less));
+    assert_eq!(cmp(7.0, 7), Some(Ordering::Equal));
+
+    assert_eq!(cmp(gstr("apple"), gstr("banana")), Some(Ordering::Less));
+    assert_eq!(cmp(gstr("rust"), sname("rust")), Some(Ordering::Equal));
+
+    // Incomparable.
+    assert_eq!(cmp(1, gstr("1")), None);
+    assert_eq!(cmp(f64::NAN, 1.0), None);
+    assert_eq!(cmp(dict! { "a": 1 }, dict! { "a": 2 }), None);
+
+    // Equal values of unordered types are still equal, like with `==`.
+    assert_eq!(
+        cmp(dict! { "a": 1 }, dict! { "a": 1 }),
+        Some(Ordering::Equal)
+    );
+
+    assert!(7.to_variant() > 3.to_variant());
+    assert!(gstr("a").to_variant() <= gstr("a").to_variant());
+}
+
+#[itest]
+fn variant_sort_by_partial_cmp() {
+    let mut values = vec![
+        3.to_variant(),
+        1.5.to_variant(),
+        (-2).to_variant(),
+        2.to_variant(),
+    ];
+    values.sort_by(|a, b| a.partial_cmp(b).expect("comparable"));
+
+    let expected = vec![
+        (-2).to_variant(),
+        1.5.to_variant(),
+        2.to_variant(),
+        3.to_variant(),
+    ];
+    assert_eq!(values, expected);
+}
+
+#[itest]
+fn variant_approx_eq() {
+    fn approx<T: ToGodot, U: ToGodot>(lhs: T, rhs: U, epsilon: f64) -> bool {
+        lhs.to_variant().approx_eq(&rhs.to_variant(), epsilon)
+    }
+
+    assert!(approx(1.0, 1.0005, 1e-3));
+    assert!(!approx(1.0, 1.0005, 1e-4));
+    assert!(approx(f64::INFINITY, f64::INFINITY, 1e-3));
+    assert!(!approx(f64::NAN, f64::NAN, 1e-3));
+
+    // Numbers of different types are compared by value; other types exactly.
+    assert!(approx(3, 3.0001, 1e-3));
+    assert!(!approx(3, 4, 1e-3));
+    assert!(approx(gstr("rust"), sname("rust"), 1e-3));
+    assert!(!approx(1, gstr("1"), 1e-3));
+
+    assert!(approx(
+        Vector2::new(0.1, 0.2),
+        Vector2::new(0.1001, 0.1999),
+        1e-3
+    ));
+    assert!(!approx(
+        Vector2::new(0.1, 0.2),
+        Vector2::new(0.1, 0.21),
+        1e-3
+    ));
+    assert!(approx(
+        Color::from_rgba(0.5, 0.5, 0.5, 1.0),
+        Color::from_rgba(0.5004, 0.5, 0.4996, 1.0),
+        1e-3
+    ));
+
+    let rotated = Transform3D::IDENTITY.rotated(Vector3::UP, TAU);
+    assert!(approx(rotated, Transform3D::IDENTITY, 1e-5));
+    assert!(!approx(rotated, Transform3D::IDENTITY, 0.0));
+}
+
+#[itest]
+fn variant_approx_eq_nested() {
+    let expected = dict! {
+        "name": "player",
+        "position": Vector3::new(1.0, 2.0, 3.0),
+        "stats": dict! { "speed": 4.5, "weights": varray![0.25, 0.75] },
+    };
+
+    let within = dict! {
+        "name": "player",
+        "position": Vector3::new(1.0001, 1.9999, 3.0),
+        "stats": dict! { "speed": 4.5002, "weights": varray![0.2501, 0.7499] },
+    };
+    assert!(within.to_variant().approx_eq(&expected.to_variant(), 1e-3));
+    assert!(expected.to_variant().approx_eq(&within.to_variant(), 1e-3));
+    assert_ne!(within, expected);
+
+    let outside = dict! {
+        "name": "player",
+        "position": Vector3::new(1.0, 2.0, 3.0),
+        "stats": dict! { "speed": 4.5, "weights": varray![0.25, 0.76] },
+    };
+    assert!(!outside.to_variant().approx_eq(&expected.to_variant(), 1e-3));
+
+    // Non-numeric values, keys and sizes must match exactly.
+    let renamed = dict! {
+        "name": "Player",
+        "position": Vector3::new(1.0, 2.0, 3.0),
+        "stats": dict! { "speed": 4.5, "weights": varray![0.25, 0.75] },
+    };
+    assert!(!renamed.to_variant().approx_eq(&expected.to_variant(), 1e-3));
+
+    let mut extra = expected.duplicate_deep();
+    extra.set("extra", Variant::nil());
+    assert!(!extra.to_variant().approx_eq(&expected.to_variant(), 1e-3));
+
+    let short = varray![0.25];
+    assert!(!short
+        .to_variant()
+        .approx_eq(&varray![0.25, 0.75].to_variant(), 1e-3));
+
+    // Typed arrays are compared by their elements.
+    let typed = array![Vector2::new(1.0, 1.0)];
+    let untyped = varray![Vector2::new(1.0001, 1.0)];
+    assert!(typed.to_variant().approx_eq(&untyped.to_variant(), 1e-3));
+}
+
+#[itest]
+fn variant_object_properties() {
+    let mut node = Node2D::new_alloc();
+    node.set_name("Probe".into());
+    node.set_position(Vector2::new(3.0, 4.0));
+
+    let variant = node.to_variant();
+    let properties = variant.object_properties().expect("object variant");
+    assert_eq!(
+        properties.get("name"),
+        Some(StringName::from("Probe").to_variant())
+    );
+    assert_eq!(
+        properties.get("position"),
+        Some(Vector2::new(3.0, 4.0).to_variant())
+    );
+
+    node.free();
+    assert_eq!(variant.object_properties(), None, "freed object");
+}
+
+#[itest]
+fn variant_object_properties_non_object() {
+    assert_eq!(Variant::nil().object_properties(), None);
+    assert_eq!(7.to_variant().object_properties(), None);
+    assert_eq!(Dictionary::new().to_variant().object_properties(), None);
+
+    let null_object = Option::<Gd<Node>>::None.to_variant();
+    assert_eq!(null_object.object_properties(), None);
+}
+
+#[itest]
+fn variant_match_macro() {
+    fn describe(value: &Variant) -> String {
+        match_variant!(value, {
+            Int(i) => format!("int {i}"),
+            Float(f) => format!("float {f}"),
+            GString(s) => format!("string {s}"),
+            Array(a) => {
+                let len = a.len();
+                format!("array of {len}")
+            },
+            Vector2(Vector2 { x, y }) => format!("vector {x},{y}"),
+            Object(object) => format!("object {}", object.get_class()),
+            Nil => "nil".to_string(),
+            _ => "other".to_string(),
+        })
+    }
+
+    let node = Node::new_alloc();
+    let cases = [
+        (42.to_variant(), "int 42"),
+        (1.5.to_variant(), "float 1.5"),
+        ("text".to_variant(), "string text"),
+        (varray![1, "two", 3.0].to_variant(), "array of 3"),
+        (Vector2::new(1.0, 2.0).to_variant(), "vector 1,2"),
+        (node.to_variant(), "object Node"),
+        (Variant::nil(), "nil"),
+        (Option::<Gd<Node>>::None.to_variant(), "nil"),
+        (StringName::from("text").to_variant(), "other"),
+        (true.to_variant(), "other"),
+    ];
+
+    for (variant, expected) in cases {
+        assert_eq!(describe(&variant), expected);
+    }
+
+    node.free();
+}
+
 #[itest]
 fn variant_display() {
     let cases = [
@@ -451,6 +1041,173 @@ fn variant_hash() {
     assert_ne!(dict! { 0: dict! { 0: 0 } }, dict! { 0: dict! { 0: 1 } });
 }
 
+#[itest]
+fn variant_expect_type() {
+    let array = varray![1, "two"].to_variant();
+    let converted = array.expect_type::<VariantArray>().expect("array matches");
+    assert_eq!(converted, varray![1, "two"]);
+
+    let value = 42.to_variant();
+    assert_eq!(value.expect_type::<i32>().expect("int matches"), 42);
+    assert_eq!(value.expect_type::<Variant>().expect("any type"), value);
+
+    let nil = Variant::nil();
+    let node = nil.expect_type::<Option<Gd<Node>>>().expect("nil object");
+    assert!(node.is_none());
+}
+
+#[itest]
+fn variant_expect_type_mismatch() {
+    let dict = Dictionary::new().to_variant();
+    let err = dict
+        .expect_type::<VariantArray>()
+        .expect_err("dictionary is not an array");
+    assert!(
+        err.to_string()
+            .starts_with("expected type ARRAY, got DICTIONARY"),
+        "unexpected error: {err}"
+    );
+
+    let err = 1.5f64
+        .to_variant()
+        .expect_type::<GString>()
+        .expect_err("float is not a string");
+    assert!(err
+        .to_string()
+        .starts_with("expected type STRING, got FLOAT"));
+    assert_eq!(err.value(), Some(&1.5f64.to_variant()));
+
+    Variant::nil()
+        .expect_type::<i64>()
+        .expect_err("nil is not an int");
+}
+
+#[itest]
+fn variant_pretty_string_scalars() {
+    assert_eq!(Variant::nil().to_pretty_string(2), "null");
+    assert_eq!(42.to_variant().to_pretty_string(2), "int 42");
+    assert_eq!(true.to_variant().to_pretty_string(2), "bool true");
+    assert_eq!(
+        "say \"hi\"".to_variant().to_pretty_string(2),
+        r#"String "say \"hi\"""#
+    );
+    assert_eq!(
+        StringName::from("name").to_variant().to_pretty_string(2),
+        r#"StringName "name""#
+    );
+    assert_eq!(
+        Vector2::new(1.0, 2.5).to_variant().to_pretty_string(2),
+        "Vector2 (1, 2.5)"
+    );
+    assert_eq!(
+        Dictionary::new().to_variant().to_pretty_string(2),
+        "Dictionary {}"
+    );
+    assert_eq!(varray![].to_variant().to_pretty_string(2), "Array []");
+}
+
+#[itest]
+fn variant_pretty_string_nested() {
+    let shared = varray![1.5, Variant::nil()];
+    let value = dict! {
+        "name": "Godot",
+        "typed": array![3, 4],
+        "nested": dict! { "a": shared.clone(), "b": shared },
+    };
+
+    let expected = r#"Dictionary {
+  String "name": String "Godot",
+  String "typed": Array [
+    int 3,
+    int 4
+  ],
+  String "nested": Dictionary {
+    String "a": Array [
+      float 1.5,
+      null
+    ],
+    String "b": Array [
+      float 1.5,
+      null
+    ]
+  }
+}"#;
+    assert_eq!(value.to_variant().to_pretty_string(2), expected);
+
+    let expected = "Array [\n\tint 1,\n\tArray [\n\t\tint 2\n\t]\n]".replace('\t', "    ");
+    assert_eq!(
+        varray![1, varray![2]].to_variant().to_pretty_string(4),
+        expected
+    );
+}
+
+#[itest]
+fn variant_pretty_string_cycle() {
+    let mut dict = dict! { "value": 1 };
+    dict.set("self", dict.clone());
+
+    let expected = r#"Dictionary {
+  String "value": int 1,
+  String "self": Dictionary <cycle>
+}"#;
+    assert_eq!(dict.to_variant().to_pretty_string(2), expected);
+
+    let mut outer = varray![];
+    let mut inner = varray![];
+    outer.push(inner.to_variant());
+    inner.push(outer.to_variant());
+
+    let expected = r#"Array [
+  Array [
+    Array <cycle>
+  ]
+]"#;
+    assert_eq!(outer.to_variant().to_pretty_string(2), expected);
+
+    // Break cycles, so that the containers can be freed.
+    dict.clear();
+    inner.clear();
+}
+
+#[itest]
+fn variant_key_object_identity() {
+    let node = Node::new_alloc();
+    let other = Node::new_alloc();
+
+    let mut seen = HashSet::new();
+    assert!(seen.insert(VariantKey::new(node.clone())));
+    assert!(!seen.insert(VariantKey::new(node.clone())));
+    assert!(!seen.insert(VariantKey::new(node.clone().upcast::<Object>())));
+    assert_eq!(seen.len(), 1);
+
+    assert!(seen.insert(VariantKey::new(other.clone())));
+    assert_eq!(seen.len(), 2);
+
+    node.free();
+    other.free();
+}
+
+#[itest]
+fn variant_key_values() {
+    let keys: HashSet<VariantKey> = [
+        VariantKey::new(1),
+        VariantKey::new(1),
+        VariantKey::new(1.0),
+        VariantKey::new(f64::NAN),
+        VariantKey::new(f64::NAN),
+        VariantKey::new("one"),
+        VariantKey::new(varray![1, "one"]),
+        VariantKey::new(varray![1, "one"]),
+    ]
+    .into_iter()
+    .collect();
+
+    // Duplicates: 1, NaN, array. Unlike with `==`, 1 and 1.0 are different keys.
+    assert_eq!(keys.len(), 5);
+    assert!(keys.contains(&VariantKey::new(1.0)));
+    assert_eq!(VariantKey::new(2).into_variant(), 2.to_variant());
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 fn truncate_bad<T>(original_value: i64)
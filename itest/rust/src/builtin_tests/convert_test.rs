@@ -5,8 +5,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::{BTreeMap, HashMap};
+
 use godot::builtin::{
-    array, dict, Array, Dictionary, GString, Variant, VariantArray, Vector2, Vector2Axis,
+    array, dict, varray, Array, Dictionary, GString, PackedByteArray, Variant, VariantArray,
+    VariantType, Vector2, Vector2Axis,
 };
 use godot::classes::{Node, Resource};
 use godot::meta::error::ConvertError;
@@ -324,3 +327,115 @@ fn slice_to_array() {
     let to = from.to_variant().try_to::<Array<f32>>();
     assert!(to.is_err());
 }
+
+#[itest]
+fn hash_map_roundtrip() {
+    let from = HashMap::from([
+        ("one".to_string(), 1),
+        ("two".to_string(), 2),
+        ("three".to_string(), 3),
+    ]);
+
+    let dictionary = from.to_variant().to::<Dictionary>();
+    assert_eq!(dictionary.len(), 3);
+    assert_eq!(dictionary.get("two"), Some(2.to_variant()));
+
+    let to = dictionary.to_variant().to::<HashMap<String, i32>>();
+    assert_eq!(to, from);
+}
+
+#[itest]
+fn btree_map_roundtrip() {
+    let from = BTreeMap::from([
+        (3, vec![GString::from("c")]),
+        (1, vec![GString::from("a"), GString::from("b")]),
+    ]);
+
+    // Entries are inserted in key order.
+    let dictionary = from.to_godot();
+    assert_eq!(dictionary.keys_array(), varray![1, 3]);
+
+    let to = dictionary.to_variant().to::<BTreeMap<i64, Vec<GString>>>();
+    assert_eq!(to, from);
+}
+
+#[itest]
+fn dictionary_to_map_fails() {
+    let bad_key = dict! { "ok": 1, 2: 3 };
+    let err = bad_key
+        .to_variant()
+        .try_to::<HashMap<String, i32>>()
+        .expect_err("integer key is not a String");
+    assert_eq!(err.value(), Some(&2.to_variant()));
+    assert!(err
+        .to_string()
+        .contains("dictionary key 2 cannot be converted"));
+
+    let bad_value = dict! { "ok": 1, "bad": "text" };
+    let err = bad_value
+        .to_variant()
+        .try_to::<BTreeMap<GString, i32>>()
+        .expect_err("String value is not an i32");
+    assert_eq!(err.value(), Some(&"text".to_variant()));
+    assert!(err
+        .to_string()
+        .contains(r#"dictionary value for key "bad" cannot be converted"#));
+
+    let not_a_dictionary = array![1, 2].to_variant();
+    assert!(not_a_dictionary.try_to::<HashMap<i32, i32>>().is_err());
+}
+
+#[itest]
+fn u128_roundtrip() {
+    for value in [
+        0,
+        1,
+        u64::MAX as u128,
+        u64::MAX as u128 + 1,
+        u128::MAX - 1,
+        u128::MAX,
+    ] {
+        let variant = value.to_variant();
+        assert_eq!(variant.get_type(), VariantType::PACKED_BYTE_ARRAY);
+        assert_eq!(variant.to::<u128>(), value);
+    }
+
+    for value in [
+        0,
+        -1,
+        i64::MIN as i128,
+        i64::MAX as i128 + 1,
+        i128::MIN,
+        i128::MAX,
+    ] {
+        assert_eq!(value.to_variant().to::<i128>(), value);
+    }
+}
+
+#[itest]
+fn u128_byte_layout() {
+    // Little-endian: least significant byte first.
+    let value: u128 = 0x0f0e0d0c_0b0a0908_07060504_03020100;
+    let bytes = value.to_godot();
+    assert_eq!(bytes.as_slice(), (0..16).collect::<Vec<u8>>());
+
+    assert_eq!(
+        (-2_i128).to_godot().as_slice(),
+        [0xfe].into_iter().chain([0xff; 15]).collect::<Vec<u8>>()
+    );
+}
+
+#[itest]
+fn u128_from_invalid_length() {
+    for len in [0, 15, 17] {
+        let bytes = PackedByteArray::from(vec![0; len].as_slice());
+
+        let err = u128::try_from_godot(bytes.clone()).expect_err("wrong byte count");
+        assert_eq!(err.value(), Some(&bytes.to_variant()));
+        assert!(err.to_string().contains("requires 16 bytes"), "{err}");
+
+        assert!(bytes.to_variant().try_to::<i128>().is_err());
+    }
+
+    assert!(42.to_variant().try_to::<u128>().is_err());
+}
@@ -157,6 +157,36 @@ fn basis_equiv() {
     )
 }
 
+#[itest]
+fn basis_slerp_equiv() {
+    let to = Basis::from_axis_angle(Vector3::new(0.0, 1.0, 1.0).normalized(), 2.5);
+    let inner = InnerBasis::from_outer(&TEST_BASIS);
+
+    for weight in [0.0, 0.5, 1.0] {
+        assert_eq_approx!(
+            TEST_BASIS.slerp(&to, weight),
+            inner.slerp(to, weight.as_f64()),
+            "weight: {weight}\n"
+        );
+    }
+
+    assert_eq_approx!(TEST_BASIS.slerp(&to, 1.0), to);
+}
+
+#[itest]
+fn basis_slerp_degenerate() {
+    let rotation = Basis::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.7);
+    let collapsed = Basis::from_scale(Vector3::ZERO);
+
+    assert_eq_approx!(collapsed.slerp(&rotation, 1.0), rotation);
+    assert_eq_approx!(rotation.slerp(&collapsed, 0.0), rotation);
+    assert_eq_approx!(
+        collapsed.slerp(&rotation, 0.25),
+        Basis::from_scale(Vector3::splat(0.25)) * rotation
+    );
+    assert_eq_approx!(collapsed.slerp(&collapsed, 0.5), collapsed);
+}
+
 fn deg_to_rad(rotation: Vector3) -> Vector3 {
     Vector3::new(
         rotation.x.to_radians(),
@@ -8,7 +8,7 @@
 use crate::framework::itest;
 
 use godot::builtin::inner::InnerTransform3D;
-use godot::builtin::{Aabb, Basis, Plane, Transform3D, VariantOperator, Vector3};
+use godot::builtin::{Aabb, Basis, Plane, RealConv, Transform3D, VariantOperator, Vector3};
 use godot::meta::ToGodot;
 use godot::private::class_macros::assert_eq_approx;
 
@@ -82,3 +82,45 @@ fn transform3d_xform_equiv() {
         "operator: Transform3D * Plane"
     );
 }
+
+#[itest]
+fn transform3d_interpolate_with_equiv() {
+    let from = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, 0.3) * Basis::from_scale(Vector3::new(1.0, 2.0, 0.5)),
+        Vector3::new(1.0, -2.0, 3.0),
+    );
+    let to = Transform3D::new(
+        Basis::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalized(), 2.0),
+        Vector3::new(-4.0, 5.0, 0.0),
+    );
+    let inner = InnerTransform3D::from_outer(&from);
+
+    for weight in [0.0, 0.5, 1.0] {
+        assert_eq_approx!(
+            from.interpolate_with(&to, weight),
+            inner.interpolate_with(to, weight.as_f64()),
+            "weight: {weight}\n"
+        );
+    }
+
+    assert_eq_approx!(from.interpolate_with(&to, 0.0), from);
+    assert_eq_approx!(from.interpolate_with(&to, 1.0), to);
+}
+
+#[itest]
+fn transform3d_interpolate_with_degenerate() {
+    let rotation = Basis::from_axis_angle(Vector3::UP, 1.0);
+    let collapsed = Transform3D::new(Basis::from_scale(Vector3::ZERO), Vector3::ZERO);
+    let target = Transform3D::new(rotation, Vector3::new(2.0, 0.0, 0.0));
+
+    // The degenerate side takes over the other rotation, so only scale and origin change.
+    let halfway = collapsed.interpolate_with(&target, 0.5);
+    assert_eq_approx!(
+        halfway.basis,
+        rotation * Basis::from_scale(Vector3::splat(0.5))
+    );
+    assert_eq_approx!(halfway.origin, Vector3::new(1.0, 0.0, 0.0));
+
+    let both = collapsed.interpolate_with(&collapsed, 0.5);
+    assert_eq_approx!(both, collapsed);
+}
@@ -31,6 +31,8 @@ mod containers {
     mod packed_array_test;
     mod rid_test;
     mod signal_test;
+    #[cfg(feature = "codegen-full")]
+    mod variant_json_test;
     mod variant_test;
 }
 
@@ -8,7 +8,7 @@
 use std::collections::HashSet;
 
 use crate::framework::itest;
-use godot::builtin::GString;
+use godot::builtin::{gstr_format, GString, StringName, Vector2};
 
 // TODO use tests from godot-rust/gdnative
 
@@ -113,3 +113,30 @@ fn string_with_null() {
         assert_eq!(left, right);
     }
 }
+
+#[itest]
+fn string_format_macro() {
+    let score = 42;
+    let position = Vector2::new(1.0, 2.5);
+
+    assert_eq!(gstr_format!("plain text"), GString::from("plain text"));
+    assert_eq!(gstr_format!("Score: {}", score), GString::from("Score: 42"));
+    assert_eq!(
+        gstr_format!("{score:>5}|{:.2}|{position}", 0.5),
+        GString::from("   42|0.50|(1, 2.5)")
+    );
+    assert_eq!(
+        gstr_format!("{} {}", GString::from("Grüße"), StringName::from("👋")),
+        GString::from("Grüße 👋")
+    );
+}
+
+#[itest]
+fn string_format_macro_long() {
+    // Exceeds the stack buffer, with a multi-byte character straddling the boundary.
+    let long = format!("{}é{}", "a".repeat(255), "b".repeat(300));
+
+    let formatted = gstr_format!("<{}>{}", long, 7);
+    assert_eq!(formatted, GString::from(format!("<{long}>7")));
+    assert_eq!(formatted.len(), long.chars().count() + 3);
+}
@@ -0,0 +1,79 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::GString;
+use godot::classes::{
+    Animation, AnimationLibrary, AnimationNodeAnimation, AnimationNodeStateMachine,
+    AnimationNodeStateMachineTransition, AnimationTree,
+};
+use godot::obj::{Gd, NewAlloc, NewGd};
+
+use crate::framework::{itest, TestContext};
+
+/// Creates a tree whose root state machine has states "idle" and "walk", connected by a transition.
+///
+/// Animation libraries are added to the tree itself, which requires `AnimationMixer` from Godot 4.2.
+fn make_state_machine_tree() -> Gd<AnimationTree> {
+    let mut library = AnimationLibrary::new_gd();
+    let mut machine = AnimationNodeStateMachine::new_gd();
+
+    for name in ["idle", "walk"] {
+        let mut animation = Animation::new_gd();
+        animation.set_length(1.0);
+        library.add_animation(name.into(), &animation);
+
+        let mut node = AnimationNodeAnimation::new_gd();
+        node.set_animation(name.into());
+        machine.add_node(name.into(), &node);
+    }
+
+    let transition = AnimationNodeStateMachineTransition::new_gd();
+    machine.add_transition("idle".into(), "walk".into(), &transition);
+
+    let mut tree = AnimationTree::new_alloc();
+    tree.add_animation_library("".into(), &library);
+    tree.set_tree_root(&machine);
+    tree
+}
+
+#[itest]
+fn animation_tree_travel(ctx: &TestContext) {
+    let mut tree = make_state_machine_tree();
+    ctx.scene_tree.clone().add_child(&tree);
+
+    let mut playback = tree
+        .state_machine_playback()
+        .expect("root is state machine");
+    playback.start("idle".into());
+    tree.advance(0.1);
+
+    assert_eq!(tree.current_state(), GString::from("idle"));
+    assert!(tree.is_playing());
+
+    assert!(tree.travel("walk"));
+    tree.advance(0.1);
+    assert_eq!(tree.current_state(), GString::from("walk"));
+
+    // Unknown states are rejected without affecting playback.
+    assert!(!tree.travel("jump"));
+    tree.advance(0.1);
+    assert_eq!(tree.current_state(), GString::from("walk"));
+
+    tree.free();
+}
+
+#[itest]
+fn animation_tree_without_state_machine() {
+    let mut tree = AnimationTree::new_alloc();
+
+    assert!(tree.state_machine_playback().is_none());
+    assert!(!tree.travel("idle"));
+    assert_eq!(tree.current_state(), GString::new());
+    assert!(!tree.is_playing());
+
+    tree.free();
+}
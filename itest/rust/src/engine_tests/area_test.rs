@@ -0,0 +1,86 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Array;
+use godot::classes::{
+    Area3D, BoxShape3D, CharacterBody3D, CollisionShape3D, Node, Node3D, RefCounted, StaticBody3D,
+};
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::{itest, TestContext};
+
+#[itest]
+fn area3d_overlaps_without_physics_step(ctx: &TestContext) {
+    let mut area = Area3D::new_alloc();
+    let body = StaticBody3D::new_alloc();
+    let node = Node::new_alloc();
+
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&area);
+    parent.add_child(&body);
+
+    // Overlaps are only known after the next physics step.
+    assert!(area.overlapping_bodies_typed::<Node3D>().is_empty());
+    assert!(!area.is_overlapping(&body));
+
+    // Nodes which are neither bodies nor areas never overlap.
+    assert!(!area.is_overlapping(&node));
+
+    // Godot would print an error on queries while monitoring is disabled.
+    area.set_monitoring(false);
+    assert!(area.overlapping_bodies_typed::<Node3D>().is_empty());
+    assert!(area.overlapping_areas_typed::<Area3D>().is_empty());
+    assert!(!area.is_overlapping(&body));
+
+    area.free();
+    body.free();
+    node.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+// Used in `test_area3d_reports_overlapping_body` in `SpecialTests.gd`, which lets physics frames pass.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct AreaOverlapChecker {}
+
+#[godot_api]
+impl AreaOverlapChecker {
+    /// Creates an area and a static body with overlapping box shapes, both added to `parent`. Returns `[area, body]`.
+    #[func]
+    fn spawn_overlapping(&self, mut parent: Gd<Node>) -> Array<Gd<Node3D>> {
+        let nodes: Array<Gd<Node3D>> = Array::from(&[
+            Area3D::new_alloc().upcast::<Node3D>(),
+            StaticBody3D::new_alloc().upcast::<Node3D>(),
+        ]);
+
+        for mut node in nodes.iter_shared() {
+            let mut shape = CollisionShape3D::new_alloc();
+            shape.set_shape(&BoxShape3D::new_gd());
+            node.add_child(&shape);
+            parent.add_child(&node);
+        }
+
+        nodes
+    }
+
+    #[func]
+    fn static_body_count(&self, area: Gd<Area3D>) -> i64 {
+        area.overlapping_bodies_typed::<StaticBody3D>().len() as i64
+    }
+
+    #[func]
+    fn character_body_count(&self, area: Gd<Area3D>) -> i64 {
+        area.overlapping_bodies_typed::<CharacterBody3D>().len() as i64
+    }
+
+    #[func]
+    fn is_overlapping(&self, area: Gd<Area3D>, node: Gd<Node>) -> bool {
+        area.is_overlapping(&node)
+    }
+}
@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::task::Poll;
+
+use godot::builtin::Signal;
+use godot::classes::AudioStreamPlayer;
+use godot::obj::{Gd, GodotClass, NewAlloc};
+use godot::tools::PlaybackEnd;
+
+use crate::framework::{itest, poll_once, TestContext};
+
+// Tests cannot wait for real frames, so the `finished` and `process_frame` signals are emitted manually.
+
+fn connection_count(object: &Gd<impl GodotClass>, signal_name: &str) -> usize {
+    Signal::from_object_signal(object, signal_name)
+        .connections()
+        .len()
+}
+
+#[itest]
+fn audio_play_and_await_finished(ctx: &TestContext) {
+    let mut player = AudioStreamPlayer::new_alloc();
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&player);
+
+    let mut future = player.play_and_await();
+    assert_eq!(poll_once(&mut future), Poll::Pending);
+    assert_eq!(connection_count(&player, "finished"), 1);
+
+    // Simulates a short stream reaching its end.
+    player.emit_signal("finished".into(), &[]);
+    assert_eq!(poll_once(&mut future), Poll::Ready(PlaybackEnd::Finished));
+    assert_eq!(connection_count(&player, "finished"), 0);
+
+    drop(future);
+    parent.remove_child(&player);
+    player.free();
+}
+
+#[itest]
+fn audio_play_and_await_stopped(ctx: &TestContext) {
+    let mut player = AudioStreamPlayer::new_alloc();
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&player);
+
+    let mut tree = parent.get_tree().unwrap();
+    let frame_connections = connection_count(&tree, "process_frame");
+
+    // Without a stream, the player is not playing -- like after stop().
+    let mut future = player.play_and_await();
+    player.stop();
+    assert_eq!(poll_once(&mut future), Poll::Pending);
+
+    // The first frame only marks the stop as pending, in case `finished` is still emitted.
+    tree.emit_signal("process_frame".into(), &[]);
+    assert_eq!(poll_once(&mut future), Poll::Pending);
+
+    tree.emit_signal("process_frame".into(), &[]);
+    assert_eq!(poll_once(&mut future), Poll::Ready(PlaybackEnd::Stopped));
+    assert_eq!(connection_count(&tree, "process_frame"), frame_connections);
+    assert_eq!(connection_count(&player, "finished"), 0);
+
+    drop(future);
+    parent.remove_child(&player);
+    player.free();
+}
+
+#[itest]
+fn audio_play_and_await_drop_disconnects(ctx: &TestContext) {
+    let mut player = AudioStreamPlayer::new_alloc();
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&player);
+
+    let future = player.play_and_await();
+    assert_eq!(connection_count(&player, "finished"), 1);
+
+    drop(future);
+    assert_eq!(connection_count(&player, "finished"), 0);
+
+    parent.remove_child(&player);
+    player.free();
+}
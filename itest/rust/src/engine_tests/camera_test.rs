@@ -0,0 +1,66 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::{Vector2, Vector3};
+use godot::classes::{Camera2D, Camera3D};
+use godot::obj::NewAlloc;
+
+use crate::framework::{itest, TestContext};
+
+#[itest]
+fn camera3d_world_screen_roundtrip(ctx: &TestContext) {
+    let camera = Camera3D::new_alloc();
+    assert_eq!(camera.world_to_screen(Vector3::FORWARD), None);
+    assert_eq!(camera.screen_ray(Vector2::ZERO), None);
+
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&camera);
+
+    // Default camera is at the origin, looking towards -Z.
+    let world_pos = Vector3::new(1.0, 2.0, -10.0);
+    let screen_pos = camera
+        .world_to_screen(world_pos)
+        .expect("in front of camera");
+
+    let projected = camera.screen_to_world(screen_pos, 10.0).unwrap();
+    assert_eq_approx!(projected, world_pos);
+
+    let (origin, normal) = camera.screen_ray(screen_pos).unwrap();
+    assert_eq_approx!(normal, (world_pos - origin).normalized());
+
+    // Behind the camera.
+    assert_eq!(camera.world_to_screen(Vector3::new(1.0, 2.0, 10.0)), None);
+
+    // Off-screen to the side, but in front of the camera.
+    let side_pos = Vector3::new(1000.0, 0.0, -1.0);
+    let side_screen = camera
+        .world_to_screen(side_pos)
+        .expect("in front of camera");
+    let visible_rect = camera.get_viewport().unwrap().get_visible_rect();
+    assert!(!visible_rect.has_point(side_screen));
+
+    camera.free();
+}
+
+#[itest]
+fn camera2d_world_screen_roundtrip(ctx: &TestContext) {
+    let camera = Camera2D::new_alloc();
+    assert_eq!(camera.world_to_screen(Vector2::ZERO), None);
+
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&camera);
+
+    let world_pos = Vector2::new(120.0, -45.0);
+    let screen_pos = camera.world_to_screen(world_pos).unwrap();
+    assert_eq_approx!(camera.screen_to_world(screen_pos).unwrap(), world_pos);
+
+    let expected = camera.get_viewport().unwrap().get_canvas_transform() * world_pos;
+    assert_eq_approx!(screen_pos, expected);
+
+    camera.free();
+}
@@ -0,0 +1,53 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, Vector2};
+use godot::classes::ConfigFile;
+use godot::obj::NewGd;
+
+use crate::framework::itest;
+
+#[itest]
+fn config_file_typed_values() {
+    let mut config = ConfigFile::new_gd();
+    config.set_value_as("audio", "volume", 0.75);
+    config.set_value_as("audio", "muted", true);
+    config.set_value_as("video", "resolution", Vector2::new(1920.0, 1080.0));
+    config.set_value_as("player", "name", GString::from("Godette"));
+
+    assert_eq!(config.get_value_as("audio", "volume", 1.0), 0.75);
+    assert!(config.get_value_as("audio", "muted", false));
+    assert_eq!(
+        config.get_value_as("video", "resolution", Vector2::ZERO),
+        Vector2::new(1920.0, 1080.0)
+    );
+    assert_eq!(
+        config.get_value_as("player", "name", GString::new()),
+        GString::from("Godette")
+    );
+
+    // Round-trip through the text format.
+    let mut reloaded = ConfigFile::new_gd();
+    reloaded.parse(config.encode_to_text());
+    assert_eq!(reloaded.get_value_as("audio", "volume", 1.0), 0.75);
+}
+
+#[itest]
+fn config_file_typed_defaults() {
+    let mut config = ConfigFile::new_gd();
+    config.set_value_as("audio", "volume", 0.75);
+
+    // Missing key, missing section.
+    assert_eq!(config.get_value_as("audio", "balance", 0.5), 0.5);
+    assert_eq!(config.get_value_as("network", "port", 8080), 8080);
+
+    // Present but incompatible type.
+    assert_eq!(
+        config.get_value_as("audio", "volume", GString::from("fallback")),
+        GString::from("fallback")
+    );
+}
@@ -0,0 +1,110 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Callable;
+use godot::classes::{Node, Object};
+use godot::obj::{Base, Gd, NewAlloc};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::ConnectionTracker;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct TrackingListener {
+    connections: ConnectionTracker,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl TrackingListener {
+    fn observe(&mut self, source: &Gd<Object>, callable: Callable) {
+        self.connections
+            .connect_tracked(source, "changed", callable)
+            .expect("connect");
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct TrackingRecorder {
+    calls: u32,
+}
+
+#[godot_api]
+impl TrackingRecorder {
+    #[func]
+    fn record(&mut self) {
+        self.calls += 1;
+    }
+}
+
+fn new_source() -> Gd<Object> {
+    let mut source = Object::new_alloc();
+    source.add_user_signal("changed".into());
+    source
+}
+
+#[itest]
+fn connection_tracker_disconnects_on_free() {
+    let mut source = new_source();
+    let recorder = TrackingRecorder::new_alloc();
+    let callable = Callable::from_object_method(&recorder, "record");
+
+    // The callable is bound to the recorder, so Godot would keep the connection after the listener is freed.
+    let mut listener = TrackingListener::new_alloc();
+    listener.bind_mut().observe(&source, callable.clone());
+    assert!(source.is_connected("changed".into(), callable.clone()));
+
+    source.emit_signal("changed".into(), &[]);
+    assert_eq!(recorder.bind().calls, 1);
+
+    listener.free();
+    assert!(!source.is_connected("changed".into(), callable.clone()));
+
+    source.emit_signal("changed".into(), &[]);
+    assert_eq!(recorder.bind().calls, 1);
+
+    source.free();
+    recorder.free();
+}
+
+#[itest]
+fn connection_tracker_disconnect_all() {
+    let source = new_source();
+    let other_source = new_source();
+    let recorder = TrackingRecorder::new_alloc();
+    let callable = Callable::from_object_method(&recorder, "record");
+
+    let mut tracker = ConnectionTracker::new();
+    tracker
+        .connect_tracked(&source, "changed", callable.clone())
+        .unwrap();
+    tracker
+        .connect_tracked(&other_source, "changed", callable.clone())
+        .unwrap();
+
+    let err = tracker
+        .connect_tracked(&source, "changed", callable.clone())
+        .expect_err("already connected");
+    assert!(err.godot_error().is_some());
+
+    let err = tracker
+        .connect_tracked(&source, "nonexistent", callable.clone())
+        .expect_err("no such signal");
+    assert!(err.is_signal_not_found());
+    assert_eq!(tracker.len(), 2);
+
+    // Freed sources are skipped.
+    other_source.free();
+    assert_eq!(tracker.disconnect_all(), 1);
+    assert!(tracker.is_empty());
+    assert!(!source.is_connected("changed".into(), callable));
+
+    source.free();
+    recorder.free();
+}
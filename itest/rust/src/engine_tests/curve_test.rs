@@ -0,0 +1,107 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{real, Vector2};
+use godot::classes::{Curve, Curve2D};
+use godot::obj::{Gd, NewGd};
+
+use crate::framework::itest;
+
+/// Curve with `y = x` on `[0, 1]`.
+fn linear_curve() -> Gd<Curve> {
+    let mut curve = Curve::new_gd();
+    curve
+        .add_point_ex(Vector2::new(0.0, 0.0))
+        .right_tangent(1.0)
+        .done();
+    curve
+        .add_point_ex(Vector2::new(1.0, 1.0))
+        .left_tangent(1.0)
+        .done();
+    curve
+}
+
+/// Straight horizontal path of length 100.
+fn linear_curve_2d() -> Gd<Curve2D> {
+    let mut curve = Curve2D::new_gd();
+    curve.add_point(Vector2::new(0.0, 0.0));
+    curve.add_point(Vector2::new(100.0, 0.0));
+    curve
+}
+
+#[itest]
+fn curve_sample_at() {
+    let curve = linear_curve();
+
+    for offset in [0.0, 0.25, 0.5, 0.8, 1.0] {
+        let value = curve.sample_at(offset);
+        assert!(
+            (value - offset).abs() < 0.01,
+            "sample_at({offset}) = {value}"
+        );
+    }
+}
+
+#[itest]
+fn curve_sample_evenly() {
+    let curve = linear_curve();
+
+    let values: Vec<real> = curve.sample_evenly(5).collect();
+    assert_eq!(values.len(), 5);
+
+    for (value, expected) in values.iter().zip([0.0, 0.25, 0.5, 0.75, 1.0]) {
+        assert!((value - expected).abs() < 0.01, "{values:?}");
+    }
+
+    assert_eq!(curve.sample_evenly(0).count(), 0);
+}
+
+#[itest]
+fn curve_sample_after_modification() {
+    let mut curve = linear_curve();
+    assert!(curve.sample_at(1.0) > 0.99);
+
+    // Moving a point invalidates the baked cache.
+    curve.set_point_value(1, 0.0);
+    assert!(curve.sample_at(1.0).abs() < 0.01);
+}
+
+#[itest]
+fn curve_2d_sample_position() {
+    let curve = linear_curve_2d();
+
+    for offset in [0.0, 10.0, 25.0, 50.0, 100.0] {
+        let position = curve.sample_position(offset);
+        assert!(
+            (position - Vector2::new(offset, 0.0)).length() < 0.01,
+            "sample_position({offset}) = {position}"
+        );
+    }
+
+    let empty = Curve2D::new_gd();
+    assert_eq!(empty.sample_position(10.0), Vector2::ZERO);
+}
+
+#[itest]
+fn curve_2d_sample_positions_evenly() {
+    let curve = linear_curve_2d();
+
+    let positions: Vec<Vector2> = curve.sample_positions_evenly(3).collect();
+    let expected = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(50.0, 0.0),
+        Vector2::new(100.0, 0.0),
+    ];
+
+    assert_eq!(positions.len(), expected.len());
+    for (position, expected) in positions.iter().zip(expected) {
+        assert!((*position - expected).length() < 0.01, "{positions:?}");
+    }
+
+    let single: Vec<Vector2> = curve.sample_positions_evenly(1).collect();
+    assert_eq!(single, [Vector2::ZERO]);
+}
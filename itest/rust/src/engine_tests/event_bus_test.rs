@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Callable, Variant};
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::EventBus;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct EventRecorder {
+    received: Vec<Variant>,
+}
+
+#[godot_api]
+impl EventRecorder {
+    #[func]
+    fn record(&mut self, payload: Variant) {
+        self.received.push(payload);
+    }
+}
+
+fn record_callable(recorder: &Gd<EventRecorder>) -> Callable {
+    Callable::from_object_method(recorder, "record")
+}
+
+#[itest]
+fn event_bus_emit_to_subscribers() {
+    let first = EventRecorder::new_alloc();
+    let second = EventRecorder::new_alloc();
+
+    let mut bus = EventBus::new();
+    assert!(bus.subscribe("damage", record_callable(&first)));
+    assert!(bus.subscribe("damage", record_callable(&second)));
+    assert!(!bus.subscribe("damage", record_callable(&first)));
+    assert_eq!(bus.subscriber_count("damage"), 2);
+
+    assert_eq!(bus.emit("damage", 25), 2);
+    assert_eq!(bus.emit("heal", 10), 0);
+
+    assert_eq!(first.bind().received, vec![25.to_variant()]);
+    assert_eq!(second.bind().received, vec![25.to_variant()]);
+
+    first.free();
+    second.free();
+}
+
+#[itest]
+fn event_bus_unsubscribe() {
+    let recorder = EventRecorder::new_alloc();
+    let callable = record_callable(&recorder);
+
+    let mut bus = EventBus::new();
+    bus.subscribe("event", callable.clone());
+    assert!(bus.unsubscribe("event", &callable));
+    assert!(!bus.unsubscribe("event", &callable));
+    assert_eq!(bus.subscriber_count("event"), 0);
+
+    assert_eq!(bus.emit("event", "payload"), 0);
+    assert!(recorder.bind().received.is_empty());
+
+    recorder.free();
+}
+
+#[itest]
+fn event_bus_removes_dead_callables() {
+    let alive = EventRecorder::new_alloc();
+    let dead = EventRecorder::new_alloc();
+
+    let mut bus = EventBus::new();
+    bus.subscribe("event", record_callable(&dead));
+    bus.subscribe("event", record_callable(&alive));
+    dead.free();
+
+    assert_eq!(bus.emit("event", 1), 1);
+    assert_eq!(bus.subscriber_count("event"), 1);
+    assert_eq!(alive.bind().received, vec![1.to_variant()]);
+
+    alive.free();
+}
@@ -0,0 +1,125 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::task::Poll;
+
+use godot::builtin::{GString, PackedByteArray, PackedStringArray, StringName, Variant};
+use godot::classes::http_request::Result as HttpRequestResult;
+use godot::classes::HttpRequest;
+use godot::global;
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc};
+use godot::tools::HttpRequestOptions;
+
+use crate::framework::{itest, poll_once, TestContext};
+
+// Requests are not sent to a real server. They target a local port without a server, and completion is simulated by emitting
+// `request_completed` before the node gets a chance to process the request.
+const URL: &str = "http://127.0.0.1:9/status";
+
+fn create_request(ctx: &TestContext) -> Gd<HttpRequest> {
+    let request = HttpRequest::new_alloc();
+    ctx.scene_tree.clone().add_child(&request);
+    request
+}
+
+fn emit_completed(
+    request: &mut Gd<HttpRequest>,
+    result: HttpRequestResult,
+    status_code: i32,
+    body: &str,
+) {
+    let headers: PackedStringArray = [GString::from("Content-Type: text/plain")]
+        .into_iter()
+        .collect();
+    let body: PackedByteArray = body.bytes().collect();
+
+    let args: [Variant; 4] = [
+        result.to_variant(),
+        status_code.to_variant(),
+        headers.to_variant(),
+        body.to_variant(),
+    ];
+    request.emit_signal(StringName::from("request_completed"), &args);
+}
+
+#[itest]
+fn http_request_async_response(ctx: &TestContext) {
+    let mut request = create_request(ctx);
+
+    let options = HttpRequestOptions {
+        headers: vec!["Accept: text/plain".to_string()],
+        timeout_seconds: Some(5.0),
+        ..Default::default()
+    };
+    let mut future = request.request_async(URL, options);
+    assert!(poll_once(&mut future).is_pending());
+    assert_eq!(request.get_timeout(), 5.0);
+
+    emit_completed(&mut request, HttpRequestResult::SUCCESS, 404, "not found");
+
+    let Poll::Ready(Ok(response)) = poll_once(&mut future) else {
+        panic!("request should complete with a response");
+    };
+    assert_eq!(response.status_code, 404);
+    assert!(!response.is_success());
+    assert_eq!(response.header("content-type"), Some("text/plain"));
+    assert_eq!(response.header("Content-Length"), None);
+    assert_eq!(response.body_text(), "not found");
+
+    drop(future);
+    request.free();
+}
+
+#[itest]
+fn http_request_async_failed(ctx: &TestContext) {
+    let mut request = create_request(ctx);
+
+    let mut future = request.request_async(URL, HttpRequestOptions::default());
+    emit_completed(&mut request, HttpRequestResult::TIMEOUT, 0, "");
+
+    let Poll::Ready(Err(err)) = poll_once(&mut future) else {
+        panic!("request should fail");
+    };
+    assert!(err.is_timeout());
+    assert_eq!(err.request_result(), Some(HttpRequestResult::TIMEOUT));
+    assert_eq!(err.godot_error(), None);
+    assert_eq!(err.url(), URL);
+
+    drop(future);
+    request.free();
+}
+
+#[itest]
+fn http_request_async_not_started() {
+    let mut request = HttpRequest::new_alloc();
+
+    let mut future = request.request_async(URL, HttpRequestOptions::default());
+    let Poll::Ready(Err(err)) = poll_once(&mut future) else {
+        panic!("request outside tree should fail immediately");
+    };
+    assert_eq!(err.godot_error(), Some(global::Error::ERR_UNCONFIGURED));
+    assert!(!err.is_timeout());
+
+    drop(future);
+    request.free();
+}
+
+#[itest]
+fn http_request_async_cancelled(ctx: &TestContext) {
+    let mut request = create_request(ctx);
+
+    let mut future = request.request_async(URL, HttpRequestOptions::default());
+    assert!(poll_once(&mut future).is_pending());
+
+    request.free();
+
+    let Poll::Ready(Err(err)) = poll_once(&mut future) else {
+        panic!("request should be cancelled once the node is freed");
+    };
+    assert!(err.is_cancelled());
+}
@@ -0,0 +1,58 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use godot::classes::Node;
+use godot::meta::ToGodot;
+use godot::obj::NewAlloc;
+use godot::tools::MainThreadChannel;
+
+use crate::framework::itest;
+
+#[itest]
+fn main_thread_channel_runs_posted_work() {
+    let channel = MainThreadChannel::new();
+    let sender = channel.sender();
+    let ran_on = Arc::new(Mutex::new(None));
+
+    let ran_on_clone = Arc::clone(&ran_on);
+    thread::spawn(move || {
+        sender.post(move || {
+            // Godot API calls are fine here, since drain() runs on the main thread.
+            let node = Node::new_alloc();
+            node.free();
+
+            *ran_on_clone.lock().unwrap() = Some(thread::current().id());
+        });
+        sender.post_value(42);
+        sender.post_value(String::from("done"));
+    })
+    .join()
+    .unwrap();
+
+    // Nothing is executed before draining.
+    assert_eq!(*ran_on.lock().unwrap(), None);
+
+    let values = channel.drain();
+    assert_eq!(values, vec![42.to_variant(), "done".to_variant()]);
+    assert_eq!(*ran_on.lock().unwrap(), Some(thread::current().id()));
+
+    // Queue is empty afterwards.
+    assert!(channel.drain().is_empty());
+}
+
+#[itest]
+fn main_thread_channel_sender_outlives_channel() {
+    let channel = MainThreadChannel::new();
+    let sender = channel.sender();
+    drop(channel);
+
+    assert!(!sender.post(|| {}));
+    assert!(!sender.post_value(1));
+}
@@ -0,0 +1,77 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, Variant};
+use godot::classes::{Node3D, RefCounted};
+use godot::meta::ToGodot;
+use godot::obj::{NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::MemoizedCall;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct CallCounter {
+    calls: i64,
+}
+
+#[godot_api]
+impl CallCounter {
+    /// Not pure: each invocation returns a different value, revealing whether the call was cached.
+    #[func]
+    fn scaled(&mut self, factor: i64) -> i64 {
+        self.calls += 1;
+        factor * 100 + self.calls
+    }
+}
+
+#[itest]
+fn memoized_call_matches_fresh_results() {
+    let mut node = Node3D::new_alloc();
+    let mut memo = MemoizedCall::new(&node);
+
+    for method in ["get_class", "get_instance_id", "is_inside_tree"] {
+        let fresh = node.call(method.into(), &[]);
+        assert_eq!(memo.call(method, &[]), fresh, "first call of {method}");
+        assert_eq!(memo.call(method, &[]), fresh, "cached call of {method}");
+    }
+    assert_eq!(
+        memo.call("get_class", &[]),
+        GString::from("Node3D").to_variant()
+    );
+
+    let args = [Variant::from("Node")];
+    let fresh = node.call("is_class".into(), &args);
+    assert_eq!(memo.call("is_class", &args), fresh);
+    assert_eq!(memo.len(), 4);
+
+    node.free();
+}
+
+#[itest]
+fn memoized_call_caches_by_arguments() {
+    let counter = CallCounter::new_gd();
+    let mut memo = MemoizedCall::new(&counter).with_frame_invalidation(false);
+
+    assert_eq!(memo.call("scaled", &[1.to_variant()]), 101.to_variant());
+    assert_eq!(memo.call("scaled", &[1.to_variant()]), 101.to_variant());
+    assert_eq!(counter.bind().calls, 1);
+
+    // Other arguments are cached separately.
+    assert_eq!(memo.call("scaled", &[2.to_variant()]), 202.to_variant());
+    assert_eq!(memo.call("scaled", &[1.to_variant()]), 101.to_variant());
+    assert_eq!(counter.bind().calls, 2);
+    assert_eq!(memo.len(), 2);
+
+    memo.invalidate();
+    assert!(memo.is_empty());
+    assert_eq!(memo.call("scaled", &[1.to_variant()]), 103.to_variant());
+    assert_eq!(counter.bind().calls, 3);
+
+    assert_eq!(memo.object().instance_id(), counter.instance_id());
+}
@@ -5,16 +5,61 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod animation_tree_test;
+#[cfg(feature = "codegen-full")]
+mod area_test;
+#[cfg(since_api = "4.2")]
+mod audio_playback_test;
+mod camera_test;
 mod codegen_enums_test;
 mod codegen_test;
+#[cfg(feature = "codegen-full")]
+mod config_file_test;
+mod connection_tracker_test;
+#[cfg(feature = "codegen-full")]
+mod curve_test;
 mod engine_enum_test;
+mod event_bus_test;
 mod gfile_test;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod http_request_test;
+mod main_thread_channel_test;
+mod memoized_call_test;
+#[cfg(feature = "codegen-full")]
+mod multi_mesh_test;
 /// Native audio structure tests are only enabled when both the `experimental-threads` and `codegen-full` features are active. The tests
 /// require these features to be able to execute.
 #[cfg(all(feature = "experimental-threads", feature = "codegen-full"))]
 mod native_audio_structures_test;
 mod native_structures_test;
 mod node_test;
+#[cfg(feature = "codegen-full")]
+mod random_test;
+#[cfg(feature = "codegen-full")]
+mod raycast_test;
+mod reactive_test;
+#[cfg(since_api = "4.2")]
+mod rust_box_test;
 mod save_load_test;
+mod server_resource_guard_test;
+#[cfg(feature = "codegen-full")]
+mod shader_material_test;
+mod shared_resource_test;
+#[cfg(feature = "codegen-full")]
+mod skeleton_test;
+mod smooth_follow_test;
+mod sticky_signal_test;
+#[cfg(feature = "codegen-full")]
+mod texture_test;
+#[cfg(feature = "codegen-full")]
+mod tile_map_test;
 mod translate_test;
+mod tree_transaction_test;
+#[cfg(all(feature = "codegen-full", since_api = "4.2"))]
+mod tween_test;
+#[cfg(feature = "codegen-full")]
+mod undo_redo_test;
+mod update_scheduler_test;
 mod utilities_test;
+mod variant_stream_test;
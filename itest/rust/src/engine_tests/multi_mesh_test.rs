@@ -0,0 +1,114 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Basis, Color, Transform2D, Transform3D, Vector2, Vector3};
+use godot::classes::multi_mesh::TransformFormat;
+use godot::classes::MultiMesh;
+use godot::obj::{Gd, NewGd};
+
+use crate::framework::{expect_panic, itest};
+
+fn make_multi_mesh(format: TransformFormat, colors: bool, custom_data: bool) -> Gd<MultiMesh> {
+    let mut multi_mesh = MultiMesh::new_gd();
+
+    // Format flags must be set while the instance count is still 0.
+    multi_mesh.set_transform_format(format);
+    multi_mesh.set_use_colors(colors);
+    multi_mesh.set_use_custom_data(custom_data);
+    multi_mesh.set_instance_count(3);
+    multi_mesh
+}
+
+#[itest]
+fn multi_mesh_set_transforms() {
+    let mut multi_mesh = make_multi_mesh(TransformFormat::TRANSFORM_3D, true, true);
+
+    let transforms = [
+        Transform3D::IDENTITY,
+        Transform3D::new(
+            Basis::from_scale(Vector3::new(2.0, 3.0, 4.0)),
+            Vector3::new(1.0, 2.0, 3.0),
+        ),
+        Transform3D::new(
+            Basis::from_rows(
+                Vector3::new(0.0, -1.0, 0.0),
+                Vector3::new(1.0, 0.0, 0.0),
+                Vector3::new(0.0, 0.0, 1.0),
+            ),
+            Vector3::new(-5.0, 0.5, 8.0),
+        ),
+    ];
+    let colors = [Color::RED, Color::GREEN, Color::BLUE];
+    let custom_data = [
+        Color::from_rgba(0.1, 0.2, 0.3, 0.4),
+        Color::from_rgba(0.5, 0.6, 0.7, 0.8),
+        Color::from_rgba(0.9, 1.0, 0.0, 0.5),
+    ];
+
+    multi_mesh.set_transforms(&transforms);
+    multi_mesh.set_custom_data(&custom_data);
+    multi_mesh.set_colors(&colors);
+
+    for i in 0..3 {
+        assert_eq!(multi_mesh.get_instance_transform(i as i32), transforms[i]);
+        assert_eq!(multi_mesh.get_instance_color(i as i32), colors[i]);
+        assert_eq!(
+            multi_mesh.get_instance_custom_data(i as i32),
+            custom_data[i]
+        );
+    }
+
+    // Buffer contains 12 + 4 + 4 floats per instance.
+    assert_eq!(multi_mesh.get_buffer().len(), 3 * 20);
+}
+
+#[itest]
+fn multi_mesh_set_transforms_2d() {
+    let mut multi_mesh = make_multi_mesh(TransformFormat::TRANSFORM_2D, false, true);
+
+    let transforms = [
+        Transform2D::IDENTITY,
+        Transform2D::from_angle_origin(0.0, Vector2::new(10.0, -20.0)),
+        Transform2D::from_cols(
+            Vector2::new(0.0, 2.0),
+            Vector2::new(-3.0, 0.0),
+            Vector2::new(4.0, 5.0),
+        ),
+    ];
+    let custom_data = [Color::WHITE, Color::BLACK, Color::TRANSPARENT_BLACK];
+
+    multi_mesh.set_transforms_2d(&transforms);
+    multi_mesh.set_custom_data(&custom_data);
+
+    for i in 0..3 {
+        assert_eq!(
+            multi_mesh.get_instance_transform_2d(i as i32),
+            transforms[i]
+        );
+        assert_eq!(
+            multi_mesh.get_instance_custom_data(i as i32),
+            custom_data[i]
+        );
+    }
+}
+
+#[itest]
+fn multi_mesh_set_transforms_invalid() {
+    let mut multi_mesh = make_multi_mesh(TransformFormat::TRANSFORM_3D, false, false);
+
+    expect_panic("wrong number of transforms", || {
+        multi_mesh.set_transforms(&[Transform3D::IDENTITY]);
+    });
+
+    expect_panic("2D transforms on 3D multimesh", || {
+        multi_mesh.set_transforms_2d(&[Transform2D::IDENTITY; 3]);
+    });
+
+    expect_panic("colors are disabled", || {
+        multi_mesh.set_colors(&[Color::RED; 3]);
+    });
+}
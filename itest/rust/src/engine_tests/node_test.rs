@@ -7,12 +7,22 @@
 
 use std::str::FromStr;
 
-use godot::builtin::{NodePath, Variant};
-use godot::classes::{Node, Node3D, PackedScene, SceneTree};
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::real_consts::FRAC_PI_2;
+use godot::builtin::{
+    dict, varray, Array, Callable, Color, GString, NodePath, PackedInt64Array, StringName, Variant,
+    Vector2, Vector3,
+};
+use godot::classes::node::InternalMode;
+use godot::classes::scene_tree::GroupCallFlags;
+use godot::classes::{ClassDb, Node, Node2D, Node3D, Object, PackedScene, SceneTree, Sprite2D};
 use godot::global;
-use godot::obj::{NewAlloc, NewGd};
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::{resolve_live_instance, ObjectProperties, ProcessToggles};
 
-use crate::framework::{itest, TestContext};
+use crate::framework::{expect_panic, itest, TestContext};
 
 #[itest]
 fn node_get_node() {
@@ -51,6 +61,1127 @@ fn node_get_node_fail() {
     child.free();
 }
 
+#[itest]
+fn node_get_unique_node() {
+    let mut root = Node::new_alloc();
+    root.set_name("root".into());
+
+    let mut parent = Node::new_alloc();
+    parent.set_name("parent".into());
+    root.add_child(&parent);
+    parent.set_owner(&root);
+
+    let mut target = Node3D::new_alloc();
+    target.set_name("Target".into());
+    parent.add_child(&target);
+    target.set_owner(&root);
+    target.set_unique_name_in_owner(true);
+
+    let found = root.get_unique_node_as::<Node3D>("Target");
+    assert_eq!(found, target);
+
+    // Resolved from any node within the same scene.
+    let found = parent.try_get_unique_node_as::<Node>("Target");
+    assert_eq!(found, Some(target.clone().upcast()));
+
+    assert!(root.try_get_unique_node_as::<Node2D>("Target").is_none());
+    assert!(root.try_get_unique_node_as::<Node>("Missing").is_none());
+
+    root.free();
+}
+
+#[itest]
+fn node_indexed_property() {
+    let mut node = Node2D::new_alloc();
+    node.set_modulate(Color::from_rgba(1.0, 1.0, 1.0, 1.0));
+
+    node.try_set_indexed("modulate:a", 0.5)
+        .expect("modulate:a exists");
+    assert_eq!(node.get_modulate().a, 0.5);
+
+    let alpha = node.get_indexed_as::<f32>("modulate:a");
+    assert_eq!(alpha, 0.5);
+
+    let err = node
+        .try_get_indexed_as::<f32>("modulate:nonexistent")
+        .expect_err("sub-property does not exist");
+    assert!(err.is_not_found());
+
+    let err = node
+        .try_set_indexed("nonexistent:a", 0.5)
+        .expect_err("property does not exist");
+    assert!(err.is_not_found());
+
+    let err = node
+        .try_get_indexed_as::<GString>("modulate:a")
+        .expect_err("float is not a string");
+    assert!(!err.is_not_found());
+    assert!(err.conversion_error().is_some());
+
+    node.free();
+}
+
+#[itest]
+fn node_get_property_checked() {
+    let mut sprite = Sprite2D::new_alloc();
+    sprite.set_name("Sprite".into());
+
+    let name = sprite
+        .get_property_checked::<StringName>("name")
+        .expect("name exists");
+    assert_eq!(name, StringName::from("Sprite"));
+
+    let err = sprite
+        .get_property_checked::<Variant>("nonexistent")
+        .expect_err("property does not exist");
+    assert!(err.is_not_found());
+    assert!(!err.is_nil());
+    assert_eq!(err.path(), "nonexistent");
+
+    // No texture assigned: nil, which is only accepted by nullable types.
+    let err = sprite
+        .get_property_checked::<Gd<Object>>("texture")
+        .expect_err("texture is nil");
+    assert!(err.is_nil());
+    assert!(!err.is_not_found());
+    assert!(err.conversion_error().is_some());
+
+    let texture = sprite
+        .get_property_checked::<Option<Gd<Object>>>("texture")
+        .expect("nil is accepted by Option");
+    assert!(texture.is_none());
+
+    let err = sprite
+        .get_property_checked::<GString>("offset")
+        .expect_err("Vector2 is not a string");
+    assert!(!err.is_nil());
+    assert!(!err.is_not_found());
+    assert!(err.conversion_error().is_some());
+
+    sprite.free();
+}
+
+#[itest]
+fn node_with_property_temp() {
+    let mut node = Node2D::new_alloc();
+    node.set_modulate(Color::from_rgba(1.0, 1.0, 1.0, 1.0));
+
+    let result = node
+        .with_property_temp("modulate:a", 0.25, |node| {
+            assert_eq!(node.get_modulate().a, 0.25);
+            7
+        })
+        .expect("modulate:a exists");
+    assert_eq!(result, 7);
+    assert_eq!(node.get_modulate().a, 1.0);
+
+    expect_panic("closure panics while property is changed", || {
+        let _ = node.with_property_temp("visible", false, |node| {
+            assert!(!node.is_visible());
+            panic!("inside closure");
+        });
+    });
+    assert!(node.is_visible());
+
+    let mut invoked = false;
+    let err = node
+        .with_property_temp("nonexistent", 1, |_| invoked = true)
+        .expect_err("property does not exist");
+    assert!(err.is_not_found());
+    assert!(!invoked);
+
+    node.free();
+}
+
+#[itest]
+fn node_try_look_at(ctx: &TestContext) {
+    let mut node = Node3D::new_alloc();
+    assert!(
+        !node.try_look_at(Vector3::FORWARD, Vector3::UP, false),
+        "not inside tree"
+    );
+
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&node);
+    node.set_global_position(Vector3::new(1.0, 0.0, 0.0));
+
+    // Forward axis (-Z) points towards target.
+    assert!(node.try_look_at(Vector3::new(1.0, 0.0, 5.0), Vector3::UP, false));
+    let forward = -node.get_global_transform().basis.col_c();
+    assert_eq_approx!(forward, Vector3::new(0.0, 0.0, 1.0));
+
+    // Model front (+Z) points towards target.
+    assert!(node.try_look_at(Vector3::new(4.0, 0.0, 0.0), Vector3::UP, true));
+    let front = node.get_global_transform().basis.col_c();
+    assert_eq_approx!(front, Vector3::new(1.0, 0.0, 0.0));
+
+    node.free();
+}
+
+#[itest]
+fn node_try_look_at_degenerate(ctx: &TestContext) {
+    let mut node = Node3D::new_alloc();
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&node);
+
+    node.set_global_position(Vector3::new(1.0, 2.0, 3.0));
+    let transform = node.get_global_transform();
+
+    // Target at own position: no direction to face.
+    assert!(!node.try_look_at(Vector3::new(1.0, 2.0, 3.0), Vector3::UP, false));
+    assert_eq!(node.get_global_transform(), transform);
+
+    // Up vector parallel to look direction.
+    assert!(!node.try_look_at(Vector3::new(1.0, 10.0, 3.0), Vector3::UP, false));
+    assert_eq!(node.get_global_transform(), transform);
+
+    // Another up vector resolves the ambiguity, and the basis stays valid.
+    assert!(node.try_look_at(Vector3::new(1.0, 10.0, 3.0), Vector3::FORWARD, false));
+    let basis = node.get_global_transform().basis;
+    assert!(basis.is_finite());
+    assert_eq_approx!(-basis.col_c(), Vector3::UP);
+
+    node.free();
+}
+
+#[itest]
+fn node2d_convert_point(ctx: &TestContext) {
+    let mut from = Node2D::new_alloc();
+    let mut to = Node2D::new_alloc();
+    assert_eq!(
+        from.to_local_of(Vector2::ZERO, &to),
+        None,
+        "not inside tree"
+    );
+
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&from);
+    parent.add_child(&to);
+
+    from.set_position(Vector2::new(10.0, 0.0));
+    from.set_rotation(FRAC_PI_2);
+    to.set_position(Vector2::new(0.0, 5.0));
+    to.set_scale(Vector2::new(2.0, 2.0));
+
+    // (1, 0) is rotated to (0, 1), moved to (10, 1), then seen from `to` at ((10, 1) - (0, 5)) / 2.
+    let point = Vector2::new(1.0, 0.0);
+    let converted = from.to_local_of(point, &to).expect("both inside tree");
+    assert_eq_approx!(converted, Vector2::new(5.0, -2.0));
+    assert_eq_approx!(converted, to.to_local(from.to_global(point)));
+
+    let back = Node2D::convert_point(converted, &to, &from).unwrap();
+    assert_eq_approx!(back, point);
+
+    to.set_scale(Vector2::ZERO);
+    assert_eq!(from.to_local_of(point, &to), None, "not invertible");
+
+    from.free();
+    to.free();
+}
+
+#[itest]
+fn node3d_convert_point(ctx: &TestContext) {
+    let mut from = Node3D::new_alloc();
+    let mut to = Node3D::new_alloc();
+    assert_eq!(
+        Node3D::convert_point(Vector3::ZERO, &from, &to),
+        None,
+        "not inside tree"
+    );
+
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&from);
+    parent.add_child(&to);
+
+    from.set_position(Vector3::new(1.0, 2.0, 3.0));
+    from.set_rotation(Vector3::new(0.0, FRAC_PI_2, 0.0));
+    to.set_position(Vector3::new(0.0, 0.0, -1.0));
+    to.set_scale(Vector3::new(2.0, 2.0, 2.0));
+
+    // (1, 0, 0) is rotated to (0, 0, -1), moved to (1, 2, 2), then seen from `to` at ((1, 2, 2) - (0, 0, -1)) / 2.
+    let point = Vector3::new(1.0, 0.0, 0.0);
+    let converted = from.to_local_of(point, &to).expect("both inside tree");
+    assert_eq_approx!(converted, Vector3::new(0.5, 1.0, 1.5));
+    assert_eq_approx!(converted, to.to_local(from.to_global(point)));
+
+    let back = Node3D::convert_point(converted, &to, &from).unwrap();
+    assert_eq_approx!(back, point);
+
+    from.free();
+    to.free();
+}
+
+#[itest]
+fn node2d_reparent_keep_position(ctx: &TestContext) {
+    let mut root = ctx.scene_tree.clone();
+
+    let mut old_parent = Node2D::new_alloc();
+    old_parent.set_position(Vector2::new(-30.0, 15.0));
+    old_parent.set_rotation(-0.2);
+    root.add_child(&old_parent);
+
+    // Non-uniform scale combined with rotation requires skew in the child's new local transform.
+    let mut new_parent = Node2D::new_alloc();
+    new_parent.set_position(Vector2::new(100.0, 50.0));
+    new_parent.set_rotation(0.5);
+    new_parent.set_scale(Vector2::new(2.0, 0.5));
+    root.add_child(&new_parent);
+
+    let mut node = Node2D::new_alloc();
+    node.set_position(Vector2::new(10.0, 20.0));
+    node.set_rotation(0.3);
+    old_parent.add_child(&node);
+    let global_transform = node.get_global_transform();
+
+    assert!(node.reparent_keep_position(&new_parent));
+    assert_eq!(node.get_parent(), Some(new_parent.clone().upcast()));
+    assert_eq_approx!(node.get_global_transform(), global_transform);
+
+    // Into own descendant.
+    let child = Node2D::new_alloc();
+    node.add_child(&child);
+    assert!(!node.reparent_keep_position(&child));
+    assert_eq!(node.get_parent(), Some(new_parent.clone().upcast()));
+
+    new_parent.free();
+    old_parent.free();
+}
+
+#[itest]
+fn node3d_reparent_keep_position(ctx: &TestContext) {
+    let mut root = ctx.scene_tree.clone();
+
+    let mut new_parent = Node3D::new_alloc();
+    new_parent.set_position(Vector3::new(1.0, 2.0, 3.0));
+    new_parent.rotate_y(0.7);
+    new_parent.set_scale(Vector3::new(1.0, 2.0, 3.0));
+    root.add_child(&new_parent);
+
+    let mut node = Node3D::new_alloc();
+    root.add_child(&node);
+    node.set_global_position(Vector3::new(-4.0, 5.0, 0.5));
+    node.rotate_x(0.4);
+    let global_transform = node.get_global_transform();
+
+    assert!(node.reparent_keep_position(&new_parent));
+    assert_eq!(node.get_parent(), Some(new_parent.clone().upcast()));
+    assert_eq_approx!(node.get_global_transform(), global_transform);
+
+    // Not inside tree.
+    let mut orphan = Node3D::new_alloc();
+    assert!(!orphan.reparent_keep_position(&new_parent));
+    orphan.free();
+
+    new_parent.free();
+}
+
+#[itest]
+fn node_resolve_path_value() {
+    let mut root = Node2D::new_alloc();
+    root.set_position(Vector2::new(3.0, 4.0));
+
+    let mut sprite = Sprite2D::new_alloc();
+    sprite.set_name("Sprite2D".into());
+    sprite.set_modulate(Color::from_rgba(1.0, 1.0, 1.0, 0.5));
+    root.add_child(&sprite);
+
+    sprite.add_child(&create_named("Child"));
+
+    let alpha: f32 = root.resolve_path_value("Sprite2D:modulate:a").unwrap();
+    assert_eq!(alpha, 0.5);
+
+    let modulate: Color = root.resolve_path_value("Sprite2D:modulate").unwrap();
+    assert_eq!(modulate, Color::from_rgba(1.0, 1.0, 1.0, 0.5));
+
+    let name: StringName = root.resolve_path_value("Sprite2D/Child:name").unwrap();
+    assert_eq!(name, StringName::from("Child"));
+
+    // Without node part, the property is read from the node itself.
+    let x: f32 = root.resolve_path_value(":position:x").unwrap();
+    assert_eq!(x, 3.0);
+
+    let err = root
+        .resolve_path_value::<f32>("Missing:modulate:a")
+        .expect_err("node does not exist");
+    assert!(err.is_node_not_found());
+    assert!(!err.is_not_found());
+    assert_eq!(err.path(), "Missing:modulate:a");
+
+    let err = root
+        .resolve_path_value::<f32>("Sprite2D:modulate:nope")
+        .expect_err("sub-property does not exist");
+    assert!(err.is_not_found());
+    assert!(!err.is_node_not_found());
+    assert_eq!(err.path(), "Sprite2D:modulate:nope");
+
+    let err = root
+        .resolve_path_value::<GString>("Sprite2D:modulate:a")
+        .expect_err("wrong type");
+    assert!(err.conversion_error().is_some());
+
+    root.free();
+}
+
+#[itest]
+fn node_apply_properties() {
+    let mut node = Node2D::new_alloc();
+
+    // Integers are accepted for float properties.
+    node.apply_properties(&dict! {
+        "position": Vector2::new(1.0, 2.0),
+        "rotation": 2,
+        "visible": false,
+    })
+    .expect("all properties valid");
+    assert_eq!(node.get_position(), Vector2::new(1.0, 2.0));
+    assert_eq!(node.get_rotation(), 2.0);
+    assert!(!node.is_visible());
+
+    let invalid = dict! {
+        "rotation": 0.5,
+        "nonexistent": 3,
+        "position": "not a vector",
+    };
+
+    let errors = node
+        .apply_properties(&invalid)
+        .expect_err("some properties invalid");
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].is_not_found());
+    assert_eq!(errors[0].path(), "nonexistent");
+    assert!(errors[1].conversion_error().is_some());
+    assert_eq!(errors[1].path(), "position");
+    assert_eq!(node.get_rotation(), 2.0, "nothing applied");
+
+    let errors = node
+        .apply_properties_best_effort(&invalid)
+        .expect_err("some properties invalid");
+    assert_eq!(errors.len(), 2);
+    assert_eq!(node.get_rotation(), 0.5, "valid entries applied");
+    assert_eq!(node.get_position(), Vector2::new(1.0, 2.0));
+
+    node.free();
+}
+
+#[itest]
+fn node_dictionary_round_trip() {
+    let mut node = Node2D::new_alloc();
+    node.set_name("Saved".into());
+    node.set_position(Vector2::new(10.0, -4.0));
+    node.set_rotation(1.25);
+    node.set_z_index(3);
+    node.set_visible(false);
+    node.set_modulate(Color::from_rgba(0.5, 0.25, 1.0, 0.75));
+
+    let material = ClassDb::singleton().instantiate("CanvasItemMaterial".into());
+    node.set("material".into(), &material);
+
+    let state = node.to_dictionary(ObjectProperties::Skip);
+    assert_eq!(
+        state.get("position"),
+        Some(Vector2::new(10.0, -4.0).to_variant())
+    );
+    assert!(!state.contains_key("material"), "object skipped");
+    assert!(
+        !state.contains_key("global_position"),
+        "not a storage property"
+    );
+
+    let mut restored = Node2D::new_alloc();
+    restored
+        .restore_from_dictionary(&state, &mut resolve_live_instance)
+        .expect("restore succeeds");
+
+    assert_eq!(restored.get_name(), StringName::from("Saved"));
+    assert_eq!(restored.get_position(), Vector2::new(10.0, -4.0));
+    assert_eq!(restored.get_rotation(), 1.25);
+    assert_eq!(restored.get_z_index(), 3);
+    assert!(!restored.is_visible());
+    assert_eq!(
+        restored.get_modulate(),
+        Color::from_rgba(0.5, 0.25, 1.0, 0.75)
+    );
+    assert!(restored.get("material".into()).is_nil());
+
+    // Objects by reference are resolved back to the same instance.
+    let state = node.to_dictionary(ObjectProperties::ByReference);
+    assert!(state.get("material").is_some_and(|value| value != material));
+
+    restored
+        .restore_from_dictionary(&state, &mut resolve_live_instance)
+        .expect("restore succeeds");
+    assert_eq!(restored.get("material".into()), material);
+
+    node.free();
+    restored.free();
+}
+
+#[itest]
+fn node_set_deferred() {
+    let mut node = Node::new_alloc();
+    node.set_name("Before".into());
+
+    node.try_set_deferred("name", "After").expect("name exists");
+    assert_eq!(node.get_name(), StringName::from("Before"));
+
+    let err = node
+        .try_set_deferred("nonexistent", 1)
+        .expect_err("property does not exist");
+    assert!(err.is_not_found());
+
+    node.free();
+}
+
+// Used in `test_object_try_set_deferred` in `SpecialTests.gd`, which lets a frame pass before checking the property.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct DeferredPropertySetter {}
+
+#[godot_api]
+impl DeferredPropertySetter {
+    #[func]
+    fn schedule(mut object: Gd<Object>, property: StringName, value: Variant) -> bool {
+        object.try_set_deferred(property, value).is_ok()
+    }
+}
+
+fn create_named(name: &str) -> Gd<Node> {
+    let mut node = Node::new_alloc();
+    node.set_name(name.into());
+    node
+}
+
+#[itest]
+fn node_find_map_child() {
+    let mut root = create_named("Root");
+    let mut middle = create_named("Middle");
+    middle.add_child(&create_named("Target"));
+
+    root.add_child(&create_named("First"));
+    root.add_child(&middle);
+    root.add_child(&create_named("Last"));
+
+    let mut visited = Vec::new();
+    let mut find_target = |node: Gd<Node>| {
+        let name = node.get_name();
+        visited.push(name.to_string());
+        (name == StringName::from("Target")).then_some(node)
+    };
+
+    let found = root.find_map_child(false, &mut find_target);
+    assert!(found.is_none());
+
+    let found = root.find_map_child(true, &mut find_target);
+    let found = found.expect("Target is a grandchild");
+    assert_eq!(found.get_name(), StringName::from("Target"));
+
+    // Second search stopped at Target, before visiting Last.
+    assert_eq!(
+        visited,
+        ["First", "Middle", "Last", "First", "Middle", "Target"]
+    );
+
+    root.free();
+}
+
+#[itest]
+fn node_descendants_bfs() {
+    // Root
+    // ├─ A
+    // │  ├─ A1
+    // │  │  └─ A1x
+    // │  └─ A2
+    // ├─ B
+    // └─ C
+    //    └─ C1
+    let mut root = create_named("Root");
+    let mut a = create_named("A");
+    let mut a1 = create_named("A1");
+    let mut c = create_named("C");
+
+    a1.add_child(&create_named("A1x"));
+    a.add_child(&a1);
+    a.add_child(&create_named("A2"));
+    c.add_child(&create_named("C1"));
+    root.add_child(&a);
+    root.add_child(&create_named("B"));
+    root.add_child(&c);
+    root.add_child_ex(&create_named("Internal"))
+        .internal(InternalMode::BACK)
+        .done();
+
+    let names: Vec<String> = root
+        .descendants_bfs()
+        .map(|node| node.get_name().to_string())
+        .collect();
+    assert_eq!(names, ["A", "B", "C", "A1", "A2", "C1", "A1x"]);
+
+    let leaf = root.get_node_as::<Node>("A/A1/A1x");
+    assert_eq!(leaf.descendants_bfs().count(), 0);
+
+    root.free();
+}
+
+#[itest]
+fn node_set_unique_name() {
+    // Root
+    // ├─ A
+    // │  └─ Player (was "Child")
+    // └─ B
+    let mut root = create_named("Root");
+    let mut a = create_named("A");
+    let mut b = create_named("B");
+    let mut child = create_named("Child");
+    a.add_child(&child);
+    root.add_child(&a);
+    root.add_child(&b);
+
+    // Without owner, unique names are not available.
+    assert!(!child.set_unique_name("Player"));
+    assert_eq!(child.get_name(), StringName::from("Child"));
+
+    for node in [&mut a, &mut b, &mut child] {
+        node.set_owner(&root);
+    }
+
+    assert!(child.set_unique_name("Player"));
+    assert_eq!(child.get_name(), StringName::from("Player"));
+    assert!(child.is_unique_name_in_owner());
+    assert_eq!(root.get_node_as::<Node>("%Player"), child);
+    assert_eq!(b.get_node_as::<Node>("%Player"), child);
+
+    // Setting the same name again is fine; other nodes cannot take it.
+    assert!(child.set_unique_name("Player"));
+    assert!(!b.set_unique_name("Player"));
+    assert_eq!(b.get_name(), StringName::from("B"));
+    assert!(!b.is_unique_name_in_owner());
+
+    // Sibling names are taken into account.
+    assert!(!b.set_unique_name("A"));
+
+    child.clear_unique_name();
+    assert!(!child.is_unique_name_in_owner());
+    assert_eq!(root.try_get_node_as::<Node>("%Player"), None);
+    assert_eq!(child.get_name(), StringName::from("Player"));
+
+    assert!(b.set_unique_name("Player"));
+    assert_eq!(root.get_node_as::<Node>("%Player"), b);
+
+    root.free();
+}
+
+#[itest]
+fn node_children_ordered() {
+    let mut root = create_named("Root");
+    for name in ["C", "A", "B"] {
+        root.add_child(&create_named(name));
+    }
+    root.add_child_ex(&create_named("Internal"))
+        .internal(InternalMode::FRONT)
+        .done();
+
+    // Moving a child changes the order accordingly.
+    let b = root.get_node_as::<Node>("B");
+    root.move_child(&b, 0);
+
+    let children: Vec<Gd<Node>> = root.children_ordered().collect();
+    assert_eq!(children.len(), root.get_child_count() as usize);
+    for (i, child) in children.iter().enumerate() {
+        assert_eq!(root.get_child(i as i32).as_ref(), Some(child));
+        assert_eq!(child.get_index(), i as i32);
+    }
+
+    let names: Vec<String> = children
+        .iter()
+        .map(|child| child.get_name().to_string())
+        .collect();
+    assert_eq!(names, ["B", "C", "A"]);
+
+    assert!(root.is_child_order_stable());
+    root.get_node_as::<Node>("C").queue_free();
+    assert!(!root.is_child_order_stable());
+
+    root.free();
+}
+
+#[itest]
+fn node_siblings() {
+    // Root
+    // ├─ A
+    // ├─ B (Node2D)
+    // │  └─ B1
+    // ├─ C
+    // └─ D (Node2D)
+    let mut root = create_named("Root");
+    let a = create_named("A");
+    let mut b = Node2D::new_alloc();
+    b.set_name("B".into());
+    let c = create_named("C");
+    let mut d = Node2D::new_alloc();
+    d.set_name("D".into());
+
+    b.add_child(&create_named("B1"));
+    root.add_child(&a);
+    root.add_child(&b);
+    root.add_child(&c);
+    root.add_child(&d);
+    root.add_child_ex(&create_named("Internal"))
+        .internal(InternalMode::BACK)
+        .done();
+
+    let names = |node: &Gd<Node>| -> Vec<String> {
+        node.siblings()
+            .map(|sibling| sibling.get_name().to_string())
+            .collect()
+    };
+    assert_eq!(names(&c), ["A", "B", "D"]);
+    assert_eq!(names(&a), ["B", "C", "D"]);
+
+    let typed: Vec<Gd<Node2D>> = c.siblings_of_type::<Node2D>().collect();
+    assert_eq!(typed, [b.clone(), d.clone()]);
+    assert_eq!(b.siblings_of_type::<Node2D>().collect::<Vec<_>>(), [d]);
+
+    // Only child, and no parent at all.
+    assert_eq!(b.get_node_as::<Node>("B1").siblings().count(), 0);
+    assert_eq!(root.siblings().count(), 0);
+
+    root.free();
+}
+
+#[itest]
+fn node_ancestor_of_type() {
+    // Node3D "World"
+    // └─ Node2D "Layer"
+    //    └─ Node "Group"
+    //       └─ Node2D "Sprite"
+    //          └─ Node "Leaf"
+    let mut world = Node3D::new_alloc();
+    world.set_name("World".into());
+    let mut layer = Node2D::new_alloc();
+    layer.set_name("Layer".into());
+    let mut group = create_named("Group");
+    let mut sprite = Node2D::new_alloc();
+    sprite.set_name("Sprite".into());
+    let leaf = create_named("Leaf");
+
+    sprite.add_child(&leaf);
+    group.add_child(&sprite);
+    layer.add_child(&group);
+    world.add_child(&layer);
+
+    // Nearest match wins, several levels up; the node itself is not considered.
+    assert_eq!(leaf.ancestor_of_type::<Node2D>(), Some(sprite.clone()));
+    assert_eq!(sprite.ancestor_of_type::<Node2D>(), Some(layer.clone()));
+    assert_eq!(leaf.ancestor_of_type::<Node3D>(), Some(world.clone()));
+    assert_eq!(
+        leaf.ancestor_of_type::<Node>(),
+        Some(sprite.clone().upcast())
+    );
+
+    assert_eq!(world.ancestor_of_type::<Node>(), None);
+    assert_eq!(layer.ancestor_of_type::<Node2D>(), None);
+
+    world.free();
+}
+
+#[itest]
+fn node_depth_in_tree(ctx: &TestContext) {
+    // <test node>
+    // └─ Parent
+    //    ├─ Child
+    //    │  └─ Grandchild
+    //    └─ Sibling
+    let mut parent = create_named("Parent");
+    let mut child = create_named("Child");
+    let grandchild = create_named("Grandchild");
+    let sibling = create_named("Sibling");
+    child.add_child(&grandchild);
+    parent.add_child(&child);
+    parent.add_child(&sibling);
+
+    assert_eq!(grandchild.depth_in_tree(), None, "outside tree");
+
+    let root = ctx.scene_tree.get_tree().unwrap().get_root().unwrap();
+    assert_eq!(root.depth_in_tree(), Some(0));
+
+    let mut test_node = ctx.scene_tree.clone();
+    let base = test_node.depth_in_tree().expect("test node inside tree");
+    test_node.add_child(&parent);
+    assert_eq!(parent.depth_in_tree(), Some(base + 1));
+    assert_eq!(child.depth_in_tree(), Some(base + 2));
+    assert_eq!(grandchild.depth_in_tree(), Some(base + 3));
+    assert_eq!(sibling.depth_in_tree(), Some(base + 2));
+
+    test_node.remove_child(&parent);
+    assert_eq!(parent.depth_in_tree(), None, "removed from tree");
+    parent.free();
+}
+
+#[itest]
+fn node_is_descendant_of() {
+    // Same hierarchy as above, but outside the tree.
+    let mut parent = create_named("Parent");
+    let mut child = create_named("Child");
+    let grandchild = create_named("Grandchild");
+    let sibling = create_named("Sibling");
+    child.add_child(&grandchild);
+    parent.add_child(&child);
+    parent.add_child(&sibling);
+
+    assert!(grandchild.is_descendant_of(&parent));
+    assert!(grandchild.is_descendant_of(&child));
+    assert!(sibling.is_descendant_of(&parent));
+    assert!(parent.is_ancestor_of(&grandchild));
+
+    assert!(!grandchild.is_descendant_of(&sibling));
+    assert!(!parent.is_descendant_of(&grandchild));
+    assert!(!child.is_descendant_of(&child), "not own descendant");
+    assert!(!sibling.is_ancestor_of(&grandchild));
+
+    let unrelated = create_named("Unrelated");
+    unrelated.free();
+    assert!(!grandchild.is_descendant_of(&unrelated), "freed ancestor");
+
+    parent.free();
+}
+
+#[itest]
+fn node_connect_node_signal() {
+    let mut root = create_named("Root");
+    let mut panel = create_named("Panel");
+    let mut button = create_named("Button");
+    button.add_user_signal("activated".into());
+    panel.add_child(&button);
+    root.add_child(&panel);
+
+    let target = create_named("Target");
+    let callable = Callable::from_object_method(&target, "set_name").bindv(&varray!["Activated"]);
+
+    root.connect_node_signal("Panel/Button", "activated", callable.clone())
+        .expect("node and signal exist");
+    button.emit_signal("activated".into(), &[]);
+    assert_eq!(target.get_name(), StringName::from("Activated"));
+
+    let err = root
+        .connect_node_signal("Panel/Button", "activated", callable.clone())
+        .expect_err("already connected");
+    assert_eq!(
+        err.godot_error(),
+        Some(global::Error::ERR_INVALID_PARAMETER)
+    );
+
+    let err = root
+        .connect_node_signal("Panel/Missing", "activated", callable.clone())
+        .expect_err("node does not exist");
+    assert!(err.is_node_not_found());
+    assert_eq!(err.path(), Some("Panel/Missing"));
+
+    let err = root
+        .connect_node_signal("Panel/Button", "missing", callable)
+        .expect_err("signal does not exist");
+    assert!(err.is_signal_not_found());
+    assert_eq!(err.signal(), "missing");
+    assert_eq!(err.path(), Some("Panel/Button"));
+
+    target.free();
+    root.free();
+}
+
+#[itest]
+fn object_connect_validated() {
+    let mut source = create_named("Source");
+    let renamed_arg = dict! { "name": "new_name" };
+    source
+        .add_user_signal_ex("renamed".into())
+        .arguments(&varray![renamed_arg])
+        .done();
+
+    let mut target = create_named("Target");
+    target.add_user_signal("forwarded".into());
+
+    source
+        .connect_validated("renamed", &target, "set_name")
+        .expect("arity matches");
+    source.emit_signal(
+        "renamed".into(),
+        &[StringName::from("Renamed").to_variant()],
+    );
+    assert_eq!(target.get_name(), StringName::from("Renamed"));
+
+    let err = source
+        .connect_validated("renamed", &target, "set_name")
+        .expect_err("already connected");
+    assert_eq!(
+        err.godot_error(),
+        Some(global::Error::ERR_INVALID_PARAMETER)
+    );
+
+    // Default parameters and varargs are accepted: `add_child(node, force_readable_name = false, internal = 0)`, `emit_signal(...)`.
+    source
+        .connect_validated("renamed", &target, "add_child")
+        .expect("default parameters");
+    source
+        .connect_validated("renamed", &target, "emit_signal")
+        .expect("vararg method");
+
+    let err = source
+        .connect_validated("renamed", &target, "missing_method")
+        .expect_err("method does not exist");
+    assert!(err.is_method_not_found());
+    assert!(err.to_string().contains("no method 'missing_method'"));
+
+    let err = source
+        .connect_validated("renamed", &target, "get_child_count")
+        .expect_err("method takes fewer arguments");
+    assert!(err.is_argument_count_mismatch());
+    assert!(err.to_string().contains("passes 1 argument(s)"));
+
+    let err = source
+        .connect_validated("tree_exited", &target, "set_name")
+        .expect_err("method takes more arguments");
+    assert!(err.is_argument_count_mismatch());
+    assert_eq!(err.signal(), "tree_exited");
+    assert_eq!(err.path(), None);
+
+    let err = source
+        .connect_validated("missing_signal", &target, "set_name")
+        .expect_err("signal does not exist");
+    assert!(err.is_signal_not_found());
+
+    target.free();
+    source.free();
+}
+
+#[itest]
+fn object_disconnect_all_from() {
+    let mut source = create_named("Source");
+    source.add_user_signal("activated".into());
+
+    let target = create_named("Target");
+    let other = create_named("Other");
+
+    let to_target = [
+        ("renamed", Callable::from_object_method(&target, "get_name")),
+        (
+            "activated",
+            Callable::from_object_method(&target, "get_name"),
+        ),
+        (
+            "activated",
+            Callable::from_object_method(&target, "get_child_count"),
+        ),
+    ];
+    let to_other = Callable::from_object_method(&other, "get_name");
+
+    for (signal, callable) in to_target.iter() {
+        assert_eq!(
+            source.connect((*signal).into(), callable.clone()),
+            global::Error::OK
+        );
+    }
+    source.connect("activated".into(), to_other.clone());
+
+    assert_eq!(source.disconnect_all_from(&target), 3);
+    for (signal, callable) in to_target {
+        assert!(!source.is_connected(signal.into(), callable));
+    }
+    assert!(source.is_connected("activated".into(), to_other));
+
+    assert_eq!(source.disconnect_all_from(&target), 0);
+
+    other.free();
+    target.free();
+    source.free();
+}
+
+#[itest]
+fn node_set_owner_recursive_pack() {
+    let mut root = create_named("Root");
+    let mut child = create_named("Child");
+    let grandchild = create_named("Grandchild");
+    child.add_child(&grandchild);
+    root.add_child(&child);
+
+    let internal = create_named("Internal");
+    root.add_child_ex(&internal)
+        .internal(InternalMode::FRONT)
+        .done();
+
+    root.set_owner_recursive(&root);
+    assert_eq!(child.get_owner(), Some(root.clone()));
+    assert_eq!(grandchild.get_owner(), Some(root.clone()));
+    assert_eq!(internal.get_owner(), None);
+
+    let mut scene = PackedScene::new_gd();
+    assert_eq!(scene.pack(&root), global::Error::OK);
+
+    let copy = scene.instantiate().expect("instantiate packed scene");
+    assert!(copy.has_node("Child/Grandchild".into()));
+    assert_eq!(copy.get_child_count_ex().include_internal(true).done(), 1);
+
+    copy.free();
+    root.free();
+}
+
+#[itest]
+fn node_set_owner_recursive_sub_scene() {
+    let mut root = create_named("Root");
+    let mut sub_scene = create_named("SubScene");
+    sub_scene.set_scene_file_path("res://sub_scene.tscn".into());
+
+    let sub_child = create_named("SubChild");
+    sub_scene.add_child(&sub_child);
+    root.add_child(&sub_scene);
+
+    root.set_owner_recursive(&root);
+    assert_eq!(sub_scene.get_owner(), Some(root.clone()));
+    assert_eq!(sub_child.get_owner(), None);
+
+    root.free();
+}
+
+#[itest]
+fn node_queue_free_all() {
+    let first = create_named("First");
+    let second = create_named("Second");
+    let mut already_queued = create_named("AlreadyQueued");
+    already_queued.queue_free();
+
+    let freed = create_named("Freed");
+    freed.clone().free();
+
+    let nodes = [
+        first.clone(),
+        second.clone(),
+        first.clone(),
+        already_queued.clone(),
+        freed,
+    ];
+
+    let queued_count = Node::queue_free_all(nodes);
+    assert_eq!(queued_count, 2);
+    assert!(first.is_queued_for_deletion());
+    assert!(second.is_queued_for_deletion());
+
+    // Deletion only happens at the end of the frame; see `test_node_queue_free_all` in `SpecialTests.gd`.
+    assert!(first.is_instance_valid());
+    assert_eq!(Node::queue_free_all([first, second]), 0);
+}
+
+#[itest]
+fn node_add_children_deferred() {
+    let mut parent = create_named("Parent");
+    let first = create_named("First");
+    let second = create_named("Second");
+
+    let mut other_parent = create_named("OtherParent");
+    let adopted = create_named("Adopted");
+    other_parent.add_child(&adopted);
+
+    let freed = create_named("Freed");
+    freed.clone().free();
+
+    let nodes = [
+        first.clone(),
+        second.clone(),
+        first.clone(),
+        adopted,
+        parent.clone(),
+        freed,
+    ];
+
+    let queued_count = parent.add_children_deferred(nodes);
+    assert_eq!(queued_count, 2);
+
+    // Children are only added at idle time; see `test_node_add_children_deferred` in `SpecialTests.gd`.
+    assert_eq!(parent.get_child_count(), 0);
+
+    // Freeing the parent drops the queued additions.
+    parent.free();
+    other_parent.free();
+    first.free();
+    second.free();
+}
+
+// Used in `test_node_queue_free_all` in `SpecialTests.gd`, which lets a frame pass before checking that nodes are freed.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct NodeBatchFreer {}
+
+#[godot_api]
+impl NodeBatchFreer {
+    #[func]
+    fn free_all(nodes: Array<Gd<Node>>) -> i64 {
+        Node::queue_free_all(nodes.iter_shared()) as i64
+    }
+}
+
+// Used in `test_node_add_children_deferred` in `SpecialTests.gd`, which lets a frame pass before checking the children.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct NodeBatchAdder {}
+
+#[godot_api]
+impl NodeBatchAdder {
+    #[func]
+    fn add_all(mut parent: Gd<Node>, nodes: Array<Gd<Node>>) -> i64 {
+        parent.add_children_deferred(nodes.iter_shared()) as i64
+    }
+}
+
+#[itest]
+fn node_set_processing() {
+    let mut node = Node::new_alloc();
+
+    let toggles = ProcessToggles {
+        process: true,
+        unhandled_input: true,
+        ..ProcessToggles::NONE
+    };
+    node.set_processing(toggles);
+    assert_eq!(node.processing(), toggles);
+    assert!(node.is_processing());
+    assert!(!node.is_physics_processing());
+
+    node.set_processing(ProcessToggles::ALL);
+    assert_eq!(node.processing(), ProcessToggles::ALL);
+
+    node.set_processing(ProcessToggles::NONE);
+    assert_eq!(node.processing(), ProcessToggles::NONE);
+
+    node.free();
+}
+
+#[itest]
+fn node_freeze_unfreeze() {
+    let mut node = Node::new_alloc();
+
+    let toggles = ProcessToggles {
+        physics_process: true,
+        input: true,
+        ..ProcessToggles::NONE
+    };
+    node.set_processing(toggles);
+    assert!(!node.is_frozen());
+
+    node.freeze();
+    assert!(node.is_frozen());
+    assert_eq!(node.processing(), ProcessToggles::NONE);
+
+    // Freezing again keeps the original toggles.
+    node.freeze();
+    node.unfreeze();
+    assert!(!node.is_frozen());
+    assert_eq!(node.processing(), toggles);
+
+    // Unfreezing a node that is not frozen changes nothing.
+    node.set_process(true);
+    node.unfreeze();
+    assert!(node.is_processing());
+
+    node.free();
+}
+
 #[itest]
 fn node_path_from_str(ctx: &TestContext) {
     let child = ctx.scene_tree.clone();
@@ -84,6 +1215,54 @@ fn node_scene_tree() {
     child.free();
 }
 
+#[itest]
+fn node_scene_tree_optional(ctx: &TestContext) {
+    let node = Node::new_alloc();
+    assert!(node.scene_tree().is_none());
+
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&node);
+    assert_eq!(node.scene_tree(), ctx.scene_tree.get_tree());
+
+    parent.remove_child(&node);
+    assert!(node.scene_tree().is_none());
+
+    node.free();
+}
+
+#[itest]
+fn node_viewport(ctx: &TestContext) {
+    let node = Node::new_alloc();
+    assert!(node.viewport().is_none());
+
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&node);
+    assert_eq!(node.viewport(), ctx.scene_tree.get_viewport());
+    assert!(node.viewport().is_some());
+
+    parent.remove_child(&node);
+    assert!(node.viewport().is_none());
+
+    node.free();
+}
+
+#[cfg(feature = "codegen-full")]
+#[itest]
+fn node_world_3d(ctx: &TestContext) {
+    let node = Node3D::new_alloc();
+    assert!(node.world_3d().is_none());
+
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&node);
+
+    let world = node.world_3d().expect("node inside tree has a world");
+    let viewport = node.viewport().expect("node inside tree has a viewport");
+    assert_eq!(Some(world), viewport.find_world_3d());
+
+    parent.remove_child(&node);
+    node.free();
+}
+
 #[itest]
 fn node_call_group(ctx: &TestContext) {
     let mut node = ctx.scene_tree.clone();
@@ -92,3 +1271,751 @@ fn node_call_group(ctx: &TestContext) {
     node.add_to_group("group".into());
     tree.call_group("group".into(), "set_name".into(), &[Variant::from("name")]);
 }
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct GroupCallReceiver {
+    received: Vec<i64>,
+}
+
+#[godot_api]
+impl GroupCallReceiver {
+    #[func]
+    fn receive(&mut self, value: i64, label: GString) {
+        assert_eq!(label, GString::from("event"));
+        self.received.push(value);
+    }
+
+    #[func]
+    fn received_values(&self) -> PackedInt64Array {
+        PackedInt64Array::from(self.received.as_slice())
+    }
+}
+
+// Used in `test_scene_tree_call_group_deferred_typed` in `SpecialTests.gd`, which lets a frame pass before checking the calls.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct GroupCallScheduler {}
+
+#[godot_api]
+impl GroupCallScheduler {
+    #[func]
+    fn call_deferred_with(mut tree: Gd<SceneTree>, group: StringName, value: i64) {
+        tree.call_group_deferred_typed(group, "receive", (value, "event"));
+    }
+}
+
+#[itest]
+fn node_call_group_typed(ctx: &TestContext) {
+    let mut parent = ctx.scene_tree.clone();
+    let mut tree = parent.get_tree().unwrap();
+
+    let mut receivers = Vec::new();
+    for _ in 0..3 {
+        let mut receiver = GroupCallReceiver::new_alloc();
+        receiver.add_to_group("receivers".into());
+        parent.add_child(&receiver);
+        receivers.push(receiver);
+    }
+
+    let bystander = GroupCallReceiver::new_alloc();
+    parent.add_child(&bystander);
+
+    tree.call_group_typed("receivers", "receive", (1, "event"));
+    tree.call_group_flags_typed(
+        &[GroupCallFlags::REVERSE],
+        "receivers",
+        "receive",
+        (2, GString::from("event")),
+    );
+
+    // Deferred calls only arrive at the end of the frame; see `test_scene_tree_call_group_deferred_typed` in `SpecialTests.gd`.
+    for receiver in &receivers {
+        assert_eq!(receiver.bind().received, [1, 2]);
+    }
+    assert!(bystander.bind().received.is_empty());
+
+    for receiver in receivers {
+        receiver.free();
+    }
+    bystander.free();
+}
+
+#[cfg(since_api = "4.2")]
+mod node_async {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+    use std::task::Poll;
+
+    use godot::builtin::{Signal, StringName};
+    use godot::classes::{INode, Node, Node3D};
+    use godot::obj::{Base, Gd, NewAlloc, OnReady};
+    use godot::register::{godot_api, GodotClass};
+    use godot::tools::{
+        EnterTreeGuard, NodeCache, ProcessCallbackHandle, SceneTreeObserver, TaskHandle,
+        TreeNodeEvent,
+    };
+
+    use crate::framework::{expect_panic, itest, poll_once, TestContext};
+
+    #[derive(GodotClass)]
+    #[class(init, base=Node)]
+    struct ReadyAwaitNode {
+        #[init(node = "Kid")]
+        kid: OnReady<Gd<Node>>,
+        base: Base<Node>,
+    }
+
+    fn create_with_child() -> Gd<ReadyAwaitNode> {
+        let mut child = Node::new_alloc();
+        child.set_name("Kid".into());
+
+        let mut node = ReadyAwaitNode::new_alloc();
+        node.add_child(child);
+        node
+    }
+
+    #[itest]
+    fn node_await_ready(ctx: &TestContext) {
+        let node = create_with_child();
+
+        let mut future = node.await_ready();
+        assert_eq!(poll_once(&mut future), Poll::Pending);
+
+        let mut parent = ctx.scene_tree.clone();
+        parent.add_child(&node);
+
+        assert_eq!(poll_once(&mut future), Poll::Ready(()));
+        assert_eq!(node.bind().kid.get_name(), StringName::from("Kid"));
+
+        let ready_signal = Signal::from_object_signal(&node, "ready");
+        assert_eq!(ready_signal.connections().len(), 0);
+
+        parent.remove_child(&node);
+        node.free();
+    }
+
+    #[itest]
+    fn node_await_ready_already_ready(ctx: &TestContext) {
+        let node = create_with_child();
+
+        let mut parent = ctx.scene_tree.clone();
+        parent.add_child(&node);
+
+        let mut future = node.await_ready();
+        assert_eq!(poll_once(&mut future), Poll::Ready(()));
+        assert_eq!(node.bind().kid.get_name(), StringName::from("Kid"));
+
+        parent.remove_child(&node);
+        node.free();
+    }
+
+    #[itest]
+    fn node_await_siblings_ready(ctx: &TestContext) {
+        let mut parent = Node::new_alloc();
+        let first = Node::new_alloc();
+        let second = create_with_child();
+        let third = create_with_child();
+        parent.add_child(&first);
+        parent.add_child(&second);
+        parent.add_child(&third);
+
+        // Created before any of the siblings is ready, like in a task spawned from the first sibling's ready().
+        let mut barrier = first.await_siblings_ready();
+        assert_eq!(poll_once(&mut barrier), Poll::Pending);
+
+        ctx.scene_tree.clone().add_child(&parent);
+
+        assert_eq!(poll_once(&mut barrier), Poll::Ready(()));
+        assert_eq!(second.bind().kid.get_name(), StringName::from("Kid"));
+        assert_eq!(third.bind().kid.get_name(), StringName::from("Kid"));
+
+        for sibling in [second.clone().upcast::<Node>(), third.clone().upcast()] {
+            let ready_signal = Signal::from_object_signal(&sibling, "ready");
+            assert_eq!(ready_signal.connections().len(), 0);
+        }
+
+        parent.free();
+    }
+
+    #[itest]
+    fn node_await_siblings_ready_immediate(ctx: &TestContext) {
+        let node = Node::new_alloc();
+        let mut barrier = node.await_siblings_ready();
+        assert_eq!(poll_once(&mut barrier), Poll::Ready(()), "no parent");
+
+        let mut parent = Node::new_alloc();
+        parent.add_child(&node);
+        let mut barrier = node.await_siblings_ready();
+        assert_eq!(poll_once(&mut barrier), Poll::Ready(()), "no siblings");
+
+        let sibling = Node::new_alloc();
+        parent.add_child(&sibling);
+        ctx.scene_tree.clone().add_child(&parent);
+        let mut barrier = node.await_siblings_ready();
+        assert_eq!(poll_once(&mut barrier), Poll::Ready(()), "siblings ready");
+
+        parent.free();
+    }
+
+    #[itest]
+    fn node_await_siblings_ready_freed_sibling() {
+        let mut parent = Node::new_alloc();
+        let first = Node::new_alloc();
+        let second = Node::new_alloc();
+        parent.add_child(&first);
+        parent.add_child(&second);
+
+        let mut barrier = first.await_siblings_ready();
+        assert_eq!(poll_once(&mut barrier), Poll::Pending);
+
+        // A sibling freed before it becomes ready is no longer waited for.
+        second.free();
+        assert_eq!(poll_once(&mut barrier), Poll::Ready(()));
+
+        parent.free();
+    }
+
+    #[itest]
+    fn scene_tree_await_scene_ready(ctx: &TestContext) {
+        // The test runner's scene has finished loading.
+        let mut barrier = ctx.scene_tree.get_tree().unwrap().await_scene_ready();
+        assert_eq!(poll_once(&mut barrier), Poll::Ready(()));
+    }
+
+    #[itest]
+    fn node_spawn_task_runs_immediately() {
+        let node = Node::new_alloc();
+        let ran = Rc::new(Cell::new(false));
+
+        let ran_clone = Rc::clone(&ran);
+        let handle = node.spawn_task(async move { ran_clone.set(true) });
+
+        assert!(ran.get());
+        assert!(handle.is_finished());
+
+        node.free();
+    }
+
+    #[itest]
+    fn node_spawn_task_cancelled_on_tree_exit(ctx: &TestContext) {
+        let node = Node::new_alloc();
+        let mut parent = ctx.scene_tree.clone();
+        parent.add_child(&node);
+
+        let handle = node.spawn_task(std::future::pending());
+        assert!(!handle.is_finished());
+
+        parent.remove_child(&node);
+        assert!(handle.is_finished());
+
+        node.free();
+    }
+
+    #[itest]
+    fn node_spawn_task_cancel() {
+        let node = Node::new_alloc();
+
+        let handle = node.spawn_task(std::future::pending());
+        handle.cancel();
+        assert!(handle.is_finished());
+
+        // Connection to `tree_exited` is removed together with the task.
+        let exited_signal = Signal::from_object_signal(&node, "tree_exited");
+        assert_eq!(exited_signal.connections().len(), 0);
+
+        node.free();
+    }
+
+    #[itest]
+    fn node_on_process_skipped_outside_tree(ctx: &TestContext) {
+        let node = Node::new_alloc();
+        let count = Rc::new(Cell::new(0));
+
+        let count_clone = Rc::clone(&count);
+        let handle = node.on_process(move |_delta| count_clone.set(count_clone.get() + 1));
+        assert!(handle.is_active());
+
+        // Emulate a frame; the node is not inside the tree yet.
+        let mut tree = ctx.scene_tree.get_tree().unwrap();
+        tree.emit_signal("process_frame".into(), &[]);
+        assert_eq!(count.get(), 0);
+
+        let mut parent = ctx.scene_tree.clone();
+        parent.add_child(&node);
+        tree.emit_signal("process_frame".into(), &[]);
+        assert_eq!(count.get(), 1);
+
+        handle.cancel();
+        assert!(!handle.is_active());
+        tree.emit_signal("process_frame".into(), &[]);
+        assert_eq!(count.get(), 1);
+
+        // Connection to `process_frame` is removed together with the callback.
+        let frame_signal = Signal::from_object_signal(&tree, "process_frame");
+        let is_still_connected = frame_signal.connections().iter_shared().any(|connection| {
+            connection
+                .get("callable")
+                .is_some_and(|callable| callable.stringify().to_string() == "Node::on_process")
+        });
+        assert!(!is_still_connected);
+
+        node.free();
+    }
+
+    #[itest]
+    fn node_after_frames(ctx: &TestContext) {
+        let mut parent = ctx.scene_tree.clone();
+        let node = Node::new_alloc();
+        parent.add_child(&node);
+
+        let mut tree = ctx.scene_tree.get_tree().unwrap();
+        let frame = Rc::new(Cell::new(0));
+        let ran_on_frame = Rc::new(Cell::new(None));
+
+        let (frame_clone, ran_clone) = (Rc::clone(&frame), Rc::clone(&ran_on_frame));
+        let handle = node.after_frames(3, move || ran_clone.set(Some(frame_clone.get())));
+
+        let immediate = Rc::new(Cell::new(None));
+        let (frame_clone, immediate_clone) = (Rc::clone(&frame), Rc::clone(&immediate));
+        let immediate_handle =
+            node.after_frames(0, move || immediate_clone.set(Some(frame_clone.get())));
+
+        for _ in 0..5 {
+            frame.set(frame.get() + 1);
+            tree.emit_signal("process_frame".into(), &[]);
+        }
+
+        assert_eq!(ran_on_frame.get(), Some(3));
+        assert_eq!(immediate.get(), Some(1));
+        assert!(!handle.is_active());
+        assert!(!immediate_handle.is_active());
+
+        node.free();
+    }
+
+    #[itest]
+    fn node_after_frames_cancel(ctx: &TestContext) {
+        let mut parent = ctx.scene_tree.clone();
+        let node = Node::new_alloc();
+        parent.add_child(&node);
+
+        let mut tree = ctx.scene_tree.get_tree().unwrap();
+        let ran = Rc::new(Cell::new(false));
+
+        let ran_clone = Rc::clone(&ran);
+        let cancelled = node.after_frames(1, move || ran_clone.set(true));
+        cancelled.cancel();
+
+        let ran_clone = Rc::clone(&ran);
+        let orphaned = node.after_frames(1, move || ran_clone.set(true));
+        node.free();
+
+        tree.emit_signal("process_frame".into(), &[]);
+        assert!(!ran.get());
+        assert!(!cancelled.is_active());
+        assert!(!orphaned.is_active());
+    }
+
+    #[itest]
+    fn node_debounce_coalesces_triggers(ctx: &TestContext) {
+        let mut parent = ctx.scene_tree.clone();
+        let node = Node::new_alloc();
+        parent.add_child(&node);
+
+        let mut tree = ctx.scene_tree.get_tree().unwrap();
+        let runs = Rc::new(Cell::new(0));
+
+        let runs_clone = Rc::clone(&runs);
+        let debouncer = node.debounce(move || runs_clone.set(runs_clone.get() + 1));
+        assert!(!debouncer.is_pending());
+
+        for _ in 0..10 {
+            debouncer.trigger();
+        }
+        assert!(debouncer.is_pending());
+        assert_eq!(runs.get(), 0, "runs on the next frame, not immediately");
+
+        tree.emit_signal("process_frame".into(), &[]);
+        assert_eq!(runs.get(), 1);
+        assert!(!debouncer.is_pending());
+
+        tree.emit_signal("process_frame".into(), &[]);
+        assert_eq!(runs.get(), 1, "no run without trigger");
+
+        // Triggers in separate frames run once per frame.
+        for _ in 0..2 {
+            debouncer.trigger();
+            debouncer.clone().trigger();
+            tree.emit_signal("process_frame".into(), &[]);
+        }
+        assert_eq!(runs.get(), 3);
+
+        node.free();
+    }
+
+    #[itest]
+    fn node_debounce_flush_cancel(ctx: &TestContext) {
+        let mut parent = ctx.scene_tree.clone();
+        let node = Node::new_alloc();
+        parent.add_child(&node);
+
+        let mut tree = ctx.scene_tree.get_tree().unwrap();
+        let runs = Rc::new(Cell::new(0));
+
+        let runs_clone = Rc::clone(&runs);
+        let debouncer = node.debounce(move || runs_clone.set(runs_clone.get() + 1));
+        assert!(!debouncer.flush(), "nothing pending");
+
+        debouncer.trigger();
+        assert!(debouncer.flush());
+        assert_eq!(runs.get(), 1);
+        tree.emit_signal("process_frame".into(), &[]);
+        assert_eq!(runs.get(), 1, "flushed run is not repeated");
+
+        debouncer.trigger();
+        debouncer.cancel();
+        assert!(!debouncer.is_pending());
+        tree.emit_signal("process_frame".into(), &[]);
+        assert_eq!(runs.get(), 1);
+
+        // Dropping the last clone cancels the pending run.
+        debouncer.trigger();
+        drop(debouncer);
+        tree.emit_signal("process_frame".into(), &[]);
+        assert_eq!(runs.get(), 1);
+
+        // Triggers are ignored once the node is freed.
+        let runs_clone = Rc::clone(&runs);
+        let orphaned = node.debounce(move || runs_clone.set(runs_clone.get() + 1));
+        node.free();
+        orphaned.trigger();
+        assert!(!orphaned.is_pending());
+        tree.emit_signal("process_frame".into(), &[]);
+        assert_eq!(runs.get(), 1);
+    }
+
+    #[itest]
+    fn node_cache_invalidation(ctx: &TestContext) {
+        let mut tree_root = ctx.scene_tree.clone();
+        let mut owner = Node::new_alloc();
+        tree_root.add_child(&owner);
+
+        let cache = NodeCache::<Node>::new("Target");
+        assert_eq!(cache.try_get(&owner), None);
+        assert!(!cache.is_cached(), "failed lookups are not cached");
+
+        let target = named_node("Target");
+        owner.add_child(&target);
+        assert_eq!(cache.get(&owner), target);
+        assert!(cache.is_cached());
+        assert_eq!(cache.get(&owner), target);
+
+        // Freed node.
+        target.free();
+        assert!(!cache.is_cached());
+        assert_eq!(cache.try_get(&owner), None);
+
+        // Node replaced by another one under the same path.
+        let first = named_node("Target");
+        owner.add_child(&first);
+        assert_eq!(cache.get(&owner), first);
+
+        owner.remove_child(&first);
+        let second = named_node("Target");
+        owner.add_child(&second);
+        assert!(!cache.is_cached(), "removed from tree");
+        assert_eq!(cache.get(&owner), second);
+
+        // Node renamed, and another one takes its name.
+        let mut second = second;
+        second.set_name("Renamed".into());
+        let third = named_node("Target");
+        owner.add_child(&third);
+        assert!(!cache.is_cached(), "renamed");
+        assert_eq!(cache.get(&owner), third);
+
+        cache.invalidate();
+        assert!(!cache.is_cached());
+        assert_eq!(cache.get(&owner), third);
+
+        first.free();
+        owner.free();
+        assert!(!cache.is_cached());
+    }
+
+    #[itest]
+    fn node_cache_wrong_type() {
+        let mut owner = Node::new_alloc();
+        owner.add_child(&named_node("Target"));
+
+        let cache = NodeCache::<Node3D>::new("Target");
+        assert_eq!(cache.try_get(&owner), None);
+        assert!(!cache.is_cached());
+
+        expect_panic("wrong node type", || {
+            cache.get(&owner);
+        });
+
+        owner.free();
+    }
+
+    fn named_node(name: &str) -> Gd<Node> {
+        let mut node = Node::new_alloc();
+        node.set_name(name.into());
+        node
+    }
+
+    // Used in `test_node_spawn_task_stops_when_freed` in `SpecialTests.gd`, which lets frames pass.
+    #[derive(GodotClass)]
+    #[class(init, base=RefCounted)]
+    struct FrameCountingTask {
+        frames: Rc<Cell<i64>>,
+        handle: Option<TaskHandle>,
+    }
+
+    #[godot_api]
+    impl FrameCountingTask {
+        /// Spawns a task tied to `node`, which counts process frames in a loop.
+        #[func]
+        fn start(&mut self, node: Gd<Node>) {
+            let tree = node.get_tree().expect("node is inside tree");
+            let frames = Rc::clone(&self.frames);
+
+            let handle = node.spawn_task(async move {
+                let process_frame = Signal::from_object_signal(&tree, "process_frame");
+                loop {
+                    process_frame.to_signal_timeout(1.0).await;
+                    frames.set(frames.get() + 1);
+                }
+            });
+
+            self.handle = Some(handle);
+        }
+
+        #[func]
+        fn frames(&self) -> i64 {
+            self.frames.get()
+        }
+
+        #[func]
+        fn is_finished(&self) -> bool {
+            self.handle.as_ref().is_some_and(TaskHandle::is_finished)
+        }
+    }
+
+    // Used in `test_node_on_process_counts_frames` in `SpecialTests.gd`, which lets frames pass.
+    #[derive(GodotClass)]
+    #[class(init, base=RefCounted)]
+    struct ProcessCounter {
+        frames: Rc<Cell<i64>>,
+        handle: Option<ProcessCallbackHandle>,
+    }
+
+    #[godot_api]
+    impl ProcessCounter {
+        /// Registers a per-frame callback on `node`, which counts frames.
+        #[func]
+        fn start(&mut self, node: Gd<Node>) {
+            let frames = Rc::clone(&self.frames);
+            let handle = node.on_process(move |_delta| frames.set(frames.get() + 1));
+
+            self.handle = Some(handle);
+        }
+
+        #[func]
+        fn frames(&self) -> i64 {
+            self.frames.get()
+        }
+
+        #[func]
+        fn is_active(&self) -> bool {
+            self.handle
+                .as_ref()
+                .is_some_and(ProcessCallbackHandle::is_active)
+        }
+    }
+
+    #[derive(GodotClass)]
+    #[class(base=Node)]
+    struct TreeGuardNode {
+        log: Rc<RefCell<Vec<&'static str>>>,
+        guard: Option<EnterTreeGuard>,
+        base: Base<Node>,
+    }
+
+    #[godot_api]
+    impl INode for TreeGuardNode {
+        fn init(base: Base<Node>) -> Self {
+            let log = Rc::new(RefCell::new(Vec::new()));
+            let enter_log = Rc::clone(&log);
+            let exit_log = Rc::clone(&log);
+
+            let guard = EnterTreeGuard::new(
+                &base.to_gd(),
+                move || enter_log.borrow_mut().push("guard_enter"),
+                move || exit_log.borrow_mut().push("guard_exit"),
+            );
+
+            Self {
+                log,
+                guard: Some(guard),
+                base,
+            }
+        }
+
+        fn enter_tree(&mut self) {
+            self.log.borrow_mut().push("enter_tree");
+        }
+
+        fn exit_tree(&mut self) {
+            self.log.borrow_mut().push("exit_tree");
+        }
+    }
+
+    #[itest]
+    fn node_enter_tree_guard_order(ctx: &TestContext) {
+        let node = TreeGuardNode::new_alloc();
+        let log = Rc::clone(&node.bind().log);
+        let mut parent = ctx.scene_tree.clone();
+
+        parent.add_child(&node);
+        assert_eq!(*log.borrow(), ["enter_tree", "guard_enter"]);
+
+        parent.remove_child(&node);
+        assert_eq!(
+            *log.borrow(),
+            ["enter_tree", "guard_enter", "exit_tree", "guard_exit"]
+        );
+
+        // Closures run again on every transition.
+        log.borrow_mut().clear();
+        parent.add_child(&node);
+        parent.remove_child(&node);
+        assert_eq!(
+            *log.borrow(),
+            ["enter_tree", "guard_enter", "exit_tree", "guard_exit"]
+        );
+
+        // Dropping the guard disconnects the closures.
+        log.borrow_mut().clear();
+        let guard = node.clone().bind_mut().guard.take().unwrap();
+        assert!(guard.is_active());
+        drop(guard);
+
+        parent.add_child(&node);
+        parent.remove_child(&node);
+        assert_eq!(*log.borrow(), ["enter_tree", "exit_tree"]);
+
+        node.free();
+    }
+
+    #[itest]
+    fn node_enter_tree_guard_freed_inside_tree(ctx: &TestContext) {
+        let node = TreeGuardNode::new_alloc();
+        let log = Rc::clone(&node.bind().log);
+
+        let mut parent = ctx.scene_tree.clone();
+        parent.add_child(&node);
+        node.free();
+
+        assert_eq!(
+            *log.borrow(),
+            ["enter_tree", "guard_enter", "exit_tree", "guard_exit"]
+        );
+    }
+
+    #[itest]
+    fn scene_tree_nodes_added_to_group(ctx: &TestContext) {
+        let mut parent = ctx.scene_tree.clone();
+        let tree = parent.get_tree().unwrap();
+
+        let mut existing = Node::new_alloc();
+        existing.add_to_group("stream_watched".into());
+        parent.add_child(&existing);
+
+        // Members at subscription time are yielded first.
+        let mut stream = tree.nodes_added_to_group("stream_watched");
+        assert_eq!(stream.try_next(), Some(existing.clone()));
+        assert_eq!(stream.try_next(), None);
+
+        let mut next = stream.next();
+        assert!(poll_once(&mut next).is_pending());
+
+        let outsider = Node::new_alloc();
+        parent.add_child(&outsider);
+
+        let mut member = Node::new_alloc();
+        member.add_to_group("stream_watched".into());
+        parent.add_child(&member);
+
+        assert_eq!(poll_once(&mut next), Poll::Ready(member.clone()));
+        assert_eq!(stream.try_next(), None);
+
+        // Connection to `node_added` is removed together with the stream.
+        drop(stream);
+        let added_signal = Signal::from_object_signal(&tree, "node_added");
+        let is_still_connected = added_signal.connections().iter_shared().any(|connection| {
+            connection.get("callable").is_some_and(|callable| {
+                callable.stringify().to_string() == "GroupNodeStream::node_added"
+            })
+        });
+        assert!(!is_still_connected);
+
+        existing.free();
+        outsider.free();
+        member.free();
+    }
+
+    #[itest]
+    fn scene_tree_observer_fans_out(ctx: &TestContext) {
+        let mut parent = ctx.scene_tree.clone();
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let first_log = Rc::clone(&log);
+        let first = SceneTreeObserver::subscribe(TreeNodeEvent::NodeAdded, move |node| {
+            first_log.borrow_mut().push(("first", node.get_name()));
+        });
+        let second_log = Rc::clone(&log);
+        let second = SceneTreeObserver::subscribe(TreeNodeEvent::NodeAdded, move |node| {
+            second_log.borrow_mut().push(("second", node.get_name()));
+        });
+        assert_eq!(
+            SceneTreeObserver::subscriber_count(TreeNodeEvent::NodeAdded),
+            2
+        );
+
+        let mut node = Node::new_alloc();
+        node.set_name("Observed".into());
+        parent.add_child(&node);
+        let name = StringName::from("Observed");
+        assert_eq!(
+            *log.borrow(),
+            [("first", name.clone()), ("second", name.clone())]
+        );
+
+        // Dropped subscriptions are no longer called.
+        first.unsubscribe();
+        log.borrow_mut().clear();
+        parent.remove_child(&node);
+        parent.add_child(&node);
+        assert_eq!(*log.borrow(), [("second", name)]);
+
+        // The shared connection is removed with the last subscriber.
+        drop(second);
+        assert_eq!(
+            SceneTreeObserver::subscriber_count(TreeNodeEvent::NodeAdded),
+            0
+        );
+        let tree = parent.get_tree().unwrap();
+        let added_signal = Signal::from_object_signal(&tree, "node_added");
+        let is_still_connected = added_signal.connections().iter_shared().any(|connection| {
+            connection.get("callable").is_some_and(|callable| {
+                callable.stringify().to_string() == "SceneTreeObserver::dispatch"
+            })
+        });
+        assert!(!is_still_connected);
+
+        node.free();
+    }
+}
@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::math::ApproxEq;
+use godot::builtin::real;
+use godot::classes::RandomNumberGenerator;
+use godot::obj::{Gd, NewGd};
+
+use crate::framework::itest;
+
+fn seeded(seed: u64) -> Gd<RandomNumberGenerator> {
+    let mut rng = RandomNumberGenerator::new_gd();
+    rng.set_seed(seed);
+    rng
+}
+
+fn sample(rng: &mut Gd<RandomNumberGenerator>) -> Vec<real> {
+    let mut values = vec![rng.rand_real(), rng.rand_real_range(-5.0, 5.0)];
+    values.push(rng.randi_range(10, 20) as real);
+
+    let unit2 = rng.rand_vector2_unit();
+    let unit3 = rng.rand_vector3_unit();
+    values.extend([unit2.x, unit2.y, unit3.x, unit3.y, unit3.z]);
+    values
+}
+
+#[itest]
+fn random_seeded_sequence_reproducible() {
+    let first = sample(&mut seeded(12345));
+    let second = sample(&mut seeded(12345));
+    assert_eq!(first, second);
+
+    let other = sample(&mut seeded(54321));
+    assert_ne!(first, other);
+}
+
+#[itest]
+fn random_matches_engine_methods() {
+    let mut rust_rng = seeded(777);
+    let mut engine_rng = seeded(777);
+
+    for _ in 0..10 {
+        let value = rust_rng.rand_real_range(2.0, 3.0);
+        assert_eq!(value, engine_rng.randf_range(2.0, 3.0) as real);
+        assert!((2.0..=3.0).contains(&value));
+    }
+}
+
+#[itest]
+fn random_unit_vectors() {
+    let mut rng = seeded(42);
+
+    for _ in 0..100 {
+        let length2 = rng.rand_vector2_unit().length();
+        let length3 = rng.rand_vector3_unit().length();
+
+        assert!(length2.approx_eq(&1.0), "2D length {length2}");
+        assert!(length3.approx_eq(&1.0), "3D length {length3}");
+    }
+}
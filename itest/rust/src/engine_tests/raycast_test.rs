@@ -0,0 +1,78 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{real, varray, Array, VariantArray, Vector3};
+use godot::classes::{BoxShape3D, CollisionShape3D, Node, Node3D, RefCounted, StaticBody3D};
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::{itest, TestContext};
+
+#[itest]
+fn node3d_raycast_forward_outside_tree() {
+    let node = Node3D::new_alloc();
+
+    // Not in a physics space; Godot would print an error when accessing the world.
+    assert!(node.raycast_forward(100.0, u32::MAX).is_none());
+
+    node.free();
+}
+
+#[itest]
+fn node3d_raycast_forward_nothing_hit(ctx: &TestContext) {
+    let mut node = Node3D::new_alloc();
+    ctx.scene_tree.clone().add_child(&node);
+    node.set_global_position(Vector3::new(0.0, 1000.0, 0.0));
+
+    assert!(node.raycast_forward(10.0, u32::MAX).is_none());
+
+    node.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+// Used in `test_node3d_raycast_forward_hits_body` in `SpecialTests.gd`, which lets physics frames pass.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct RaycastChecker {}
+
+#[godot_api]
+impl RaycastChecker {
+    /// Adds an eye node at the origin, and a static body 5 units in front of it (towards -Z), to `parent`. Returns `[eye, body]`.
+    #[func]
+    fn spawn_scene(&self, mut parent: Gd<Node>) -> Array<Gd<Node3D>> {
+        let eye = Node3D::new_alloc();
+
+        let mut body = StaticBody3D::new_alloc();
+        body.set_position(Vector3::new(0.0, 0.0, -5.0));
+
+        let mut shape = CollisionShape3D::new_alloc();
+        shape.set_shape(&BoxShape3D::new_gd()); // 1x1x1 units.
+        body.add_child(&shape);
+
+        parent.add_child(&eye);
+        parent.add_child(&body);
+
+        Array::from(&[eye, body.upcast::<Node3D>()])
+    }
+
+    /// Returns `[position, collider]` of the hit, or an empty array.
+    #[func]
+    fn raycast(&self, eye: Gd<Node3D>, distance: real, mask: u32) -> VariantArray {
+        match eye.raycast_forward(distance, mask) {
+            Some(hit) => {
+                assert_eq!(
+                    hit.collider_id,
+                    hit.collider.as_ref().map(|c| c.instance_id())
+                );
+                varray![hit.position, hit.collider.to_variant()]
+            }
+            None => VariantArray::new(),
+        }
+    }
+}
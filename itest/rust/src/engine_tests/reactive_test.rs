@@ -0,0 +1,76 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use godot::builtin::Callable;
+use godot::obj::NewAlloc;
+use godot::register::{godot_api, GodotClass};
+use godot::tools::Reactive;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct ChangeRecorder {
+    received: Vec<i64>,
+}
+
+#[godot_api]
+impl ChangeRecorder {
+    #[signal]
+    fn changed(value: i64);
+
+    #[func]
+    fn record(&mut self, value: i64) {
+        self.received.push(value);
+    }
+}
+
+#[itest]
+fn reactive_fires_only_on_change() {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+
+    let mut reactive = Reactive::new(1);
+    let calls_inner = calls.clone();
+    reactive.on_changed(move |value| calls_inner.borrow_mut().push(*value));
+
+    assert!(!reactive.set(1), "same value");
+    assert!(calls.borrow().is_empty());
+
+    assert!(reactive.set(2), "different value");
+    assert_eq!(*calls.borrow(), vec![2]);
+    assert_eq!(*reactive.get(), 2);
+
+    assert!(!reactive.set(2), "same value after change");
+    assert_eq!(*calls.borrow(), vec![2]);
+
+    reactive.clear_callbacks();
+    assert!(reactive.set(3));
+    assert_eq!(*calls.borrow(), vec![2]);
+}
+
+#[itest]
+fn reactive_emits_signal() {
+    let mut recorder = ChangeRecorder::new_alloc();
+    let callable = Callable::from_object_method(&recorder, "record");
+    recorder.connect("changed".into(), callable);
+
+    let mut reactive = Reactive::<i64>::default();
+    reactive.emit_on_change(&recorder, "changed");
+
+    reactive.set(0);
+    reactive.set(5);
+    reactive.set(5);
+    reactive.set(7);
+    assert_eq!(recorder.bind().received, vec![5, 7]);
+
+    // No more signals once the object is freed.
+    recorder.free();
+    assert!(reactive.set(9));
+}
@@ -0,0 +1,120 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use godot::builtin::{Callable, Variant, VariantArray};
+use godot::classes::Node;
+use godot::meta::{FromGodot, ToGodot};
+use godot::obj::NewAlloc;
+use godot::tools::RustBox;
+
+use crate::framework::itest;
+
+#[derive(Clone, PartialEq, Debug)]
+struct Inventory {
+    items: Vec<String>,
+    gold: u32,
+}
+
+fn inventory() -> Inventory {
+    Inventory {
+        items: vec!["sword".to_string(), "shield".to_string()],
+        gold: 42,
+    }
+}
+
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[itest]
+fn rust_box_downcast() {
+    let boxed = RustBox::new(inventory());
+
+    assert!(boxed.is::<Inventory>());
+    assert!(!boxed.is::<String>());
+    assert_eq!(boxed.downcast::<Inventory>(), Some(inventory()));
+    assert_eq!(boxed.downcast::<String>(), None);
+}
+
+#[itest]
+fn rust_box_variant_roundtrip() {
+    let variant = RustBox::new(inventory()).to_variant();
+
+    let boxed = variant.to::<RustBox>();
+    assert_eq!(boxed.downcast::<Inventory>(), Some(inventory()));
+    assert_eq!(boxed, variant.to::<RustBox>());
+
+    // Store in metadata, which only accepts Godot types.
+    let mut node = Node::new_alloc();
+    node.set_meta("inventory".into(), &variant);
+    let boxed = node.get_meta("inventory".into()).to::<RustBox>();
+    assert_eq!(boxed.downcast::<Inventory>(), Some(inventory()));
+
+    node.free();
+}
+
+#[itest]
+fn rust_box_from_other_variant() {
+    assert!(Variant::nil().try_to::<RustBox>().is_err());
+    assert!(42.to_variant().try_to::<RustBox>().is_err());
+    assert!(RustBox::try_from_godot(Callable::invalid()).is_err());
+
+    // Boxes are not recognized by their name, even if the ID exists.
+    let boxed = RustBox::new(1);
+    let name = boxed.to_godot().to_string();
+    let callable = Callable::from_fn(name, |_args| Ok(Variant::nil()));
+    assert!(RustBox::try_from_godot(callable).is_err());
+}
+
+#[itest]
+fn rust_box_downcast_clone_uses_boxes() {
+    #[derive(PartialEq, Debug)]
+    struct CreatesBoxOnClone(i32);
+
+    impl Clone for CreatesBoxOnClone {
+        fn clone(&self) -> Self {
+            // Would deadlock if `downcast()` still held the registry lock.
+            let _ = RustBox::new(self.0);
+            Self(self.0)
+        }
+    }
+
+    let boxed = RustBox::new(CreatesBoxOnClone(7));
+    assert_eq!(
+        boxed.downcast::<CreatesBoxOnClone>(),
+        Some(CreatesBoxOnClone(7))
+    );
+}
+
+#[itest]
+fn rust_box_drop() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let boxed = RustBox::new(DropFlag(dropped.clone()));
+
+    let mut array = VariantArray::new();
+    array.push(boxed.to_variant());
+    drop(boxed);
+    assert!(!dropped.load(Ordering::SeqCst), "kept alive by array");
+
+    let boxed = array.at(0).to::<RustBox>();
+    assert!(boxed.is::<DropFlag>());
+    array.clear();
+    assert!(!dropped.load(Ordering::SeqCst), "kept alive by RustBox");
+
+    drop(boxed);
+    assert!(
+        dropped.load(Ordering::SeqCst),
+        "dropped with last reference"
+    );
+}
@@ -5,11 +5,29 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::obj::NewGd;
+#[cfg(since_api = "4.2")]
+use std::cell::RefCell;
+#[cfg(since_api = "4.2")]
+use std::rc::Rc;
+#[cfg(since_api = "4.2")]
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[cfg(since_api = "4.2")]
+use godot::builtin::{Callable, StringName, Variant};
+#[cfg(since_api = "4.2")]
+use godot::classes::{Engine, IResource, Resource, ResourceLoader, SceneTree};
+use godot::classes::{Node, Node2D, Node3D, PackedScene};
+#[cfg(since_api = "4.2")]
+use godot::obj::{Base, Gd, WithBaseField};
+use godot::obj::{NewAlloc, NewGd};
+#[cfg(since_api = "4.2")]
+use godot::register::godot_api;
 use godot::register::GodotClass;
+#[cfg(since_api = "4.2")]
+use godot::tools::spawn_task;
 use godot::tools::{load, save, try_load, try_save};
 
-use crate::framework::itest;
+use crate::framework::{itest, suppress_godot_print, TestContext};
 
 fn remove_test_file(file_name: &str) {
     let godot_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
@@ -69,3 +87,146 @@ fn load_test() {
 
     remove_test_file(RESOURCE_NAME);
 }
+
+/// Connects a counter to its own `changed` signal on the first property assignment, like a handler attached during construction.
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(base=Resource, init)]
+struct OnChangeSavedGame {
+    #[export]
+    #[var(on_change)]
+    level: u32,
+
+    connected: bool,
+    base: Base<Resource>,
+}
+
+#[cfg(since_api = "4.2")]
+static ON_CHANGE_EMISSIONS: AtomicU32 = AtomicU32::new(0);
+
+#[cfg(since_api = "4.2")]
+#[godot_api]
+impl IResource for OnChangeSavedGame {
+    fn set_property(&mut self, _property: StringName, _value: Variant) -> bool {
+        if !self.connected {
+            self.connected = true;
+            let counter = Callable::from_fn("count_changed", |_args| {
+                ON_CHANGE_EMISSIONS.fetch_add(1, Ordering::SeqCst);
+                Ok(Variant::nil())
+            });
+            self.base_mut().connect("changed".into(), counter);
+        }
+
+        // Continue with the regular setter.
+        false
+    }
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn load_emits_on_change() {
+    const ON_CHANGE_RESOURCE_NAME: &str = "test_on_change_resource.tres";
+    let res_path = format!("res://{ON_CHANGE_RESOURCE_NAME}");
+
+    let mut resource = OnChangeSavedGame::new_gd();
+    resource.bind_mut().set_level(12);
+    save(resource, &res_path);
+
+    let loaded = ResourceLoader::singleton()
+        .load(res_path.into())
+        .expect("resource is loaded")
+        .cast::<OnChangeSavedGame>();
+
+    // The loader assigned the stored value through the setter, after the counter was connected. This is reported as a change.
+    assert_eq!(loaded.bind().level, 12);
+    assert!(loaded.bind().connected);
+    assert_eq!(ON_CHANGE_EMISSIONS.load(Ordering::SeqCst), 1);
+
+    remove_test_file(ON_CHANGE_RESOURCE_NAME);
+}
+
+const SCENE_NAME: &str = "test_scene_change.tscn";
+
+#[itest]
+fn change_scene_to_errors(ctx: &TestContext) {
+    let scene_path = format!("res://{SCENE_NAME}");
+
+    let root = Node3D::new_alloc();
+    let mut scene = PackedScene::new_gd();
+    scene.pack(&root);
+    save(scene, &scene_path);
+    root.free();
+
+    let mut tree = ctx.scene_tree.get_tree().unwrap();
+    let current_scene = tree.get_current_scene();
+
+    let mut err = None;
+    suppress_godot_print(|| err = tree.change_scene_to::<Node>(FAULTY_PATH).err());
+    let err = err.expect("loading from faulty path fails");
+    assert!(err.is_load_failed());
+    assert_eq!(err.path(), FAULTY_PATH);
+
+    let err = tree
+        .change_scene_to::<Node2D>(&scene_path)
+        .expect_err("root is a Node3D");
+    assert!(err.is_wrong_root_type());
+    assert_eq!(
+        err.to_string(),
+        format!("root of scene '{scene_path}' has class 'Node3D', expected 'Node2D'")
+    );
+
+    // Failed changes leave the current scene untouched.
+    assert_eq!(tree.get_current_scene(), current_scene);
+
+    remove_test_file(SCENE_NAME);
+}
+
+#[cfg(since_api = "4.2")]
+const AWAITED_SCENE_NAME: &str = "test_scene_await_ready.tscn";
+
+// Used in `test_change_scene_and_await_ready` in `SpecialTests.gd`, which lets frames pass.
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct SceneChangeAwaiter {
+    root: Rc<RefCell<Option<Gd<Node2D>>>>,
+}
+
+#[cfg(since_api = "4.2")]
+#[godot_api]
+impl SceneChangeAwaiter {
+    /// Saves a scene with a `Node2D` root, changes to it, and spawns a task awaiting its readiness.
+    #[func]
+    fn start(&mut self) {
+        let scene_path = format!("res://{AWAITED_SCENE_NAME}");
+
+        let mut root = Node2D::new_alloc();
+        root.set_name("AwaitedScene".into());
+        let mut scene = PackedScene::new_gd();
+        scene.pack(&root);
+        save(scene, &scene_path);
+        root.free();
+
+        let mut tree = Engine::singleton()
+            .get_main_loop()
+            .expect("main loop exists")
+            .cast::<SceneTree>();
+        let ready = tree
+            .change_scene_and_await_ready::<Node2D>(&scene_path)
+            .expect("scene has a Node2D root");
+
+        // The scene is already loaded.
+        remove_test_file(AWAITED_SCENE_NAME);
+
+        let slot = Rc::clone(&self.root);
+        spawn_task(async move {
+            *slot.borrow_mut() = ready.await;
+        });
+    }
+
+    /// The new root, once it is the current scene and ready.
+    #[func]
+    fn root(&self) -> Option<Gd<Node2D>> {
+        self.root.borrow().clone()
+    }
+}
@@ -0,0 +1,127 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use godot::builtin::Rid;
+use godot::classes::{INode, Node, RenderingServer};
+use godot::obj::{Base, NewAlloc};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::{RidServer, ServerResourceGuard};
+
+use crate::framework::{itest, TestContext};
+
+/// Records freed RIDs instead of freeing them on a real server.
+#[derive(Clone, Default)]
+struct MockServer {
+    next_id: Rc<RefCell<u64>>,
+    freed: Rc<RefCell<Vec<Rid>>>,
+}
+
+impl MockServer {
+    fn allocate(&self) -> Rid {
+        let mut next_id = self.next_id.borrow_mut();
+        *next_id += 1;
+        Rid::new(*next_id)
+    }
+
+    fn track(&self, guard: &mut ServerResourceGuard) -> Rid {
+        let freed = Rc::clone(&self.freed);
+        guard.track_with(self.allocate(), move |rid| freed.borrow_mut().push(rid))
+    }
+
+    fn freed(&self) -> Vec<Rid> {
+        self.freed.borrow().clone()
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct RidOwnerNode {
+    server: MockServer,
+    resources: ServerResourceGuard,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl INode for RidOwnerNode {
+    fn enter_tree(&mut self) {
+        self.server.track(&mut self.resources);
+    }
+
+    fn exit_tree(&mut self) {
+        self.resources.free_all();
+    }
+}
+
+#[itest]
+fn server_resource_guard_frees_on_exit_tree(ctx: &TestContext) {
+    let server = MockServer::default();
+    let mut node = RidOwnerNode::new_alloc();
+    node.bind_mut().server = server.clone();
+
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&node);
+    assert_eq!(node.bind().resources.len(), 1);
+    assert!(server.freed().is_empty());
+
+    parent.remove_child(&node);
+    assert_eq!(server.freed(), vec![Rid::new(1)]);
+    assert!(node.bind().resources.is_empty());
+
+    // Re-entering allocates again. Freeing the node while inside the tree also exits the tree.
+    parent.add_child(&node);
+    let server_clone = node.bind().server.clone();
+    server_clone.track(&mut node.bind_mut().resources);
+    assert_eq!(node.bind().resources.len(), 2);
+
+    node.free();
+    assert_eq!(
+        server.freed(),
+        vec![Rid::new(1), Rid::new(3), Rid::new(2)],
+        "freed in reverse order"
+    );
+}
+
+#[itest]
+fn server_resource_guard_frees_on_drop() {
+    let server = MockServer::default();
+    let mut guard = ServerResourceGuard::new();
+
+    let first = server.track(&mut guard);
+    let second = server.track(&mut guard);
+    let untracked = server.track(&mut guard);
+
+    assert!(guard.untrack(untracked));
+    assert!(!guard.untrack(untracked));
+    assert!(guard.contains(first));
+
+    // Invalid RIDs are not tracked.
+    guard.track_with(Rid::Invalid, |_| panic!("invalid RID freed"));
+    assert_eq!(guard.len(), 2);
+
+    assert!(guard.free(first));
+    assert!(!guard.free(first));
+    assert_eq!(server.freed(), vec![first]);
+
+    drop(guard);
+    assert_eq!(server.freed(), vec![first, second]);
+}
+
+#[itest]
+fn server_resource_guard_rendering_server() {
+    let mut server = RenderingServer::singleton();
+    let mut guard = ServerResourceGuard::new();
+
+    let item = guard.track(RidServer::Rendering, server.canvas_item_create());
+    assert!(item.is_valid());
+    assert!(guard.contains(item));
+
+    assert_eq!(guard.free_all(), 1);
+    assert!(guard.is_empty());
+}
@@ -0,0 +1,56 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Variant, Vector3};
+use godot::classes::{Shader, ShaderMaterial};
+use godot::obj::{Gd, NewGd};
+
+use crate::framework::itest;
+
+fn make_material() -> Gd<ShaderMaterial> {
+    let mut shader = Shader::new_gd();
+    shader.set_code(
+        "shader_type spatial;
+        uniform float strength = 1.0;
+        uniform vec3 tint;"
+            .into(),
+    );
+
+    let mut material = ShaderMaterial::new_gd();
+    material.set_shader(&shader);
+    material
+}
+
+#[itest]
+fn shader_material_typed_params() {
+    let mut material = make_material();
+    material.set_param("strength", 0.25);
+    material.set_param("tint", Vector3::new(1.0, 0.5, 0.0));
+
+    assert_eq!(material.get_param::<f64>("strength"), Some(0.25));
+    assert_eq!(
+        material.get_param::<Vector3>("tint"),
+        Some(Vector3::new(1.0, 0.5, 0.0))
+    );
+
+    // Wrong type.
+    assert_eq!(material.get_param::<Vector3>("strength"), None);
+
+    // Resetting to the shader's default.
+    material.set_param("strength", Variant::nil());
+    assert_eq!(material.get_param::<f64>("strength"), None);
+}
+
+#[itest]
+fn shader_material_unknown_param() {
+    let mut material = make_material();
+    assert_eq!(material.get_param::<f64>("nonexistent"), None);
+
+    // Godot does not validate uniform names; the value is stored, but ignored during rendering.
+    material.set_param("nonexistent", 3.0);
+    assert_eq!(material.get_param::<f64>("nonexistent"), Some(3.0));
+}
@@ -0,0 +1,90 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Callable;
+use godot::classes::{Node, Resource};
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::SharedResource;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct ChangeCounter {
+    count: u32,
+}
+
+#[godot_api]
+impl ChangeCounter {
+    #[func]
+    fn on_changed(&mut self) {
+        self.count += 1;
+    }
+}
+
+fn on_changed_callable(counter: &Gd<ChangeCounter>) -> Callable {
+    Callable::from_object_method(counter, "on_changed")
+}
+
+#[itest]
+fn shared_resource_users() {
+    let first = Node::new_alloc();
+    let second = Node::new_alloc();
+
+    let mut shared = SharedResource::new(Resource::new_gd());
+    assert!(shared.add_user(&first));
+    assert!(shared.add_user(&second));
+    assert!(!shared.add_user(&first), "already registered");
+    assert_eq!(shared.users(), [first.clone(), second.clone()]);
+
+    assert!(shared.remove_user(&first));
+    assert!(!shared.remove_user(&first), "already removed");
+    assert_eq!(shared.users(), [second.clone()]);
+
+    second.free();
+    assert!(shared.users().is_empty(), "freed users are not reported");
+
+    first.free();
+}
+
+#[itest]
+fn shared_resource_notifies_subscribers() {
+    let first = ChangeCounter::new_alloc();
+    let second = ChangeCounter::new_alloc();
+
+    let mut resource = Resource::new_gd();
+    let mut shared = SharedResource::new(resource.clone());
+    assert!(shared.subscribe(on_changed_callable(&first)));
+    assert!(shared.subscribe(on_changed_callable(&second)));
+    assert!(
+        !shared.subscribe(on_changed_callable(&first)),
+        "already subscribed"
+    );
+
+    // Modifying the resource through any reference notifies all subscribers.
+    resource.set_name("renamed".into());
+    assert_eq!(first.bind().count, 1);
+    assert_eq!(second.bind().count, 1);
+
+    shared.resource().clone().emit_changed();
+    assert_eq!(first.bind().count, 2);
+    assert_eq!(second.bind().count, 2);
+
+    assert!(shared.unsubscribe(&on_changed_callable(&first)));
+    assert!(
+        !shared.unsubscribe(&on_changed_callable(&first)),
+        "already unsubscribed"
+    );
+
+    resource.emit_changed();
+    assert_eq!(first.bind().count, 2);
+    assert_eq!(second.bind().count, 3);
+
+    first.free();
+    second.free();
+}
@@ -0,0 +1,85 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::{Basis, PackedFloat32Array, RealConv, Transform3D, Vector3};
+use godot::classes::Skeleton3D;
+use godot::obj::{Gd, NewAlloc};
+
+use crate::framework::{expect_panic, itest};
+
+fn make_skeleton(bone_count: usize) -> Gd<Skeleton3D> {
+    let mut skeleton = Skeleton3D::new_alloc();
+    for i in 0..bone_count {
+        skeleton.add_bone(format!("bone_{i}").into());
+    }
+    skeleton
+}
+
+fn pose_buffer(poses: &[Transform3D]) -> PackedFloat32Array {
+    poses
+        .iter()
+        .flat_map(|pose| {
+            let [x, y, z] = pose.basis.rows;
+            let o = pose.origin;
+            [x.x, x.y, x.z, o.x, y.x, y.y, y.z, o.y, z.x, z.y, z.z, o.z]
+        })
+        .map(RealConv::as_f32)
+        .collect()
+}
+
+#[itest]
+fn skeleton_set_bone_poses() {
+    let mut skeleton = make_skeleton(3);
+
+    let poses = [
+        Transform3D::IDENTITY,
+        Transform3D::new(
+            Basis::from_axis_angle(Vector3::UP, 0.5),
+            Vector3::new(1.0, 2.0, 3.0),
+        ),
+        Transform3D::new(
+            Basis::from_axis_angle(Vector3::RIGHT, -1.0)
+                * Basis::from_scale(Vector3::new(2.0, 3.0, 4.0)),
+            Vector3::new(-5.0, 0.5, 8.0),
+        ),
+    ];
+
+    skeleton.set_bone_poses(&pose_buffer(&poses));
+
+    for (bone, pose) in poses.iter().enumerate() {
+        assert_eq_approx!(skeleton.get_bone_pose(bone as i32), *pose);
+    }
+
+    // Reading back yields the same buffer, up to rounding.
+    let read_back = skeleton.bone_poses();
+    let expected = pose_buffer(&poses);
+    assert_eq!(read_back.len(), 3 * Skeleton3D::BONE_POSE_FLOATS);
+    for (actual, expected) in read_back.as_slice().iter().zip(expected.as_slice()) {
+        assert!((actual - expected).abs() < 1e-5, "{actual} != {expected}");
+    }
+
+    skeleton.free();
+}
+
+#[itest]
+fn skeleton_set_bone_poses_invalid_size() {
+    let mut skeleton = make_skeleton(2);
+
+    let too_short = pose_buffer(&[Transform3D::IDENTITY]);
+    expect_panic("buffer for fewer bones", || {
+        skeleton.set_bone_poses(&too_short);
+    });
+
+    let mut misaligned = pose_buffer(&[Transform3D::IDENTITY; 2]);
+    misaligned.push(0.0);
+    expect_panic("buffer with extra floats", || {
+        skeleton.set_bone_poses(&misaligned);
+    });
+
+    skeleton.free();
+}
@@ -0,0 +1,168 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::{real, Vector2, Vector3};
+use godot::classes::{Node2D, Node3D, Sprite2D};
+use godot::obj::NewAlloc;
+use godot::tools::SmoothFollow;
+
+use crate::framework::{expect_panic, itest, TestContext};
+
+#[itest]
+fn smooth_follow_converges_to_moving_target(ctx: &TestContext) {
+    let mut tree = ctx.scene_tree.clone();
+    let mut target = Node2D::new_alloc();
+    let mut follower = Sprite2D::new_alloc();
+    tree.add_child(&target);
+    tree.add_child(&follower);
+
+    let follow = SmoothFollow::<Node2D>::new(&target, 8.0);
+    let delta = 1.0 / 60.0;
+
+    // Target moves right for one second; the follower trails behind, but keeps approaching.
+    for frame in 1..=60 {
+        target.set_global_position(Vector2::new(frame as real * 5.0, 0.0));
+        let before = follower.get_global_position();
+
+        assert!(follow.update(&mut follower, delta));
+
+        let after = follower.get_global_position();
+        assert!(after.x > before.x, "follower moves towards target");
+        assert!(after.x < target.get_global_position().x, "no overshoot");
+    }
+
+    // Target stops; the remaining distance shrinks each frame until the follower arrives.
+    let mut distance = follower
+        .get_global_position()
+        .distance_to(target.get_global_position());
+    for _ in 0..120 {
+        follow.update(&mut follower, delta);
+
+        let new_distance = follower
+            .get_global_position()
+            .distance_to(target.get_global_position());
+        assert!(new_distance <= distance);
+        distance = new_distance;
+    }
+    assert!(distance < 0.01, "converged, remaining distance {distance}");
+
+    target.free();
+    follower.free();
+}
+
+#[itest]
+fn smooth_follow_framerate_independent(ctx: &TestContext) {
+    let mut tree = ctx.scene_tree.clone();
+    let mut target = Node2D::new_alloc();
+    let mut slow = Node2D::new_alloc();
+    let mut fast = Node2D::new_alloc();
+    tree.add_child(&target);
+    tree.add_child(&slow);
+    tree.add_child(&fast);
+    target.set_global_position(Vector2::new(100.0, -50.0));
+
+    let follow = SmoothFollow::<Node2D>::new(&target, 3.0);
+
+    // Half a second at 10 FPS and at 120 FPS.
+    for _ in 0..5 {
+        follow.update(&mut slow, 0.1);
+    }
+    for _ in 0..60 {
+        follow.update(&mut fast, 1.0 / 120.0);
+    }
+
+    // Remaining distance shrinks by exp(-3 * 0.5) in both cases.
+    let expected = target.get_global_position() * (1.0 - real::exp(-1.5));
+    assert!(slow.get_global_position().distance_to(expected) < 0.01);
+    assert!(fast.get_global_position().distance_to(expected) < 0.01);
+
+    target.free();
+    slow.free();
+    fast.free();
+}
+
+#[itest]
+fn smooth_follow_3d_offset(ctx: &TestContext) {
+    let mut tree = ctx.scene_tree.clone();
+    let mut target = Node3D::new_alloc();
+    let mut follower = Node3D::new_alloc();
+    tree.add_child(&target);
+    tree.add_child(&follower);
+    target.set_global_position(Vector3::new(1.0, 2.0, 3.0));
+
+    let follow = SmoothFollow::<Node3D>::new(&target, f64::INFINITY)
+        .with_offset(Vector3::new(0.0, 5.0, 10.0));
+
+    // Infinite smoothing snaps to the offset position.
+    assert!(follow.update(&mut follower, 0.016));
+    assert_eq_approx!(follower.get_global_position(), Vector3::new(1.0, 7.0, 13.0));
+
+    // Zero and negative deltas do not move the follower.
+    target.set_global_position(Vector3::ZERO);
+    follow.update(&mut follower, 0.0);
+    follow.update(&mut follower, -1.0);
+    assert_eq_approx!(follower.get_global_position(), Vector3::new(1.0, 7.0, 13.0));
+
+    target.free();
+    follower.free();
+}
+
+#[itest]
+fn smooth_follow_stops_when_target_freed(ctx: &TestContext) {
+    let mut tree = ctx.scene_tree.clone();
+    let mut target = Node2D::new_alloc();
+    let mut follower = Node2D::new_alloc();
+    tree.add_child(&target);
+    tree.add_child(&follower);
+    target.set_global_position(Vector2::new(10.0, 10.0));
+
+    let follow = SmoothFollow::<Node2D>::new(&target, 5.0);
+    assert!(follow.is_following());
+    assert_eq!(follow.target(), Some(target.clone()));
+
+    assert!(follow.update(&mut follower, 0.1));
+    let position = follower.get_global_position();
+
+    target.free();
+    assert!(!follow.is_following());
+    assert_eq!(follow.target(), None);
+
+    assert!(!follow.update(&mut follower, 0.1));
+    assert_eq!(follower.get_global_position(), position);
+
+    follower.free();
+}
+
+#[itest]
+fn smooth_follow_outside_tree() {
+    let mut target = Node3D::new_alloc();
+    let mut follower = Node3D::new_alloc();
+    target.set_position(Vector3::new(4.0, 0.0, 0.0));
+
+    let follow = SmoothFollow::<Node3D>::new(&target, 5.0);
+
+    assert!(follow.update(&mut follower, 0.1), "target still alive");
+    assert_eq!(follower.get_position(), Vector3::ZERO);
+
+    target.free();
+    follower.free();
+}
+
+#[itest]
+fn smooth_follow_invalid_smoothing() {
+    let target = Node2D::new_alloc();
+
+    expect_panic("negative smoothing", || {
+        SmoothFollow::<Node2D>::new(&target, -1.0);
+    });
+    expect_panic("NaN smoothing", || {
+        SmoothFollow::<Node2D>::new(&target, f64::NAN);
+    });
+
+    target.free();
+}
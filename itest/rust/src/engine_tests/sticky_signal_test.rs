@@ -0,0 +1,97 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Callable, Variant};
+use godot::classes::Object;
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::StickySignal;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct StickyReceiver {
+    received: Vec<i64>,
+}
+
+#[godot_api]
+impl StickyReceiver {
+    #[func]
+    fn receive(&mut self, value: i64) {
+        self.received.push(value);
+    }
+}
+
+fn receiver_callable(receiver: &Gd<StickyReceiver>) -> Callable {
+    Callable::from_object_method(receiver, "receive")
+}
+
+#[itest]
+fn sticky_signal_replays_last_emission() {
+    let emitter = Object::new_alloc();
+    let mut sticky = StickySignal::new(&emitter, "health_changed");
+    assert!(emitter.has_signal("health_changed".into()));
+
+    sticky.emit(&[10.to_variant()]);
+    sticky.emit(&[7.to_variant()]);
+    assert_eq!(sticky.last_args(), Some([7.to_variant()].as_slice()));
+
+    // Connected after the emissions: called immediately with the last one, then like a regular handler.
+    let late = StickyReceiver::new_alloc();
+    sticky.connect(receiver_callable(&late)).unwrap();
+    assert_eq!(late.bind().received, vec![7]);
+
+    sticky.emit(&[3.to_variant()]);
+    assert_eq!(late.bind().received, vec![7, 3]);
+
+    // Connecting twice is rejected, without replaying again.
+    let err = sticky.connect(receiver_callable(&late)).unwrap_err();
+    assert!(err.godot_error().is_some());
+    assert_eq!(late.bind().received, vec![7, 3]);
+
+    assert!(sticky.disconnect(&receiver_callable(&late)));
+    assert!(!sticky.disconnect(&receiver_callable(&late)));
+    sticky.emit(&[1.to_variant()]);
+    assert_eq!(late.bind().received, vec![7, 3]);
+
+    late.free();
+    emitter.free();
+}
+
+#[itest]
+fn sticky_signal_without_emission() {
+    let emitter = Object::new_alloc();
+    let mut sticky = StickySignal::new(&emitter, "level_loaded");
+    assert!(sticky.last_args().is_none());
+
+    let receiver = StickyReceiver::new_alloc();
+    sticky.connect(receiver_callable(&receiver)).unwrap();
+    assert!(receiver.bind().received.is_empty(), "nothing to replay");
+
+    sticky.emit(&[5.to_variant()]);
+    assert_eq!(receiver.bind().received, vec![5]);
+
+    // After clear(), new subscribers are not called until the next emission.
+    sticky.clear();
+    let other = StickyReceiver::new_alloc();
+    sticky.connect(receiver_callable(&other)).unwrap();
+    assert!(other.bind().received.is_empty());
+
+    emitter.free();
+    let err = sticky.connect(receiver_callable(&other)).unwrap_err();
+    assert!(err.is_object_freed());
+    assert!(!err.is_signal_not_found());
+
+    // Emitting on a freed object only records the arguments.
+    sticky.emit(&[Variant::nil()]);
+    assert_eq!(sticky.last_args(), Some([Variant::nil()].as_slice()));
+
+    receiver.free();
+    other.free();
+}
@@ -0,0 +1,45 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Rect2i, Vector2, Vector2i};
+use godot::classes::image::Format;
+use godot::classes::{AtlasTexture, Image, ImageTexture, PlaceholderTexture2D};
+use godot::obj::NewGd;
+
+use crate::framework::itest;
+
+#[itest]
+fn texture_size() {
+    let image = Image::create(16, 8, false, Format::RGBA8).expect("image created");
+    let texture = ImageTexture::create_from_image(&image).expect("texture created");
+
+    assert_eq!(texture.size(), Vector2i::new(16, 8));
+}
+
+#[itest]
+fn texture_placeholder_has_no_image() {
+    let mut texture = PlaceholderTexture2D::new_gd();
+    texture.set_size(Vector2::new(4.0, 2.0));
+
+    assert_eq!(texture.size(), Vector2i::new(4, 2));
+    assert!(texture.to_image().is_none());
+}
+
+#[itest]
+fn texture_atlas_pixel_region() {
+    let image = Image::create(64, 64, false, Format::RGBA8).expect("image created");
+    let atlas = ImageTexture::create_from_image(&image).expect("texture created");
+
+    let mut texture = AtlasTexture::new_gd();
+    texture.set_atlas(&atlas);
+
+    let region = Rect2i::from_components(16, 32, 8, 4);
+    texture.set_pixel_region(region);
+
+    assert_eq!(texture.pixel_region(), region);
+    assert_eq!(texture.size(), Vector2i::new(8, 4));
+}
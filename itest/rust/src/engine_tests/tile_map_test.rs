@@ -0,0 +1,71 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{Vector2, Vector2i};
+use godot::classes::{TileMap, TileSet};
+use godot::obj::{NewAlloc, NewGd};
+
+use crate::framework::{itest, TestContext};
+
+// Default tile size of a TileSet is 16x16; map_to_local() returns the cell's center.
+
+#[itest]
+fn tile_map_coordinate_roundtrip(ctx: &TestContext) {
+    let mut tile_map = TileMap::new_alloc();
+    tile_map.set_tileset(TileSet::new_gd());
+    tile_map.set_position(Vector2::new(100.0, -50.0));
+
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&tile_map);
+
+    let coords = Vector2i::new(2, -3);
+    assert_eq!(tile_map.map_to_local(coords), Vector2::new(40.0, -40.0));
+    assert_eq!(tile_map.map_to_global(coords), Vector2::new(140.0, -90.0));
+
+    assert_eq!(tile_map.global_to_map(Vector2::new(140.0, -90.0)), coords);
+    assert_eq!(tile_map.global_to_map(Vector2::new(133.0, -95.0)), coords);
+    assert_eq!(
+        tile_map.global_to_map(tile_map.map_to_global(coords)),
+        coords
+    );
+
+    assert_eq!(tile_map.cell_source_id(0, coords), None);
+
+    parent.remove_child(&tile_map);
+    tile_map.free();
+}
+
+#[cfg(since_api = "4.3")]
+#[itest]
+fn tile_map_layer_coordinate_roundtrip(ctx: &TestContext) {
+    use godot::classes::TileMapLayer;
+
+    let mut layer = TileMapLayer::new_alloc();
+    layer.set_tile_set(TileSet::new_gd());
+    layer.set_position(Vector2::new(100.0, -50.0));
+
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&layer);
+
+    for coords in [
+        Vector2i::new(0, 0),
+        Vector2i::new(2, -3),
+        Vector2i::new(-7, 11),
+    ] {
+        let local = layer.map_to_local(coords);
+        assert_eq!(layer.local_to_map(local), coords);
+        assert_eq!(layer.global_to_map(layer.map_to_global(coords)), coords);
+        assert_eq!(
+            layer.map_to_global(coords),
+            local + Vector2::new(100.0, -50.0)
+        );
+        assert_eq!(layer.cell_source_id(coords), None);
+    }
+
+    parent.remove_child(&layer);
+    layer.free();
+}
@@ -0,0 +1,164 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::Node;
+use godot::obj::{Gd, NewAlloc};
+use godot::tools::{tree_transaction, TreeTransaction};
+
+use crate::framework::{itest, TestContext};
+
+fn named(name: &str) -> Gd<Node> {
+    let mut node = Node::new_alloc();
+    node.set_name(name.into());
+    node
+}
+
+fn child_names(node: &Gd<Node>) -> Vec<String> {
+    node.get_children()
+        .iter_shared()
+        .map(|child| child.get_name().to_string())
+        .collect()
+}
+
+#[itest]
+fn tree_transaction_restructures(ctx: &TestContext) {
+    let mut level = named("Level");
+    let a = named("A");
+    let b = named("B");
+    let c = named("C");
+    let obsolete = named("Obsolete");
+    for node in [&a, &b, &c, &obsolete] {
+        level.add_child(node);
+    }
+    ctx.scene_tree.clone().add_child(&level);
+
+    let group = named("Group");
+    let nested = named("Nested");
+
+    tree_transaction(|tx| {
+        tx.add_child(&level, &group);
+        tx.reparent(&c, &group);
+        tx.reparent(&a, &group);
+        tx.add_child(&group, &nested);
+
+        // Removed nodes can be added elsewhere in the same transaction.
+        tx.remove_child(&level, &b);
+        tx.add_child(&nested, &b);
+
+        tx.queue_free(&obsolete);
+    })
+    .expect("valid restructuring");
+
+    assert_eq!(child_names(&level), ["Group"]);
+    assert_eq!(child_names(&group), ["C", "A", "Nested"]);
+    assert_eq!(child_names(&nested), ["B"]);
+    assert!(b.is_inside_tree());
+
+    assert_eq!(obsolete.get_parent(), None);
+    assert!(obsolete.is_queued_for_deletion());
+
+    level.free();
+}
+
+#[itest]
+fn tree_transaction_rejects_whole_batch() {
+    let mut parent = named("Parent");
+    let other = named("Other");
+    let child = named("Child");
+    let orphan = named("Orphan");
+    parent.add_child(&child);
+
+    let err = tree_transaction(|tx| {
+        tx.add_child(&parent, &orphan);
+        tx.add_child(&other, &child);
+    })
+    .expect_err("child already has a parent");
+
+    assert!(err.is_already_parented());
+    assert_eq!(err.index(), 1);
+    assert_eq!(err.operation(), "add_child");
+    assert_eq!(err.applied(), 0);
+
+    // First operation was not applied either.
+    assert_eq!(orphan.get_parent(), None);
+    assert_eq!(child_names(&parent), ["Child"]);
+    assert!(child_names(&other).is_empty());
+
+    parent.free();
+    other.free();
+    orphan.free();
+}
+
+#[itest]
+fn tree_transaction_validates_against_recorded_state() {
+    let mut root = named("Root");
+    let mut inner = named("Inner");
+    let leaf = named("Leaf");
+    inner.add_child(&leaf);
+    root.add_child(&inner);
+
+    // Cycle through an existing relation.
+    let err = tree_transaction(|tx| tx.reparent(&root, &leaf)).expect_err("cycle");
+    assert!(err.is_cycle());
+    assert_eq!(err.node(), root.to_string());
+
+    // Cycle through a relation created earlier in the transaction.
+    let detached = named("Detached");
+    let err = tree_transaction(|tx| {
+        tx.add_child(&leaf, &detached);
+        tx.reparent(&root, &detached);
+    })
+    .expect_err("cycle");
+    assert!(err.is_cycle());
+    assert_eq!(err.index(), 1);
+
+    // Leaf is no longer a child of Inner after the reparent.
+    let err = tree_transaction(|tx| {
+        tx.reparent(&leaf, &root);
+        tx.remove_child(&inner, &leaf);
+    })
+    .expect_err("not a child");
+    assert!(err.is_not_child());
+    assert_eq!(err.operation(), "remove_child");
+
+    // Queued nodes cannot be used afterwards.
+    let err = tree_transaction(|tx| {
+        tx.queue_free(&detached);
+        tx.add_child(&root, &detached);
+    })
+    .expect_err("freed");
+    assert!(err.is_node_freed());
+
+    assert_eq!(child_names(&root), ["Inner"]);
+    assert_eq!(child_names(&inner), ["Leaf"]);
+    assert!(!detached.is_queued_for_deletion());
+
+    detached.free();
+    root.free();
+}
+
+#[itest]
+fn tree_transaction_freed_node() {
+    let parent = named("Parent");
+    let child = named("Child");
+
+    let mut tx = TreeTransaction::new();
+    tx.add_child(&parent, &child);
+    assert_eq!(tx.len(), 1);
+
+    // Freed between recording and committing.
+    child.free();
+
+    let err = tx.commit().expect_err("child freed");
+    assert!(err.is_node_freed());
+    assert_eq!(err.node(), "<Freed Object>");
+
+    assert!(TreeTransaction::new().is_empty());
+    assert!(TreeTransaction::new().commit().is_ok());
+
+    parent.free();
+}
@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::task::Poll;
+
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::Vector2;
+use godot::classes::tween::{EaseType, TransitionType};
+use godot::classes::Node2D;
+use godot::obj::NewAlloc;
+use godot::tools::TweenEnd;
+
+use crate::framework::{expect_panic, itest, poll_once, TestContext};
+
+// Tests cannot wait for real frames, so tweens are advanced with `custom_step()`, and `process_frame` is emitted manually.
+
+#[itest]
+fn tween_to_reaches_target(ctx: &TestContext) {
+    let mut node = Node2D::new_alloc();
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&node);
+
+    let target = Vector2::new(100.0, -50.0);
+    let mut future = node.tween_to(target, 0.5);
+    assert_eq!(poll_once(&mut future), Poll::Pending);
+
+    let mut tween = future.tween().clone();
+    tween.custom_step(0.25);
+    assert_eq!(poll_once(&mut future), Poll::Pending);
+    assert_eq_approx!(node.get_global_position(), target / 2.0);
+
+    tween.custom_step(0.25);
+    assert_eq!(poll_once(&mut future), Poll::Ready(TweenEnd::Finished));
+    assert_eq_approx!(node.get_global_position(), target);
+
+    parent.remove_child(&node);
+    node.free();
+}
+
+#[itest]
+fn tween_to_eased_reaches_target(ctx: &TestContext) {
+    let mut node = Node2D::new_alloc();
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&node);
+
+    let target = Vector2::new(-20.0, 30.0);
+    let mut future = node.tween_to_eased(target, 1.0, TransitionType::QUAD, EaseType::IN);
+
+    let mut tween = future.tween().clone();
+    tween.custom_step(0.5);
+
+    // Quadratic ease-in covers a quarter of the distance in half the time.
+    assert_eq_approx!(node.get_global_position(), target / 4.0);
+
+    tween.custom_step(0.5);
+    assert_eq!(poll_once(&mut future), Poll::Ready(TweenEnd::Finished));
+    assert_eq_approx!(node.get_global_position(), target);
+
+    parent.remove_child(&node);
+    node.free();
+}
+
+#[itest]
+fn tween_to_node_freed(ctx: &TestContext) {
+    let mut node = Node2D::new_alloc();
+    let mut parent = ctx.scene_tree.clone();
+    parent.add_child(&node);
+
+    let mut future = node.tween_to(Vector2::new(10.0, 10.0), 1.0);
+    assert_eq!(poll_once(&mut future), Poll::Pending);
+
+    parent.remove_child(&node);
+    node.free();
+
+    let mut tree = ctx.scene_tree.get_tree().unwrap();
+    tree.emit_signal("process_frame".into(), &[]);
+    assert_eq!(poll_once(&mut future), Poll::Ready(TweenEnd::Cancelled));
+    assert!(!future.tween().is_valid());
+}
+
+#[itest]
+fn tween_to_outside_tree_panics() {
+    let mut node = Node2D::new_alloc();
+
+    expect_panic("node outside tree", || {
+        let _future = node.tween_to(Vector2::new(1.0, 1.0), 1.0);
+    });
+
+    node.free();
+}
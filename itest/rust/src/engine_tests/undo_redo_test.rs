@@ -0,0 +1,125 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// UndoRedo stands in for the editor's EditorUndoRedoManager, which is only available in the editor.
+
+use godot::builtin::{GString, Vector2};
+use godot::classes::{Node2D, UndoRedo};
+use godot::meta::ToGodot;
+use godot::obj::NewAlloc;
+
+use crate::framework::{expect_panic, itest};
+
+#[itest]
+fn undo_redo_with_undo_property() {
+    let mut undo_redo = UndoRedo::new_alloc();
+    let mut node = Node2D::new_alloc();
+    node.set_position(Vector2::new(1.0, 2.0));
+
+    undo_redo.with_undo("Move node", |action| {
+        action.set_property(&node, "position", Vector2::new(5.0, 6.0));
+    });
+
+    assert_eq!(node.get_position(), Vector2::new(5.0, 6.0));
+    assert_eq!(undo_redo.get_history_count(), 1);
+    assert_eq!(
+        undo_redo.get_current_action_name(),
+        GString::from("Move node")
+    );
+
+    assert!(undo_redo.undo());
+    assert_eq!(node.get_position(), Vector2::new(1.0, 2.0));
+
+    assert!(undo_redo.redo());
+    assert_eq!(node.get_position(), Vector2::new(5.0, 6.0));
+
+    undo_redo.free();
+    node.free();
+}
+
+#[itest]
+fn undo_redo_with_undo_method() {
+    let mut undo_redo = UndoRedo::new_alloc();
+    let mut node = Node2D::new_alloc();
+    node.set_name("Before".into());
+
+    undo_redo.with_undo("Rename node", |action| {
+        action.add_do_method(&node, "set_name", &["After".to_variant()]);
+        action.add_undo_method(&node, "set_name", &["Before".to_variant()]);
+    });
+    assert_eq!(node.get_name(), "After".into());
+
+    assert!(undo_redo.undo());
+    assert_eq!(node.get_name(), "Before".into());
+
+    undo_redo.free();
+    node.free();
+}
+
+#[itest]
+fn undo_redo_with_undo_empty() {
+    let mut undo_redo = UndoRedo::new_alloc();
+
+    undo_redo.with_undo("Nothing", |action| {
+        assert!(action.is_empty());
+    });
+    assert!(!undo_redo.has_undo());
+
+    undo_redo.free();
+}
+
+#[itest]
+fn undo_redo_try_with_undo_error() {
+    let mut undo_redo = UndoRedo::new_alloc();
+    let mut node = Node2D::new_alloc();
+    node.set_position(Vector2::new(1.0, 2.0));
+
+    let result = undo_redo.try_with_undo("Failing move", |action| {
+        action.set_property(&node, "position", Vector2::new(5.0, 6.0));
+        assert_eq!(action.len(), 2);
+
+        Err("validation failed")
+    });
+
+    assert_eq!(result, Err::<(), _>("validation failed"));
+    assert_eq!(node.get_position(), Vector2::new(1.0, 2.0));
+    assert!(!undo_redo.has_undo());
+    assert!(!undo_redo.is_committing_action());
+
+    let result = undo_redo.try_with_undo("Move", |action| {
+        action.set_property(&node, "position", Vector2::new(5.0, 6.0));
+        Ok::<_, ()>(42)
+    });
+
+    assert_eq!(result, Ok(42));
+    assert_eq!(node.get_position(), Vector2::new(5.0, 6.0));
+    assert_eq!(undo_redo.get_history_count(), 1);
+
+    undo_redo.free();
+    node.free();
+}
+
+#[itest]
+fn undo_redo_with_undo_panic() {
+    let mut undo_redo = UndoRedo::new_alloc();
+    let mut node = Node2D::new_alloc();
+    node.set_position(Vector2::new(1.0, 2.0));
+
+    expect_panic("panic while recording undo action", || {
+        undo_redo.with_undo("Panicking move", |action| {
+            action.set_property(&node, "position", Vector2::new(5.0, 6.0));
+            panic!("recording failed");
+        });
+    });
+
+    assert_eq!(node.get_position(), Vector2::new(1.0, 2.0));
+    assert!(!undo_redo.has_undo());
+    assert!(!undo_redo.is_committing_action());
+
+    undo_redo.free();
+    node.free();
+}
@@ -0,0 +1,116 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use godot::classes::{Node, Node2D};
+use godot::obj::{Gd, Inherits, NewAlloc};
+use godot::tools::UpdateScheduler;
+
+use crate::framework::{itest, TestContext};
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+fn register_logging<T>(scheduler: &mut UpdateScheduler, node: &Gd<T>, priority: i32, log: &Log)
+where
+    T: Inherits<Node>,
+{
+    let log = log.clone();
+    scheduler.register(node, priority, move |node, _delta| {
+        let name = node.upcast::<Node>().get_name();
+        log.borrow_mut().push(name.to_string());
+    });
+}
+
+fn named_child<T>(ctx: &TestContext, name: &str) -> Gd<T>
+where
+    T: Inherits<Node> + NewAlloc,
+{
+    let mut node = T::new_alloc();
+    node.clone().upcast::<Node>().set_name(name.into());
+    ctx.scene_tree.clone().add_child(&node);
+    node
+}
+
+#[itest]
+fn update_scheduler_priority_order(ctx: &TestContext) {
+    let log = Log::default();
+    let physics: Gd<Node> = named_child(ctx, "Physics");
+    let input: Gd<Node> = named_child(ctx, "Input");
+    let render: Gd<Node2D> = named_child(ctx, "Render");
+
+    // Registration order differs from priority order; tree order differs from both.
+    let mut scheduler = UpdateScheduler::new();
+    register_logging(&mut scheduler, &physics, 0, &log);
+    register_logging(&mut scheduler, &render, 10, &log);
+    register_logging(&mut scheduler, &input, -5, &log);
+    assert_eq!(scheduler.len(), 3);
+
+    scheduler.run(0.016);
+    assert_eq!(*log.borrow(), ["Input", "Physics", "Render"]);
+
+    log.borrow_mut().clear();
+    scheduler.run(0.016);
+    assert_eq!(*log.borrow(), ["Input", "Physics", "Render"]);
+
+    physics.free();
+    input.free();
+    render.free();
+}
+
+#[itest]
+fn update_scheduler_equal_priority(ctx: &TestContext) {
+    let log = Log::default();
+    let first: Gd<Node> = named_child(ctx, "First");
+    let second: Gd<Node> = named_child(ctx, "Second");
+
+    let mut scheduler = UpdateScheduler::new();
+    register_logging(&mut scheduler, &second, 1, &log);
+    register_logging(&mut scheduler, &first, 1, &log);
+
+    scheduler.run(0.0);
+    assert_eq!(*log.borrow(), ["Second", "First"]);
+
+    first.free();
+    second.free();
+}
+
+#[itest]
+fn update_scheduler_nodes_come_and_go(ctx: &TestContext) {
+    let log = Log::default();
+    let stays: Gd<Node> = named_child(ctx, "Stays");
+    let leaves: Gd<Node> = named_child(ctx, "Leaves");
+    let freed: Gd<Node> = named_child(ctx, "Freed");
+
+    let mut scheduler = UpdateScheduler::new();
+    register_logging(&mut scheduler, &stays, 0, &log);
+    register_logging(&mut scheduler, &leaves, 1, &log);
+    register_logging(&mut scheduler, &freed, 2, &log);
+    let id = scheduler.register(&stays, 3, |_node, _delta| panic!("unregistered"));
+
+    assert!(scheduler.unregister(id));
+    assert!(!scheduler.unregister(id), "already removed");
+
+    // Outside the tree: skipped, but kept. Freed: removed.
+    ctx.scene_tree.clone().remove_child(&leaves);
+    freed.free();
+    scheduler.run(0.0);
+    assert_eq!(*log.borrow(), ["Stays"]);
+    assert_eq!(scheduler.len(), 2);
+
+    log.borrow_mut().clear();
+    ctx.scene_tree.clone().add_child(&leaves);
+    scheduler.run(0.0);
+    assert_eq!(*log.borrow(), ["Stays", "Leaves"]);
+
+    assert_eq!(scheduler.unregister_node(&stays), 1);
+    assert_eq!(scheduler.len(), 1);
+
+    stays.free();
+    leaves.free();
+}
@@ -10,9 +10,10 @@
 
 use crate::framework::itest;
 
-use godot::builtin::{GString, Variant};
+use godot::builtin::{varray, GString, Variant, Vector2i};
 use godot::classes::Node3D;
 use godot::global::*;
+use godot::meta::ToGodot;
 use godot::obj::NewAlloc;
 
 #[itest]
@@ -90,3 +91,51 @@ fn utilities_is_instance_valid() {
     node.free();
     assert!(!is_instance_valid(variant));
 }
+
+#[itest]
+fn utilities_godot_dbg_format() {
+    let vector = Vector2i::new(1, 2);
+    let line = godot::private::format_dbg_line("src/lib.rs", 7, 13, "vector", &vector.to_variant());
+
+    // Same formatting as GDScript's `print()`, not Rust's `Debug`.
+    assert_eq!(line, "[src/lib.rs:7:13] vector = (1, 2)");
+
+    let line = godot::private::format_dbg_line("src/lib.rs", 8, 5, "nothing", &Variant::nil());
+    assert_eq!(line, "[src/lib.rs:8:5] nothing = <null>");
+}
+
+#[itest]
+fn utilities_godot_dbg_returns_value() {
+    let value = godot_dbg!(6 * 7);
+    assert_eq!(value, 42);
+
+    let (a, b) = godot_dbg!(GString::from("text"), true);
+    assert_eq!(a, GString::from("text"));
+    assert!(b);
+}
+
+#[itest]
+fn utilities_godot_dbg_returns_non_copy_value() {
+    let array = varray![1, "two", Vector2i::new(3, 4)];
+
+    // The clone shares its storage with `array`, and is returned unchanged.
+    let mut returned = godot_dbg!(array.clone());
+    assert_eq!(returned, array);
+
+    returned.push(5);
+    assert_eq!(array.len(), 4);
+}
+
+#[itest]
+fn utilities_godot_print_variants() {
+    // Output cannot be captured; check that arguments of different types are accepted.
+    godot_print_variants!(
+        "int: ",
+        5,
+        ", vector: ",
+        Vector2i::new(3, 4),
+        ", nil: ",
+        Variant::nil()
+    );
+    godot_print_variants!();
+}
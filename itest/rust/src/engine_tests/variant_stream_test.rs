@@ -0,0 +1,116 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::{BufReader, BufWriter, Cursor, ErrorKind};
+
+use godot::builtin::{dict, Dictionary, GString, Variant, VariantArray, Vector2};
+use godot::classes::file_access::ModeFlags;
+use godot::meta::ToGodot;
+use godot::tools::{GFile, VariantReader, VariantWriter};
+
+use crate::framework::itest;
+
+const TEST_FULL_PATH: &str = "res://variant_stream_tests";
+
+fn remove_test_file() {
+    let test_file_path = std::path::Path::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../godot/",
+        "variant_stream_tests"
+    ));
+    std::fs::remove_file(test_file_path)
+        .unwrap_or_else(|_| panic!("couldn't remove test file: {}", test_file_path.display()));
+}
+
+fn make_entity(i: i64) -> Dictionary {
+    dict! {
+        "id": i,
+        "name": GString::from(format!("entity_{i}")),
+        "position": Vector2::new(i as f32, -i as f32),
+    }
+}
+
+#[itest]
+fn variant_stream_roundtrip_chunked() {
+    const ENTITY_COUNT: i64 = 5000;
+
+    let entities: VariantArray = (0..ENTITY_COUNT)
+        .map(|i| make_entity(i).to_variant())
+        .collect();
+
+    let mut writer = VariantWriter::new(Vec::new()).unwrap();
+    writer.write_elements(&entities).unwrap();
+    assert_eq!(writer.count(), ENTITY_COUNT as usize);
+    let bytes = writer.into_inner().unwrap();
+
+    let mut reader = VariantReader::new(Cursor::new(bytes)).unwrap();
+    let mut read_back = VariantArray::new();
+    let mut chunk_count = 0;
+    loop {
+        let chunk = reader.read_chunk(512).unwrap();
+        if chunk.is_empty() {
+            break;
+        }
+
+        assert!(chunk.len() <= 512);
+        read_back.extend_array(&chunk);
+        chunk_count += 1;
+    }
+
+    assert_eq!(chunk_count, 10); // 9 full chunks, 1 partial.
+    assert_eq!(reader.count(), ENTITY_COUNT as usize);
+    assert_eq!(read_back, entities);
+}
+
+#[itest]
+fn variant_stream_gfile_roundtrip() {
+    let file = GFile::open(TEST_FULL_PATH, ModeFlags::WRITE).unwrap();
+    let mut writer = VariantWriter::new(BufWriter::new(file)).unwrap();
+    for i in 0..1000 {
+        writer.write(&make_entity(i)).unwrap();
+    }
+    writer.write(&Variant::nil()).unwrap();
+    drop(writer.into_inner().unwrap());
+
+    let file = GFile::open(TEST_FULL_PATH, ModeFlags::READ).unwrap();
+    let reader = VariantReader::new(BufReader::new(file)).unwrap();
+    let values: Vec<Variant> = reader.map(Result::unwrap).collect();
+
+    assert_eq!(values.len(), 1001);
+    for (i, value) in values[..1000].iter().enumerate() {
+        assert_eq!(value.to::<Dictionary>(), make_entity(i as i64));
+    }
+    assert!(values[1000].is_nil());
+
+    remove_test_file();
+}
+
+#[itest]
+fn variant_stream_invalid_data() {
+    let err = VariantReader::new(Cursor::new(b"NOPE\x01".to_vec())).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let err = VariantReader::new(Cursor::new(b"GDVS\x63".to_vec())).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let mut writer = VariantWriter::new(Vec::new()).unwrap();
+    writer.write(&GString::from("truncated")).unwrap();
+    let mut bytes = writer.into_inner().unwrap();
+    bytes.truncate(bytes.len() - 2);
+
+    let mut reader = VariantReader::new(Cursor::new(bytes)).unwrap();
+    let err = reader.read().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+    // Empty stream: header only.
+    let bytes = VariantWriter::new(Vec::new())
+        .unwrap()
+        .into_inner()
+        .unwrap();
+    let mut reader = VariantReader::new(Cursor::new(bytes)).unwrap();
+    assert!(reader.read().unwrap().is_none());
+}
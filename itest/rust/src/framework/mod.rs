@@ -125,6 +125,25 @@ pub fn expect_panic(context: &str, code: impl FnOnce()) {
     );
 }
 
+/// Polls a future exactly once, with a waker that does nothing.
+///
+/// Tests are synchronous, so futures are driven manually: trigger the event, then poll again.
+pub fn poll_once<F: std::future::Future + Unpin>(future: &mut F) -> std::task::Poll<F::Output> {
+    use std::sync::Arc;
+    use std::task::{Context, Wake, Waker};
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut context = Context::from_waker(&waker);
+
+    std::pin::Pin::new(future).poll(&mut context)
+}
+
 /// Disable printing errors from Godot. Ideally we should catch and handle errors, ensuring they happen when
 /// expected. But that isn't possible, so for now we can just disable printing the error to avoid spamming
 /// the terminal when tests should error.
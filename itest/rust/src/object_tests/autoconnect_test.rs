@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::StringName;
+use godot::classes::notify::NodeNotification;
+use godot::classes::Node;
+use godot::meta::ToGodot;
+use godot::obj::{Base, Gd, NewAlloc};
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Node, autoconnect)]
+struct AutoconnectNode {
+    pressed_count: i32,
+    toggled_with: Option<bool>,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AutoconnectNode {
+    #[func]
+    #[connect(from = "Button", signal = "pressed")]
+    #[connect(from = "Nested/OtherButton", signal = "pressed")]
+    fn on_button_pressed(&mut self) {
+        self.pressed_count += 1;
+    }
+
+    #[func(rename = toggled_renamed)]
+    #[connect(from = "Button", signal = "toggled")]
+    fn on_button_toggled(&mut self, on: bool) {
+        self.toggled_with = Some(on);
+    }
+}
+
+fn make_button(name: &str) -> Gd<Node> {
+    let mut button = Node::new_alloc();
+    button.set_name(name.into());
+    button.add_user_signal("pressed".into());
+    button.add_user_signal("toggled".into());
+    button
+}
+
+#[itest]
+fn autoconnect_child_signals() {
+    let mut obj = AutoconnectNode::new_alloc();
+
+    let mut button = make_button("Button");
+    obj.add_child(&button);
+
+    let mut nested = Node::new_alloc();
+    nested.set_name("Nested".into());
+    let mut other_button = make_button("OtherButton");
+    nested.add_child(&other_button);
+    obj.add_child(&nested);
+
+    obj.notify(NodeNotification::READY);
+
+    button.emit_signal(StringName::from("pressed"), &[]);
+    assert_eq!(obj.bind().pressed_count, 1);
+
+    other_button.emit_signal(StringName::from("pressed"), &[]);
+    assert_eq!(obj.bind().pressed_count, 2);
+
+    button.emit_signal(StringName::from("toggled"), &[true.to_variant()]);
+    assert_eq!(obj.bind().toggled_with, Some(true));
+
+    obj.free();
+}
@@ -5,8 +5,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 use crate::framework::itest;
-use godot::builtin::{GString, StringName};
-use godot::meta::ClassName;
+use godot::builtin::{dict, GString, StringName};
+use godot::meta::{ClassName, PropertyInfo};
 use godot::obj::bounds::implement_godot_bounds;
 use godot::obj::GodotClass;
 use godot::sys;
@@ -14,9 +14,11 @@ use std::borrow::Cow;
 
 struct A;
 struct U;
+struct R;
 
 implement_godot_bounds!(A);
 implement_godot_bounds!(U);
+implement_godot_bounds!(R);
 
 impl GodotClass for A {
     type Base = godot::classes::Object;
@@ -34,6 +36,15 @@ impl GodotClass for U {
     }
 }
 
+// Only used in `class_name_runtime_before_static`, so its name is first seen at runtime.
+impl GodotClass for R {
+    type Base = godot::classes::Object;
+
+    fn class_name() -> ClassName {
+        ClassName::new_cached::<R>(|| "RuntimeBeforeStatic".to_string())
+    }
+}
+
 #[itest]
 fn class_name_dynamic() {
     let a = A::class_name();
@@ -71,3 +82,18 @@ fn class_name_dynamic_unicode() {
 #[derive(godot::register::GodotClass)]
 #[class(no_init)]
 struct 统一码 {}
+
+#[itest]
+fn class_name_runtime_before_static() {
+    let info = PropertyInfo::from_dictionary(&dict! {
+        "name": "target",
+        "class_name": "RuntimeBeforeStatic",
+    });
+
+    let runtime = info.class_name;
+    let declared = R::class_name();
+
+    assert_eq!(runtime, declared);
+    assert_eq!(sys::hash_value(&runtime), sys::hash_value(&declared));
+    assert_eq!(declared.to_string(), "RuntimeBeforeStatic");
+}
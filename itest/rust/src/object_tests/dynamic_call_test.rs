@@ -10,6 +10,8 @@ use godot::classes::{Node, Node3D, Object};
 use godot::meta::error::CallError;
 use godot::meta::{FromGodot, ToGodot};
 use godot::obj::{InstanceId, NewAlloc};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::CallFallback;
 use std::error::Error;
 
 use crate::framework::{expect_panic, itest, runs_release};
@@ -275,3 +277,158 @@ fn dynamic_call_return_mismatch() {
     // The parser will fail since it knows the signature of take_1_int(). And if we enforce `: Variant` type hints, it will just
     // cause a runtime error, but that's entirely handled in GDScript.
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Calls with CallFallback
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct CallFallbackProxy {
+    forwarded: Vec<(StringName, Vec<Variant>)>,
+}
+
+#[godot_api]
+impl CallFallbackProxy {
+    #[func]
+    fn declared(&self, value: i64) -> i64 {
+        value * 2
+    }
+}
+
+impl CallFallback for CallFallbackProxy {
+    fn call_fallback(&mut self, method: StringName, args: &[Variant]) -> Variant {
+        self.forwarded.push((method, args.to_vec()));
+        "forwarded".to_variant()
+    }
+}
+
+#[itest]
+fn dynamic_call_fallback() {
+    let mut proxy = CallFallbackProxy::new_alloc();
+
+    let result = proxy.call_or_fallback("spawn_enemy", &[7.to_variant(), "orc".to_variant()]);
+    assert_eq!(result.unwrap(), "forwarded".to_variant());
+
+    let forwarded = proxy.bind().forwarded.clone();
+    assert_eq!(
+        forwarded,
+        [(
+            StringName::from("spawn_enemy"),
+            vec![7.to_variant(), "orc".to_variant()]
+        )]
+    );
+
+    proxy.free();
+}
+
+#[itest]
+fn dynamic_call_fallback_precedence() {
+    let mut proxy = CallFallbackProxy::new_alloc();
+
+    // `#[func]` and engine methods are called directly.
+    let doubled = proxy.call_or_fallback("declared", &[21.to_variant()]);
+    assert_eq!(doubled.unwrap(), 42.to_variant());
+
+    let class = proxy.call_or_fallback("get_class", &[]);
+    assert_eq!(class.unwrap(), "CallFallbackProxy".to_variant());
+
+    // Wrong arguments for existing methods are reported, not forwarded.
+    let err = proxy
+        .call_or_fallback("declared", &[])
+        .expect_err("too few arguments");
+    assert_eq!(err.method_name(), "call");
+    assert!(proxy.bind().forwarded.is_empty());
+
+    proxy.free();
+}
+
+#[cfg(feature = "experimental-threads")]
+mod call_on_main {
+    use std::sync::{Arc, Mutex};
+
+    use godot::builtin::{StringName, Variant};
+    use godot::classes::{Node, Object};
+    use godot::meta::ToGodot;
+    use godot::obj::{Gd, NewAlloc};
+    use godot::register::{godot_api, GodotClass};
+
+    use crate::framework::{expect_panic, itest};
+
+    #[itest]
+    fn dynamic_call_on_main_direct() {
+        let node = Node::new_alloc();
+        let mut object = node.clone().upcast::<Object>();
+
+        // On the main thread, the call happens right away.
+        object.call_on_main("set_name", &["Direct".to_variant()]);
+        assert_eq!(node.get_name(), StringName::from("Direct"));
+
+        // Blocking on the main thread would wait for itself.
+        #[cfg(since_api = "4.2")]
+        expect_panic("call_on_main_blocking() on main thread", || {
+            object.call_on_main_blocking::<StringName>("get_name", &[]);
+        });
+
+        node.free();
+    }
+
+    #[itest]
+    fn dynamic_call_on_main_from_thread() {
+        let mut node = Node::new_alloc();
+        node.set_name("Before".into());
+        let id = node.instance_id();
+
+        std::thread::spawn(move || {
+            let mut object = Gd::<Object>::from_instance_id(id);
+            object.call_on_main("set_name", &["After".to_variant()]);
+        })
+        .join()
+        .unwrap();
+
+        // From another thread, the call is deferred to idle time, so it has not happened yet. Freeing the node cancels it.
+        assert_eq!(node.get_name(), StringName::from("Before"));
+        node.free();
+    }
+
+    // Used in `test_call_on_main_from_thread` in `SpecialTests.gd`, which lets frames pass.
+    #[derive(GodotClass)]
+    #[class(init, base=RefCounted)]
+    struct MainThreadCaller {
+        blocking_result: Arc<Mutex<Option<StringName>>>,
+    }
+
+    #[godot_api]
+    impl MainThreadCaller {
+        /// Renames `node` from a spawned thread, without waiting.
+        #[func]
+        fn rename_from_thread(&self, node: Gd<Node>, name: StringName) {
+            let id = node.instance_id();
+            std::thread::spawn(move || {
+                let mut object = Gd::<Object>::from_instance_id(id);
+                object.call_on_main("set_name", &[name.to_variant()]);
+            });
+        }
+
+        /// Reads the name of `node` from a spawned thread, which blocks until the main thread has performed the call.
+        #[cfg(since_api = "4.2")]
+        #[func]
+        fn read_name_from_thread(&self, node: Gd<Node>) {
+            let id = node.instance_id();
+            let result = Arc::clone(&self.blocking_result);
+            std::thread::spawn(move || {
+                let mut object = Gd::<Object>::from_instance_id(id);
+                let name = object.call_on_main_blocking::<StringName>("get_name", &[]);
+                *result.lock().unwrap() = name;
+            });
+        }
+
+        /// Result of `read_name_from_thread()`, or nil while not available.
+        #[func]
+        fn blocking_result(&self) -> Variant {
+            match self.blocking_result.lock().unwrap().as_ref() {
+                Some(name) => name.to_variant(),
+                None => Variant::nil(),
+            }
+        }
+    }
+}
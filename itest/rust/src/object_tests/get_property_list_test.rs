@@ -78,3 +78,41 @@ fn get_property_list_returns() {
 
     obj.free();
 }
+
+#[itest]
+fn object_property_list_typed() {
+    let mut obj = GetPropertyListTest::new_alloc();
+
+    let expected = obj.bind_mut().get_property_list();
+    let actual = obj.property_list();
+
+    for info in expected {
+        let found = actual
+            .iter()
+            .find(|prop| prop.property_name == info.property_name)
+            .unwrap_or_else(|| panic!("missing property {}", info.property_name));
+
+        assert_eq!(found.variant_type, info.variant_type);
+        assert_eq!(found.class_name, info.class_name);
+        assert_eq!(found.hint_info, info.hint_info);
+        assert_eq!(found.usage, info.usage);
+    }
+
+    obj.free();
+}
+
+#[itest]
+fn object_property_list_engine_class() {
+    let node = Node::new_alloc();
+
+    let properties = node.property_list();
+    let name = properties
+        .iter()
+        .find(|prop| prop.property_name == StringName::from("name"))
+        .expect("Node has property `name`");
+
+    assert_eq!(name.variant_type, VariantType::STRING_NAME);
+    assert_eq!(name.class_name.to_string(), "");
+
+    node.free();
+}
@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod autoconnect_test;
 mod base_test;
 mod class_name_test;
 mod class_rename_test;
@@ -20,7 +21,9 @@ mod onready_test;
 mod property_template_test;
 mod property_test;
 mod reentrant_test;
+mod serialize_test;
 mod singleton_test;
+mod typed_method_test;
 mod virtual_methods_test;
 
 // Need to test this in the init level method.
@@ -10,8 +10,8 @@ use std::rc::Rc;
 
 use godot::builtin::{GString, StringName, Variant, Vector3};
 use godot::classes::{
-    file_access, Area2D, Camera3D, Engine, FileAccess, IRefCounted, Node, Node3D, Object,
-    RefCounted,
+    file_access, Area2D, Camera3D, Engine, FileAccess, GDScript, INode, IRefCounted, Label, Node,
+    Node2D, Node3D, Object, RefCounted, Sprite2D,
 };
 #[allow(deprecated)]
 use godot::global::instance_from_id;
@@ -122,6 +122,59 @@ fn object_user_display() {
     assert_eq!(actual, expected);
 }
 
+#[itest]
+fn object_user_to_string_in_godot() {
+    let obj = Gd::from_object(RefcPayload { value: 774 });
+
+    // Godot's own string conversion (also used by `str()` and `print()`) goes through the override.
+    let variant = obj.to_variant();
+    assert_eq!(variant.stringify(), GString::from("value=774"));
+}
+
+#[itest]
+fn object_user_data() {
+    struct Health(i32);
+
+    let mut node = Node3D::new_alloc();
+    assert!(node.get_user_data::<Health>().is_none());
+
+    node.set_user_data(Health(100));
+    node.set_user_data(String::from("player"));
+    assert_eq!(node.get_user_data::<Health>().map(|h| h.0), Some(100));
+    let name = node.get_user_data::<String>().expect("name attached");
+    assert_eq!(*name, "player");
+
+    // Values are looked up by type, also through other pointers to the same object.
+    node.set_user_data(Health(75));
+    let same_node = Gd::<Node3D>::from_instance_id(node.instance_id());
+    assert_eq!(same_node.get_user_data::<Health>().map(|h| h.0), Some(75));
+
+    let removed = node.remove_user_data::<Health>();
+    assert_eq!(removed.map(|h| h.0), Some(75));
+    assert!(node.get_user_data::<Health>().is_none());
+
+    node.free();
+}
+
+#[itest]
+fn object_user_data_dropped_on_free() {
+    struct DropTracker(Rc<Cell<bool>>);
+
+    impl Drop for DropTracker {
+        fn drop(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    let dropped = Rc::new(Cell::new(false));
+    let mut node = Node::new_alloc();
+    node.set_user_data(DropTracker(Rc::clone(&dropped)));
+    assert!(!dropped.get());
+
+    node.free();
+    assert!(dropped.get());
+}
+
 #[itest]
 fn object_engine_display() {
     let obj = Node3D::new_alloc();
@@ -146,6 +199,93 @@ fn object_debug() {
     obj.free();
 }
 
+#[itest]
+fn object_debug_display_named_node() {
+    let mut node = Node3D::new_alloc();
+    node.set_name("PlayerSprite".into());
+    let id = node.instance_id();
+
+    assert_eq!(
+        format!("{node:?}"),
+        format!("Gd {{ id: {id}, class: Node3D, name: \"PlayerSprite\" }}")
+    );
+    assert_eq!(format!("{node}"), format!("PlayerSprite:<Node3D#{id}>"));
+
+    // Upcasting doesn't change the output, as the dynamic class is used.
+    let upcast = node.clone().upcast::<Object>();
+    assert_eq!(format!("{upcast:?}"), format!("{node:?}"));
+
+    node.free();
+}
+
+#[itest]
+fn object_debug_display_non_node() {
+    let obj = RefCounted::new_gd();
+    let id = obj.instance_id();
+
+    assert_eq!(
+        format!("{obj:?}"),
+        format!("Gd {{ id: {id}, class: RefCounted }}")
+    );
+    assert_eq!(format!("{obj}"), format!("<RefCounted#{id}>"));
+}
+
+#[itest]
+fn object_debug_display_freed() {
+    let node = Node3D::new_alloc();
+    let freed = node.clone();
+    node.free();
+
+    assert_eq!(format!("{freed:?}"), "Gd { freed obj }");
+    assert_eq!(format!("{freed}"), "<Freed Object>");
+}
+
+#[itest]
+fn object_debug_base_while_bound_mut() {
+    let mut node = DebugFormattedNode::new_alloc();
+    node.set_name("Bound".into());
+    let id = node.instance_id();
+
+    // Formatting must not bind the instance again, e.g. through `get_property()`.
+    let formatted = node.bind_mut().format_base();
+    assert_eq!(
+        formatted,
+        format!("Gd {{ id: {id}, class: DebugFormattedNode, name: \"Bound\" }}")
+    );
+
+    node.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct DebugFormattedNode {
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl INode for DebugFormattedNode {
+    fn get_property(&self, _property: StringName) -> Option<Variant> {
+        None
+    }
+}
+
+impl DebugFormattedNode {
+    fn format_base(&mut self) -> String {
+        format!("{:?}", *self.base())
+    }
+}
+
+#[itest]
+fn object_dynamic_class_name() {
+    let node3d = Node3D::new_alloc();
+    let node: Gd<Node> = node3d.upcast();
+    assert_eq!(node.dynamic_class_name(), StringName::from("Node3D"));
+    node.free();
+
+    let user: Gd<RefCounted> = RefcPayload::new_gd().upcast();
+    assert_eq!(user.dynamic_class_name(), StringName::from("RefcPayload"));
+}
+
 #[itest]
 fn object_instance_id() {
     let value: i16 = 17943;
@@ -171,6 +311,51 @@ fn object_instance_id_when_freed() {
     });
 }
 
+#[itest]
+fn object_eq_instance_id() {
+    let node = Node3D::new_alloc();
+    let other = Node3D::new_alloc();
+    let id = node.instance_id();
+
+    assert!(node == id);
+    assert!(id == node);
+    assert!(node != other.instance_id());
+    assert!(other.instance_id() != node);
+
+    let ids = [other.instance_id(), id];
+    assert_eq!(ids.iter().position(|&i| node == i), Some(1));
+
+    // Dead objects are compared by their stored ID, without panicking.
+    node.clone().free();
+    assert!(node == id);
+    assert!(node != other.instance_id());
+
+    other.free();
+}
+
+#[itest]
+fn object_forget_keeps_alive() {
+    let node = Node3D::new_alloc();
+    let id = node.instance_id();
+
+    node.forget();
+
+    let node = Gd::<Node3D>::try_from_instance_id(id).expect("forget() must not destroy object");
+    assert!(node.is_instance_valid());
+
+    node.free();
+    Gd::<Node3D>::try_from_instance_id(id).expect_err("free() must destroy object");
+}
+
+#[itest]
+fn object_forget_refcounted_dynamic_type() {
+    let obj = RefCounted::new_gd().upcast::<Object>();
+
+    expect_panic("forget() on dynamic ref-counted type", move || {
+        obj.forget();
+    });
+}
+
 #[itest]
 fn object_from_invalid_instance_id() {
     let id = InstanceId::try_from_i64(0xDEADBEEF).unwrap();
@@ -604,6 +789,28 @@ fn object_engine_upcast_reflexive() {
     object.free();
 }
 
+#[itest]
+fn object_is_instance_of() {
+    let sprite: Gd<Node> = Sprite2D::new_alloc().upcast();
+
+    assert!(sprite.is_instance_of::<Sprite2D>());
+    assert!(sprite.is_instance_of::<Node2D>());
+    assert!(sprite.is_instance_of::<Node>());
+    assert!(sprite.is_instance_of::<Object>());
+    assert!(!sprite.is_instance_of::<Label>());
+    assert!(!sprite.is_instance_of::<Node3D>());
+    assert!(!sprite.is_instance_of::<RefCounted>());
+
+    sprite.clone().free();
+    assert!(!sprite.is_instance_of::<Node>(), "freed object");
+
+    // User-defined classes.
+    let user: Gd<RefCounted> = RefcPayload::new_gd().upcast();
+    assert!(user.is_instance_of::<RefcPayload>());
+    assert!(user.is_instance_of::<RefCounted>());
+    assert!(!user.is_instance_of::<ObjPayload>());
+}
+
 #[itest]
 fn object_engine_downcast() {
     let pos = Vector3::new(1.0, 2.0, 3.0);
@@ -872,6 +1079,35 @@ fn object_try_to_unique() {
     assert_eq!(ref_count, 2);
 }
 
+#[itest]
+fn object_script_var() {
+    let code = r#"
+extends Node
+
+var health: int = 42
+var title = "Knight"
+"#;
+
+    let mut script = GDScript::new_gd();
+    script.set_source_code(code.into());
+    script.reload();
+
+    let mut node = Node::new_alloc();
+    node.set_name("Scripted".into());
+    assert_eq!(node.script_var::<i64>("health"), None);
+
+    node.set_script(&script.to_variant());
+    assert_eq!(node.script_var::<i64>("health"), Some(42));
+    assert_eq!(node.script_var::<GString>("title"), Some("Knight".into()));
+
+    // Engine properties, missing variables and mismatched types are not returned.
+    assert_eq!(node.script_var::<StringName>("name"), None);
+    assert_eq!(node.script_var::<i64>("missing"), None);
+    assert_eq!(node.script_var::<GString>("health"), None);
+
+    node.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[derive(GodotClass)]
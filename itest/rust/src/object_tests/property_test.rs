@@ -447,6 +447,53 @@ fn override_export() {
     check_property(&property, "usage", PropertyUsageFlags::GROUP.ord());
 }
 
+#[derive(GodotClass)]
+#[class(init, base=Resource)]
+struct OnChangeResource {
+    #[export]
+    #[var(on_change)]
+    strength: i64,
+
+    #[export]
+    silent: i64,
+
+    base: Base<Resource>,
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn var_on_change_emits_changed() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    use godot::builtin::Callable;
+
+    let mut resource = OnChangeResource::new_gd();
+
+    let emitted = Arc::new(AtomicU32::new(0));
+    let emitted_clone = Arc::clone(&emitted);
+    resource.connect(
+        "changed".into(),
+        Callable::from_fn("on_changed", move |_args| {
+            emitted_clone.fetch_add(1, Ordering::SeqCst);
+            Ok(Variant::nil())
+        }),
+    );
+
+    // Through the property, as the editor and loaders do.
+    resource.set("strength".into(), &5.to_variant());
+    assert_eq!(resource.bind().strength, 5);
+    assert_eq!(emitted.load(Ordering::SeqCst), 1);
+
+    // Through the generated setter in Rust.
+    resource.bind_mut().set_strength(7);
+    assert_eq!(emitted.load(Ordering::SeqCst), 2);
+
+    // Fields without `on_change` don't emit.
+    resource.set("silent".into(), &3.to_variant());
+    assert_eq!(emitted.load(Ordering::SeqCst), 2);
+}
+
 fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
@@ -0,0 +1,240 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{
+    dict, varray, Dictionary, GString, PackedInt32Array, Variant, VariantArray, Vector2,
+};
+use godot::classes::{Node, Object};
+use godot::meta::ToGodot;
+use godot::obj::{Base, Gd, InstanceId, NewAlloc};
+use godot::register::{godot_api, GodotClass, GodotSerialize};
+use godot::tools::{resolve_live_instance, GodotSerialize};
+
+use crate::framework::itest;
+
+#[derive(GodotClass, GodotSerialize)]
+#[class(init, base=Node)]
+struct SaveState {
+    #[var]
+    health: i32,
+    #[var]
+    speed: f64,
+    #[export]
+    name: GString,
+    #[export]
+    position: Vector2,
+    #[var]
+    scores: PackedInt32Array,
+    #[var]
+    target: Option<Gd<Node>>,
+
+    // Not serialized.
+    transient: i32,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl SaveState {}
+
+#[derive(GodotClass, GodotSerialize)]
+#[class(init, base=Node)]
+struct DictionaryState {
+    #[var]
+    data: Dictionary,
+    #[var]
+    target: Option<Gd<Node>>,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl DictionaryState {}
+
+#[derive(GodotClass, GodotSerialize)]
+#[class(init, base=Node)]
+struct NestedState {
+    #[var(rename = hit_points)]
+    health: i32,
+    #[var]
+    targets: VariantArray,
+    #[var]
+    lookup: Dictionary,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl NestedState {}
+
+fn make_state(target: Option<Gd<Node>>) -> Gd<SaveState> {
+    let mut obj = SaveState::new_alloc();
+    {
+        let mut state = obj.bind_mut();
+        state.health = 75;
+        state.speed = 3.5;
+        state.name = GString::from("Godette");
+        state.position = Vector2::new(10.0, -4.0);
+        state.scores = PackedInt32Array::from(&[3, 1, 4]);
+        state.target = target;
+        state.transient = 99;
+    }
+    obj
+}
+
+#[itest]
+fn serialize_roundtrip_bytes() {
+    let target = Node::new_alloc();
+    let original = make_state(Some(target.clone()));
+
+    let fields = original.bind().serialize_fields();
+    assert_eq!(fields.len(), 6);
+    assert!(!fields.contains_key("transient"));
+
+    let bytes = original.bind().serialize_to_bytes();
+
+    let mut restored = SaveState::new_alloc();
+    restored
+        .bind_mut()
+        .deserialize_from_bytes(&bytes, &mut resolve_live_instance)
+        .expect("deserialize");
+
+    {
+        let state = restored.bind();
+        assert_eq!(state.health, 75);
+        assert_eq!(state.speed, 3.5);
+        assert_eq!(state.name, GString::from("Godette"));
+        assert_eq!(state.position, Vector2::new(10.0, -4.0));
+        assert_eq!(state.scores, PackedInt32Array::from(&[3, 1, 4]));
+        assert_eq!(state.target, Some(target.clone()));
+        assert_eq!(state.transient, 0);
+    }
+
+    original.free();
+    restored.free();
+    target.free();
+}
+
+#[itest]
+fn serialize_custom_resolver() {
+    let old_target = Node::new_alloc();
+    let new_target = Node::new_alloc();
+    let original = make_state(Some(old_target.clone()));
+    let fields = original.bind().serialize_fields();
+
+    // Map the old instance to a different, "recreated" object.
+    let old_id = old_target.instance_id();
+    let new_object = new_target.clone().upcast::<Object>();
+    let mut remap = |id: InstanceId| (id == old_id).then(|| new_object.clone());
+
+    let mut restored = SaveState::new_alloc();
+    restored
+        .bind_mut()
+        .deserialize_fields(&fields, &mut remap)
+        .expect("deserialize");
+    assert_eq!(restored.bind().target, Some(new_target.clone()));
+
+    // Unresolved references become None.
+    restored
+        .bind_mut()
+        .deserialize_fields(&fields, &mut |_| None)
+        .expect("deserialize");
+    assert_eq!(restored.bind().target, None);
+
+    original.free();
+    restored.free();
+    old_target.free();
+    new_target.free();
+}
+
+#[itest]
+fn serialize_dictionary_like_reference() {
+    let target = Node::new_alloc();
+
+    // User dictionaries in the shape of the reference encoding must not be resolved as objects.
+    let dictionaries = [
+        dict! { "$instance_id": target.instance_id().to_i64() },
+        dict! { "$dictionary": 7 },
+        dict! { "$dictionary": dict! { "$instance_id": 1 } },
+        dict! { "$instance_id": 1, "other": 2 },
+    ];
+
+    for data in dictionaries {
+        let mut original = DictionaryState::new_alloc();
+        original.bind_mut().data = data.clone();
+        original.bind_mut().target = Some(target.clone());
+
+        let bytes = original.bind().serialize_to_bytes();
+
+        let mut restored = DictionaryState::new_alloc();
+        restored
+            .bind_mut()
+            .deserialize_from_bytes(&bytes, &mut resolve_live_instance)
+            .expect("deserialize");
+
+        assert_eq!(restored.bind().data, data);
+        assert_eq!(restored.bind().target, Some(target.clone()));
+
+        original.free();
+        restored.free();
+    }
+
+    target.free();
+}
+
+#[itest]
+fn serialize_renamed_field() {
+    let mut original = NestedState::new_alloc();
+    original.bind_mut().health = 42;
+
+    // Stored under the name that Godot sees.
+    let fields = original.bind().serialize_fields();
+    assert_eq!(fields.get("hit_points"), Some(42.to_variant()));
+    assert!(!fields.contains_key("health"));
+    assert_eq!(original.get("hit_points".into()), 42.to_variant());
+
+    let mut restored = NestedState::new_alloc();
+    restored
+        .bind_mut()
+        .deserialize_fields(&fields, &mut resolve_live_instance)
+        .expect("deserialize");
+    assert_eq!(restored.bind().health, 42);
+
+    original.free();
+    restored.free();
+}
+
+#[itest]
+fn serialize_nested_objects() {
+    let target = Node::new_alloc();
+
+    let mut original = NestedState::new_alloc();
+    original.bind_mut().targets = varray![target, 5, varray![target]];
+    original.bind_mut().lookup =
+        dict! { "main": target.clone(), "nested": dict! { "inner": target.clone() } };
+
+    let bytes = original.bind().serialize_to_bytes();
+
+    let mut restored = NestedState::new_alloc();
+    restored
+        .bind_mut()
+        .deserialize_from_bytes(&bytes, &mut resolve_live_instance)
+        .expect("deserialize");
+    assert_eq!(restored.bind().targets, original.bind().targets);
+    assert_eq!(restored.bind().lookup, original.bind().lookup);
+
+    // Unresolved references become nil.
+    restored
+        .bind_mut()
+        .deserialize_from_bytes(&bytes, &mut |_| None)
+        .expect("deserialize");
+    assert_eq!(
+        restored.bind().targets,
+        varray![Variant::nil(), 5, varray![Variant::nil()]]
+    );
+
+    original.free();
+    restored.free();
+    target.free();
+}
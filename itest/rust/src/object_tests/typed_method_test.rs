@@ -0,0 +1,133 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{GString, StringName, Variant};
+use godot::classes::{Node, Node3D, RefCounted};
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
+use godot::tools::TypedMethod;
+
+use crate::framework::{expect_panic, itest};
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct TypedMethodTarget {}
+
+#[godot_api]
+impl TypedMethodTarget {
+    #[func]
+    fn add(&self, a: i64, b: i64) -> i64 {
+        a + b
+    }
+
+    #[func]
+    fn describe(&self, value: Variant) -> GString {
+        format!("value={value}").into()
+    }
+}
+
+#[itest]
+fn typed_method_engine_class() {
+    let set_name = TypedMethod::<(StringName,), ()>::new("Node", "set_name").unwrap();
+    let get_name = TypedMethod::<(), StringName>::new("Node", "get_name").unwrap();
+
+    // Also callable on derived classes.
+    let mut node = Node3D::new_alloc();
+    set_name.call(&mut node, (StringName::from("Typed"),));
+    assert_eq!(get_name.call(&mut node, ()), StringName::from("Typed"));
+    assert_eq!(node.get_name(), StringName::from("Typed"));
+
+    node.free();
+}
+
+#[itest]
+fn typed_method_user_class() {
+    let add = TypedMethod::<(i64, i64), i64>::new("TypedMethodTarget", "add").unwrap();
+    let describe =
+        TypedMethod::<(Variant,), GString>::new("TypedMethodTarget", "describe").unwrap();
+
+    let mut object = TypedMethodTarget::new_gd();
+    assert_eq!(add.call(&mut object, (20, 22)), 42);
+    assert_eq!(
+        describe.call(&mut object, (7.to_variant(),)),
+        GString::from("value=7")
+    );
+}
+
+#[itest]
+fn typed_method_variant_signature() {
+    // Variant in Rust accepts any declared type.
+    let add = TypedMethod::<(Variant, i64), Variant>::new("TypedMethodTarget", "add").unwrap();
+
+    let mut object = TypedMethodTarget::new_gd();
+    let result = add.call(&mut object, (1.to_variant(), 2));
+    assert_eq!(result, 3.to_variant());
+}
+
+#[itest]
+fn typed_method_not_found() {
+    let err = TypedMethod::<(), ()>::new("NonExistentClass", "method").unwrap_err();
+    assert!(err.is_not_found());
+    assert_eq!(err.class_name(), "NonExistentClass");
+
+    let err = TypedMethod::<(), ()>::new("Node", "non_existent_method").unwrap_err();
+    assert!(err.is_not_found());
+    assert_eq!(err.method_name(), "non_existent_method");
+}
+
+#[itest]
+fn typed_method_signature_mismatch() {
+    let err = TypedMethod::<(GString, i64), i64>::new("TypedMethodTarget", "add").unwrap_err();
+    assert!(!err.is_not_found());
+    assert_eq!(
+        err.to_string(),
+        "parameter 0 of method 'TypedMethodTarget::add' has type INT, but signature has STRING"
+    );
+
+    let err = TypedMethod::<(i64,), i64>::new("TypedMethodTarget", "add").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "method 'TypedMethodTarget::add' takes 2 parameters, but signature has 1"
+    );
+
+    let err = TypedMethod::<(i64, i64), GString>::new("TypedMethodTarget", "add").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "method 'TypedMethodTarget::add' returns type INT, but signature has STRING"
+    );
+
+    // `()` only matches methods without return value.
+    let err = TypedMethod::<(i64, i64), ()>::new("TypedMethodTarget", "add").unwrap_err();
+    assert!(!err.is_not_found());
+}
+
+#[itest]
+fn typed_method_default_parameters() {
+    // Node::add_child(node, force_readable_name = false, internal = INTERNAL_MODE_DISABLED)
+    let add_child = TypedMethod::<(Gd<Node>,), ()>::new("Node", "add_child").unwrap();
+
+    let mut parent = Node::new_alloc();
+    add_child.call(&mut parent, (Node::new_alloc(),));
+    assert_eq!(parent.get_child_count(), 1);
+
+    TypedMethod::<(Gd<Node>, bool, i64, i64), ()>::new("Node", "add_child")
+        .expect_err("too many parameters");
+
+    parent.free();
+}
+
+#[cfg(debug_assertions)]
+#[itest]
+fn typed_method_wrong_class() {
+    let add = TypedMethod::<(i64, i64), i64>::new("TypedMethodTarget", "add").unwrap();
+    let mut object = RefCounted::new_gd();
+
+    expect_panic("call on object of unrelated class", || {
+        add.call(&mut object, (1, 2));
+    });
+}
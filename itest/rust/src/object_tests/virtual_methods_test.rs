@@ -14,7 +14,7 @@ use godot::builtin::{
     PackedInt32Array, PackedStringArray, PackedVector2Array, PackedVector3Array, RealConv,
     StringName, Variant, VariantArray, Vector2, Vector3,
 };
-use godot::classes::notify::NodeNotification;
+use godot::classes::notify::{NodeNotification, ObjectNotification};
 use godot::classes::resource_loader::CacheMode;
 #[cfg(feature = "codegen-full")]
 use godot::classes::Material;
@@ -248,6 +248,28 @@ impl INode for NotificationTest {
     }
 }
 
+/// Custom notification, not known to Godot.
+const CUSTOM_NOTIFICATION: i32 = 47_001;
+
+// Used in `test_object_notify_deferred` in `SpecialTests.gd`, which lets a frame pass before checking the notification.
+#[godot_api]
+impl NotificationTest {
+    #[func]
+    fn notify_custom_deferred(&mut self) {
+        self.base_mut()
+            .notify_deferred(NodeNotification::from(CUSTOM_NOTIFICATION));
+    }
+
+    #[func]
+    fn custom_notification_count(&self) -> i64 {
+        let custom = ReceivedEvent::Notification(NodeNotification::Unknown(CUSTOM_NOTIFICATION));
+        self.sequence
+            .iter()
+            .filter(|event| **event == custom)
+            .count() as i64
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[derive(GodotClass)]
@@ -595,6 +617,28 @@ fn test_notifications() {
     obj.free();
 }
 
+#[itest]
+fn test_notifications_custom() {
+    let obj = NotificationTest::new_alloc();
+    let mut object = obj.clone().upcast::<Object>();
+    object.notify(ObjectNotification::from(CUSTOM_NOTIFICATION));
+
+    assert_eq!(
+        obj.bind().sequence,
+        vec![ReceivedEvent::Notification(NodeNotification::Unknown(
+            CUSTOM_NOTIFICATION
+        ))]
+    );
+
+    // Deferred notifications are only sent at the end of the frame.
+    obj.clone()
+        .upcast::<Node>()
+        .notify_deferred(NodeNotification::Unknown(CUSTOM_NOTIFICATION));
+    assert_eq!(obj.bind().sequence.len(), 1);
+
+    obj.free();
+}
+
 #[itest]
 fn test_get_called() {
     let obj = GetTest::new_gd();
@@ -0,0 +1,129 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct IterObj {
+    values: Vec<i64>,
+}
+
+#[godot_api]
+impl IterObj {
+    #[func]
+    fn set_values(&mut self, values: PackedInt64Array) {
+        self.values = values.to_vec();
+    }
+
+    #[iter]
+    fn values(&self) -> impl Iterator<Item = GString> + 'static {
+        self.values
+            .clone()
+            .into_iter()
+            .map(|value| format!("#{value}").into())
+    }
+}
+
+/// Iterates like GDScript's `for` loop, going through the object's `_iter_*` methods.
+fn collect_via_protocol(obj: &Gd<IterObj>) -> Vec<Variant> {
+    let mut obj = obj.clone().upcast::<Object>();
+    let state = varray![Variant::nil()];
+
+    let mut items = vec![];
+    let mut has_item = obj
+        .call("_iter_init".into(), &[state.to_variant()])
+        .to::<bool>();
+    while has_item {
+        let current = state.at(0);
+        items.push(obj.call("_iter_get".into(), &[current]));
+
+        has_item = obj
+            .call("_iter_next".into(), &[state.to_variant()])
+            .to::<bool>();
+    }
+
+    items
+}
+
+#[itest]
+fn iter_protocol_methods_registered() {
+    let obj = IterObj::new_gd();
+
+    assert!(obj.has_method("_iter_init".into()));
+    assert!(obj.has_method("_iter_next".into()));
+    assert!(obj.has_method("_iter_get".into()));
+
+    // The Rust method returning the iterator itself is not exposed.
+    assert!(!obj.has_method("values".into()));
+}
+
+#[itest]
+fn iter_protocol_yields_items() {
+    let mut obj = IterObj::new_gd();
+    obj.bind_mut().values = vec![1, 2, 3];
+
+    let items = collect_via_protocol(&obj);
+    assert_eq!(
+        items,
+        vec!["#1".to_variant(), "#2".to_variant(), "#3".to_variant()]
+    );
+}
+
+#[itest]
+fn iter_protocol_empty() {
+    let obj = IterObj::new_gd();
+
+    let state = varray![Variant::nil()];
+    let has_item = obj
+        .clone()
+        .upcast::<Object>()
+        .call("_iter_init".into(), &[state.to_variant()]);
+
+    assert_eq!(has_item, false.to_variant());
+    assert!(collect_via_protocol(&obj).is_empty());
+}
+
+#[itest]
+fn iter_protocol_releases_finished_state() {
+    let mut obj = IterObj::new_gd();
+    obj.bind_mut().values = vec![7];
+
+    let mut object = obj.clone().upcast::<Object>();
+    let state = varray![Variant::nil()];
+
+    assert!(object
+        .call("_iter_init".into(), &[state.to_variant()])
+        .to::<bool>());
+    assert_eq!(state.at(0).get_type(), VariantType::CALLABLE);
+
+    assert!(!object
+        .call("_iter_next".into(), &[state.to_variant()])
+        .to::<bool>());
+    assert!(state.at(0).is_nil(), "iterator released after last item");
+}
+
+#[itest]
+fn iter_protocol_nested_loops() {
+    let mut obj = IterObj::new_gd();
+    obj.bind_mut().values = vec![1, 2];
+
+    let mut object = obj.clone().upcast::<Object>();
+    let outer_state = varray![Variant::nil()];
+
+    // Each loop gets its own iterator, so a nested loop doesn't advance the outer one.
+    assert!(object
+        .call("_iter_init".into(), &[outer_state.to_variant()])
+        .to::<bool>());
+    let inner = collect_via_protocol(&obj);
+    let outer_current = object.call("_iter_get".into(), &[outer_state.at(0)]);
+
+    assert_eq!(inner, vec!["#1".to_variant(), "#2".to_variant()]);
+    assert_eq!(outer_current, "#1".to_variant());
+}
@@ -10,6 +10,8 @@ mod conversion_test;
 mod derive_godotconvert_test;
 mod func_test;
 mod gdscript_ffi_test;
+#[cfg(since_api = "4.2")]
+mod iter_test;
 mod naming_tests;
 mod option_ffi_test;
 #[cfg(feature = "codegen-full")]